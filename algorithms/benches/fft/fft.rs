@@ -107,6 +107,18 @@ fn bench_coset_ifft_in_place<F: PrimeField>(b: &mut Bencher, degree: &usize) {
     });
 }
 
+fn bench_mixed_radix_fft_in_place<F: PrimeField>(b: &mut Bencher, degree: &usize) {
+    // Benchmark the domain of size 3 * degree, which is not a power of two.
+    let domain = EvaluationDomain::<F>::new_mixed_radix(3 * *degree).unwrap();
+    let mut a = DensePolynomial::<F>::rand(domain.size() - 1, &mut thread_rng())
+        .coeffs()
+        .to_vec();
+
+    b.iter(|| {
+        domain.fft_in_place(&mut a);
+    });
+}
+
 fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     let description = format!("{:?} - subgroup_fft_in_place", name);
     setup_bench(c, &description, bench_fft_in_place::<F>);
@@ -122,5 +134,10 @@ fn bench_bls12_377(c: &mut Criterion) {
     fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
 }
 
-criterion_group!(benches, bench_bls12_377);
+fn bench_bls12_377_mixed_radix(c: &mut Criterion) {
+    let description = format!("{:?} - subgroup_fft_in_place", "BLS12-377 - mixed-radix");
+    setup_bench(c, &description, bench_mixed_radix_fft_in_place::<Bls12_377_Fr>);
+}
+
+criterion_group!(benches, bench_bls12_377, bench_bls12_377_mixed_radix);
 criterion_main!(benches);
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use criterion::{BenchmarkId, Criterion};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use snarkvm_algorithms::msm::*;
+use snarkvm_curves::{
+    bls12_377::{Fr, G1Affine, G1Projective},
+    traits::ProjectiveCurve,
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::rand::UniformRand;
+
+#[macro_use]
+extern crate criterion;
+
+fn precomputed(c: &mut Criterion) {
+    const MAX_SAMPLES: usize = 1 << 16;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..MAX_SAMPLES)
+        .map(|_| Fr::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let g = (0..MAX_SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("MSM Precomputed Bases");
+    for size in (4..=16).step_by(2).map(|log_size| 1 << log_size) {
+        let window_size = PrecomputedBases::<G1Affine>::recommended_window_size(size);
+        let bases = PrecomputedBases::new(&g[..size], window_size);
+
+        group.bench_with_input(BenchmarkId::new("Variable Base", size), &size, |b, &size| {
+            b.iter(|| VariableBaseMSM::multi_scalar_mul(&g[..size], &v[..size]))
+        });
+        group.bench_with_input(BenchmarkId::new("Precomputed", size), &size, |b, &size| {
+            b.iter(|| bases.multi_scalar_mul(&v[..size]))
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = precomputed_group;
+    config = Criterion::default().sample_size(10);
+    targets = precomputed
+}
+
+criterion_main!(precomputed_group);
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use criterion::Criterion;
+use criterion::{BenchmarkId, Criterion};
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use snarkvm_algorithms::msm::*;
@@ -45,10 +45,31 @@ fn variable_base(c: &mut Criterion) {
     });
 }
 
+fn variable_base_sizes(c: &mut Criterion) {
+    const MAX_SAMPLES: usize = 1 << 16;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..MAX_SAMPLES)
+        .map(|_| Fr::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let g = (0..MAX_SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("MSM Variable Base Sizes");
+    for size in (4..=16).step_by(2).map(|log_size| 1 << log_size) {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| VariableBaseMSM::multi_scalar_mul(&g[..size], &v[..size]))
+        });
+    }
+    group.finish();
+}
+
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base
+    targets = variable_base, variable_base_sizes
 }
 
 criterion_main!(variable_base_group);
@@ -0,0 +1,349 @@
+# Computes the known-answer vectors in `algorithms/src/hash_to_curve/tests.rs`.
+#
+# This is an independent implementation of `expand_message_xmd` and `hash_to_field` (RFC 9380, Section 5),
+# the Shallue-van de Woestijne map (Section 6.6.1) for BLS12-377 G1 and G2, and the Elligator 2 map
+# (Section 6.7.1) for the twisted Edwards curves over the BLS12-377 scalar and base fields.
+# The constant `Z` of each map is selected as in Appendix H.1, by trying 1, -1, 2, -2, ... in order.
+#
+# Usage: python3 hash_to_curve_vectors.py
+
+import hashlib
+
+# The BLS12-377 base field, and the quadratic extension Fq2 = Fq[u] / (u^2 + 5).
+FQ = 258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177
+FQ2_NONRESIDUE = FQ - 5
+# The BLS12-377 scalar field.
+FR = 8444461749428370424248824938781546531375899335154063827935233455917409239041
+
+BLS12_377_G1_B = 1
+BLS12_377_G1_COFACTOR = 30631250834960419227450344600217059328
+BLS12_377_G2_B = (0, 155198655607781456406391640216936120121836107652948796323930557600032281009004493664981332883744016074664192874906)
+BLS12_377_G2_COFACTOR = int(
+    "7923214915284317143930293550643874566881017850177945424769256759165301436616933228209277966774092486467289478618404761412630691835764674559376407658497"
+)
+
+# (base field, A, D, cofactor) of the twisted Edwards curves `A * x^2 + y^2 = 1 + D * x^2 * y^2`.
+EDWARDS_BLS12 = (FR, FR - 1, 3021, 4)
+EDWARDS_SW6 = (FQ, FQ - 1, 79743, 8)
+
+SECURITY_PARAMETER = 128
+
+
+def expand_message_xmd(message, dst, length):
+    if len(dst) > 255:
+        dst = hashlib.sha256(b"H2C-OVERSIZE-DST-" + dst).digest()
+    dst_prime = dst + bytes([len(dst)])
+    ell = (length + 31) // 32
+    b_0 = hashlib.sha256(bytes(64) + message + length.to_bytes(2, "big") + b"\x00" + dst_prime).digest()
+    b = [hashlib.sha256(b_0 + b"\x01" + dst_prime).digest()]
+    for i in range(2, ell + 1):
+        b.append(hashlib.sha256(bytes(x ^ y for x, y in zip(b_0, b[-1])) + bytes([i]) + dst_prime).digest())
+    return b"".join(b)[:length]
+
+
+def hash_to_field(message, dst, p, count, degree):
+    size = (p.bit_length() + SECURITY_PARAMETER + 7) // 8
+    uniform_bytes = expand_message_xmd(message, dst, count * degree * size)
+    elements = []
+    for i in range(count):
+        offset = i * degree * size
+        chunks = [uniform_bytes[offset + j * size : offset + (j + 1) * size] for j in range(degree)]
+        coefficients = tuple(int.from_bytes(chunk, "big") % p for chunk in chunks)
+        elements.append(PrimeField(p, coefficients[0]) if degree == 1 else Fq2(*[PrimeField(p, c) for c in coefficients]))
+    return elements
+
+
+class PrimeField:
+    def __init__(self, p, value):
+        self.p = p
+        self.value = value % p
+
+    def new(self, value):
+        return PrimeField(self.p, value)
+
+    def __add__(self, other):
+        return self.new(self.value + other.value)
+
+    def __sub__(self, other):
+        return self.new(self.value - other.value)
+
+    def __mul__(self, other):
+        return self.new(self.value * other.value)
+
+    def __neg__(self):
+        return self.new(-self.value)
+
+    def __eq__(self, other):
+        return self.value == other.value
+
+    def is_zero(self):
+        return self.value == 0
+
+    def inverse0(self):
+        return self.new(pow(self.value, self.p - 2, self.p))
+
+    def from_int(self, value):
+        return self.new(value)
+
+    def is_square(self):
+        return pow(self.value, (self.p - 1) // 2, self.p) != self.p - 1
+
+    def sgn0(self):
+        return self.value % 2 == 1
+
+    def sqrt(self):
+        # Tonelli-Shanks.
+        if self.is_zero():
+            return self
+        assert self.is_square()
+        p = self.p
+        s, q = 0, p - 1
+        while q % 2 == 0:
+            s, q = s + 1, q // 2
+        z = 2
+        while pow(z, (p - 1) // 2, p) != p - 1:
+            z += 1
+        m, c, t, r = s, pow(z, q, p), pow(self.value, q, p), pow(self.value, (q + 1) // 2, p)
+        while t != 1:
+            i, t_i = 0, t
+            while t_i != 1:
+                i, t_i = i + 1, t_i * t_i % p
+            b = pow(c, 1 << (m - i - 1), p)
+            m, c, t, r = i, b * b % p, t * b * b % p, r * b % p
+        return self.new(r)
+
+    def __str__(self):
+        return str(self.value)
+
+
+class Fq2:
+    def __init__(self, c0, c1):
+        self.c0 = c0
+        self.c1 = c1
+
+    def new(self, c0, c1):
+        return Fq2(c0, c1)
+
+    def __add__(self, other):
+        return self.new(self.c0 + other.c0, self.c1 + other.c1)
+
+    def __sub__(self, other):
+        return self.new(self.c0 - other.c0, self.c1 - other.c1)
+
+    def __mul__(self, other):
+        nonresidue = self.c0.new(FQ2_NONRESIDUE)
+        c0 = self.c0 * other.c0 + nonresidue * self.c1 * other.c1
+        c1 = self.c0 * other.c1 + self.c1 * other.c0
+        return self.new(c0, c1)
+
+    def __neg__(self):
+        return self.new(-self.c0, -self.c1)
+
+    def __eq__(self, other):
+        return self.c0 == other.c0 and self.c1 == other.c1
+
+    def is_zero(self):
+        return self.c0.is_zero() and self.c1.is_zero()
+
+    def norm(self):
+        return self.c0 * self.c0 - self.c0.new(FQ2_NONRESIDUE) * self.c1 * self.c1
+
+    def inverse0(self):
+        norm_inverse = self.norm().inverse0()
+        return self.new(self.c0 * norm_inverse, -(self.c1 * norm_inverse))
+
+    def from_int(self, value):
+        return self.new(self.c0.new(value), self.c0.new(0))
+
+    def is_square(self):
+        return self.norm().is_square()
+
+    def sgn0(self):
+        return self.c0.sgn0() or (self.c0.is_zero() and self.c1.sgn0())
+
+    def sqrt(self):
+        if self.c1.is_zero():
+            if self.c0.is_square():
+                return self.new(self.c0.sqrt(), self.c1)
+            # c0 = nonresidue * x^2, so the root is x * u.
+            return self.new(self.c1, (self.c0 * self.c0.new(FQ2_NONRESIDUE).inverse0()).sqrt())
+        alpha = self.norm().sqrt()
+        half = self.c0.new(2).inverse0()
+        delta = (self.c0 + alpha) * half
+        if not delta.is_square():
+            delta = (self.c0 - alpha) * half
+        x0 = delta.sqrt()
+        x1 = self.c1 * (x0 + x0).inverse0()
+        root = self.new(x0, x1)
+        assert root * root == self
+        return root
+
+    def __str__(self):
+        return "({}, {})".format(self.c0, self.c1)
+
+
+def cmov(a, b, condition):
+    return b if condition else a
+
+
+def find_z_svdw(g, a, one):
+    counter = 1
+    while True:
+        for z in [one.from_int(counter), -one.from_int(counter)]:
+            g_z = g(z)
+            if g_z.is_zero():
+                continue
+            h_z = -(one.from_int(3) * z * z + one.from_int(4) * a) * (one.from_int(4) * g_z).inverse0()
+            if h_z.is_zero() or not h_z.is_square():
+                continue
+            if g_z.is_square() or g(-z * one.from_int(2).inverse0()).is_square():
+                return z
+        counter += 1
+
+
+def find_z_elligator2(one):
+    counter = 1
+    while True:
+        for z in [one.from_int(counter), -one.from_int(counter)]:
+            if not z.is_square():
+                return z
+        counter += 1
+
+
+def map_to_curve_svdw(u, a, b):
+    one = u.from_int(1)
+    g = lambda x: (x * x + a) * x + b
+    z = find_z_svdw(g, a, one)
+    denominator = one.from_int(3) * z * z + one.from_int(4) * a
+    c1 = g(z)
+    c2 = -z * one.from_int(2).inverse0()
+    c3 = (-(c1 * denominator)).sqrt()
+    c3 = cmov(c3, -c3, c3.sgn0())
+    c4 = -(one.from_int(4) * c1) * denominator.inverse0()
+
+    tv1 = u * u * c1
+    tv2 = one + tv1
+    tv1 = one - tv1
+    tv3 = (tv1 * tv2).inverse0()
+    tv4 = u * tv1 * tv3 * c3
+    x1 = c2 - tv4
+    e1 = g(x1).is_square()
+    x2 = c2 + tv4
+    e2 = g(x2).is_square() and not e1
+    x3 = (tv2 * tv2 * tv3) * (tv2 * tv2 * tv3) * c4 + z
+    x = cmov(x3, x1, e1)
+    x = cmov(x, x2, e2)
+    y = g(x).sqrt()
+    y = cmov(-y, y, u.sgn0() == y.sgn0())
+    return (x, y)
+
+
+def map_to_curve_elligator2(u, a, d):
+    one = u.from_int(1)
+    j = (a + d + a + d) * (a - d).inverse0()
+    k = one.from_int(4) * (a - d).inverse0()
+    z = find_z_elligator2(one)
+    c1 = j * k.inverse0()
+    c2 = (k * k).inverse0()
+
+    tv1 = z * u * u
+    e1 = tv1 == -one
+    tv1 = cmov(tv1, one.from_int(0), e1)
+    x1 = -c1 * (tv1 + one).inverse0()
+    gx1 = ((x1 + c1) * x1 + c2) * x1
+    x2 = -x1 - c1
+    gx2 = tv1 * gx1
+    e2 = gx1.is_square()
+    x = cmov(x2, x1, e2)
+    y2 = cmov(gx2, gx1, e2)
+    y = y2.sqrt()
+    y = cmov(y, -y, e2 != y.sgn0())
+    s, t = x * k, y * k
+
+    tv1 = s + one
+    tv2 = (tv1 * t).inverse0()
+    v = tv2 * tv1 * s
+    w = tv2 * t * (s - one)
+    w = cmov(w, one, tv2.is_zero())
+    return (v, w)
+
+
+def sw_add(p, q):
+    # Affine addition on `y^2 = x^3 + b`, with `None` as the point at infinity.
+    if p is None:
+        return q
+    if q is None:
+        return p
+    (x1, y1), (x2, y2) = p, q
+    if x1 == x2:
+        if (y1 + y2).is_zero():
+            return None
+        slope = x1.from_int(3) * x1 * x1 * (y1 + y1).inverse0()
+    else:
+        slope = (y2 - y1) * (x2 - x1).inverse0()
+    x3 = slope * slope - x1 - x2
+    return (x3, slope * (x1 - x3) - y1)
+
+
+def te_add(a, d):
+    def add(p, q):
+        (x1, y1), (x2, y2) = p, q
+        one = x1.from_int(1)
+        dxy = d * x1 * x2 * y1 * y2
+        return ((x1 * y2 + y1 * x2) * (one + dxy).inverse0(), (y1 * y2 - a * x1 * x2) * (one - dxy).inverse0())
+
+    return add
+
+
+def mul(add, zero, point, scalar):
+    result = zero
+    for bit in bin(scalar)[2:]:
+        result = add(result, result)
+        if bit == "1":
+            result = add(result, point)
+    return result
+
+
+def hash_to_sw(message, dst, degree, b, cofactor):
+    u = hash_to_field(message, dst, FQ, 2, degree)
+    a = u[0].from_int(0)
+    b = u[0].from_int(b) if degree == 1 else Fq2(PrimeField(FQ, b[0]), PrimeField(FQ, b[1]))
+    q = sw_add(map_to_curve_svdw(u[0], a, b), map_to_curve_svdw(u[1], a, b))
+    return mul(sw_add, None, q, cofactor)
+
+
+def hash_to_edwards(message, dst, curve):
+    p, a, d, cofactor = curve
+    u = hash_to_field(message, dst, p, 2, 1)
+    a, d = u[0].from_int(a), u[0].from_int(d)
+    add = te_add(a, d)
+    q = add(map_to_curve_elligator2(u[0], a, d), map_to_curve_elligator2(u[1], a, d))
+    return mul(add, (a.from_int(0), a.from_int(1)), q, cofactor)
+
+
+def print_point(name, message, point):
+    print("{}({!r}):\n    x = {}\n    y = {}".format(name, message, point[0], point[1]))
+
+
+if __name__ == "__main__":
+    for message in [b"", b"abc"]:
+        print_point(
+            "BLS12-377 G1",
+            message,
+            hash_to_sw(message, b"QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SVDW_RO_", 1, BLS12_377_G1_B, BLS12_377_G1_COFACTOR),
+        )
+        print_point(
+            "BLS12-377 G2",
+            message,
+            hash_to_sw(message, b"QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SVDW_RO_", 2, BLS12_377_G2_B, BLS12_377_G2_COFACTOR),
+        )
+        print_point(
+            "Edwards BLS12",
+            message,
+            hash_to_edwards(message, b"QUUX-V01-CS02-with-EDBLS12_XMD:SHA-256_ELL2_RO_", EDWARDS_BLS12),
+        )
+        print_point(
+            "Edwards SW6",
+            message,
+            hash_to_edwards(message, b"QUUX-V01-CS02-with-EDSW6_XMD:SHA-256_ELL2_RO_", EDWARDS_SW6),
+        )
@@ -214,7 +214,7 @@ mod tests {
                 .unwrap();
         assert_eq!(
             &*output.to_string(),
-            "GroupAffine(x=232405123812771034726439972860096518067116445442313271493943612938654881935, y=752634260468672343124870935373206613671657768711738358314821821547485346646)"
+            "GroupAffine(x=1458830605996255967666145170206084970380287513737423487919697505288312101007, y=4724361822497728774087744092818831022870480949262013688485525440243906394966)"
         );
     }
 }
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use std::{
+    fmt,
+    io::{Read, Result as IoResult, Write},
+};
+
+/// The 32-byte output of a byte-oriented hash function, such as SHA-256 or Keccak-256.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByteDigest(pub [u8; 32]);
+
+impl ToBytes for ByteDigest {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl FromBytes for ByteDigest {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self(FromBytes::read(reader)?))
+    }
+}
+
+impl fmt::Display for ByteDigest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{crh::ByteDigest, errors::CRHError, traits::CRH};
+
+use rand::Rng;
+use sha3::{Digest, Keccak256, Sha3_256};
+
+/// Returns the Keccak-256 digest of `data`, as used by Ethereum.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let digest = Keccak256::digest(data);
+    let mut ret = [0u8; 32];
+    ret.copy_from_slice(&digest);
+
+    ret
+}
+
+/// Returns the SHA3-256 digest of `data`, as standardized in FIPS 202.
+pub fn sha3_256(data: &[u8]) -> [u8; 32] {
+    let digest = Sha3_256::digest(data);
+    let mut ret = [0u8; 32];
+    ret.copy_from_slice(&digest);
+
+    ret
+}
+
+/// The output of the Keccak-256 CRH.
+pub type Keccak256Digest = ByteDigest;
+
+/// Keccak-256 as a collision-resistant hash function.
+///
+/// The input size is set to two digests, matching the inner nodes of a binary Merkle tree.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Keccak256CRH;
+
+impl CRH for Keccak256CRH {
+    type Output = Keccak256Digest;
+    type Parameters = ();
+
+    const INPUT_SIZE_BITS: usize = 512;
+
+    fn setup<R: Rng>(_: &mut R) -> Self {
+        Self
+    }
+
+    fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
+        Ok(ByteDigest(keccak256(input)))
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &()
+    }
+}
+
+impl From<()> for Keccak256CRH {
+    fn from(_: ()) -> Self {
+        Self
+    }
+}
@@ -23,6 +23,12 @@ pub use bowe_hopwood_pedersen_compressed::*;
 pub mod bowe_hopwood_pedersen_parameters;
 pub use bowe_hopwood_pedersen_parameters::*;
 
+pub mod byte_digest;
+pub use byte_digest::*;
+
+pub mod keccak;
+pub use keccak::*;
+
 pub mod pedersen;
 pub use pedersen::*;
 
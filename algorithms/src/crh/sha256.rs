@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{crh::ByteDigest, errors::CRHError, traits::CRH};
+
+use rand::Rng;
 use sha2::{Digest, Sha256};
 
 pub fn sha256(data: &[u8]) -> [u8; 32] {
@@ -38,3 +41,38 @@ pub fn sha256d_to_u64(data: &[u8]) -> u64 {
     hash[..].copy_from_slice(&hash_slice[..8]);
     u64::from_le_bytes(hash)
 }
+
+/// The output of the SHA-256 CRH.
+pub type Sha256Digest = ByteDigest;
+
+/// SHA-256 as a collision-resistant hash function.
+///
+/// The input size is set to two digests, so that Merkle trees over SHA-256
+/// hash their inner nodes in a single compression.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Sha256CRH;
+
+impl CRH for Sha256CRH {
+    type Output = Sha256Digest;
+    type Parameters = ();
+
+    const INPUT_SIZE_BITS: usize = 512;
+
+    fn setup<R: Rng>(_: &mut R) -> Self {
+        Self
+    }
+
+    fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
+        Ok(ByteDigest(sha256(input)))
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &()
+    }
+}
+
+impl From<()> for Sha256CRH {
+    fn from(_: ()) -> Self {
+        Self
+    }
+}
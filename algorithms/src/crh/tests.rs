@@ -15,7 +15,17 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    crh::{BoweHopwoodPedersenCRH, BoweHopwoodPedersenCompressedCRH, PedersenCRH, PedersenCompressedCRH},
+    crh::{
+        keccak256,
+        sha256,
+        sha3_256,
+        BoweHopwoodPedersenCRH,
+        BoweHopwoodPedersenCompressedCRH,
+        Keccak256CRH,
+        PedersenCRH,
+        PedersenCompressedCRH,
+        Sha256CRH,
+    },
     traits::CRH,
 };
 use snarkvm_curves::edwards_bls12::EdwardsProjective;
@@ -77,3 +87,44 @@ fn simple_bowe_hopwood_crh() {
 
     BoweHopwoodCRH::hash(&parameters, &[1, 2, 3]).unwrap();
 }
+
+#[test]
+fn sha256_crh() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let crh = Sha256CRH::setup(rng);
+    let output = crh.hash(b"abc").unwrap();
+
+    assert_eq!(output.0, sha256(b"abc"));
+    assert_eq!(
+        output.to_string(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+
+    let output_bytes = to_bytes![output].unwrap();
+    assert_eq!(output, FromBytes::read(&output_bytes[..]).unwrap());
+}
+
+#[test]
+fn keccak256_crh() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let crh = Keccak256CRH::setup(rng);
+    let output = crh.hash(b"").unwrap();
+
+    assert_eq!(output.0, keccak256(b""));
+    assert_eq!(
+        output.to_string(),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+    assert_eq!(
+        sha3_256(b"")
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>(),
+        "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+    );
+
+    let output_bytes = to_bytes![output].unwrap();
+    assert_eq!(output, FromBytes::read(&output_bytes[..]).unwrap());
+}
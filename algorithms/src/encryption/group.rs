@@ -238,15 +238,36 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
 
         let record_view_key = c_0.mul(*private_key);
 
+        self.decrypt_with_record_view_key(&record_view_key, ciphertext)
+    }
+
+    fn parameters(&self) -> &<Self as EncryptionScheme>::Parameters {
+        &self.parameters
+    }
+
+    fn private_key_size_in_bits() -> usize {
+        <Self as EncryptionScheme>::PrivateKey::size_in_bits()
+    }
+}
+
+impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeserialize, D: Digest + Send + Sync>
+    GroupEncryption<G, SG, D>
+{
+    /// Decrypts the ciphertext given its record view key `c_0 · sk`.
+    pub(crate) fn decrypt_with_record_view_key(
+        &self,
+        record_view_key: &G,
+        ciphertext: &[G],
+    ) -> Result<Vec<G>, EncryptionError> {
         let affine = record_view_key.into_affine();
         debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
         let z_bytes = to_bytes![affine.to_x_coordinate()]?;
 
-        let z = Self::Randomness::read(&z_bytes[..])?;
+        let z = <G as Group>::ScalarField::read(&z_bytes[..])?;
 
-        let one = Self::Randomness::one();
+        let one = <G as Group>::ScalarField::one();
         let mut plaintext = Vec::with_capacity(ciphertext.len().saturating_sub(1));
-        let mut i = Self::Randomness::one();
+        let mut i = <G as Group>::ScalarField::one();
 
         for c_i in ciphertext.iter().skip(1) {
             // h_i <- 1 [/] (z [+] i) * record_view_key
@@ -264,14 +285,6 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
 
         Ok(plaintext)
     }
-
-    fn parameters(&self) -> &<Self as EncryptionScheme>::Parameters {
-        &self.parameters
-    }
-
-    fn private_key_size_in_bits() -> usize {
-        <Self as EncryptionScheme>::PrivateKey::size_in_bits()
-    }
 }
 
 impl<G: Group + ProjectiveCurve, SG: Group, D: Digest> From<GroupEncryptionParameters<G>>
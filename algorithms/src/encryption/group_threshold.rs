@@ -0,0 +1,481 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    encryption::{GroupEncryption, GroupEncryptionPublicKey},
+    errors::EncryptionError,
+    secret_sharing::{evaluate_commitments, evaluate_polynomial, hash_to_scalar, lagrange_coefficient},
+    signature::SchnorrOutput,
+    traits::{EncryptionScheme, SignatureScheme},
+};
+use snarkvm_curves::traits::{Group, ProjectiveCurve};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{rand::UniformRand, serialize::*, to_bytes, ToBytes};
+
+use digest::Digest;
+use rand::Rng;
+use std::{collections::HashSet, hash::Hash};
+
+const GROUP_ENCRYPTION_POSSESSION_DOMAIN: &[u8] = b"SNARKVM_GROUP_ENCRYPTION_POSSESSION";
+const GROUP_ENCRYPTION_DLEQ_DOMAIN: &[u8] = b"SNARKVM_GROUP_ENCRYPTION_DLEQ";
+
+/// Ensures `0 < threshold <= num_participants`.
+fn check_threshold(threshold: u16, num_participants: u16) -> Result<(), EncryptionError> {
+    if threshold == 0 || threshold > num_participants {
+        return Err(EncryptionError::Message(format!(
+            "invalid threshold {} for {} participants",
+            threshold, num_participants
+        )));
+    }
+    Ok(())
+}
+
+/// Ensures `0 < index <= num_participants`.
+fn check_index(index: u16, num_participants: u16) -> Result<(), EncryptionError> {
+    if index == 0 || index > num_participants {
+        return Err(EncryptionError::Message(format!(
+            "invalid participant index {} for {} participants",
+            index, num_participants
+        )));
+    }
+    Ok(())
+}
+
+/// The secret polynomial of a participant, kept between the rounds of distributed key generation.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G: Group"), Debug(bound = "G: Group"))]
+pub struct GroupEncryptionDKGSecret<G: Group> {
+    index: u16,
+    threshold: u16,
+    num_participants: u16,
+    coefficients: Vec<<G as Group>::ScalarField>,
+}
+
+/// The commitments each participant broadcasts in the first round of distributed key generation.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G: Group, SG: Group"), Debug(bound = "G: Group, SG: Group"))]
+pub struct GroupEncryptionDKGCommitment<G: Group, SG: Group> {
+    pub index: u16,
+    /// The commitments `a_k · g` to the coefficients of the participant's secret polynomial.
+    pub commitments: Vec<G>,
+    /// A signature under `a_0`, proving knowledge of the participant's secret.
+    pub proof_of_possession: SchnorrOutput<SG>,
+}
+
+/// The secret share a participant privately sends to another participant in the second round
+/// of distributed key generation.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G: Group"), Debug(bound = "G: Group"))]
+pub struct GroupEncryptionDKGShare<G: Group> {
+    pub sender: u16,
+    pub receiver: u16,
+    pub share: <G as Group>::ScalarField,
+}
+
+/// A participant's share of a group encryption private key.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "G: Group + ProjectiveCurve + CanonicalSerialize + CanonicalDeserialize"),
+    Debug(bound = "G: Group + ProjectiveCurve + CanonicalSerialize + CanonicalDeserialize"),
+    PartialEq(bound = "G: Group + ProjectiveCurve + CanonicalSerialize + CanonicalDeserialize"),
+    Eq(bound = "G: Group + ProjectiveCurve + CanonicalSerialize + CanonicalDeserialize")
+)]
+pub struct GroupEncryptionKeyShare<G: Group + ProjectiveCurve + CanonicalSerialize + CanonicalDeserialize> {
+    pub index: u16,
+    pub threshold: u16,
+    pub private_key_share: <G as Group>::ScalarField,
+    /// The verification keys `x_j · g` of all participants, ordered by index.
+    pub verification_keys: Vec<G>,
+    /// The public key of the group, as used by `GroupEncryption::encrypt`.
+    pub public_key: GroupEncryptionPublicKey<G>,
+}
+
+/// A participant's share `c_0 · x_i` of the record view key of a ciphertext, with a proof that
+/// it uses the same `x_i` as the participant's verification key `x_i · g`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G: Group"), Debug(bound = "G: Group"))]
+pub struct GroupEncryptionDecryptionShare<G: Group> {
+    pub index: u16,
+    pub threshold: u16,
+    pub share: G,
+    /// The challenge of the discrete log equality proof.
+    pub challenge: <G as Group>::ScalarField,
+    /// The response of the discrete log equality proof.
+    pub response: <G as Group>::ScalarField,
+}
+
+impl<
+        G: Group + ProjectiveCurve + CanonicalSerialize + CanonicalDeserialize,
+        SG: Group + Hash + CanonicalSerialize + CanonicalDeserialize,
+        D: Digest + Send + Sync,
+    > GroupEncryption<G, SG, D>
+where
+    <G as Group>::ScalarField: PrimeField,
+{
+    /// Starts distributed key generation for the participant `index`, out of `num_participants`
+    /// with a decryption threshold of `threshold`.
+    ///
+    /// Returns the secret state of the participant, and the commitment to broadcast to all participants.
+    pub fn dkg_commit<R: Rng>(
+        &self,
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(GroupEncryptionDKGSecret<G>, GroupEncryptionDKGCommitment<G, SG>), EncryptionError> {
+        check_threshold(threshold, num_participants)?;
+        check_index(index, num_participants)?;
+
+        let coefficients = (0..threshold)
+            .map(|_| <G as Group>::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let commitments = self.commit_coefficients(&coefficients)?;
+        let proof_of_possession = <Self as SignatureScheme>::sign(
+            self,
+            &coefficients[0],
+            &Self::possession_message(index, &commitments[0])?,
+            rng,
+        )?;
+
+        let secret = GroupEncryptionDKGSecret {
+            index,
+            threshold,
+            num_participants,
+            coefficients,
+        };
+        let commitment = GroupEncryptionDKGCommitment {
+            index,
+            commitments,
+            proof_of_possession,
+        };
+        Ok((secret, commitment))
+    }
+
+    /// Verifies the commitments of all participants, and returns the secret shares
+    /// to send privately to each of the other participants.
+    pub fn dkg_distribute(
+        &self,
+        secret: &GroupEncryptionDKGSecret<G>,
+        commitments: &[GroupEncryptionDKGCommitment<G, SG>],
+    ) -> Result<Vec<GroupEncryptionDKGShare<G>>, EncryptionError> {
+        self.verify_dkg_commitments(secret, commitments)?;
+
+        Ok((1..=secret.num_participants)
+            .filter(|&receiver| receiver != secret.index)
+            .map(|receiver| GroupEncryptionDKGShare {
+                sender: secret.index,
+                receiver,
+                share: evaluate_polynomial(&secret.coefficients, receiver),
+            })
+            .collect())
+    }
+
+    /// Verifies the secret shares received from all other participants against their commitments,
+    /// and returns the participant's share of the group private key.
+    pub fn dkg_finalize(
+        &self,
+        secret: &GroupEncryptionDKGSecret<G>,
+        commitments: &[GroupEncryptionDKGCommitment<G, SG>],
+        shares: &[GroupEncryptionDKGShare<G>],
+    ) -> Result<GroupEncryptionKeyShare<G>, EncryptionError> {
+        self.verify_dkg_commitments(secret, commitments)?;
+
+        let mut senders = HashSet::with_capacity(shares.len());
+        let mut private_key_share = evaluate_polynomial(&secret.coefficients, secret.index);
+        for share in shares {
+            if share.receiver != secret.index || share.sender == secret.index || !senders.insert(share.sender) {
+                return Err(EncryptionError::Message(format!(
+                    "unexpected share from participant {} to participant {}",
+                    share.sender, share.receiver
+                )));
+            }
+
+            let sender_commitments = commitments
+                .iter()
+                .find(|commitment| commitment.index == share.sender)
+                .ok_or_else(|| EncryptionError::Message(format!("unknown participant {}", share.sender)))?;
+            if <Self as EncryptionScheme>::generate_public_key(self, &share.share)?.0
+                != evaluate_commitments(&sender_commitments.commitments, secret.index)
+            {
+                return Err(EncryptionError::Message(format!(
+                    "invalid share from participant {}",
+                    share.sender
+                )));
+            }
+
+            private_key_share += &share.share;
+        }
+
+        if senders.len() + 1 != secret.num_participants as usize {
+            return Err(EncryptionError::Message(format!(
+                "expected {} shares, found {}",
+                secret.num_participants - 1,
+                senders.len()
+            )));
+        }
+
+        // The group commits to the sum of all secret polynomials.
+        let mut group_commitments = vec![G::zero(); secret.threshold as usize];
+        for commitment in commitments {
+            for (group_commitment, coefficient_commitment) in group_commitments.iter_mut().zip(&commitment.commitments)
+            {
+                *group_commitment += coefficient_commitment;
+            }
+        }
+
+        self.key_share(
+            secret.index,
+            secret.threshold,
+            secret.num_participants,
+            private_key_share,
+            &group_commitments,
+        )
+    }
+
+    /// Splits the private key into `num_participants` shares, any `threshold` of which can decrypt.
+    ///
+    /// Unlike distributed key generation, this requires a trusted dealer who knows the private key,
+    /// such as the holder of an existing account.
+    pub fn split_private_key<R: Rng>(
+        &self,
+        private_key: &<G as Group>::ScalarField,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<Vec<GroupEncryptionKeyShare<G>>, EncryptionError> {
+        check_threshold(threshold, num_participants)?;
+
+        let mut coefficients = vec![*private_key];
+        coefficients.extend((1..threshold).map(|_| <G as Group>::ScalarField::rand(rng)));
+        let commitments = self.commit_coefficients(&coefficients)?;
+
+        (1..=num_participants)
+            .map(|index| {
+                let private_key_share = evaluate_polynomial(&coefficients, index);
+                self.key_share(index, threshold, num_participants, private_key_share, &commitments)
+            })
+            .collect()
+    }
+
+    /// Returns the participant's share of the record view key of the ciphertext,
+    /// with a proof that it was computed with the participant's key share.
+    pub fn partial_decrypt<R: Rng>(
+        &self,
+        key_share: &GroupEncryptionKeyShare<G>,
+        ciphertext: &[G],
+        rng: &mut R,
+    ) -> Result<GroupEncryptionDecryptionShare<G>, EncryptionError> {
+        let c_0 = ciphertext
+            .first()
+            .ok_or_else(|| EncryptionError::Message("empty ciphertext".into()))?;
+        let verification_key = key_share
+            .verification_keys
+            .get((key_share.index as usize).wrapping_sub(1))
+            .ok_or_else(|| {
+                EncryptionError::Message(format!("missing verification key of participant {}", key_share.index))
+            })?;
+        let share = c_0.mul(key_share.private_key_share);
+
+        // Prove log_g(Y_i) = log_{c_0}(S_i) with the nonce commitments k·g and k·c_0.
+        let nonce = <G as Group>::ScalarField::rand(rng);
+        let nonce_commitments = [
+            <Self as EncryptionScheme>::generate_public_key(self, &nonce)?.0,
+            c_0.mul(nonce),
+        ];
+        let challenge = self.dleq_challenge(key_share.index, verification_key, c_0, &share, &nonce_commitments)?;
+        let response = nonce - (challenge * key_share.private_key_share);
+
+        Ok(GroupEncryptionDecryptionShare {
+            index: key_share.index,
+            threshold: key_share.threshold,
+            share,
+            challenge,
+            response,
+        })
+    }
+
+    /// Returns `true` if `share` is the decryption share of the ciphertext under the verification key
+    /// of its participant.
+    pub fn verify_decryption_share(
+        &self,
+        verification_keys: &[G],
+        ciphertext: &[G],
+        share: &GroupEncryptionDecryptionShare<G>,
+    ) -> Result<bool, EncryptionError> {
+        let c_0 = match ciphertext.first() {
+            Some(c_0) => c_0,
+            None => return Ok(false),
+        };
+        let verification_key = match verification_keys.get((share.index as usize).wrapping_sub(1)) {
+            Some(verification_key) => verification_key,
+            None => return Ok(false),
+        };
+
+        // z·g + e·Y_i = k·g and z·c_0 + e·S_i = k·c_0
+        let nonce_commitments = [
+            <Self as EncryptionScheme>::generate_public_key(self, &share.response)?.0
+                + (*verification_key * share.challenge),
+            (*c_0 * share.response) + (share.share * share.challenge),
+        ];
+        let challenge = self.dleq_challenge(share.index, verification_key, c_0, &share.share, &nonce_commitments)?;
+        Ok(challenge == share.challenge)
+    }
+
+    /// Verifies the decryption shares of at least `threshold` participants, and decrypts the ciphertext.
+    ///
+    /// The private key is never reconstructed; only the record view key of this ciphertext is.
+    pub fn combine_decryption_shares(
+        &self,
+        verification_keys: &[G],
+        shares: &[GroupEncryptionDecryptionShare<G>],
+        ciphertext: &[G],
+    ) -> Result<Vec<G>, EncryptionError> {
+        let threshold = shares.first().map_or(0, |share| share.threshold);
+        let mut indices = HashSet::with_capacity(shares.len());
+        if ciphertext.is_empty()
+            || shares.is_empty()
+            || shares.len() < threshold as usize
+            || !shares
+                .iter()
+                .all(|share| share.index != 0 && share.threshold == threshold && indices.insert(share.index))
+        {
+            return Err(EncryptionError::Message("invalid set of decryption shares".into()));
+        }
+
+        let indices = shares.iter().map(|share| share.index).collect::<Vec<_>>();
+        let mut record_view_key = G::zero();
+        for share in shares {
+            if !self.verify_decryption_share(verification_keys, ciphertext, share)? {
+                return Err(EncryptionError::Message(format!(
+                    "invalid decryption share from participant {}",
+                    share.index
+                )));
+            }
+
+            let lagrange: <G as Group>::ScalarField = lagrange_coefficient(share.index, &indices)
+                .ok_or_else(|| EncryptionError::Message("duplicate participant indices".into()))?;
+            record_view_key += share.share.mul(lagrange);
+        }
+
+        self.decrypt_with_record_view_key(&record_view_key, ciphertext)
+    }
+
+    /// Returns the commitments `a_k · g` to the given coefficients.
+    fn commit_coefficients(&self, coefficients: &[<G as Group>::ScalarField]) -> Result<Vec<G>, EncryptionError> {
+        coefficients
+            .iter()
+            .map(|coefficient| Ok(<Self as EncryptionScheme>::generate_public_key(self, coefficient)?.0))
+            .collect()
+    }
+
+    /// Returns the key share of the participant `index`, given the commitments to the group polynomial.
+    fn key_share(
+        &self,
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        private_key_share: <G as Group>::ScalarField,
+        group_commitments: &[G],
+    ) -> Result<GroupEncryptionKeyShare<G>, EncryptionError> {
+        let verification_keys = (1..=num_participants)
+            .map(|j| evaluate_commitments(group_commitments, j))
+            .collect::<Vec<_>>();
+
+        if <Self as EncryptionScheme>::generate_public_key(self, &private_key_share)?.0
+            != verification_keys[index as usize - 1]
+        {
+            return Err(EncryptionError::Message(format!(
+                "inconsistent key share for participant {}",
+                index
+            )));
+        }
+
+        Ok(GroupEncryptionKeyShare {
+            index,
+            threshold,
+            private_key_share,
+            verification_keys,
+            public_key: GroupEncryptionPublicKey(group_commitments[0]),
+        })
+    }
+
+    /// Ensures there is exactly one well-formed commitment from each participant.
+    fn verify_dkg_commitments(
+        &self,
+        secret: &GroupEncryptionDKGSecret<G>,
+        commitments: &[GroupEncryptionDKGCommitment<G, SG>],
+    ) -> Result<(), EncryptionError> {
+        if commitments.len() != secret.num_participants as usize {
+            return Err(EncryptionError::Message(format!(
+                "expected {} commitments, found {}",
+                secret.num_participants,
+                commitments.len()
+            )));
+        }
+
+        let mut indices = HashSet::with_capacity(commitments.len());
+        for commitment in commitments {
+            check_index(commitment.index, secret.num_participants)?;
+            if !indices.insert(commitment.index) || commitment.commitments.len() != secret.threshold as usize {
+                return Err(EncryptionError::Message(format!(
+                    "malformed commitment from participant {}",
+                    commitment.index
+                )));
+            }
+
+            let public_key = GroupEncryptionPublicKey(commitment.commitments[0]);
+            let message = Self::possession_message(commitment.index, &commitment.commitments[0])?;
+            if !<Self as SignatureScheme>::verify(self, &public_key, &message, &commitment.proof_of_possession)? {
+                return Err(EncryptionError::Message(format!(
+                    "invalid proof of possession from participant {}",
+                    commitment.index
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the message signed by a participant to prove knowledge of its secret.
+    fn possession_message(index: u16, commitment: &G) -> Result<Vec<u8>, EncryptionError> {
+        let mut message = GROUP_ENCRYPTION_POSSESSION_DOMAIN.to_vec();
+        message.extend_from_slice(&index.to_le_bytes());
+        message.extend_from_slice(&to_bytes![commitment.into_affine()]?);
+        Ok(message)
+    }
+
+    /// Returns the challenge `e := H(salt || i || Y_i || c_0 || S_i || k·g || k·c_0)` of the
+    /// discrete log equality proof of a decryption share, over the affine encodings of the points.
+    fn dleq_challenge(
+        &self,
+        index: u16,
+        verification_key: &G,
+        c_0: &G,
+        share: &G,
+        nonce_commitments: &[G; 2],
+    ) -> Result<<G as Group>::ScalarField, EncryptionError> {
+        let mut hash_input = GROUP_ENCRYPTION_DLEQ_DOMAIN.to_vec();
+        hash_input.extend_from_slice(&index.to_le_bytes());
+        hash_input.extend_from_slice(&to_bytes![
+            verification_key.into_affine(),
+            c_0.into_affine(),
+            share.into_affine(),
+            nonce_commitments[0].into_affine(),
+            nonce_commitments[1].into_affine()
+        ]?);
+        Ok(hash_to_scalar::<_, D>(&self.parameters.salt, &hash_input))
+    }
+}
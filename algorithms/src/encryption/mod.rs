@@ -17,6 +17,9 @@
 pub mod group;
 pub use group::*;
 
+pub mod group_threshold;
+pub use group_threshold::*;
+
 pub mod group_parameters;
 pub use group_parameters::*;
 
@@ -68,3 +68,114 @@ fn encryption_public_key_serialization() {
         assert_eq!(public_key, recovered_public_key);
     }
 }
+
+#[test]
+fn threshold_decryption() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let encryption_scheme = TestEncryptionScheme::setup(rng);
+
+    let private_key = encryption_scheme.generate_private_key(rng);
+    let public_key = encryption_scheme.generate_public_key(&private_key).unwrap();
+
+    let randomness = encryption_scheme.generate_randomness(&public_key, rng).unwrap();
+    let message = generate_input(32, rng);
+    let ciphertext = encryption_scheme.encrypt(&public_key, &randomness, &message).unwrap();
+
+    let key_shares = encryption_scheme.split_private_key(&private_key, 3, 5, rng).unwrap();
+    let verification_keys = &key_shares[0].verification_keys;
+    assert_eq!(public_key, key_shares[0].public_key);
+
+    let decryption_shares = key_shares
+        .iter()
+        .map(|key_share| encryption_scheme.partial_decrypt(key_share, &ciphertext, rng).unwrap())
+        .collect::<Vec<_>>();
+
+    // Any subset of at least `threshold` participants can decrypt.
+    for subset in &[vec![0, 1, 2], vec![4, 2, 0], vec![0, 1, 2, 3, 4]] {
+        let shares = subset.iter().map(|&i| decryption_shares[i].clone()).collect::<Vec<_>>();
+        let decrypted_message = encryption_scheme
+            .combine_decryption_shares(verification_keys, &shares, &ciphertext)
+            .unwrap();
+        assert_eq!(message, decrypted_message);
+    }
+
+    // Fewer than `threshold` participants cannot.
+    assert!(encryption_scheme
+        .combine_decryption_shares(verification_keys, &decryption_shares[..2], &ciphertext)
+        .is_err());
+
+    // A participant cannot submit a share that was not computed with its key share.
+    let mut invalid_share = decryption_shares[1].clone();
+    invalid_share.share += EdwardsProjective::prime_subgroup_generator();
+    assert!(!encryption_scheme
+        .verify_decryption_share(verification_keys, &ciphertext, &invalid_share)
+        .unwrap());
+    assert!(encryption_scheme
+        .combine_decryption_shares(
+            verification_keys,
+            &[
+                decryption_shares[0].clone(),
+                invalid_share,
+                decryption_shares[2].clone()
+            ],
+            &ciphertext
+        )
+        .is_err());
+}
+
+#[test]
+fn threshold_decryption_dkg() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let encryption_scheme = TestEncryptionScheme::setup(rng);
+    let (threshold, num_participants) = (2, 3);
+
+    let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+        .map(|index| {
+            encryption_scheme
+                .dkg_commit(index, threshold, num_participants, rng)
+                .unwrap()
+        })
+        .unzip();
+    let shares = secrets
+        .iter()
+        .flat_map(|secret| encryption_scheme.dkg_distribute(secret, &commitments).unwrap())
+        .collect::<Vec<_>>();
+    let key_shares = secrets
+        .iter()
+        .zip(1..=num_participants)
+        .map(|(secret, index)| {
+            let received = shares
+                .iter()
+                .filter(|share| share.receiver == index)
+                .cloned()
+                .collect::<Vec<_>>();
+            encryption_scheme.dkg_finalize(secret, &commitments, &received).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // All participants agree on the public key, which no participant knows the private key of.
+    let public_key = key_shares[0].public_key;
+    assert!(key_shares.iter().all(|key_share| key_share.public_key == public_key));
+
+    let randomness = encryption_scheme.generate_randomness(&public_key, rng).unwrap();
+    let message = generate_input(32, rng);
+    let ciphertext = encryption_scheme.encrypt(&public_key, &randomness, &message).unwrap();
+
+    let decryption_shares = [&key_shares[0], &key_shares[2]]
+        .iter()
+        .map(|key_share| encryption_scheme.partial_decrypt(key_share, &ciphertext, rng).unwrap())
+        .collect::<Vec<_>>();
+    let decrypted_message = encryption_scheme
+        .combine_decryption_shares(&key_shares[0].verification_keys, &decryption_shares, &ciphertext)
+        .unwrap();
+    assert_eq!(message, decrypted_message);
+
+    // A participant whose proof of possession does not match its commitment is rejected.
+    let mut invalid_commitments = commitments.clone();
+    invalid_commitments[1].commitments[0] = invalid_commitments[2].commitments[0];
+    assert!(encryption_scheme
+        .dkg_distribute(&secrets[0], &invalid_commitments)
+        .is_err());
+}
@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    envelope::{ENVELOPE_MAGIC, ENVELOPE_VERSION},
+    errors::EnvelopeError,
+};
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use std::io::{self, Read, Result as IoResult, Write};
+
+macro_rules! envelope_identifier {
+    ($(#[$meta: meta])* $name: ident, $description: expr, { $($(#[$variant_meta: meta])* $variant: ident = $id: expr),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant = $id,)*
+        }
+
+        impl $name {
+            /// Returns the identifier written to the envelope header.
+            pub fn id(&self) -> u8 {
+                *self as u8
+            }
+
+            /// Returns the variant with the given envelope header identifier.
+            pub fn from_id(id: u8) -> Result<Self, EnvelopeError> {
+                match id {
+                    $($id => Ok($name::$variant),)*
+                    _ => Err(EnvelopeError::UnknownIdentifier($description, id)),
+                }
+            }
+        }
+    };
+}
+
+envelope_identifier!(
+    /// The kind of object stored in an envelope.
+    EnvelopeKind, "kind", {
+        ProvingKey = 0,
+        VerifyingKey = 1,
+        Proof = 2,
+        UniversalSRS = 3,
+    }
+);
+
+envelope_identifier!(
+    /// The proof system or commitment scheme an object belongs to.
+    EnvelopeScheme, "scheme", {
+        Groth16 = 0,
+        Marlin = 1,
+        KZG10 = 2,
+    }
+);
+
+envelope_identifier!(
+    /// The proving mode an object was generated for.
+    EnvelopeMode, "mode", {
+        /// The scheme does not distinguish between modes.
+        None = 0,
+        /// The object does not support recursive proofs.
+        NonRecursive = 1,
+        /// The object supports recursive proofs of at least depth-1.
+        Recursive = 2,
+    }
+);
+
+/// The header of an envelope, which precedes the serialized object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvelopeHeader {
+    /// The envelope format version.
+    pub version: u16,
+    /// The kind of the enclosed object.
+    pub kind: EnvelopeKind,
+    /// The scheme of the enclosed object.
+    pub scheme: EnvelopeScheme,
+    /// The mode of the enclosed object.
+    pub mode: EnvelopeMode,
+    /// The fingerprint of the scalar field of the curve.
+    pub curve: [u8; 8],
+    /// The digest of the circuit verifying key, or zero if the object is not circuit-specific.
+    pub circuit_id: [u8; 32],
+    /// The scheme-specific sizes of the enclosed object.
+    pub sizes: Vec<u64>,
+    /// The number of bytes in the payload.
+    pub payload_size: u64,
+    /// The SHA-256 digest of the payload.
+    pub checksum: [u8; 32],
+}
+
+impl EnvelopeHeader {
+    /// Returns an error if the enclosed object was not generated for the given mode.
+    pub fn ensure_mode(&self, mode: EnvelopeMode) -> Result<(), EnvelopeError> {
+        match self.mode == mode {
+            true => Ok(()),
+            false => Err(EnvelopeError::ModeMismatch(mode, self.mode)),
+        }
+    }
+
+    /// Returns an error if the enclosed object does not belong to the given circuit.
+    pub fn ensure_circuit_id(&self, circuit_id: &[u8; 32]) -> Result<(), EnvelopeError> {
+        match &self.circuit_id == circuit_id {
+            true => Ok(()),
+            false => Err(EnvelopeError::CircuitMismatch(hex(circuit_id), hex(&self.circuit_id))),
+        }
+    }
+
+    /// Reads the header fields that follow the envelope magic bytes.
+    pub(crate) fn read_after_magic<R: Read>(mut reader: R) -> Result<Self, EnvelopeError> {
+        let version: u16 = FromBytes::read(&mut reader)?;
+        if version > ENVELOPE_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(version, ENVELOPE_VERSION));
+        }
+
+        let kind = EnvelopeKind::from_id(FromBytes::read(&mut reader)?)?;
+        let scheme = EnvelopeScheme::from_id(FromBytes::read(&mut reader)?)?;
+        let mode = EnvelopeMode::from_id(FromBytes::read(&mut reader)?)?;
+        let curve: [u8; 8] = FromBytes::read(&mut reader)?;
+        let circuit_id: [u8; 32] = FromBytes::read(&mut reader)?;
+
+        let num_sizes: u8 = FromBytes::read(&mut reader)?;
+        let mut sizes = Vec::with_capacity(num_sizes as usize);
+        for _ in 0..num_sizes {
+            sizes.push(FromBytes::read(&mut reader)?);
+        }
+
+        let payload_size: u64 = FromBytes::read(&mut reader)?;
+        let checksum: [u8; 32] = FromBytes::read(&mut reader)?;
+
+        Ok(Self {
+            version,
+            kind,
+            scheme,
+            mode,
+            curve,
+            circuit_id,
+            sizes,
+            payload_size,
+            checksum,
+        })
+    }
+}
+
+impl ToBytes for EnvelopeHeader {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        if self.sizes.len() > u8::MAX as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "too many envelope sizes"));
+        }
+
+        writer.write_all(&ENVELOPE_MAGIC)?;
+        self.version.write(&mut writer)?;
+        self.kind.id().write(&mut writer)?;
+        self.scheme.id().write(&mut writer)?;
+        self.mode.id().write(&mut writer)?;
+        self.curve.write(&mut writer)?;
+        self.circuit_id.write(&mut writer)?;
+        (self.sizes.len() as u8).write(&mut writer)?;
+        for size in &self.sizes {
+            size.write(&mut writer)?;
+        }
+        self.payload_size.write(&mut writer)?;
+        self.checksum.write(&mut writer)
+    }
+}
+
+impl FromBytes for EnvelopeHeader {
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != ENVELOPE_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "missing envelope magic bytes",
+            ));
+        }
+
+        Ok(Self::read_after_magic(reader)?)
+    }
+}
+
+/// Returns the lowercase hexadecimal encoding of the given bytes.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
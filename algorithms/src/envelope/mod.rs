@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A self-describing envelope for SNARK keys, proofs and reference strings.
+//!
+//! An envelope prefixes the canonical serialization of an object with a header
+//! recording the scheme, curve, mode, sizes and circuit of the object, along
+//! with a checksum of the payload. Readers fall back to the raw serialization
+//! when the envelope magic bytes are absent, so existing files remain loadable.
+
+mod header;
+pub use header::*;
+
+#[cfg(test)]
+mod tests;
+
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_utilities::bytes::ToBytes;
+
+use sha2::{Digest, Sha256};
+use std::io::{Read, Result as IoResult, Write};
+
+/// The magic bytes that open every envelope.
+pub const ENVELOPE_MAGIC: [u8; 4] = *b"SVMK";
+
+/// The latest envelope version.
+pub const ENVELOPE_VERSION: u16 = 1;
+
+/// Returns the SHA-256 digest of the given bytes.
+pub fn envelope_digest(bytes: &[u8]) -> [u8; 32] {
+    let mut digest = [0u8; 32];
+    digest.copy_from_slice(&Sha256::digest(bytes));
+    digest
+}
+
+/// Returns a fingerprint of the modulus of the given scalar field,
+/// which identifies the curve an object is defined over.
+pub fn scalar_field_fingerprint<F: PrimeField>() -> [u8; 8] {
+    let mut modulus = vec![];
+    F::Parameters::MODULUS
+        .write(&mut modulus)
+        .expect("failed to serialize the field modulus");
+
+    let mut fingerprint = [0u8; 8];
+    fingerprint.copy_from_slice(&envelope_digest(&modulus)[..8]);
+    fingerprint
+}
+
+/// A writer that computes the size and SHA-256 digest of the bytes written through it.
+pub(crate) struct DigestWriter<W: Write> {
+    inner: W,
+    hasher: Sha256,
+    size: u64,
+}
+
+impl<W: Write> DigestWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+            size: 0,
+        }
+    }
+
+    /// Returns the number of bytes written and their digest.
+    pub(crate) fn finalize(self) -> (u64, [u8; 32]) {
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&self.hasher.finalize());
+        (self.size, digest)
+    }
+}
+
+impl<W: Write> Write for DigestWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let num_written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..num_written]);
+        self.size += num_written as u64;
+        Ok(num_written)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
+
+/// A reader that computes the size and SHA-256 digest of the bytes read through it.
+pub(crate) struct DigestReader<R: Read> {
+    inner: R,
+    hasher: Sha256,
+    size: u64,
+}
+
+impl<R: Read> DigestReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+            size: 0,
+        }
+    }
+
+    /// Reads the remaining bytes, and returns the number of bytes read and their digest.
+    pub(crate) fn finalize(mut self) -> IoResult<(u64, [u8; 32])> {
+        std::io::copy(&mut self, &mut std::io::sink())?;
+
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&self.hasher.finalize());
+        Ok((self.size, digest))
+    }
+}
+
+impl<R: Read> Read for DigestReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let num_read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..num_read]);
+        self.size += num_read as u64;
+        Ok(num_read)
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    envelope::{EnvelopeHeader, EnvelopeKind, EnvelopeMode, EnvelopeScheme, ENVELOPE_VERSION},
+    errors::EnvelopeError,
+};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    to_bytes,
+};
+
+fn sample_header() -> EnvelopeHeader {
+    EnvelopeHeader {
+        version: ENVELOPE_VERSION,
+        kind: EnvelopeKind::VerifyingKey,
+        scheme: EnvelopeScheme::Marlin,
+        mode: EnvelopeMode::Recursive,
+        curve: [1u8; 8],
+        circuit_id: [2u8; 32],
+        sizes: vec![3, 4, 5],
+        payload_size: 6,
+        checksum: [7u8; 32],
+    }
+}
+
+#[test]
+fn test_header_serialization() {
+    let header = sample_header();
+    let bytes = to_bytes![header].unwrap();
+    assert_eq!(&bytes[..4], b"SVMK");
+
+    let recovered = EnvelopeHeader::read(&bytes[..]).unwrap();
+    assert_eq!(recovered, header);
+}
+
+#[test]
+fn test_header_rejects_unknown_version() {
+    let mut header = sample_header();
+    header.version = ENVELOPE_VERSION + 1;
+    let bytes = to_bytes![header].unwrap();
+
+    assert!(matches!(
+        EnvelopeHeader::read_after_magic(&bytes[4..]),
+        Err(EnvelopeError::UnsupportedVersion(..))
+    ));
+}
+
+#[test]
+fn test_header_checks() {
+    let header = sample_header();
+
+    assert!(header.ensure_mode(EnvelopeMode::Recursive).is_ok());
+    assert!(matches!(
+        header.ensure_mode(EnvelopeMode::NonRecursive),
+        Err(EnvelopeError::ModeMismatch(
+            EnvelopeMode::NonRecursive,
+            EnvelopeMode::Recursive
+        ))
+    ));
+
+    assert!(header.ensure_circuit_id(&[2u8; 32]).is_ok());
+    assert!(matches!(
+        header.ensure_circuit_id(&[0u8; 32]),
+        Err(EnvelopeError::CircuitMismatch(..))
+    ));
+}
+
+#[test]
+fn test_identifiers() {
+    for kind in [
+        EnvelopeKind::ProvingKey,
+        EnvelopeKind::VerifyingKey,
+        EnvelopeKind::Proof,
+        EnvelopeKind::UniversalSRS,
+    ] {
+        assert_eq!(EnvelopeKind::from_id(kind.id()).unwrap(), kind);
+    }
+    assert!(matches!(
+        EnvelopeScheme::from_id(255),
+        Err(EnvelopeError::UnknownIdentifier("scheme", 255))
+    ));
+}
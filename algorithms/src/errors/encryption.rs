@@ -24,6 +24,9 @@ pub enum EncryptionError {
 
     #[error("{}", _0)]
     Message(String),
+
+    #[error("{}", _0)]
+    SignatureError(Box<crate::SignatureError>),
 }
 
 impl From<std::io::Error> for EncryptionError {
@@ -32,6 +35,12 @@ impl From<std::io::Error> for EncryptionError {
     }
 }
 
+impl From<crate::SignatureError> for EncryptionError {
+    fn from(error: crate::SignatureError) -> Self {
+        EncryptionError::SignatureError(Box::new(error))
+    }
+}
+
 impl From<EncryptionError> for std::io::Error {
     fn from(error: EncryptionError) -> Self {
         std::io::Error::new(std::io::ErrorKind::Other, format!("{}", error))
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::io::{Error, ErrorKind};
+
+#[derive(Debug, Error)]
+pub enum EnvelopeError {
+    #[error("expected payload checksum {}, found checksum {}", _0, _1)]
+    ChecksumMismatch(String, String),
+
+    #[error("expected circuit {}, found circuit {}", _0, _1)]
+    CircuitMismatch(String, String),
+
+    #[error("{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[error("expected curve with scalar field fingerprint {}, found fingerprint {}", _0, _1)]
+    CurveMismatch(String, String),
+
+    #[error("expected {:?}, found {:?}", _0, _1)]
+    KindMismatch(crate::envelope::EnvelopeKind, crate::envelope::EnvelopeKind),
+
+    #[error("{}", _0)]
+    Message(String),
+
+    #[error("expected {:?} mode, found {:?} mode", _0, _1)]
+    ModeMismatch(crate::envelope::EnvelopeMode, crate::envelope::EnvelopeMode),
+
+    #[error("expected the {:?} scheme, found the {:?} scheme", _0, _1)]
+    SchemeMismatch(crate::envelope::EnvelopeScheme, crate::envelope::EnvelopeScheme),
+
+    #[error("expected sizes {:?}, found sizes {:?}", _0, _1)]
+    SizeMismatch(Vec<u64>, Vec<u64>),
+
+    #[error("unknown {} identifier {}", _0, _1)]
+    UnknownIdentifier(&'static str, u8),
+
+    #[error("unsupported envelope version {}, the latest supported version is {}", _0, _1)]
+    UnsupportedVersion(u16, u16),
+}
+
+impl From<Error> for EnvelopeError {
+    fn from(error: Error) -> Self {
+        EnvelopeError::Crate("std::io", format!("{:?}", error))
+    }
+}
+
+impl From<EnvelopeError> for Error {
+    fn from(error: EnvelopeError) -> Error {
+        Error::new(ErrorKind::Other, error.to_string())
+    }
+}
@@ -26,6 +26,9 @@ pub use encoding::*;
 pub mod encryption;
 pub use encryption::*;
 
+pub mod envelope;
+pub use envelope::*;
+
 pub mod merkle;
 pub use merkle::*;
 
@@ -25,9 +25,14 @@
 //! roots to be the powers of a 2^n root of unity in the field.
 //! This allows us to perform polynomial operations in O(n)
 //! by performing an O(n log n) FFT over such a domain.
+//!
+//! For fields that define a small multiplicative subgroup of order
+//! `SMALL_SUBGROUP_BASE^SMALL_SUBGROUP_BASE_ADICITY`, domains of size
+//! 2^i * q^j are also supported, which allows domains beyond the
+//! two-adicity of the field.
 
 use crate::fft::{DomainCoeff, SparsePolynomial};
-use snarkvm_fields::{batch_inversion, FftField, FftParameters};
+use snarkvm_fields::{batch_inversion_and_mul, FftField, FftParameters};
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
 
 use rand::Rng;
@@ -41,6 +46,10 @@ use rayon::prelude::*;
 #[cfg(feature = "parallel")]
 const LOG_ROOTS_OF_UNITY_PARALLEL_SIZE: usize = 7;
 
+/// Defines the log2 of the domain size above which the six-step FFT is used.
+#[cfg(feature = "parallel")]
+const LOG_SIX_STEP_FFT_MIN_SIZE: u32 = 20;
+
 /// Returns the log2 value of the given number.
 #[cfg(feature = "parallel")]
 fn log2(number: usize) -> usize {
@@ -48,13 +57,13 @@ fn log2(number: usize) -> usize {
 }
 
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
-/// only for fields that have a large multiplicative subgroup of size that is
-/// a power-of-2.
+/// for fields that have a large multiplicative subgroup of size that is
+/// a power-of-2, or of size 2^i * q^j if the field defines a small subgroup base `q`.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
-    /// `log_2(self.size)`.
+    /// The 2-adicity of `self.size`, which is `log_2(self.size)` for radix-2 domains.
     pub log_size_of_group: u32,
     /// Size of the domain as a field element.
     pub size_as_field_element: F,
@@ -86,21 +95,30 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Construct a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
+    ///
+    /// A radix-2 domain is returned whenever the field supports one of the required size.
+    /// Otherwise, a mixed-radix domain is returned if the field defines a small subgroup base.
     pub fn new(num_coeffs: usize) -> Option<Self> {
-        // Compute the size of our evaluation domain
-        let size = num_coeffs.next_power_of_two() as u64;
-        let log_size_of_group = size.trailing_zeros();
+        Self::from_size(Self::compute_size_of_domain(num_coeffs)? as u64)
+    }
 
-        // libfqfft uses > https://github.com/scipr-lab/libfqfft/blob/e0183b2cef7d4c5deb21a6eaf3fe3b586d738fe0/libfqfft/evaluation_domain/domains/basic_radix2_domain.tcc#L33
-        if log_size_of_group > F::FftParameters::TWO_ADICITY {
-            return None;
-        }
+    /// Construct the smallest mixed-radix domain, of size 2^i * q^j where `q` is the
+    /// small subgroup base of the field, that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    ///
+    /// Returns `None` if the field does not define a small subgroup base.
+    pub fn new_mixed_radix(num_coeffs: usize) -> Option<Self> {
+        Self::from_size(Self::compute_mixed_radix_size_of_domain(num_coeffs)? as u64)
+    }
+
+    fn from_size(size: u64) -> Option<Self> {
+        let log_size_of_group = size.trailing_zeros();
 
         // Compute the generator for the multiplicative subgroup.
-        // It should be the 2^(log_size_of_group) root of unity.
+        // It should be the root of unity of order `size`.
         let group_gen = F::get_root_of_unity(size as usize)?;
 
-        // Check that it is indeed the 2^(log_size_of_group) root of unity.
+        // Check that it is indeed the root of unity of order `size`.
         debug_assert_eq!(group_gen.pow([size]), F::one());
 
         let size_as_field_element = F::from(size);
@@ -120,14 +138,46 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Return the size of a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
-        let size = num_coeffs.next_power_of_two();
+        let size = num_coeffs.checked_next_power_of_two()?;
+        // libfqfft uses > https://github.com/scipr-lab/libfqfft/blob/e0183b2cef7d4c5deb21a6eaf3fe3b586d738fe0/libfqfft/evaluation_domain/domains/basic_radix2_domain.tcc#L33
         if size.trailing_zeros() <= F::FftParameters::TWO_ADICITY {
             Some(size)
         } else {
-            None
+            Self::compute_mixed_radix_size_of_domain(num_coeffs)
         }
     }
 
+    /// Return the size of the smallest mixed-radix domain, of size 2^i * q^j, that is large enough
+    /// for evaluations of a polynomial having `num_coeffs` coefficients.
+    pub fn compute_mixed_radix_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let q = F::FftParameters::SMALL_SUBGROUP_BASE? as usize;
+        let q_adicity = F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY?;
+        let num_coeffs = num_coeffs.max(1);
+
+        let mut best: Option<usize> = None;
+        let mut q_part = 1usize;
+        for _ in 0..=q_adicity {
+            // Compute the smallest 2^i such that 2^i * q^j >= num_coeffs.
+            if let Some(two_part) = num_coeffs.div_ceil(q_part).checked_next_power_of_two() {
+                if two_part.trailing_zeros() <= F::FftParameters::TWO_ADICITY {
+                    if let Some(size) = two_part.checked_mul(q_part) {
+                        best = Some(best.map_or(size, |best| best.min(size)));
+                    }
+                }
+            }
+            q_part = match q_part.checked_mul(q) {
+                Some(q_part) => q_part,
+                None => break,
+            };
+        }
+        best
+    }
+
+    /// Returns `true` if the size of `self` is a power of two.
+    pub fn is_radix2(&self) -> bool {
+        self.size.is_power_of_two()
+    }
+
     /// Return the size of `self`.
     pub fn size(&self) -> usize {
         self.size as usize
@@ -215,20 +265,12 @@ impl<F: FftField> EvaluationDomain<F> {
             }
             u
         } else {
-            let mut l = (t_size - one) * self.size_inv;
-            let mut r = one;
-            let mut u = vec![F::zero(); size];
-            let mut ls = vec![F::zero(); size];
-            for i in 0..size {
-                u[i] = tau - r;
-                ls[i] = l;
-                l *= &self.group_gen;
-                r *= &self.group_gen;
-            }
-
-            batch_inversion(u.as_mut_slice());
-            cfg_iter_mut!(u).zip(ls).for_each(|(tau_minus_r, l)| {
-                *tau_minus_r = l * *tau_minus_r;
+            // L_i(tau) = (tau^n - 1) / n * g^i / (tau - g^i)
+            let elements: Vec<F> = self.elements().collect();
+            let mut u: Vec<F> = cfg_iter!(elements).map(|r| tau - r).collect();
+            batch_inversion_and_mul(&mut u, &((t_size - one) * self.size_inv));
+            cfg_iter_mut!(u).zip(elements).for_each(|(tau_minus_r, r)| {
+                *tau_minus_r *= r;
             });
             u
         }
@@ -320,8 +362,8 @@ impl<F: FftField> EvaluationDomain<F> {
         // TODO: check if this method can replace parallel compute powers.
         let log_size = log2(self.size as usize);
 
-        // Early exit for short inputs.
-        if log_size <= LOG_ROOTS_OF_UNITY_PARALLEL_SIZE {
+        // Early exit for short inputs and mixed-radix domains.
+        if log_size <= LOG_ROOTS_OF_UNITY_PARALLEL_SIZE || !self.is_radix2() {
             Self::compute_powers_serial((self.size as usize) / 2, root)
         } else {
             let mut tmp = root;
@@ -391,15 +433,27 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 }
 
-#[allow(unused_variables)]
 #[cfg(not(feature = "parallel"))]
-fn best_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, log_n: u32) {
-    serial_radix2_fft(a, omega, log_n);
+fn best_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, two_adicity: u32) {
+    serial_fft(a, omega, two_adicity);
 }
 
 #[cfg(feature = "parallel")]
-fn best_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, log_n: u32) {
+fn best_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, two_adicity: u32) {
     let num_cpus = rayon::current_num_threads();
+
+    // The six-step FFT only pays for its transposes when the sub-FFTs run in parallel.
+    if num_cpus > 1 && a.len() > 1 << LOG_SIX_STEP_FFT_MIN_SIZE {
+        six_step_fft(a, omega, two_adicity);
+        return;
+    }
+
+    if !a.len().is_power_of_two() {
+        serial_mixed_radix_fft(a, omega, two_adicity);
+        return;
+    }
+
+    let log_n = two_adicity;
     let log_cpus = log2_floor(num_cpus);
 
     if log_n <= log_cpus {
@@ -409,18 +463,27 @@ fn best_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, log_n: u32) {
     }
 }
 
-#[allow(clippy::many_single_char_names)]
-pub(crate) fn serial_radix2_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, log_n: u32) {
-    #[inline]
-    fn bitreverse(mut n: u32, l: u32) -> u32 {
-        let mut r = 0;
-        for _ in 0..l {
-            r = (r << 1) | (n & 1);
-            n >>= 1;
-        }
-        r
+/// Computes a serial FFT, using the radix-2 FFT if the size of `a` is a power of two,
+/// and the mixed-radix FFT otherwise.
+pub(crate) fn serial_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, two_adicity: u32) {
+    if a.len().is_power_of_two() {
+        serial_radix2_fft(a, omega, two_adicity);
+    } else {
+        serial_mixed_radix_fft(a, omega, two_adicity);
     }
+}
+
+#[inline]
+fn bitreverse(mut n: u32, l: u32) -> u32 {
+    let mut r = 0;
+    for _ in 0..l {
+        r = (r << 1) | (n & 1);
+        n >>= 1;
+    }
+    r
+}
 
+pub(crate) fn serial_radix2_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, log_n: u32) {
     let n = a.len() as u32;
     assert_eq!(n, 1 << log_n);
 
@@ -431,20 +494,27 @@ pub(crate) fn serial_radix2_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], ome
         }
     }
 
-    let mut m = 1;
-    for _ in 0..log_n {
-        let w_m = omega.pow(&[(n / (2 * m)) as u64]);
+    serial_radix2_butterflies(a, omega, 1);
+}
+
+/// Performs the radix-2 butterfly passes of an FFT, starting from sub-FFTs of size `m`,
+/// until a single FFT of size `a.len()` remains.
+#[allow(clippy::many_single_char_names)]
+fn serial_radix2_butterflies<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, mut m: usize) {
+    let n = a.len();
+    while m < n {
+        let w_m = omega.pow([(n / (2 * m)) as u64]);
 
         let mut k = 0;
         while k < n {
             let mut w = F::one();
             for j in 0..m {
-                let mut t = a[(k + j + m) as usize];
+                let mut t = a[k + j + m];
                 t *= w;
-                let mut tmp = a[(k + j) as usize];
+                let mut tmp = a[k + j];
                 tmp -= t;
-                a[(k + j + m) as usize] = tmp;
-                a[(k + j) as usize] += t;
+                a[k + j + m] = tmp;
+                a[k + j] += t;
                 w.mul_assign(&w_m);
             }
 
@@ -455,6 +525,170 @@ pub(crate) fn serial_radix2_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], ome
     }
 }
 
+/// Computes an FFT over a domain of size `2^two_adicity * q^k`, where `q` is the
+/// small subgroup base of the field. The radix-`q` passes are performed first,
+/// followed by the radix-2 passes.
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, two_adicity: u32) {
+    let n = a.len();
+    let q = F::FftParameters::SMALL_SUBGROUP_BASE.expect("mixed-radix FFTs require a small subgroup base") as usize;
+    let q_adicity = F::k_adicity(q, n);
+    let q_part = q.pow(q_adicity);
+    assert_eq!(n, q_part << two_adicity);
+
+    // Move each element to its digit-reversed position. Unlike the radix-2 case,
+    // this permutation is not an involution, so each cycle is followed in turn.
+    let mut seen = vec![false; n];
+    for k in 0..n {
+        let mut i = k;
+        let mut value = a[k];
+        while !seen[i] {
+            let dest = mixed_radix_permute(two_adicity, q_adicity, q, n, i);
+            std::mem::swap(&mut a[dest], &mut value);
+            seen[i] = true;
+            i = dest;
+        }
+    }
+
+    // Precompute the q-th roots of unity.
+    let omega_q = omega.pow([(n / q) as u64]);
+    let mut qth_roots = Vec::with_capacity(q);
+    qth_roots.push(F::one());
+    for i in 1..q {
+        qth_roots.push(qth_roots[i - 1] * omega_q);
+    }
+
+    // Perform the radix-q passes.
+    let mut terms = vec![T::zero(); q];
+    let mut m = 1;
+    for _ in 0..q_adicity {
+        let w_m = omega.pow([(n / (q * m)) as u64]);
+
+        let mut k = 0;
+        while k < n {
+            let mut w_j = F::one();
+            for j in 0..m {
+                // terms[s] = w_j^s * a[k + j + s * m]
+                let mut w_j_s = F::one();
+                for (s, term) in terms.iter_mut().enumerate() {
+                    *term = a[k + j + s * m];
+                    *term *= w_j_s;
+                    w_j_s *= w_j;
+                }
+
+                // a[k + j + i * m] = sum_s omega_q^(i * s) * terms[s]
+                for i in 0..q {
+                    let mut sum = terms[0];
+                    for (s, term) in terms.iter().enumerate().skip(1) {
+                        let mut t = *term;
+                        t *= qth_roots[(i * s) % q];
+                        sum += t;
+                    }
+                    a[k + j + i * m] = sum;
+                }
+
+                w_j *= w_m;
+            }
+
+            k += q * m;
+        }
+
+        m *= q;
+    }
+
+    // Perform the radix-2 passes.
+    serial_radix2_butterflies(a, omega, m);
+}
+
+/// Returns the position of the `i`-th element after the digit-reversal permutation of a
+/// mixed-radix FFT that performs `q_adicity` radix-`q` passes followed by `two_adicity` radix-2 passes.
+fn mixed_radix_permute(two_adicity: u32, q_adicity: u32, q: usize, n: usize, mut i: usize) -> usize {
+    // Write i = b_0 + 2 b_1 + ... + 2^(two_adicity - 1) b_(two_adicity - 1)
+    //         + 2^two_adicity (x_0 + q x_1 + ... + q^(q_adicity - 1) x_(q_adicity - 1)).
+    // The result is b_0 (n / 2) + ... + b_(two_adicity - 1) (n / 2^two_adicity)
+    //         + x_0 (n / (2^two_adicity q)) + ... + x_(q_adicity - 1) (n / (2^two_adicity q^q_adicity)).
+    let mut result = 0;
+    let mut shift = n;
+    for _ in 0..two_adicity {
+        shift /= 2;
+        result += (i % 2) * shift;
+        i /= 2;
+    }
+    for _ in 0..q_adicity {
+        shift /= q;
+        result += (i % q) * shift;
+        i /= q;
+    }
+    result
+}
+
+/// Computes an FFT of size `n = n_1 * n_2` using the six-step algorithm.
+///
+/// The input is viewed as an `n_2 x n_1` matrix, which is transposed, followed by `n_1` FFTs of size `n_2`
+/// on the rows, a multiplication by the twiddle factors, a second transpose, `n_2` FFTs of size `n_1`
+/// on the rows, and a final transpose. Each sub-FFT fits in cache and is computed independently,
+/// which makes this well-suited for large domains and for parallelism.
+#[cfg(feature = "parallel")]
+pub(crate) fn six_step_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, two_adicity: u32) {
+    let n = a.len();
+    let log_n1 = two_adicity / 2;
+    let n1 = 1 << log_n1;
+    let n2 = n / n1;
+
+    let omega_n1 = omega.pow([n2 as u64]);
+    let omega_n2 = omega.pow([n1 as u64]);
+
+    // Step 1: Transpose the n_2 x n_1 input into an n_1 x n_2 matrix.
+    let mut scratch = vec![T::zero(); n];
+    transpose(a, &mut scratch, n2, n1);
+
+    // Steps 2 and 3: Compute the FFT of each row, and multiply the entry (i, j) by omega^(i * j).
+    scratch.par_chunks_mut(n2).enumerate().for_each(|(i, row)| {
+        serial_fft(row, omega_n2, two_adicity - log_n1);
+
+        let omega_i = omega.pow([i as u64]);
+        let mut twiddle = F::one();
+        for value in row.iter_mut() {
+            *value *= twiddle;
+            twiddle *= omega_i;
+        }
+    });
+
+    // Step 4: Transpose the n_1 x n_2 matrix into an n_2 x n_1 matrix.
+    transpose(&scratch, a, n1, n2);
+
+    // Step 5: Compute the FFT of each row.
+    a.par_chunks_mut(n1)
+        .for_each(|row| serial_radix2_fft(row, omega_n1, log_n1));
+
+    // Step 6: Transpose the n_2 x n_1 matrix into the output order.
+    transpose(a, &mut scratch, n2, n1);
+    a.copy_from_slice(&scratch);
+}
+
+/// Writes the transpose of the `rows x cols` matrix `src` into `dst`, one tile at a time.
+#[cfg(feature = "parallel")]
+fn transpose<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], rows: usize, cols: usize) {
+    const TILE_SIZE: usize = 16;
+    assert_eq!(src.len(), rows * cols);
+    assert_eq!(dst.len(), rows * cols);
+
+    dst.par_chunks_mut(rows * TILE_SIZE)
+        .enumerate()
+        .for_each(|(tile, dst)| {
+            let col_start = tile * TILE_SIZE;
+            let col_end = std::cmp::min(col_start + TILE_SIZE, cols);
+            for row_start in (0..rows).step_by(TILE_SIZE) {
+                let row_end = std::cmp::min(row_start + TILE_SIZE, rows);
+                for col in col_start..col_end {
+                    for row in row_start..row_end {
+                        dst[(col - col_start) * rows + row] = src[row * cols + col];
+                    }
+                }
+            }
+        });
+}
+
 #[cfg(feature = "parallel")]
 pub(crate) fn parallel_radix2_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], omega: F, log_n: u32, log_cpus: u32) {
     assert!(log_n >= log_cpus);
@@ -24,7 +24,13 @@ pub mod evaluations;
 pub use evaluations::Evaluations;
 
 pub mod polynomial;
-pub use polynomial::{DenseOrSparsePolynomial, DensePolynomial, SparsePolynomial};
+pub use polynomial::{
+    barycentric_lagrange_coefficients,
+    DenseOrSparsePolynomial,
+    DensePolynomial,
+    SparsePolynomial,
+    SubproductTree,
+};
 
 #[cfg(test)]
 mod tests;
@@ -29,6 +29,9 @@ use std::{
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// Defines the degree below which polynomial division falls back to schoolbook division.
+const FAST_DIVISION_THRESHOLD: usize = 1024;
+
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
 pub struct DensePolynomial<F: Field> {
@@ -161,6 +164,85 @@ impl<F: PrimeField> DensePolynomial<F> {
     }
 }
 
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Divide `self` by `divisor` in O(M(n)) time, where M(n) is the cost of an FFT-based
+    /// multiplication, by computing the inverse of the reversed divisor with Newton iteration.
+    /// Small divisions fall back to schoolbook division.
+    /// Returns the quotient and remainder of the division.
+    pub fn fast_divide_with_q_and_r(&self, divisor: &Self) -> Option<(DensePolynomial<F>, DensePolynomial<F>)> {
+        if self.is_zero() {
+            return Some((DensePolynomial::zero(), DensePolynomial::zero()));
+        } else if divisor.is_zero() {
+            panic!("Dividing by zero polynomial")
+        } else if self.degree() < divisor.degree() {
+            return Some((DensePolynomial::zero(), self.clone()));
+        }
+
+        let quotient_len = self.degree() - divisor.degree() + 1;
+        if divisor.degree() < FAST_DIVISION_THRESHOLD || quotient_len < FAST_DIVISION_THRESHOLD {
+            return DenseOrSparsePolynomial::from(self).divide_with_q_and_r(&divisor.into());
+        }
+
+        // Let rev_k(a) denote the polynomial with the first k coefficients of a in reverse order.
+        // Then rev(q) = rev(self) * rev(divisor)^{-1} mod x^{quotient_len}.
+        let reversed_divisor = Self::from_coefficients_vec(divisor.coeffs.iter().rev().copied().collect());
+        let reversed_divisor_inverse = reversed_divisor.inverse_mod_x_power(quotient_len)?;
+        let reversed_self = Self::from_coefficients_vec(self.coeffs.iter().rev().take(quotient_len).copied().collect());
+
+        let mut quotient = (&reversed_self * &reversed_divisor_inverse).coeffs;
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+        let quotient = Self::from_coefficients_vec(quotient);
+
+        // The subtraction does not strip the zero leading coefficients of the remainder.
+        let remainder = Self::from_coefficients_vec((self - &(divisor * &quotient)).coeffs);
+        Some((quotient, remainder))
+    }
+
+    /// Returns the polynomial `g` such that `self * g = 1 mod x^k`, using Newton iteration.
+    /// Returns `None` if the constant term of `self` is zero.
+    fn inverse_mod_x_power(&self, k: usize) -> Option<DensePolynomial<F>> {
+        let mut inverse = Self::from_coefficients_vec(vec![self.coeffs.first()?.inverse()?]);
+        let mut precision = 1;
+        while precision < k {
+            precision = std::cmp::min(2 * precision, k);
+
+            // inverse = inverse * (2 - self * inverse) mod x^precision
+            let truncated_self =
+                Self::from_coefficients_slice(&self.coeffs[..std::cmp::min(precision, self.coeffs.len())]);
+            let mut error = (&truncated_self * &inverse).coeffs;
+            error.truncate(precision);
+            let mut correction = -Self::from_coefficients_vec(error);
+            correction += &Self::from_coefficients_vec(vec![F::one().double()]);
+
+            let mut next = (&inverse * &correction).coeffs;
+            next.truncate(precision);
+            inverse = Self::from_coefficients_vec(next);
+        }
+        Some(inverse)
+    }
+
+    /// Returns the monic greatest common divisor of `self` and `other`,
+    /// or the zero polynomial if both are zero.
+    pub fn gcd(&self, other: &Self) -> DensePolynomial<F> {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, remainder) = a.fast_divide_with_q_and_r(&b).expect("division failed");
+            a = b;
+            b = remainder;
+        }
+
+        match a.coeffs.last() {
+            Some(leading_coefficient) => {
+                let leading_coefficient_inv = leading_coefficient.inverse().unwrap();
+                a.coeffs.iter_mut().for_each(|c| *c *= leading_coefficient_inv);
+                a
+            }
+            None => a,
+        }
+    }
+}
+
 impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
@@ -474,6 +556,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fast_divide_polynomials_random() {
+        let rng = &mut thread_rng();
+
+        for a_degree in (0..300).step_by(23) {
+            for b_degree in (0..300).step_by(17) {
+                let dividend = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let divisor = DensePolynomial::<Fr>::rand(b_degree, rng);
+                let (quotient, remainder) = dividend.fast_divide_with_q_and_r(&divisor).unwrap();
+                let (expected_quotient, expected_remainder) =
+                    DenseOrSparsePolynomial::divide_with_q_and_r(&(&dividend).into(), &(&divisor).into()).unwrap();
+                assert_eq!(quotient, expected_quotient);
+                assert_eq!(remainder, expected_remainder);
+                assert_eq!(dividend, &(&divisor * &quotient) + &remainder);
+            }
+        }
+
+        // Exercise the Newton iteration, which is only used for large divisions.
+        for (a_degree, b_degree) in [(2048, 1024), (3000, 1500), (2500, 1200)] {
+            let dividend = DensePolynomial::<Fr>::rand(a_degree, rng);
+            let divisor = DensePolynomial::<Fr>::rand(b_degree, rng);
+            let (quotient, remainder) = dividend.fast_divide_with_q_and_r(&divisor).unwrap();
+            assert!(remainder.is_zero() || remainder.degree() < divisor.degree());
+            assert_eq!(dividend, &(&divisor * &quotient) + &remainder);
+        }
+    }
+
+    #[test]
+    fn gcd_polynomials_random() {
+        let rng = &mut thread_rng();
+
+        for common_degree in [0, 1, 10, 100] {
+            let mut common = DensePolynomial::<Fr>::rand(common_degree, rng);
+            let leading_coefficient_inv = common.coeffs.last().unwrap().inverse().unwrap();
+            common.coeffs.iter_mut().for_each(|c| *c *= leading_coefficient_inv);
+
+            let a = &DensePolynomial::<Fr>::rand(150, rng) * &common;
+            let b = &DensePolynomial::<Fr>::rand(90, rng) * &common;
+            // Random polynomials are coprime with overwhelming probability.
+            assert_eq!(a.gcd(&b), common);
+            assert_eq!(b.gcd(&a), common);
+        }
+
+        let a = DensePolynomial::<Fr>::rand(10, rng);
+        assert_eq!(a.gcd(&DensePolynomial::zero()), DensePolynomial::zero().gcd(&a));
+        assert!(DensePolynomial::<Fr>::zero().gcd(&DensePolynomial::zero()).is_zero());
+    }
+
     #[test]
     fn evaluate_polynomials() {
         let rng = &mut thread_rng();
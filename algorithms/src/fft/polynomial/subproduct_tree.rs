@@ -0,0 +1,338 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A subproduct tree for fast multipoint evaluation and interpolation over arbitrary points.
+
+use crate::fft::DensePolynomial;
+use snarkvm_fields::{batch_inversion, Field, PrimeField};
+
+/// Defines the number of points below which a remainder is evaluated directly at each point.
+const DIRECT_EVALUATION_THRESHOLD: usize = 64;
+
+/// A node of the subproduct tree, storing the product of `(x - x_i)` over the points below it.
+#[derive(Clone, Debug)]
+struct SubproductTreeNode<F: PrimeField> {
+    /// The product of `(x - x_i)` for all points `x_i` in the subtree.
+    polynomial: DensePolynomial<F>,
+    /// The left and right subtrees, or `None` if this node is a leaf.
+    children: Option<Box<(SubproductTreeNode<F>, SubproductTreeNode<F>)>>,
+}
+
+impl<F: PrimeField> SubproductTreeNode<F> {
+    fn new(points: &[F]) -> Self {
+        if points.len() == 1 {
+            return Self {
+                polynomial: DensePolynomial::from_coefficients_vec(vec![-points[0], F::one()]),
+                children: None,
+            };
+        }
+
+        let (left, right) = points.split_at(points.len() / 2);
+        let (left, right) = (Self::new(left), Self::new(right));
+        Self {
+            polynomial: &left.polynomial * &right.polynomial,
+            children: Some(Box::new((left, right))),
+        }
+    }
+
+    /// Evaluates `remainder`, which is reduced modulo `self.polynomial`, at each of the `points` of this subtree.
+    fn evaluate(&self, remainder: &DensePolynomial<F>, points: &[F], evaluations: &mut [F]) {
+        match &self.children {
+            Some(children) if points.len() > DIRECT_EVALUATION_THRESHOLD => {
+                let (left, right) = &**children;
+                let (left_points, right_points) = points.split_at(points.len() / 2);
+                let (left_evaluations, right_evaluations) = evaluations.split_at_mut(points.len() / 2);
+
+                let left_remainder = reduce(remainder, &left.polynomial);
+                let right_remainder = reduce(remainder, &right.polynomial);
+                left.evaluate(&left_remainder, left_points, left_evaluations);
+                right.evaluate(&right_remainder, right_points, right_evaluations);
+            }
+            _ => {
+                for (point, evaluation) in points.iter().zip(evaluations.iter_mut()) {
+                    *evaluation = remainder.evaluate(*point);
+                }
+            }
+        }
+    }
+
+    /// Returns `sum_i c_i * prod_{j != i} (x - x_j)` over the points of this subtree.
+    fn linear_combination(&self, coefficients: &[F]) -> DensePolynomial<F> {
+        match &self.children {
+            Some(children) => {
+                let (left, right) = &**children;
+                let (left_coefficients, right_coefficients) = coefficients.split_at(coefficients.len() / 2);
+
+                let left_combination = left.linear_combination(left_coefficients);
+                let right_combination = right.linear_combination(right_coefficients);
+                &(&left_combination * &right.polynomial) + &(&right_combination * &left.polynomial)
+            }
+            None => DensePolynomial::from_coefficients_vec(vec![coefficients[0]]),
+        }
+    }
+}
+
+/// Returns `polynomial mod divisor`.
+fn reduce<F: PrimeField>(polynomial: &DensePolynomial<F>, divisor: &DensePolynomial<F>) -> DensePolynomial<F> {
+    polynomial.fast_divide_with_q_and_r(divisor).expect("division failed").1
+}
+
+/// A subproduct tree over a set of distinct points `x_0, ..., x_{n-1}`.
+///
+/// The root of the tree is the vanishing polynomial `Z(x) = prod_i (x - x_i)`, and each node
+/// is the product of its children. This allows evaluating a polynomial at all `n` points,
+/// and interpolating a polynomial from its `n` evaluations, in O(M(n) log n) time.
+#[derive(Clone, Debug)]
+pub struct SubproductTree<F: PrimeField> {
+    /// The points of the tree.
+    points: Vec<F>,
+    /// The root of the tree, or `None` if there are no points.
+    root: Option<SubproductTreeNode<F>>,
+    /// The evaluations of the derivative of the vanishing polynomial at each point.
+    vanishing_derivative_evaluations: Vec<F>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Constructs the subproduct tree over the given points.
+    /// Returns `None` if the points are not distinct.
+    pub fn new(points: &[F]) -> Option<Self> {
+        let mut tree = Self {
+            points: points.to_vec(),
+            root: match points.is_empty() {
+                true => None,
+                false => Some(SubproductTreeNode::new(points)),
+            },
+            vanishing_derivative_evaluations: vec![],
+        };
+
+        // Z'(x_i) = prod_{j != i} (x_i - x_j), which is zero exactly when the points are not distinct.
+        let vanishing_derivative = derivative(&tree.vanishing_polynomial());
+        tree.vanishing_derivative_evaluations = tree.evaluate(&vanishing_derivative);
+        if tree.vanishing_derivative_evaluations.iter().any(|e| e.is_zero()) {
+            return None;
+        }
+        Some(tree)
+    }
+
+    /// Returns the points of the tree.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// Returns the vanishing polynomial `Z(x) = prod_i (x - x_i)` of the points.
+    pub fn vanishing_polynomial(&self) -> DensePolynomial<F> {
+        match &self.root {
+            Some(root) => root.polynomial.clone(),
+            None => DensePolynomial::from_coefficients_vec(vec![F::one()]),
+        }
+    }
+
+    /// Returns the evaluations of the derivative of the vanishing polynomial at each point.
+    pub fn vanishing_derivative_evaluations(&self) -> &[F] {
+        &self.vanishing_derivative_evaluations
+    }
+
+    /// Evaluates `polynomial` at each of the points.
+    pub fn evaluate(&self, polynomial: &DensePolynomial<F>) -> Vec<F> {
+        let mut evaluations = vec![F::zero(); self.points.len()];
+        if let Some(root) = &self.root {
+            let remainder = reduce(polynomial, &root.polynomial);
+            root.evaluate(&remainder, &self.points, &mut evaluations);
+        }
+        evaluations
+    }
+
+    /// Returns the unique polynomial of degree less than the number of points
+    /// that evaluates to `evaluations[i]` at each point `x_i`.
+    pub fn interpolate(&self, evaluations: &[F]) -> DensePolynomial<F> {
+        assert_eq!(evaluations.len(), self.points.len());
+
+        match &self.root {
+            Some(root) => {
+                // f(x) = sum_i (y_i / Z'(x_i)) * Z(x) / (x - x_i)
+                let mut coefficients = self.vanishing_derivative_evaluations.clone();
+                batch_inversion(&mut coefficients);
+                coefficients
+                    .iter_mut()
+                    .zip(evaluations)
+                    .for_each(|(coefficient, evaluation)| *coefficient *= evaluation);
+                root.linear_combination(&coefficients)
+            }
+            None => DensePolynomial::zero(),
+        }
+    }
+
+    /// Evaluates all the Lagrange polynomials defined by the points at the point `x`.
+    pub fn evaluate_all_lagrange_coefficients(&self, x: F) -> Vec<F> {
+        let vanishing_evaluation = self.vanishing_polynomial().evaluate(x);
+        barycentric_lagrange_coefficients(
+            x,
+            &self.points,
+            &self.vanishing_derivative_evaluations,
+            vanishing_evaluation,
+        )
+    }
+}
+
+/// Returns the formal derivative of `polynomial`.
+fn derivative<F: PrimeField>(polynomial: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let coeffs = polynomial
+        .coeffs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, coeff)| F::from(i as u64) * coeff)
+        .collect();
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Evaluates the Lagrange polynomials `L_i(X) = Z(X) / (Z'(x_i) * (X - x_i))` at the point `x`,
+/// given the points `x_i`, the evaluations `Z'(x_i)` of the derivative of the vanishing polynomial,
+/// and the evaluation `Z(x)` of the vanishing polynomial.
+///
+/// The vanishing polynomial may vanish on a superset of the points, in which case the
+/// evaluations at the remaining points are treated as zero.
+pub fn barycentric_lagrange_coefficients<F: Field>(
+    x: F,
+    points: &[F],
+    vanishing_derivative_evaluations: &[F],
+    vanishing_evaluation: F,
+) -> Vec<F> {
+    assert_eq!(points.len(), vanishing_derivative_evaluations.len());
+
+    // If `x` is a root of the vanishing polynomial, the Lagrange coefficients are indicators.
+    if vanishing_evaluation.is_zero() {
+        return points
+            .iter()
+            .map(|point| if *point == x { F::one() } else { F::zero() })
+            .collect();
+    }
+
+    let mut coefficients = points
+        .iter()
+        .zip(vanishing_derivative_evaluations)
+        .map(|(point, derivative)| (x - point) * derivative)
+        .collect::<Vec<_>>();
+    batch_inversion(&mut coefficients);
+    coefficients
+        .iter_mut()
+        .for_each(|coefficient| *coefficient *= vanishing_evaluation);
+    coefficients
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fft::{barycentric_lagrange_coefficients, DensePolynomial, EvaluationDomain, SubproductTree};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{Field, One, Zero};
+    use snarkvm_utilities::rand::UniformRand;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn multipoint_evaluation() {
+        let rng = &mut thread_rng();
+        for num_points in 0..100 {
+            let points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let tree = SubproductTree::new(&points).unwrap();
+            for degree in [0, num_points / 2, num_points, 2 * num_points + 1] {
+                let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+                let evaluations = tree.evaluate(&polynomial);
+                for (point, evaluation) in points.iter().zip(evaluations) {
+                    assert_eq!(polynomial.evaluate(*point), evaluation);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn interpolation() {
+        let rng = &mut thread_rng();
+        for num_points in 1..100 {
+            let points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let tree = SubproductTree::new(&points).unwrap();
+
+            let polynomial = DensePolynomial::<Fr>::rand(num_points - 1, rng);
+            let evaluations = points
+                .iter()
+                .map(|point| polynomial.evaluate(*point))
+                .collect::<Vec<_>>();
+            assert_eq!(tree.interpolate(&evaluations), polynomial);
+
+            for point in &points {
+                assert!(tree.vanishing_polynomial().evaluate(*point).is_zero());
+            }
+        }
+    }
+
+    #[test]
+    fn repeated_points() {
+        let points = [Fr::one(), Fr::from(2u64), Fr::one()];
+        assert!(SubproductTree::new(&points).is_none());
+    }
+
+    #[test]
+    fn lagrange_coefficients() {
+        let rng = &mut thread_rng();
+        let points = (0..20).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let tree = SubproductTree::new(&points).unwrap();
+
+        let polynomial = DensePolynomial::<Fr>::rand(points.len() - 1, rng);
+        let evaluations = tree.evaluate(&polynomial);
+
+        // Interpolate at a random point.
+        let x = Fr::rand(rng);
+        let interpolation = tree
+            .evaluate_all_lagrange_coefficients(x)
+            .iter()
+            .zip(&evaluations)
+            .fold(Fr::zero(), |acc, (coefficient, evaluation)| {
+                acc + *coefficient * evaluation
+            });
+        assert_eq!(interpolation, polynomial.evaluate(x));
+
+        // Interpolate at one of the points.
+        let coefficients = tree.evaluate_all_lagrange_coefficients(points[3]);
+        for (i, coefficient) in coefficients.iter().enumerate() {
+            assert_eq!(*coefficient, if i == 3 { Fr::one() } else { Fr::zero() });
+        }
+    }
+
+    #[test]
+    fn lagrange_coefficients_match_domain() {
+        let rng = &mut thread_rng();
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let points = domain.elements().collect::<Vec<_>>();
+        let tree = SubproductTree::new(&points).unwrap();
+
+        // On a multiplicative subgroup, Z'(g^i) = n * g^{-i}.
+        let size = Fr::from(domain.size() as u64);
+        let derivatives = points
+            .iter()
+            .map(|point| size * point.inverse().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(tree.vanishing_derivative_evaluations(), &derivatives[..]);
+
+        let x = Fr::rand(rng);
+        let expected = domain.evaluate_all_lagrange_coefficients(x);
+        let vanishing_evaluation = domain.evaluate_vanishing_polynomial(x);
+        assert_eq!(tree.evaluate_all_lagrange_coefficients(x), expected);
+        assert_eq!(
+            barycentric_lagrange_coefficients(x, &points, &derivatives, vanishing_evaluation),
+            expected
+        );
+    }
+}
@@ -149,3 +149,110 @@ fn parallel_fft_consistency() {
     test_basic_consistency::<Bls12_377, _>(rng, 10);
     test_consistency::<Bls12_377, _>(rng, 10);
 }
+
+/// Test that mixed-radix domains are used beyond the two-adicity of the field.
+#[test]
+fn mixed_radix_domain_size() {
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::FftParameters;
+
+    let two_adicity = <Fr as FftField>::FftParameters::TWO_ADICITY;
+
+    // Radix-2 domains are preferred whenever they exist.
+    assert_eq!(EvaluationDomain::<Fr>::compute_size_of_domain(9), Some(16));
+    assert_eq!(
+        EvaluationDomain::<Fr>::compute_size_of_domain(1 << two_adicity),
+        Some(1 << two_adicity)
+    );
+
+    // Mixed-radix domains are the smallest domains of size 2^i * 3^j.
+    assert_eq!(EvaluationDomain::<Fr>::compute_mixed_radix_size_of_domain(0), Some(1));
+    assert_eq!(EvaluationDomain::<Fr>::compute_mixed_radix_size_of_domain(3), Some(3));
+    assert_eq!(EvaluationDomain::<Fr>::compute_mixed_radix_size_of_domain(8), Some(8));
+    assert_eq!(EvaluationDomain::<Fr>::compute_mixed_radix_size_of_domain(9), Some(12));
+    assert_eq!(EvaluationDomain::<Fr>::compute_mixed_radix_size_of_domain(13), Some(16));
+    assert_eq!(EvaluationDomain::<Fr>::compute_mixed_radix_size_of_domain(17), Some(24));
+
+    // Beyond the two-adicity, the domain falls back to a mixed-radix domain.
+    assert_eq!(
+        EvaluationDomain::<Fr>::compute_size_of_domain((1 << two_adicity) + 1),
+        Some(3 << (two_adicity - 1))
+    );
+    assert_eq!(
+        EvaluationDomain::<Fr>::compute_size_of_domain((3 << two_adicity) + 1),
+        None
+    );
+
+    // Fields without a small subgroup base only support radix-2 domains.
+    type EdwardsFr = snarkvm_curves::edwards_bls12::Fr;
+    assert_eq!(
+        EvaluationDomain::<EdwardsFr>::compute_mixed_radix_size_of_domain(2),
+        None
+    );
+    assert_eq!(EvaluationDomain::<EdwardsFr>::compute_size_of_domain(3), None);
+}
+
+/// Test that mixed-radix FFTs agree with naive evaluations and are consistent with their inverses.
+#[test]
+fn mixed_radix_fft_correctness() {
+    use crate::fft::DensePolynomial;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{Field, One, Zero};
+
+    let rng = &mut rand::thread_rng();
+
+    for log_size in 0..8 {
+        let size = 3 << log_size;
+        let domain = EvaluationDomain::<Fr>::new_mixed_radix(size).unwrap();
+        assert_eq!(domain.size(), size);
+        assert!(!domain.is_radix2());
+        assert_eq!(domain.group_gen.pow([size as u64]), Fr::one());
+        assert_ne!(domain.group_gen.pow([(size / 3) as u64]), Fr::one());
+        assert_ne!(domain.group_gen.pow([(size / 2) as u64]), Fr::one());
+        assert_eq!(domain.elements().count(), size);
+
+        let polynomial = DensePolynomial::<Fr>::rand(size - 1, rng);
+        let evaluations = domain.fft(&polynomial.coeffs);
+        let coset_evaluations = domain.coset_fft(&polynomial.coeffs);
+        for (i, x) in domain.elements().enumerate() {
+            assert_eq!(evaluations[i], polynomial.evaluate(x));
+            assert_eq!(
+                coset_evaluations[i],
+                polynomial.evaluate(Fr::multiplicative_generator() * x)
+            );
+        }
+
+        assert_eq!(domain.ifft(&evaluations), polynomial.coeffs);
+        assert_eq!(domain.coset_ifft(&coset_evaluations), polynomial.coeffs);
+        for point in domain.elements() {
+            assert!(domain.evaluate_vanishing_polynomial(point).is_zero());
+        }
+    }
+}
+
+/// Test that the six-step FFT agrees with the serial FFTs, for radix-2 and mixed-radix domains.
+#[cfg(feature = "parallel")]
+#[test]
+fn six_step_fft_consistency() {
+    use snarkvm_curves::bls12_377::Fr;
+
+    let rng = &mut rand::thread_rng();
+
+    for log_size in 0..12 {
+        for size in [1 << log_size, 3 << log_size] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(size).unwrap();
+            assert_eq!(domain.size(), size);
+
+            let mut expected = (0..size).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let mut actual = expected.clone();
+
+            serial_fft(&mut expected, domain.group_gen, domain.log_size_of_group);
+            six_step_fft(&mut actual, domain.group_gen, domain.log_size_of_group);
+            assert_eq!(expected, actual);
+
+            serial_fft(&mut expected, domain.group_gen_inv, domain.log_size_of_group);
+            six_step_fft(&mut actual, domain.group_gen_inv, domain.log_size_of_group);
+            assert_eq!(expected, actual);
+        }
+    }
+}
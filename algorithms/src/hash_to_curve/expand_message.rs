@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use sha2::{Digest, Sha256};
+
+/// The output size of SHA-256 in bytes (`b_in_bytes` in RFC 9380).
+const SHA256_OUTPUT_BYTES: usize = 32;
+
+/// The input block size of SHA-256 in bytes (`s_in_bytes` in RFC 9380).
+const SHA256_BLOCK_BYTES: usize = 64;
+
+/// The prefix used to hash domain separation tags longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Returns `length` uniformly random bytes derived from `message` and the domain separation tag `dst`,
+/// using `expand_message_xmd` with SHA-256 as specified in RFC 9380, Section 5.3.1.
+///
+/// Panics if `length` exceeds `255 * 32` bytes.
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], length: usize) -> Vec<u8> {
+    let ell = length.div_ceil(SHA256_OUTPUT_BYTES);
+    assert!(ell <= 255, "expand_message_xmd cannot output more than 8160 bytes");
+
+    // DST_prime = DST || I2OSP(len(DST), 1), where oversized tags are hashed first (Section 5.3.3).
+    let mut dst_prime = match dst.len() > 255 {
+        true => Sha256::new().chain(OVERSIZE_DST_PREFIX).chain(dst).finalize().to_vec(),
+        false => dst.to_vec(),
+    };
+    dst_prime.push(dst_prime.len() as u8);
+
+    let b_0 = Sha256::new()
+        .chain([0u8; SHA256_BLOCK_BYTES])
+        .chain(message)
+        .chain((length as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(&dst_prime)
+        .finalize();
+
+    let mut b_i = Sha256::new().chain(b_0).chain([1u8]).chain(&dst_prime).finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let xor = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>();
+        b_i = Sha256::new().chain(xor).chain([i as u8]).chain(&dst_prime).finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(length);
+    uniform_bytes
+}
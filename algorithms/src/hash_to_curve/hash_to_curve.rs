@@ -22,7 +22,11 @@ use snarkvm_curves::bls12_377::{G2Affine, Fq2Parameters};
 use snarkvm_utilities::UniformRand;
 
 pub fn hash_to_curve<G: AffineCurve<BaseField = F>, F: Field>(input: &str) -> G {
-    let mut rng = ChaCha8Rng::from_seed(sha256(input.as_bytes()));
+    hash_bytes_to_curve::<G, F>(input.as_bytes())
+}
+
+pub fn hash_bytes_to_curve<G: AffineCurve<BaseField = F>, F: Field>(input: &[u8]) -> G {
+    let mut rng = ChaCha8Rng::from_seed(sha256(input));
     let mut x = G::BaseField::rand(&mut rng);
     loop {
         for bit_y in [false, true] {
@@ -43,11 +47,11 @@ fn hash_bls12_377_g1() {
     assert!(g1.is_in_correct_subgroup_assuming_on_curve());
     assert_eq!(
         g1.x.to_string(),
-        "55965310611925736182344266514489161724026037910766990800227254498080679430741845649557376683529002177041434636955"
+        "119541175634861695543042154525918354274233167682392231927176252189114705443266466335225291391566792068815195959963"
     );
     assert_eq!(
         g1.y.to_string(),
-        "47988536770352052135994920271989708481287122349204081233627486611634010967671014168936729210354715817984213346861761"
+        "102502890120787270529476375751532898813106623073178406284804891538977797185212088627612449982832658574088637694657"
     );
 }
 
@@ -57,10 +61,10 @@ fn hash_bls12_377_g2() {
     assert!(g2.is_in_correct_subgroup_assuming_on_curve());
     assert_eq!(
         g2.x.to_string(),
-        "Fp2(4992399534085505417963743334831158275796011776806012206913627929721152622217262086900539913823010591400808061223857 + 1036762444117213072465519435695624108541353692644436531612240142717350874959576322311161822617113219647751577789565 * u)"
+        "Fp2(106637004535943700047035293118759783015275644377934208342886187714368737718316889432620822070664168676677783012273 + 221451111702905799000262593369838511758483164320026025519737257253073122432008531381449403742349270559445346542717 * u)"
     );
     assert_eq!(
         g2.y.to_string(),
-        "Fp2(39593503541928207112672510593382719635487710939976849056149175312270166418672211148604934770065425690518397740619 + 89614077544256851417964173631152027483300723040827568531499279961411912919170578736548531738044390218650282162770 * u)"
+        "Fp2(84571207978748673112474998299134799989932042856190170982569630061722535005319945943763114215428212068471231047243 + 19141448493555765572303384609393693765328924993968799664094315144777275741573945433012312913885230710465314486866 * u)"
     );
 }
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::hash_to_curve::expand_message_xmd;
+use snarkvm_fields::{
+    Field,
+    Fp2,
+    Fp256,
+    Fp256Parameters,
+    Fp2Parameters,
+    Fp320,
+    Fp320Parameters,
+    Fp384,
+    Fp384Parameters,
+    Fp768,
+    Fp768Parameters,
+    Fp832,
+    Fp832Parameters,
+    PrimeField,
+    Zero,
+};
+use snarkvm_utilities::biginteger::BigInteger;
+
+/// The target security level `k` in bits, as used to compute `L` in RFC 9380, Section 5.
+const SECURITY_PARAMETER: usize = 128;
+
+/// A field whose elements can be sampled from uniform bytes, as specified in RFC 9380, Section 5.
+pub trait HashToField: Field {
+    /// The number of uniform bytes consumed per field element (`m * L` in RFC 9380).
+    const UNIFORM_BYTES: usize;
+
+    /// Returns the field element for the given `UNIFORM_BYTES` uniform bytes.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// Returns the sign of the field element, as specified in RFC 9380, Section 4.1.
+    fn sgn0(&self) -> bool;
+}
+
+macro_rules! impl_hash_to_field_for_prime_field {
+    ($field: ident, $parameters: ident) => {
+        impl<P: $parameters> HashToField for $field<P> {
+            const UNIFORM_BYTES: usize = (P::MODULUS_BITS as usize + SECURITY_PARAMETER).div_ceil(8);
+
+            fn from_uniform_bytes(bytes: &[u8]) -> Self {
+                debug_assert_eq!(bytes.len(), Self::UNIFORM_BYTES);
+                // Interprets the bytes as a big-endian integer, reduced modulo the field characteristic.
+                let base = Self::from(256u16);
+                bytes
+                    .iter()
+                    .fold(Self::zero(), |acc, byte| acc * base + Self::from(*byte))
+            }
+
+            fn sgn0(&self) -> bool {
+                self.into_repr().is_odd()
+            }
+        }
+    };
+}
+
+impl_hash_to_field_for_prime_field!(Fp256, Fp256Parameters);
+impl_hash_to_field_for_prime_field!(Fp320, Fp320Parameters);
+impl_hash_to_field_for_prime_field!(Fp384, Fp384Parameters);
+impl_hash_to_field_for_prime_field!(Fp768, Fp768Parameters);
+impl_hash_to_field_for_prime_field!(Fp832, Fp832Parameters);
+
+impl<P: Fp2Parameters> HashToField for Fp2<P>
+where
+    P::Fp: HashToField,
+{
+    const UNIFORM_BYTES: usize = 2 * P::Fp::UNIFORM_BYTES;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (c0, c1) = bytes.split_at(P::Fp::UNIFORM_BYTES);
+        Self::new(P::Fp::from_uniform_bytes(c0), P::Fp::from_uniform_bytes(c1))
+    }
+
+    fn sgn0(&self) -> bool {
+        let sign_0 = self.c0.sgn0();
+        let zero_0 = self.c0.is_zero();
+        let sign_1 = self.c1.sgn0();
+        sign_0 | (zero_0 & sign_1)
+    }
+}
+
+/// Returns `count` field elements derived from `message` and the domain separation tag `dst`,
+/// using `hash_to_field` with `expand_message_xmd` as specified in RFC 9380, Section 5.2.
+pub fn hash_to_field<F: HashToField>(message: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    let uniform_bytes = expand_message_xmd(message, dst, count * F::UNIFORM_BYTES);
+    uniform_bytes
+        .chunks(F::UNIFORM_BYTES)
+        .map(F::from_uniform_bytes)
+        .collect()
+}
@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    hash_to_curve::{hash_to_field, HashToField},
+    traits::HashToCurve,
+};
+use snarkvm_curves::{
+    templates::{
+        short_weierstrass::short_weierstrass_jacobian::GroupAffine as SWAffine,
+        twisted_edwards_extended::GroupAffine as TEAffine,
+    },
+    traits::{AffineCurve, ProjectiveCurve, SWModelParameters, TEModelParameters},
+};
+use snarkvm_fields::{Field, One, SquareRootField, Zero};
+
+use once_cell::sync::Lazy;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::RwLock,
+};
+
+/// Returns `b` if `condition` is set, and `a` otherwise (`CMOV` in RFC 9380).
+#[inline]
+fn cmov<F: Field>(a: F, b: F, condition: bool) -> F {
+    let choice = F::from(condition as u8);
+    a + choice * (b - a)
+}
+
+/// Returns the inverse of `x`, or zero if `x` is zero (`inv0` in RFC 9380).
+#[inline]
+fn inv0<F: Field>(x: F) -> F {
+    x.inverse().unwrap_or_else(F::zero)
+}
+
+/// Returns `true` if `x` is a square, including zero (`is_square` in RFC 9380).
+#[inline]
+fn is_square<F: SquareRootField>(x: &F) -> bool {
+    !x.legendre().is_qnr()
+}
+
+/// Returns the constants `C` of a map for the curve `P`, computing them with `compute` on first use.
+fn cached_constants<P: 'static, C: Copy + Send + Sync + 'static>(compute: fn() -> C) -> C {
+    static CONSTANTS: Lazy<RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>> = Lazy::new(Default::default);
+
+    let key = TypeId::of::<(P, C)>();
+    if let Some(constants) = CONSTANTS.read().unwrap().get(&key) {
+        return *constants
+            .downcast_ref::<C>()
+            .expect("the constants are keyed by their type");
+    }
+
+    let constants = compute();
+    CONSTANTS.write().unwrap().insert(key, Box::new(constants));
+    constants
+}
+
+/// Returns the non-zero constant `Z` for the Shallue-van de Woestijne method,
+/// selected as specified in RFC 9380, Appendix H.1.
+fn find_z_svdw<P: SWModelParameters>() -> P::BaseField {
+    let (a, b) = (P::COEFF_A, P::COEFF_B);
+    let g = |x: P::BaseField| (x.square() + a) * x + b;
+    let two = P::BaseField::from(2u8);
+    let three = P::BaseField::from(3u8);
+    let four = P::BaseField::from(4u8);
+
+    let mut counter = 1u64;
+    loop {
+        for z in [P::BaseField::from(counter), -P::BaseField::from(counter)] {
+            let g_z = g(z);
+            if g_z.is_zero() {
+                continue;
+            }
+            let h_z = -(three * z.square() + four * a) / (four * g_z);
+            if h_z.is_zero() || !is_square(&h_z) {
+                continue;
+            }
+            if is_square(&g_z) || is_square(&g(-z / two)) {
+                return z;
+            }
+        }
+        counter += 1;
+    }
+}
+
+/// Returns the non-square constant `Z` for the Elligator 2 method,
+/// selected as specified in RFC 9380, Appendix H.1.
+fn find_z_elligator2<F: SquareRootField>() -> F {
+    let mut counter = 1u64;
+    loop {
+        for z in [F::from(counter), -F::from(counter)] {
+            if !is_square(&z) {
+                return z;
+            }
+        }
+        counter += 1;
+    }
+}
+
+/// Returns the constants `(Z, c1, c2, c3, c4)` of the Shallue-van de Woestijne method,
+/// as specified in RFC 9380, Section 6.6.1.
+fn svdw_constants<P: SWModelParameters>() -> [P::BaseField; 5]
+where
+    P::BaseField: HashToField,
+{
+    let (a, b) = (P::COEFF_A, P::COEFF_B);
+    let three = P::BaseField::from(3u8);
+    let four = P::BaseField::from(4u8);
+
+    let z = find_z_svdw::<P>();
+    let denominator = three * z.square() + four * a;
+    let c1 = (z.square() + a) * z + b;
+    let c2 = -z / P::BaseField::from(2u8);
+    let c3 = (-(c1 * denominator)).sqrt().expect("Z is chosen such that c3 exists");
+    let c3 = cmov(c3, -c3, c3.sgn0());
+    let c4 = -(four * c1) / denominator;
+
+    [z, c1, c2, c3, c4]
+}
+
+/// Maps a field element to a point on the short Weierstrass curve `y^2 = x^3 + A * x + B`,
+/// using the Shallue-van de Woestijne method in RFC 9380, Section 6.6.1.
+///
+/// Unlike the simplified SWU map, this method applies to curves with `A = 0`,
+/// such as BLS12-377 G1 and G2, without requiring an isogenous curve.
+/// The map selects its outputs with `cmov`, but it is not constant time,
+/// as `is_square`, `sqrt` and `inv0` use variable-time field arithmetic.
+pub fn map_to_curve_svdw<P: SWModelParameters>(u: P::BaseField) -> SWAffine<P>
+where
+    P::BaseField: HashToField,
+{
+    let (a, b) = (P::COEFF_A, P::COEFF_B);
+    let g = |x: P::BaseField| (x.square() + a) * x + b;
+
+    let [z, c1, c2, c3, c4] = cached_constants::<P, _>(svdw_constants::<P>);
+
+    let tv1 = u.square() * c1;
+    let tv2 = P::BaseField::one() + tv1;
+    let tv1 = P::BaseField::one() - tv1;
+    let tv3 = inv0(tv1 * tv2);
+    let tv4 = u * tv1 * tv3 * c3;
+
+    let x1 = c2 - tv4;
+    let e1 = is_square(&g(x1));
+    let x2 = c2 + tv4;
+    let e2 = is_square(&g(x2)) & !e1;
+    let x3 = (tv2.square() * tv3).square() * c4 + z;
+
+    let x = cmov(x3, x1, e1);
+    let x = cmov(x, x2, e2);
+    let y = g(x).sqrt().expect("one of g(x1), g(x2), g(x3) is a square");
+    let e3 = u.sgn0() == y.sgn0();
+    let y = cmov(-y, y, e3);
+
+    SWAffine::new(x, y, false)
+}
+
+/// Returns the constants `(Z, K, c1, c2)` of the Elligator 2 method on the Montgomery curve
+/// that is birationally equivalent to the twisted Edwards curve, as specified in RFC 9380, Section 6.7.1.
+fn elligator2_constants<P: TEModelParameters>() -> [P::BaseField; 4] {
+    let (a, d) = (P::COEFF_A, P::COEFF_D);
+    let j = (a + d).double() / (a - d);
+    let k = P::BaseField::from(4u8) / (a - d);
+
+    let z = find_z_elligator2::<P::BaseField>();
+    let c1 = j / k;
+    let c2 = k.square().inverse().expect("K is non-zero");
+
+    [z, k, c1, c2]
+}
+
+/// Maps a field element to a point on the twisted Edwards curve `a * v^2 + w^2 = 1 + d * v^2 * w^2`,
+/// using the Elligator 2 method in RFC 9380, Section 6.7.1, on the birationally equivalent
+/// Montgomery curve `K * t^2 = s^3 + J * s^2 + s`, followed by the rational map in Appendix D.1.
+///
+/// The map selects its outputs with `cmov`, but it is not constant time,
+/// as `is_square`, `sqrt` and `inv0` use variable-time field arithmetic.
+pub fn map_to_curve_elligator2<P: TEModelParameters>(u: P::BaseField) -> TEAffine<P>
+where
+    P::BaseField: HashToField,
+{
+    let [z, k, c1, c2] = cached_constants::<P, _>(elligator2_constants::<P>);
+
+    // Elligator 2 onto the Montgomery curve.
+    let tv1 = z * u.square();
+    let e1 = tv1 == -P::BaseField::one();
+    let tv1 = cmov(tv1, P::BaseField::zero(), e1);
+    let x1 = -c1 * inv0(tv1 + P::BaseField::one());
+    let gx1 = ((x1 + c1) * x1 + c2) * x1;
+    let x2 = -x1 - c1;
+    let gx2 = tv1 * gx1;
+    let e2 = is_square(&gx1);
+    let x = cmov(x2, x1, e2);
+    let y2 = cmov(gx2, gx1, e2);
+    let y = y2.sqrt().expect("one of gx1, gx2 is a square");
+    let e3 = y.sgn0();
+    let y = cmov(y, -y, e2 ^ e3);
+    let (s, t) = (x * k, y * k);
+
+    // The rational map from the Montgomery curve to the twisted Edwards curve.
+    let tv1 = s + P::BaseField::one();
+    let tv2 = inv0(tv1 * t);
+    let v = tv2 * tv1 * s;
+    let w = tv2 * t * (s - P::BaseField::one());
+    let w = cmov(w, P::BaseField::one(), tv2.is_zero());
+
+    TEAffine::new(v, w)
+}
+
+/// Returns `clear_cofactor(map_to_curve(u0) + map_to_curve(u1))` for two field elements
+/// hashed from `message`, as specified in RFC 9380, Section 3.
+fn hash_with_map<G: AffineCurve, F: HashToField>(message: &[u8], dst: &[u8], map_to_curve: fn(F) -> G) -> G {
+    let u = hash_to_field::<F>(message, dst, 2);
+    let q0 = map_to_curve(u[0]).into_projective();
+    let q1 = map_to_curve(u[1]).into_projective();
+    (q0 + q1).into_affine().mul_by_cofactor()
+}
+
+/// Returns `clear_cofactor(map_to_curve(u))` for one field element hashed from `message`,
+/// as specified in RFC 9380, Section 3.
+fn encode_with_map<G: AffineCurve, F: HashToField>(message: &[u8], dst: &[u8], map_to_curve: fn(F) -> G) -> G {
+    let u = hash_to_field::<F>(message, dst, 1);
+    map_to_curve(u[0]).mul_by_cofactor()
+}
+
+impl<P: SWModelParameters> HashToCurve for SWAffine<P>
+where
+    P::BaseField: HashToField,
+{
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_with_map(message, dst, map_to_curve_svdw::<P>)
+    }
+
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        encode_with_map(message, dst, map_to_curve_svdw::<P>)
+    }
+}
+
+impl<P: TEModelParameters> HashToCurve for TEAffine<P>
+where
+    P::BaseField: HashToField,
+{
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_with_map(message, dst, map_to_curve_elligator2::<P>)
+    }
+
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        encode_with_map(message, dst, map_to_curve_elligator2::<P>)
+    }
+}
@@ -14,5 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod expand_message;
+pub use expand_message::*;
+
 pub mod hash_to_curve;
 pub use hash_to_curve::*;
+
+pub mod hash_to_field;
+pub use hash_to_field::*;
+
+pub mod map_to_curve;
+pub use map_to_curve::*;
+
+#[cfg(test)]
+mod tests;
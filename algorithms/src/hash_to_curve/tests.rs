@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The known answers for BLS12-377 and the Edwards curves are computed with an independent
+//! implementation of the RFC 9380 suites in `algorithms/scripts/hash_to_curve_vectors.py`.
+
+use crate::{
+    hash_to_curve::{expand_message_xmd, hash_to_field},
+    traits::HashToCurve,
+};
+use snarkvm_curves::{
+    bls12_377::{Fq, Fq2, G1Affine, G2Affine},
+    edwards_bls12::{EdwardsAffine, Fq as EdwardsFq},
+    edwards_sw6::{EdwardsAffine as EdwardsSW6Affine, Fq as EdwardsSW6Fq},
+    traits::AffineCurve,
+};
+
+use std::str::FromStr;
+
+/// The base field of BLS12-381, for the `hash_to_field` test vectors of the RFC 9380 BLS12-381 suite.
+#[derive(snarkvm_utilities::PrimeFieldParams)]
+#[modulus = "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab"]
+#[generator = "2"]
+struct Bls12_381FqParameters;
+
+const EXPANDER_DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+const BLS12_377_G1_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SVDW_RO_";
+const BLS12_377_G2_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SVDW_RO_";
+const EDWARDS_BLS12_DST: &[u8] = b"QUUX-V01-CS02-with-EDBLS12_XMD:SHA-256_ELL2_RO_";
+const EDWARDS_SW6_DST: &[u8] = b"QUUX-V01-CS02-with-EDSW6_XMD:SHA-256_ELL2_RO_";
+const BLS12_381_G1_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn assert_in_subgroup<G: AffineCurve>(point: &G) {
+    assert!(!point.is_zero());
+    assert!(point.is_on_curve());
+    assert!(point.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_expand_message_xmd() {
+    // Test vectors from RFC 9380, Appendix K.1.
+    let q128 = format!("q128_{}", "q".repeat(128));
+    let a512 = format!("a512_{}", "a".repeat(512));
+
+    assert_eq!(
+        hex(&expand_message_xmd(b"", EXPANDER_DST, 0x20)),
+        "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+    );
+    assert_eq!(
+        hex(&expand_message_xmd(b"abc", EXPANDER_DST, 0x20)),
+        "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+    );
+    assert_eq!(
+        hex(&expand_message_xmd(b"abcdef0123456789", EXPANDER_DST, 0x20)),
+        "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1"
+    );
+    assert_eq!(
+        hex(&expand_message_xmd(q128.as_bytes(), EXPANDER_DST, 0x20)),
+        "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9"
+    );
+    assert_eq!(
+        hex(&expand_message_xmd(a512.as_bytes(), EXPANDER_DST, 0x20)),
+        "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c"
+    );
+    assert_eq!(
+        hex(&expand_message_xmd(b"", EXPANDER_DST, 0x80)),
+        "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e\
+         188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb7\
+         49d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+    );
+    assert_eq!(
+        hex(&expand_message_xmd(b"abc", EXPANDER_DST, 0x80)),
+        "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0af\
+         a5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4\
+         ec65e8afc50303a22c0f99b0509b4c895f40"
+    );
+}
+
+#[test]
+fn test_expand_message_xmd_oversize_dst() {
+    let long_dst = [0x42u8; 256];
+    let output = expand_message_xmd(b"abc", &long_dst, 0x20);
+    assert_eq!(output.len(), 0x20);
+    assert_ne!(output, expand_message_xmd(b"abc", &long_dst[..255], 0x20));
+}
+
+#[test]
+fn test_hash_to_field() {
+    // Test vectors for `u` from RFC 9380, Appendix J.9.1.
+    let vectors = [
+        (
+            &b""[..],
+            "1790030616568561980207134218344899338736900885118493183248255875682123737756800213955590674957414534085508415116879",
+            "247470258331762152370823329280394074170045058453263356372873997375166908584899100434893060702108665825589810322121",
+        ),
+        (
+            &b"abc"[..],
+            "2088728490498894818688784437928579501848367107744050576780266498473771518428420173373487118890161663886009635645777",
+            "32138924938310862093169606408734331410171587925844216752733293543601988453843327878077294514665889481436558332217",
+        ),
+    ];
+
+    for (message, u0, u1) in vectors.iter() {
+        let u = hash_to_field::<Bls12_381Fq>(message, BLS12_381_G1_DST, 2);
+        assert_eq!(u, vec![
+            Bls12_381Fq::from_str(u0).unwrap(),
+            Bls12_381Fq::from_str(u1).unwrap()
+        ]);
+    }
+}
+
+#[test]
+fn test_hash_to_field_is_deterministic() {
+    let a = hash_to_field::<Fq>(b"abc", BLS12_377_G1_DST, 2);
+    let b = hash_to_field::<Fq>(b"abc", BLS12_377_G1_DST, 2);
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 2);
+    assert_ne!(a[0], a[1]);
+}
+
+#[test]
+fn test_bls12_377_g1_hash_to_curve() {
+    let vectors = [
+        (
+            &b""[..],
+            "251337337623946727197918912240734475425817975904179632987228433087990246157222369002072235244483975718532162975111",
+            "173553133979398678439144093823329814464502085265521150186904682654121525965086280734306323478767677358381742515774",
+        ),
+        (
+            &b"abc"[..],
+            "115868840133496761296458638901199588397958029987242810661200183593901149567270482653357904005353457463642779050531",
+            "98382909079735478427619013607531652235083884131037892389793365001960234035143828370331992869801654427911281535710",
+        ),
+    ];
+
+    for (message, x, y) in vectors.iter() {
+        let point = G1Affine::hash_to_curve(message, BLS12_377_G1_DST);
+        assert_in_subgroup(&point);
+        assert_eq!(point.x, Fq::from_str(x).unwrap());
+        assert_eq!(point.y, Fq::from_str(y).unwrap());
+    }
+}
+
+#[test]
+fn test_bls12_377_g2_hash_to_curve() {
+    let vectors = [
+        (
+            &b""[..],
+            (
+                "190955461573567000865269267528362167211069076262550666830439441963431279995894239041794345238287370456620217963664",
+                "176721373618845104953446694246988926098098769462125644930384122926695070632633151558317501812584280339290968379839",
+            ),
+            (
+                "10652953648419141375111615337285826093311466888889365367837299804821789548128941112525016910526734927214707378276",
+                "31378453920514466995367094203754819224583796500577019884580397324221003964740134384488826667546854096487763869026",
+            ),
+        ),
+        (
+            &b"abc"[..],
+            (
+                "194235013039719318993657341487413947812008860824852111652722134286394161585073373762991265847128062135108870834128",
+                "61603075753659268160557008281781100093820274261416698465442276555188122682078863150316979820672201861432020993252",
+            ),
+            (
+                "196155438781014552453111622351308327449622819475485379918622851240750325996065260168288028540461389042667906338797",
+                "32230311516416426908346891119394622561233187163602153007972226838896365413525876146866759402302394193552780746323",
+            ),
+        ),
+    ];
+
+    let fq2 = |(c0, c1): &(&str, &str)| Fq2::new(Fq::from_str(c0).unwrap(), Fq::from_str(c1).unwrap());
+    for (message, x, y) in vectors.iter() {
+        let point = G2Affine::hash_to_curve(message, BLS12_377_G2_DST);
+        assert_in_subgroup(&point);
+        assert_eq!(point.x, fq2(x));
+        assert_eq!(point.y, fq2(y));
+    }
+
+    let point = G2Affine::hash_to_curve(b"abc", BLS12_377_G2_DST);
+    assert_in_subgroup(&point);
+    assert_eq!(point, G2Affine::hash_to_curve(b"abc", BLS12_377_G2_DST));
+    assert_ne!(point, G2Affine::hash_to_curve(b"abcd", BLS12_377_G2_DST));
+    assert_ne!(point, G2Affine::hash_to_curve(b"abc", BLS12_377_G1_DST));
+
+    assert_in_subgroup(&G2Affine::encode_to_curve(b"abc", BLS12_377_G2_DST));
+}
+
+#[test]
+fn test_edwards_bls12_hash_to_curve() {
+    let vectors = [
+        (
+            &b""[..],
+            "4757804866803866505586161134930178809420029468933449464040506206612571883084",
+            "1391617910002294040696115451322311466779930679410417677781221885098109404541",
+        ),
+        (
+            &b"abc"[..],
+            "1583699388661473442468618845687120444915835578474987102014852672700097487861",
+            "3381026589796859458934547764980103645811249337643403072042220171382215047413",
+        ),
+    ];
+
+    for (message, x, y) in vectors.iter() {
+        let point = EdwardsAffine::hash_to_curve(message, EDWARDS_BLS12_DST);
+        assert_in_subgroup(&point);
+        assert_eq!(point.x, EdwardsFq::from_str(x).unwrap());
+        assert_eq!(point.y, EdwardsFq::from_str(y).unwrap());
+    }
+}
+
+#[test]
+fn test_edwards_sw6_hash_to_curve() {
+    let vectors = [
+        (
+            &b""[..],
+            "79380396643282124700918000589331121692809479226408571434832094248230964075326834516978040343116022504473672403247",
+            "26540850578934737233214945658510916382728016421517402878596627648866100976022292635561462106376285656088118870968",
+        ),
+        (
+            &b"abc"[..],
+            "57820910746958946257329361371543662498543607424648019449739782257901160233055368746124852785524719326439984279686",
+            "59433394696746314649938323035269269440210951890734941532725366324857043016125306730161160776740014632197053413307",
+        ),
+    ];
+
+    for (message, x, y) in vectors.iter() {
+        let point = EdwardsSW6Affine::hash_to_curve(message, EDWARDS_SW6_DST);
+        assert_in_subgroup(&point);
+        assert_eq!(point.x, EdwardsSW6Fq::from_str(x).unwrap());
+        assert_eq!(point.y, EdwardsSW6Fq::from_str(y).unwrap());
+    }
+
+    let point = EdwardsSW6Affine::hash_to_curve(b"abc", EDWARDS_SW6_DST);
+    assert_in_subgroup(&point);
+    assert_ne!(point, EdwardsSW6Affine::hash_to_curve(b"abc", EDWARDS_BLS12_DST));
+
+    assert_in_subgroup(&EdwardsSW6Affine::encode_to_curve(b"abc", EDWARDS_SW6_DST));
+}
+
+#[test]
+fn test_encode_to_curve() {
+    assert_in_subgroup(&G1Affine::encode_to_curve(b"abc", BLS12_377_G1_DST));
+    assert_in_subgroup(&EdwardsAffine::encode_to_curve(b"abc", EDWARDS_BLS12_DST));
+}
@@ -49,6 +49,8 @@ pub mod encoding;
 #[cfg(feature = "encryption")]
 pub mod encryption;
 
+pub mod envelope;
+
 pub mod errors;
 pub use errors::*;
 
@@ -64,6 +66,8 @@ pub mod msm;
 #[cfg(feature = "prf")]
 pub mod prf;
 
+pub mod secret_sharing;
+
 #[cfg(feature = "signature")]
 pub mod signature;
 
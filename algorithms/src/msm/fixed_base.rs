@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_curves::traits::ProjectiveCurve;
-use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_curves::traits::{glv_scalar_bits, ProjectiveCurve};
+use snarkvm_fields::{FieldParameters, PrimeField, Zero};
 use snarkvm_utilities::biginteger::BigInteger;
 
 #[cfg(feature = "parallel")]
@@ -54,22 +54,58 @@ impl FixedBaseMSM {
         multiples_of_g
     }
 
+    /// Returns the table of window multiples of `g` for the halves of GLV decomposed scalars,
+    /// or for full scalars if the curve has no GLV endomorphism.
+    pub fn get_glv_window_table<T: ProjectiveCurve>(window: usize, g: T) -> Vec<Vec<T>> {
+        Self::get_window_table(Self::glv_table_scalar_size::<T>(), window, g)
+    }
+
     pub fn windowed_mul<T: ProjectiveCurve>(
         outerc: usize,
         window: usize,
         multiples_of_g: &[Vec<T>],
         scalar: &T::ScalarField,
     ) -> T {
-        let mut scalar_val = scalar.into_repr().to_bits_be();
+        let num_bits = <T::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize;
+        Self::windowed_mul_repr(outerc, window, multiples_of_g, &scalar.into_repr(), num_bits)
+    }
+
+    /// Multiplies by `scalar` using the GLV decomposition of the scalar, given a table for half-size scalars.
+    pub fn glv_windowed_mul<T: ProjectiveCurve>(
+        outerc: usize,
+        window: usize,
+        multiples_of_g: &[Vec<T>],
+        scalar: &T::ScalarField,
+    ) -> T {
+        let decomposition = T::glv_decomposition(scalar).expect("the curve has no GLV endomorphism");
+        let num_bits = glv_scalar_bits::<T::ScalarField>();
+
+        let mut k1 = Self::windowed_mul_repr(outerc, window, multiples_of_g, &decomposition.k1, num_bits);
+        let mut k2 = Self::windowed_mul_repr(outerc, window, multiples_of_g, &decomposition.k2, num_bits);
+        if decomposition.k1_neg {
+            k1 = -k1;
+        }
+        if decomposition.k2_neg {
+            k2 = -k2;
+        }
+        k1 + k2.glv_endomorphism().expect("the curve has no GLV endomorphism")
+    }
+
+    fn windowed_mul_repr<T: ProjectiveCurve>(
+        outerc: usize,
+        window: usize,
+        multiples_of_g: &[Vec<T>],
+        scalar: &<T::ScalarField as PrimeField>::BigInteger,
+        num_bits: usize,
+    ) -> T {
+        let mut scalar_val = scalar.to_bits_be();
         scalar_val.reverse();
 
         let mut res = multiples_of_g[0][0];
         for outer in 0..outerc {
             let mut inner = 0usize;
             for i in 0..window {
-                if outer * window + i < (<T::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize)
-                    && scalar_val[outer * window + i]
-                {
+                if outer * window + i < num_bits && scalar_val[outer * window + i] {
                     inner |= 1 << i;
                 }
             }
@@ -91,4 +127,30 @@ impl FixedBaseMSM {
             .map(|e| Self::windowed_mul::<T>(outerc, window, table, e))
             .collect::<Vec<_>>()
     }
+
+    /// Multiplies by each scalar using the GLV endomorphism of the curve, given a table from `get_glv_window_table`.
+    /// Falls back to `multi_scalar_mul` if the curve has no GLV endomorphism.
+    ///
+    /// The endomorphism only acts as multiplication by `lambda` on the prime order subgroup,
+    /// so the result is only correct if the base of the table is in the prime order subgroup.
+    pub fn glv_multi_scalar_mul<T: ProjectiveCurve>(window: usize, table: &[Vec<T>], v: &[T::ScalarField]) -> Vec<T> {
+        if T::glv_decomposition(&T::ScalarField::zero()).is_none() {
+            return Self::multi_scalar_mul(T::ScalarField::size_in_bits(), window, table, v);
+        }
+
+        let outerc = glv_scalar_bits::<T::ScalarField>().div_ceil(window);
+        assert!(outerc <= table.len());
+
+        cfg_iter!(v)
+            .map(|e| Self::glv_windowed_mul::<T>(outerc, window, table, e))
+            .collect::<Vec<_>>()
+    }
+
+    /// Returns the scalar size of the tables from `get_glv_window_table`.
+    fn glv_table_scalar_size<T: ProjectiveCurve>() -> usize {
+        match T::glv_decomposition(&T::ScalarField::zero()) {
+            Some(_) => glv_scalar_bits::<T::ScalarField>(),
+            None => T::ScalarField::size_in_bits(),
+        }
+    }
 }
@@ -16,10 +16,11 @@
 
 use crate::msm::*;
 use snarkvm_curves::{
-    bls12_377::{Fr, G1Projective},
+    bls12_377::{Fr, G1Projective, G2Projective},
+    bw6_761,
     traits::{AffineCurve, ProjectiveCurve},
 };
-use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::{rand::UniformRand, BitIteratorBE};
 
 use rand::SeedableRng;
@@ -72,3 +73,79 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.into_affine(), fast.into_affine());
 }
+
+#[test]
+fn variable_base_test_with_bw6() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    for &samples in &[1, 10, 100] {
+        let v = (0..samples)
+            .map(|_| bw6_761::Fr::rand(&mut rng).into_repr())
+            .collect::<Vec<_>>();
+        let g = (0..samples)
+            .map(|_| bw6_761::G1Projective::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+
+        let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+        let fast = VariableBaseMSM::multi_scalar_mul(g.as_slice(), v.as_slice());
+
+        assert_eq!(naive.into_affine(), fast.into_affine());
+    }
+}
+
+#[test]
+fn variable_base_test_glv_chunked() {
+    const SAMPLES: usize = 1 << 10;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES - 1).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let v_repr = v.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    let fast = VariableBaseMSM::multi_scalar_mul(g.as_slice(), v_repr.as_slice());
+
+    for &chunk_size in &[1, 100, SAMPLES, 2 * SAMPLES] {
+        let chunked = VariableBaseMSM::multi_scalar_mul_glv_chunked(g.as_slice(), v.as_slice(), chunk_size);
+        assert_eq!(fast.into_affine(), chunked.into_affine());
+    }
+}
+
+fn fixed_base_test<G: ProjectiveCurve>() {
+    const SAMPLES: usize = 1 << 6;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let mut v = (0..SAMPLES).map(|_| G::ScalarField::rand(&mut rng)).collect::<Vec<_>>();
+    v.extend_from_slice(&[G::ScalarField::zero(), G::ScalarField::one(), -G::ScalarField::one()]);
+    let g = G::rand(&mut rng);
+
+    let scalar_size = G::ScalarField::size_in_bits();
+    let window = FixedBaseMSM::get_mul_window_size(v.len());
+    let table = FixedBaseMSM::get_window_table(scalar_size, window, g);
+    let fast = FixedBaseMSM::multi_scalar_mul(scalar_size, window, &table, &v);
+
+    for (scalar, result) in v.iter().zip(&fast) {
+        let naive = g.into_affine().mul_bits(BitIteratorBE::new(scalar.into_repr()));
+        assert_eq!(naive, *result);
+    }
+
+    // Check the GLV variant, which falls back to the full scalars on the curves without an endomorphism.
+    let glv_table = FixedBaseMSM::get_glv_window_table(window, g);
+    let glv = FixedBaseMSM::glv_multi_scalar_mul(window, &glv_table, &v);
+    assert_eq!(fast, glv);
+}
+
+#[test]
+fn fixed_base_test_with_bls12() {
+    fixed_base_test::<G1Projective>();
+    fixed_base_test::<G2Projective>();
+}
+
+#[test]
+fn fixed_base_test_with_bw6() {
+    fixed_base_test::<bw6_761::G1Projective>();
+    fixed_base_test::<bw6_761::G2Projective>();
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::max_num_bits;
+use snarkvm_curves::traits::{AffineCurve, Group, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Returns the window size for signed-digit bucketing over `num_points` points.
+pub(super) fn window_size(num_points: usize) -> usize {
+    if num_points < 32 {
+        3
+    } else {
+        (f64::from(num_points as u32)).ln().ceil() as usize
+    }
+}
+
+/// Returns the number of signed digits of width `window_size` needed to represent scalars of `num_bits` bits.
+pub(super) fn num_windows(num_bits: usize, window_size: usize) -> usize {
+    // The top bit of the last window is carried out of the representation, so it must be above the scalar's bits.
+    (num_bits + window_size) / window_size
+}
+
+/// Returns the signed base `2^c` digit of `scalar` in the given window, in `[-2^(c-1), 2^(c-1)]`.
+///
+/// The digit is the window's bits, plus the top bit of the window below it, minus `2^c` times its own top bit.
+/// As each digit only depends on `c + 1` bits of the scalar, the digits of a window can be computed on their own.
+pub(super) fn signed_digit<S: AsRef<[u64]>>(scalar: &S, window_size: usize, window: usize) -> i64 {
+    let limbs = scalar.as_ref();
+    let bit_offset = window * window_size;
+    let (limb, shift) = (bit_offset / 64, bit_offset % 64);
+
+    let mut bits = limbs.get(limb).map_or(0, |limb| limb >> shift);
+    if shift + window_size > 64 {
+        bits |= limbs.get(limb + 1).map_or(0, |limb| limb << (64 - shift));
+    }
+    let bits = (bits & ((1u64 << window_size) - 1)) as i64;
+
+    let carry_in = match bit_offset.checked_sub(1) {
+        Some(bit) => limbs.get(bit / 64).map_or(0, |limb| (limb >> (bit % 64)) & 1) as i64,
+        None => 0,
+    };
+    let carry_out = bits >> (window_size - 1);
+
+    bits + carry_in - (carry_out << window_size)
+}
+
+/// Returns the sum of the points assigned to each digit, weighted by the digit.
+///
+/// Each bucket holds an affine sum, to which points are added in batches of additions that share a single
+/// field inversion. A point whose bucket already has an addition in the current batch is added to a projective
+/// sum of the bucket instead. This keeps the memory to `O(num_buckets)`, independently of the number of points.
+/// Negative digits use the negated point.
+pub(super) fn sum_buckets<G: AffineCurve, I: IntoIterator<Item = (i64, G)>>(
+    assignments: I,
+    num_buckets: usize,
+) -> G::Projective {
+    // Batches cover a fraction of the buckets, so that few points collide with a pending addition.
+    let batch_size = (num_buckets / 4).clamp(1, MAX_BATCH_SIZE);
+
+    let mut affine_sums = vec![G::zero(); num_buckets];
+    let mut projective_sums = vec![G::Projective::zero(); num_buckets];
+    let mut is_pending = vec![false; num_buckets];
+    let mut batch = Batch::new(batch_size);
+
+    for (digit, point) in assignments {
+        let bucket = digit.unsigned_abs() as usize - 1;
+        let point = if digit > 0 { point } else { -point };
+
+        if is_pending[bucket] {
+            projective_sums[bucket].add_assign_mixed(&point);
+        } else if affine_sums[bucket].is_zero() {
+            affine_sums[bucket] = point;
+        } else {
+            is_pending[bucket] = true;
+            batch.buckets.push(bucket);
+            batch.points.push(point);
+            if batch.buckets.len() == batch_size {
+                batch.add_to(&mut affine_sums, &mut is_pending);
+            }
+        }
+    }
+    batch.add_to(&mut affine_sums, &mut is_pending);
+
+    // sum_k (k + 1) · bucket_k, as a running sum from the highest bucket.
+    let mut running_sum = G::Projective::zero();
+    let mut result = G::Projective::zero();
+    for (affine_sum, projective_sum) in affine_sums.iter().zip(&projective_sums).rev() {
+        running_sum += projective_sum;
+        running_sum.add_assign_mixed(affine_sum);
+        result += running_sum;
+    }
+    result
+}
+
+/// The maximum number of bucket additions that share a field inversion.
+const MAX_BATCH_SIZE: usize = 1 << 10;
+
+/// A batch of pending additions of points to the affine sums of distinct buckets.
+struct Batch<G: AffineCurve> {
+    buckets: Vec<usize>,
+    points: Vec<G>,
+    sums: Vec<G>,
+}
+
+impl<G: AffineCurve> Batch<G> {
+    fn new(batch_size: usize) -> Self {
+        Self {
+            buckets: Vec::with_capacity(batch_size),
+            points: Vec::with_capacity(batch_size),
+            sums: Vec::with_capacity(batch_size),
+        }
+    }
+
+    /// Adds the pending points to the affine sums of their buckets, and empties the batch.
+    fn add_to(&mut self, affine_sums: &mut [G], is_pending: &mut [bool]) {
+        self.sums.clear();
+        self.sums.extend(self.buckets.iter().map(|bucket| affine_sums[*bucket]));
+        G::batch_add_assign(&mut self.sums, &self.points);
+
+        for (bucket, sum) in self.buckets.iter().zip(&self.sums) {
+            affine_sums[*bucket] = *sum;
+            is_pending[*bucket] = false;
+        }
+        self.buckets.clear();
+        self.points.clear();
+    }
+}
+
+/// Computes the multi-scalar multiplication with signed-digit windows and batch affine bucket additions.
+pub fn msm_batched<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> G::Projective {
+    let size = std::cmp::min(bases.len(), scalars.len());
+    let (bases, scalars) = (&bases[..size], &scalars[..size]);
+
+    let c = window_size(size);
+    let num_windows = num_windows(max_num_bits(scalars), c);
+    let num_buckets = 1 << (c - 1);
+
+    // Each window computes its digits on the fly, so that its memory is bounded by its buckets.
+    let window_sums: Vec<_> = cfg_into_iter!(0..num_windows)
+        .map(|window| {
+            let assignments = scalars
+                .iter()
+                .zip(bases)
+                .map(|(scalar, base)| (signed_digit(scalar, c, window), *base))
+                .filter(|(digit, base)| *digit != 0 && !base.is_zero());
+            sum_buckets(assignments, num_buckets)
+        })
+        .collect();
+
+    // We're traversing windows from high to low.
+    window_sums.iter().rev().fold(G::Projective::zero(), |mut total, sum| {
+        for _ in 0..c {
+            total.double_in_place();
+        }
+        total + sum
+    })
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::batched::{num_windows, signed_digit, sum_buckets, window_size};
+use snarkvm_curves::traits::{AffineCurve, Group, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The multiples `2^(c·j) · base` of a fixed set of bases, such as the powers in a KZG10 committer key.
+///
+/// A multi-scalar multiplication over the precomputed bases sums all windows in a single
+/// set of buckets, with no doublings. This trades `num_windows` times the memory of the bases
+/// for faster multi-scalar multiplications, which pays off when the bases are reused.
+#[derive(Clone, Debug)]
+pub struct PrecomputedBases<G: AffineCurve> {
+    window_size: usize,
+    num_windows: usize,
+    /// The multiples of each base, ordered by base and then by window.
+    tables: Vec<G>,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Precomputes the multiples of `bases` for signed digits of width `window_size`.
+    pub fn new(bases: &[G], window_size: usize) -> Self {
+        assert!((2..64).contains(&window_size), "invalid window size {}", window_size);
+        let num_windows = num_windows(G::ScalarField::size_in_bits(), window_size);
+
+        let tables = cfg_iter!(bases)
+            .flat_map(|base| {
+                let mut multiple = base.into_projective();
+                let mut multiples = Vec::with_capacity(num_windows);
+                for _ in 0..num_windows {
+                    multiples.push(multiple);
+                    for _ in 0..window_size {
+                        multiple.double_in_place();
+                    }
+                }
+                G::Projective::batch_normalization_into_affine(multiples)
+            })
+            .collect();
+
+        Self {
+            window_size,
+            num_windows,
+            tables,
+        }
+    }
+
+    /// Returns the recommended window size for precomputing `num_bases` bases.
+    pub fn recommended_window_size(num_bases: usize) -> usize {
+        // All windows share a single set of buckets, which favours larger windows.
+        window_size(num_bases) + 3
+    }
+
+    /// Returns the number of precomputed bases.
+    pub fn num_bases(&self) -> usize {
+        self.tables.len() / self.num_windows
+    }
+
+    /// Returns the multi-scalar multiplication of the first `scalars.len()` precomputed bases with `scalars`.
+    pub fn multi_scalar_mul(&self, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        assert!(scalars.len() <= self.num_bases());
+
+        #[cfg(feature = "parallel")]
+        let num_chunks = rayon::current_num_threads();
+        #[cfg(not(feature = "parallel"))]
+        let num_chunks = 1;
+
+        let num_buckets = 1 << (self.window_size - 1);
+        let chunk_size = std::cmp::max(1, scalars.len().div_ceil(num_chunks));
+
+        let chunk_sums: Vec<_> = cfg_chunks!(scalars, chunk_size)
+            .zip(cfg_chunks!(self.tables, chunk_size * self.num_windows))
+            .map(|(scalars, tables)| {
+                let assignments = scalars
+                    .iter()
+                    .zip(tables.chunks(self.num_windows))
+                    .flat_map(|(scalar, multiples)| {
+                        multiples
+                            .iter()
+                            .enumerate()
+                            .map(move |(window, multiple)| (signed_digit(scalar, self.window_size, window), *multiple))
+                    })
+                    .filter(|(digit, multiple)| *digit != 0 && !multiple.is_zero());
+                sum_buckets(assignments, num_buckets)
+            })
+            .collect();
+
+        chunk_sums
+            .into_iter()
+            .fold(G::Projective::zero(), |total, sum| total + sum)
+    }
+}
@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::max_num_bits;
 use snarkvm_curves::{traits::AffineCurve, Group, ProjectiveCurve};
-use snarkvm_fields::{FieldParameters, One, PrimeField, Zero};
+use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::BigInteger;
 
 #[cfg(feature = "parallel")]
@@ -31,7 +32,7 @@ pub fn msm_standard<G: AffineCurve>(
         (2.0 / 3.0 * (f64::from(scalars.len() as u32)).log2() + 2.0).ceil() as usize
     };
 
-    let num_bits = <G::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize;
+    let num_bits = max_num_bits(scalars);
     let fr_one = G::ScalarField::one().into_repr();
 
     let zero = G::zero().into_projective();
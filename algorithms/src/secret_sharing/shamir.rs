@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::traits::Group;
+use snarkvm_fields::{Field, PrimeField};
+
+use digest::Digest;
+
+/// Returns the evaluation at `index` of the polynomial with the given coefficients.
+pub fn evaluate_polynomial<F: Field>(coefficients: &[F], index: u16) -> F {
+    let x = F::from(index);
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+/// Returns the evaluation at `index` of the polynomial committed to by `commitments`,
+/// where each commitment is `a_k · g` for the coefficient `a_k`.
+pub fn evaluate_commitments<G: Group>(commitments: &[G], index: u16) -> G {
+    let x = G::ScalarField::from(index);
+    commitments
+        .iter()
+        .rev()
+        .fold(G::zero(), |acc, commitment| acc * x + commitment)
+}
+
+/// Returns the Lagrange coefficient at zero of the participant `index`, for the set of participants `indices`.
+pub fn lagrange_coefficient<F: Field>(index: u16, indices: &[u16]) -> Option<F> {
+    let x_i = F::from(index);
+    let mut numerator = F::one();
+    let mut denominator = F::one();
+    for &j in indices.iter().filter(|&&j| j != index) {
+        let x_j = F::from(j);
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+    denominator.inverse().map(|inverse| numerator * inverse)
+}
+
+/// Returns two digests of `prefix || domain || input` reduced modulo the field, so the bias is negligible.
+pub fn hash_to_scalar<F: PrimeField, D: Digest>(prefix: &[u8], input: &[u8]) -> F {
+    let base = F::from(256u16);
+    [0u8, 1u8]
+        .iter()
+        .flat_map(|domain| D::digest(&[prefix, &[*domain], input].concat()))
+        .fold(F::zero(), |acc, byte| acc * base + F::from(byte))
+}
@@ -0,0 +1,408 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::SignatureError,
+    traits::{HashToCurve, SignatureScheme},
+};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, Zero};
+use snarkvm_utilities::{
+    bytes::{bytes_to_bits, FromBytes, ToBytes},
+    errors::SerializationError,
+    rand::UniformRand,
+    serialize::*,
+    to_bytes,
+};
+
+use rand::Rng;
+use std::{
+    collections::HashSet,
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::Mul,
+};
+
+/// The domain separation tag for hashing messages to G1.
+///
+/// Messages are hashed with the RFC 9380 suite for this tag, so signatures and proofs of possession
+/// created with the previous `AleoBLSSignature_G1` and `AleoBLSPossession_G1` domains no longer verify.
+const BLS_SIGNATURE_DOMAIN: &[u8] = b"BLS_SIG_BLS12377G1_XMD:SHA-256_SVDW_RO_POP_";
+
+/// The domain separation tag for hashing public keys to G1 in proofs of possession.
+const BLS_POSSESSION_DOMAIN: &[u8] = b"BLS_POP_BLS12377G1_XMD:SHA-256_SVDW_RO_POP_";
+
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "E: PairingEngine"),
+    Clone(bound = "E: PairingEngine"),
+    PartialEq(bound = "E: PairingEngine"),
+    Eq(bound = "E: PairingEngine"),
+    Debug(bound = "E: PairingEngine"),
+    Default(bound = "E: PairingEngine")
+)]
+pub struct BLSOutput<E: PairingEngine>(pub E::G1Affine);
+
+impl<E: PairingEngine> ToBytes for BLSOutput<E> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.0.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for BLSOutput<E> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self(E::G1Affine::read(&mut reader)?))
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "E: PairingEngine"),
+    Clone(bound = "E: PairingEngine"),
+    PartialEq(bound = "E: PairingEngine"),
+    Eq(bound = "E: PairingEngine"),
+    Debug(bound = "E: PairingEngine"),
+    Hash(bound = "E: PairingEngine"),
+    Default(bound = "E: PairingEngine")
+)]
+pub struct BLSPublicKey<E: PairingEngine>(pub E::G2Affine);
+
+impl<E: PairingEngine> ToBytes for BLSPublicKey<E> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.0.write(&mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for BLSPublicKey<E> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self(E::G2Affine::read(&mut reader)?))
+    }
+}
+
+impl<E: PairingEngine> CanonicalSerialize for BLSPublicKey<E> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.0.serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.0.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.0.uncompressed_size()
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for BLSPublicKey<E> {
+    #[inline]
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self(E::G2Affine::deserialize(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self(E::G2Affine::deserialize_uncompressed(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self(E::G2Affine::deserialize_unchecked(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Self(E::G2Affine::deserialize_uncompressed_unchecked(reader)?))
+    }
+}
+
+/// BLS signatures over a pairing-friendly curve, with signatures in G1 and public keys in G2.
+///
+/// Signatures on distinct messages aggregate into a single G1 element, verified with one
+/// product of pairings. Signatures on a common message may also be aggregated, in which
+/// case every public key must come with a valid proof of possession to rule out rogue keys.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "E: PairingEngine"),
+    Debug(bound = "E: PairingEngine"),
+    PartialEq(bound = "E: PairingEngine"),
+    Eq(bound = "E: PairingEngine")
+)]
+pub struct BLSSignature<E: PairingEngine> {
+    _engine: PhantomData<E>,
+}
+
+impl<E: PairingEngine> BLSSignature<E>
+where
+    E::G1Affine: HashToCurve,
+{
+    /// Returns the hash of `message` in G1.
+    pub fn hash_message(message: &[u8]) -> E::G1Affine {
+        E::G1Affine::hash_to_curve(message, BLS_SIGNATURE_DOMAIN)
+    }
+
+    /// Returns `e(signature, g2) == prod_i e(hash_i, public_key_i)`, as a single product of pairings.
+    fn verify_pairing<'a, I: Iterator<Item = (E::G1Affine, &'a BLSPublicKey<E>)>>(
+        signature: &E::G1Affine,
+        pairs: I,
+    ) -> bool {
+        let mut g1_prepared = vec![signature.prepare()];
+        let mut g2_prepared = vec![(-E::G2Affine::prime_subgroup_generator()).prepare()];
+        for (hash, public_key) in pairs {
+            g1_prepared.push(hash.prepare());
+            g2_prepared.push(public_key.0.prepare());
+        }
+
+        E::product_of_pairings(g1_prepared.iter().zip(g2_prepared.iter())).is_one()
+    }
+
+    /// Returns the sum of the given signatures.
+    pub fn aggregate_signatures(signatures: &[BLSOutput<E>]) -> BLSOutput<E> {
+        let aggregate = signatures
+            .iter()
+            .fold(E::G1Projective::zero(), |acc, signature| acc + signature.0.into_projective());
+        BLSOutput(aggregate.into_affine())
+    }
+
+    /// Returns the sum of the given public keys.
+    ///
+    /// The aggregate public key is only meaningful if every public key has a valid proof of possession.
+    pub fn aggregate_public_keys(public_keys: &[BLSPublicKey<E>]) -> BLSPublicKey<E> {
+        let aggregate = public_keys
+            .iter()
+            .fold(E::G2Projective::zero(), |acc, public_key| acc + public_key.0.into_projective());
+        BLSPublicKey(aggregate.into_affine())
+    }
+
+    /// Verifies an aggregate signature on pairwise distinct messages.
+    pub fn verify_aggregate(
+        &self,
+        public_keys: &[BLSPublicKey<E>],
+        messages: &[&[u8]],
+        aggregate_signature: &BLSOutput<E>,
+    ) -> Result<bool, SignatureError> {
+        let verify_time = start_timer!(|| "BLSSignature::verify_aggregate");
+
+        if public_keys.len() != messages.len() {
+            return Err(SignatureError::Message(format!(
+                "mismatched number of public keys ({}) and messages ({})",
+                public_keys.len(),
+                messages.len()
+            )));
+        }
+
+        // Aggregation over repeated messages is only secure with proofs of possession.
+        let mut seen = HashSet::with_capacity(messages.len());
+        if public_keys.is_empty()
+            || aggregate_signature.0.is_zero()
+            || public_keys.iter().any(|public_key| public_key.0.is_zero())
+            || !messages.iter().all(|message| seen.insert(message))
+        {
+            end_timer!(verify_time);
+            return Ok(false);
+        }
+
+        let hashes = messages.iter().map(|message| Self::hash_message(message));
+        let result = Self::verify_pairing(&aggregate_signature.0, hashes.zip(public_keys));
+
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    /// Verifies an aggregate signature of a common message, assuming each public key
+    /// has been checked with `verify_possession`.
+    pub fn verify_fast_aggregate(
+        &self,
+        public_keys: &[BLSPublicKey<E>],
+        message: &[u8],
+        aggregate_signature: &BLSOutput<E>,
+    ) -> Result<bool, SignatureError> {
+        if public_keys.is_empty() || public_keys.iter().any(|public_key| public_key.0.is_zero()) {
+            return Ok(false);
+        }
+
+        let aggregate_public_key = Self::aggregate_public_keys(public_keys);
+        self.verify(&aggregate_public_key, message, aggregate_signature)
+    }
+
+    /// Verifies a batch of independent signatures using random linear combinations.
+    ///
+    /// This computes a single product of `n + 1` pairings, instead of `n` pairing checks.
+    pub fn batch_verify<R: Rng>(
+        &self,
+        public_keys: &[BLSPublicKey<E>],
+        messages: &[&[u8]],
+        signatures: &[BLSOutput<E>],
+        rng: &mut R,
+    ) -> Result<bool, SignatureError> {
+        let verify_time = start_timer!(|| "BLSSignature::batch_verify");
+
+        if public_keys.len() != messages.len() || public_keys.len() != signatures.len() {
+            return Err(SignatureError::Message(format!(
+                "mismatched number of public keys ({}), messages ({}) and signatures ({})",
+                public_keys.len(),
+                messages.len(),
+                signatures.len()
+            )));
+        }
+
+        if public_keys.iter().any(|public_key| public_key.0.is_zero())
+            || signatures.iter().any(|signature| signature.0.is_zero())
+        {
+            end_timer!(verify_time);
+            return Ok(false);
+        }
+
+        let mut combined_signature = E::G1Projective::zero();
+        let mut scaled_hashes = Vec::with_capacity(messages.len());
+        for (message, signature) in messages.iter().zip(signatures) {
+            let randomizer = E::Fr::rand(rng);
+            combined_signature += &signature.0.mul(randomizer).into_projective();
+            scaled_hashes.push(Self::hash_message(message).mul(randomizer));
+        }
+
+        let result = Self::verify_pairing(
+            &combined_signature.into_affine(),
+            scaled_hashes.into_iter().zip(public_keys),
+        );
+
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    /// Returns a proof that the holder of `private_key` knows the discrete logarithm of its public key.
+    pub fn prove_possession(&self, private_key: &E::Fr) -> Result<BLSOutput<E>, SignatureError> {
+        let public_key = self.generate_public_key(private_key)?;
+        let hash = E::G1Affine::hash_to_curve(&to_bytes![public_key]?, BLS_POSSESSION_DOMAIN);
+        Ok(BLSOutput(hash.mul(*private_key)))
+    }
+
+    /// Verifies a proof of possession for `public_key`.
+    pub fn verify_possession(&self, public_key: &BLSPublicKey<E>, proof: &BLSOutput<E>) -> Result<bool, SignatureError> {
+        if public_key.0.is_zero() || proof.0.is_zero() {
+            return Ok(false);
+        }
+
+        let hash = E::G1Affine::hash_to_curve(&to_bytes![public_key]?, BLS_POSSESSION_DOMAIN);
+        Ok(Self::verify_pairing(&proof.0, std::iter::once((hash, public_key))))
+    }
+
+    /// Returns the scalar encoded by the little-endian bits of `randomness`.
+    fn randomizer(randomness: &[u8]) -> E::Fr {
+        let mut base = E::Fr::one();
+        let mut randomizer = E::Fr::zero();
+        for bit in bytes_to_bits(randomness) {
+            if bit {
+                randomizer += &base;
+            }
+            base.double_in_place();
+        }
+        randomizer
+    }
+}
+
+impl<E: PairingEngine> SignatureScheme for BLSSignature<E>
+where
+    E::G1Affine: HashToCurve,
+{
+    type Output = BLSOutput<E>;
+    type Parameters = ();
+    type PrivateKey = E::Fr;
+    type PublicKey = BLSPublicKey<E>;
+
+    fn setup<R: Rng>(_: &mut R) -> Result<Self, SignatureError> {
+        Ok(Self { _engine: PhantomData })
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &()
+    }
+
+    fn generate_private_key<R: Rng>(&self, rng: &mut R) -> Result<Self::PrivateKey, SignatureError> {
+        let keygen_time = start_timer!(|| "BLSSignature::generate_private_key");
+        let mut private_key = E::Fr::rand(rng);
+        while private_key.is_zero() {
+            private_key = E::Fr::rand(rng);
+        }
+        end_timer!(keygen_time);
+        Ok(private_key)
+    }
+
+    fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Result<Self::PublicKey, SignatureError> {
+        let keygen_time = start_timer!(|| "BLSSignature::generate_public_key");
+        let public_key = E::G2Affine::prime_subgroup_generator().mul(*private_key);
+        end_timer!(keygen_time);
+        Ok(BLSPublicKey(public_key))
+    }
+
+    fn sign<R: Rng>(
+        &self,
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        _: &mut R,
+    ) -> Result<Self::Output, SignatureError> {
+        let sign_time = start_timer!(|| "BLSSignature::sign");
+        let signature = Self::hash_message(message).mul(*private_key);
+        end_timer!(sign_time);
+        Ok(BLSOutput(signature))
+    }
+
+    fn verify(
+        &self,
+        public_key: &Self::PublicKey,
+        message: &[u8],
+        signature: &Self::Output,
+    ) -> Result<bool, SignatureError> {
+        let verify_time = start_timer!(|| "BLSSignature::verify");
+        if public_key.0.is_zero() || signature.0.is_zero() {
+            end_timer!(verify_time);
+            return Ok(false);
+        }
+
+        let result = Self::verify_pairing(&signature.0, std::iter::once((Self::hash_message(message), public_key)));
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    fn randomize_public_key(
+        &self,
+        public_key: &Self::PublicKey,
+        randomness: &[u8],
+    ) -> Result<Self::PublicKey, SignatureError> {
+        Ok(BLSPublicKey(public_key.0.mul(Self::randomizer(randomness))))
+    }
+
+    fn randomize_signature(&self, signature: &Self::Output, randomness: &[u8]) -> Result<Self::Output, SignatureError> {
+        Ok(BLSOutput(signature.0.mul(Self::randomizer(randomness))))
+    }
+}
+
+impl<E: PairingEngine> From<()> for BLSSignature<E> {
+    fn from(_: ()) -> Self {
+        Self { _engine: PhantomData }
+    }
+}
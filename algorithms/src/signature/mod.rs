@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod bls;
+pub use bls::*;
+
 pub mod group;
 pub use group::*;
 
@@ -23,5 +26,8 @@ pub use schnorr::*;
 pub mod schnorr_parameters;
 pub use schnorr_parameters::*;
 
+pub mod threshold_schnorr;
+pub use threshold_schnorr::*;
+
 #[cfg(test)]
 mod tests;
@@ -14,12 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{encryption::GroupEncryption, signature::SchnorrSignature, traits::SignatureScheme};
+use crate::{
+    encryption::GroupEncryption,
+    signature::{BLSOutput, BLSPublicKey, BLSSignature, SchnorrOutput, SchnorrSignature, ThresholdSchnorrKeyShare},
+    traits::SignatureScheme,
+};
 use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fr, G1Affine, G2Affine},
     edwards_bls12::{EdwardsAffine, EdwardsProjective},
     edwards_sw6::EdwardsAffine as Edwards,
     traits::Group,
 };
+use snarkvm_fields::Zero;
 use snarkvm_utilities::{
     bytes::{FromBytes, ToBytes},
     rand::UniformRand,
@@ -32,6 +38,7 @@ use rand_xorshift::XorShiftRng;
 
 type TestSignature = SchnorrSignature<Edwards, Blake2s>;
 type TestGroupEncryptionSignature = GroupEncryption<EdwardsProjective, EdwardsAffine, Blake2s>;
+type TestBLSSignature = BLSSignature<Bls12_377>;
 
 fn sign_and_verify<S: SignatureScheme>(message: &[u8]) {
     let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
@@ -108,3 +115,300 @@ fn group_encryption_signature_test() {
 fn group_encryption_signature_scheme_parameters_serialization() {
     signature_scheme_parameter_serialization::<TestGroupEncryptionSignature>();
 }
+
+#[test]
+fn bls_signature_test() {
+    let message = "Hi, I am a BLS signature!";
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    sign_and_verify::<TestBLSSignature>(message.as_bytes());
+    failed_verification::<TestBLSSignature>(message.as_bytes(), b"Bad message");
+    let random_scalar = to_bytes!(Fr::rand(rng)).unwrap();
+    randomize_and_verify::<TestBLSSignature>(message.as_bytes(), &random_scalar.as_slice());
+}
+
+#[test]
+fn bls_aggregate_signature_test() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let bls = TestBLSSignature::setup(rng).unwrap();
+
+    let messages: Vec<&[u8]> = vec![b"first message", b"second message", b"third message"];
+    let private_keys = (0..messages.len())
+        .map(|_| bls.generate_private_key(rng).unwrap())
+        .collect::<Vec<_>>();
+    let public_keys = private_keys
+        .iter()
+        .map(|private_key| bls.generate_public_key(private_key).unwrap())
+        .collect::<Vec<_>>();
+    let signatures = private_keys
+        .iter()
+        .zip(&messages)
+        .map(|(private_key, message)| bls.sign(private_key, message, rng).unwrap())
+        .collect::<Vec<_>>();
+
+    let aggregate_signature = TestBLSSignature::aggregate_signatures(&signatures);
+    assert!(bls.verify_aggregate(&public_keys, &messages, &aggregate_signature).unwrap());
+
+    // Swapping two messages must fail.
+    let swapped_messages: Vec<&[u8]> = vec![messages[1], messages[0], messages[2]];
+    assert!(!bls.verify_aggregate(&public_keys, &swapped_messages, &aggregate_signature).unwrap());
+
+    // Repeated messages are rejected.
+    let repeated_messages: Vec<&[u8]> = vec![messages[0], messages[0], messages[2]];
+    assert!(!bls.verify_aggregate(&public_keys, &repeated_messages, &aggregate_signature).unwrap());
+
+    // Mismatched lengths are an error.
+    assert!(bls.verify_aggregate(&public_keys[..2], &messages, &aggregate_signature).is_err());
+}
+
+#[test]
+fn bls_fast_aggregate_signature_test() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let bls = TestBLSSignature::setup(rng).unwrap();
+    let message = b"common message";
+
+    let private_keys = (0..4).map(|_| bls.generate_private_key(rng).unwrap()).collect::<Vec<_>>();
+    let public_keys = private_keys
+        .iter()
+        .map(|private_key| bls.generate_public_key(private_key).unwrap())
+        .collect::<Vec<_>>();
+
+    for (private_key, public_key) in private_keys.iter().zip(&public_keys) {
+        let proof = bls.prove_possession(private_key).unwrap();
+        assert!(bls.verify_possession(public_key, &proof).unwrap());
+        assert!(!bls.verify_possession(&public_keys[0], &bls.sign(private_key, message, rng).unwrap()).unwrap());
+    }
+
+    let signatures = private_keys
+        .iter()
+        .map(|private_key| bls.sign(private_key, message, rng).unwrap())
+        .collect::<Vec<_>>();
+    let aggregate_signature = TestBLSSignature::aggregate_signatures(&signatures);
+
+    assert!(bls.verify_fast_aggregate(&public_keys, message, &aggregate_signature).unwrap());
+    assert!(!bls.verify_fast_aggregate(&public_keys[1..], message, &aggregate_signature).unwrap());
+    assert!(!bls.verify_fast_aggregate(&public_keys, b"Bad message", &aggregate_signature).unwrap());
+}
+
+#[test]
+fn bls_batch_verification_test() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let bls = TestBLSSignature::setup(rng).unwrap();
+
+    let messages: Vec<&[u8]> = vec![b"first message", b"second message", b"first message"];
+    let private_keys = (0..messages.len())
+        .map(|_| bls.generate_private_key(rng).unwrap())
+        .collect::<Vec<_>>();
+    let public_keys = private_keys
+        .iter()
+        .map(|private_key| bls.generate_public_key(private_key).unwrap())
+        .collect::<Vec<_>>();
+    let mut signatures = private_keys
+        .iter()
+        .zip(&messages)
+        .map(|(private_key, message)| bls.sign(private_key, message, rng).unwrap())
+        .collect::<Vec<_>>();
+
+    assert!(bls.batch_verify(&public_keys, &messages, &signatures, rng).unwrap());
+
+    // A single invalid signature invalidates the batch.
+    signatures.swap(0, 1);
+    assert!(!bls.batch_verify(&public_keys, &messages, &signatures, rng).unwrap());
+}
+
+#[test]
+fn bls_identity_rejection_test() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let bls = TestBLSSignature::setup(rng).unwrap();
+
+    let message: &[u8] = b"message";
+    let private_key = bls.generate_private_key(rng).unwrap();
+    let public_key = bls.generate_public_key(&private_key).unwrap();
+    let signature = bls.sign(&private_key, message, rng).unwrap();
+
+    // The identity public key with the identity signature satisfies the pairing equation.
+    let zero_public_key = BLSPublicKey::<Bls12_377>(G2Affine::zero());
+    let zero_signature = BLSOutput::<Bls12_377>(G1Affine::zero());
+
+    assert!(!bls.verify(&zero_public_key, message, &zero_signature).unwrap());
+    assert!(!bls.verify(&public_key, message, &zero_signature).unwrap());
+    assert!(!bls.verify_possession(&zero_public_key, &zero_signature).unwrap());
+    assert!(!bls.verify_possession(&public_key, &zero_signature).unwrap());
+
+    let zero_public_keys = [zero_public_key.clone()];
+    let mixed_public_keys = [public_key, zero_public_key];
+    assert!(!bls.verify_aggregate(&zero_public_keys, &[message], &zero_signature).unwrap());
+    assert!(!bls.verify_aggregate(&mixed_public_keys, &[message, b"other message"], &signature).unwrap());
+    assert!(!bls.verify_fast_aggregate(&zero_public_keys, message, &zero_signature).unwrap());
+    assert!(!bls.verify_fast_aggregate(&mixed_public_keys, message, &signature).unwrap());
+
+    // A pair of identities must not pass in a batch, on its own or next to a valid signature.
+    let zero_signatures = [zero_signature.clone()];
+    let mixed_signatures = [signature, zero_signature];
+    assert!(!bls.batch_verify(&zero_public_keys, &[message], &zero_signatures, rng).unwrap());
+    assert!(!bls.batch_verify(&mixed_public_keys, &[message, message], &mixed_signatures, rng).unwrap());
+}
+
+#[test]
+fn bls_signature_serialization() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let bls = TestBLSSignature::setup(rng).unwrap();
+    let private_key = bls.generate_private_key(rng).unwrap();
+    let public_key = bls.generate_public_key(&private_key).unwrap();
+    let signature = bls.sign(&private_key, b"message", rng).unwrap();
+
+    let public_key_bytes = to_bytes![public_key].unwrap();
+    assert_eq!(public_key, FromBytes::read(&public_key_bytes[..]).unwrap());
+
+    let signature_bytes = to_bytes![signature].unwrap();
+    assert_eq!(signature, FromBytes::read(&signature_bytes[..]).unwrap());
+}
+
+fn threshold_schnorr_dkg(
+    schnorr: &TestSignature,
+    threshold: u16,
+    num_participants: u16,
+    rng: &mut XorShiftRng,
+) -> Vec<ThresholdSchnorrKeyShare<Edwards>> {
+    let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+        .map(|index| schnorr.dkg_commit(index, threshold, num_participants, rng).unwrap())
+        .unzip();
+    let shares = secrets
+        .iter()
+        .flat_map(|secret| schnorr.dkg_distribute(secret, &commitments).unwrap())
+        .collect::<Vec<_>>();
+
+    secrets
+        .iter()
+        .zip(1..=num_participants)
+        .map(|(secret, index)| {
+            let received = shares
+                .iter()
+                .filter(|share| share.receiver == index)
+                .cloned()
+                .collect::<Vec<_>>();
+            schnorr.dkg_finalize(secret, &commitments, &received).unwrap()
+        })
+        .collect()
+}
+
+fn threshold_schnorr_sign(
+    schnorr: &TestSignature,
+    key_shares: &[&ThresholdSchnorrKeyShare<Edwards>],
+    message: &[u8],
+    rng: &mut XorShiftRng,
+) -> SchnorrOutput<Edwards> {
+    let (nonces, commitments): (Vec<_>, Vec<_>) = key_shares
+        .iter()
+        .map(|key_share| schnorr.commit_nonces(key_share.index, rng).unwrap())
+        .unzip();
+    let shares = key_shares
+        .iter()
+        .zip(nonces)
+        .map(|(key_share, nonces)| schnorr.sign_share(key_share, nonces, message, &commitments).unwrap())
+        .collect::<Vec<_>>();
+
+    schnorr
+        .aggregate_signature_shares(&key_shares[0].verification_keys, message, &commitments, &shares)
+        .unwrap()
+}
+
+#[test]
+fn threshold_schnorr_signature_test() {
+    let message = b"Hi, I am a threshold Schnorr signature!";
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let schnorr = TestSignature::setup(rng).unwrap();
+
+    let key_shares = threshold_schnorr_dkg(&schnorr, 3, 5, rng);
+    let public_key = key_shares[0].public_key;
+    for key_share in &key_shares {
+        assert_eq!(public_key, key_share.public_key);
+        assert_eq!(key_shares[0].verification_keys, key_share.verification_keys);
+    }
+
+    // Any subset of at least `threshold` participants can sign.
+    for signers in &[vec![0, 2, 4], vec![1, 3, 4], vec![0, 1, 2, 3]] {
+        let signers = signers.iter().map(|&i| &key_shares[i]).collect::<Vec<_>>();
+        let signature = threshold_schnorr_sign(&schnorr, &signers, message, rng);
+        assert!(schnorr.verify(&public_key, message, &signature).unwrap());
+        assert!(!schnorr.verify(&public_key, b"Bad message", &signature).unwrap());
+
+        let randomness = to_bytes!(<Edwards as Group>::ScalarField::rand(rng)).unwrap();
+        let randomized_public_key = schnorr.randomize_public_key(&public_key, &randomness).unwrap();
+        let randomized_signature = schnorr.randomize_signature(&signature, &randomness).unwrap();
+        assert!(schnorr
+            .verify(&randomized_public_key, message, &randomized_signature)
+            .unwrap());
+    }
+}
+
+#[test]
+fn threshold_schnorr_split_private_key_test() {
+    let message = b"Hi, I am a threshold Schnorr signature!";
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let schnorr = TestSignature::setup(rng).unwrap();
+
+    let private_key = schnorr.generate_private_key(rng).unwrap();
+    let public_key = schnorr.generate_public_key(&private_key).unwrap();
+    let key_shares = schnorr.split_private_key(&private_key, 2, 3, rng).unwrap();
+    assert_eq!(public_key, key_shares[0].public_key);
+
+    let signature = threshold_schnorr_sign(&schnorr, &[&key_shares[2], &key_shares[0]], message, rng);
+    assert!(schnorr.verify(&public_key, message, &signature).unwrap());
+
+    assert!(schnorr.split_private_key(&private_key, 4, 3, rng).is_err());
+    assert!(schnorr.split_private_key(&private_key, 0, 3, rng).is_err());
+}
+
+#[test]
+fn threshold_schnorr_invalid_shares_test() {
+    let message = b"Hi, I am a threshold Schnorr signature!";
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let schnorr = TestSignature::setup(rng).unwrap();
+
+    // A tampered key generation share is rejected.
+    let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=3)
+        .map(|index| schnorr.dkg_commit(index, 2, 3, rng).unwrap())
+        .unzip();
+    let mut shares = secrets
+        .iter()
+        .flat_map(|secret| schnorr.dkg_distribute(secret, &commitments).unwrap())
+        .filter(|share| share.receiver == 1)
+        .collect::<Vec<_>>();
+    assert!(schnorr.dkg_finalize(&secrets[0], &commitments, &shares).is_ok());
+    assert!(schnorr.dkg_finalize(&secrets[0], &commitments, &shares[1..]).is_err());
+    assert!(schnorr.dkg_distribute(&secrets[0], &commitments[1..]).is_err());
+    shares[0].share += <Edwards as Group>::ScalarField::rand(rng);
+    assert!(schnorr.dkg_finalize(&secrets[0], &commitments, &shares).is_err());
+
+    // A tampered signature share is rejected.
+    let key_shares = threshold_schnorr_dkg(&schnorr, 2, 3, rng);
+    let (nonces, commitments): (Vec<_>, Vec<_>) = key_shares[..2]
+        .iter()
+        .map(|key_share| schnorr.commit_nonces(key_share.index, rng).unwrap())
+        .unzip();
+    let mut shares = key_shares
+        .iter()
+        .zip(nonces)
+        .map(|(key_share, nonces)| schnorr.sign_share(key_share, nonces, message, &commitments).unwrap())
+        .collect::<Vec<_>>();
+    let verification_keys = &key_shares[0].verification_keys;
+    assert!(schnorr
+        .verify_share(verification_keys, message, &commitments, &shares[0])
+        .unwrap());
+    assert!(!schnorr
+        .verify_share(verification_keys, b"Bad message", &commitments, &shares[0])
+        .unwrap());
+    shares[1].response += <Edwards as Group>::ScalarField::rand(rng);
+    assert!(!schnorr
+        .verify_share(verification_keys, message, &commitments, &shares[1])
+        .unwrap());
+    assert!(schnorr
+        .aggregate_signature_shares(verification_keys, message, &commitments, &shares)
+        .is_err());
+
+    // Fewer than `threshold` signers cannot sign.
+    let (nonces, commitment) = schnorr.commit_nonces(key_shares[0].index, rng).unwrap();
+    assert!(schnorr
+        .sign_share(&key_shares[0], nonces, message, &[commitment])
+        .is_err());
+}
@@ -0,0 +1,586 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::SignatureError,
+    secret_sharing::{evaluate_commitments, evaluate_polynomial, hash_to_scalar, lagrange_coefficient},
+    signature::{SchnorrOutput, SchnorrPublicKey, SchnorrSignature},
+    traits::SignatureScheme,
+};
+use snarkvm_curves::traits::Group;
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::{rand::UniformRand, serialize::*, to_bytes, ToBytes};
+
+use digest::Digest;
+use rand::Rng;
+use std::{collections::HashSet, hash::Hash};
+
+const THRESHOLD_SCHNORR_POSSESSION_DOMAIN: &[u8] = b"SNARKVM_THRESHOLD_SCHNORR_POSSESSION";
+const THRESHOLD_SCHNORR_BINDING_DOMAIN: &[u8] = b"SNARKVM_THRESHOLD_SCHNORR_BINDING";
+
+/// Ensures `0 < threshold <= num_participants`.
+fn check_threshold(threshold: u16, num_participants: u16) -> Result<(), SignatureError> {
+    if threshold == 0 || threshold > num_participants {
+        return Err(SignatureError::Message(format!(
+            "invalid threshold {} for {} participants",
+            threshold, num_participants
+        )));
+    }
+    Ok(())
+}
+
+/// Ensures `0 < index <= num_participants`.
+fn check_index(index: u16, num_participants: u16) -> Result<(), SignatureError> {
+    if index == 0 || index > num_participants {
+        return Err(SignatureError::Message(format!(
+            "invalid participant index {} for {} participants",
+            index, num_participants
+        )));
+    }
+    Ok(())
+}
+
+/// The secret polynomial of a participant, kept between the rounds of distributed key generation.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G: Group"), Debug(bound = "G: Group"))]
+pub struct ThresholdSchnorrDKGSecret<G: Group> {
+    index: u16,
+    threshold: u16,
+    num_participants: u16,
+    coefficients: Vec<<G as Group>::ScalarField>,
+}
+
+/// The commitments each participant broadcasts in the first round of distributed key generation.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G: Group"), Debug(bound = "G: Group"))]
+pub struct ThresholdSchnorrDKGCommitment<G: Group> {
+    pub index: u16,
+    /// The commitments `a_k · g` to the coefficients of the participant's secret polynomial.
+    pub commitments: Vec<G>,
+    /// A Schnorr signature under `a_0`, proving knowledge of the participant's secret.
+    pub proof_of_possession: SchnorrOutput<G>,
+}
+
+/// The secret share a participant privately sends to another participant in the second round
+/// of distributed key generation.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G: Group"), Debug(bound = "G: Group"))]
+pub struct ThresholdSchnorrDKGShare<G: Group> {
+    pub sender: u16,
+    pub receiver: u16,
+    pub share: <G as Group>::ScalarField,
+}
+
+/// A participant's share of a threshold Schnorr private key.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "G: Group + CanonicalSerialize + CanonicalDeserialize"),
+    Debug(bound = "G: Group + CanonicalSerialize + CanonicalDeserialize"),
+    PartialEq(bound = "G: Group + CanonicalSerialize + CanonicalDeserialize"),
+    Eq(bound = "G: Group + CanonicalSerialize + CanonicalDeserialize")
+)]
+pub struct ThresholdSchnorrKeyShare<G: Group + CanonicalSerialize + CanonicalDeserialize> {
+    pub index: u16,
+    pub threshold: u16,
+    pub private_key_share: <G as Group>::ScalarField,
+    /// The verification keys `x_j · g` of all participants, ordered by index.
+    pub verification_keys: Vec<G>,
+    /// The public key of the group, as used by `SchnorrSignature::verify`.
+    pub public_key: SchnorrPublicKey<G>,
+}
+
+/// The secret nonces of a participant for a single signing session.
+///
+/// The nonces are consumed by `sign_share` and must never be reused.
+#[derive(Derivative)]
+#[derivative(Debug(bound = "G: Group"))]
+pub struct ThresholdSchnorrNonces<G: Group> {
+    index: u16,
+    hiding: <G as Group>::ScalarField,
+    binding: <G as Group>::ScalarField,
+}
+
+/// The commitments a participant broadcasts to its nonces for a single signing session.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "G: Group"),
+    Clone(bound = "G: Group"),
+    Debug(bound = "G: Group"),
+    PartialEq(bound = "G: Group"),
+    Eq(bound = "G: Group")
+)]
+pub struct ThresholdSchnorrNonceCommitment<G: Group> {
+    pub index: u16,
+    pub hiding: G,
+    pub binding: G,
+}
+
+/// A participant's share of a threshold Schnorr signature.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "G: Group"), Debug(bound = "G: Group"))]
+pub struct ThresholdSchnorrSignatureShare<G: Group> {
+    pub index: u16,
+    pub response: <G as Group>::ScalarField,
+}
+
+impl<G: Group + Hash + CanonicalSerialize + CanonicalDeserialize, D: Digest + Send + Sync> SchnorrSignature<G, D>
+where
+    <G as Group>::ScalarField: PrimeField,
+{
+    /// Starts distributed key generation for the participant `index`, out of `num_participants`
+    /// with a signing threshold of `threshold`.
+    ///
+    /// Returns the secret state of the participant, and the commitment to broadcast to all participants.
+    pub fn dkg_commit<R: Rng>(
+        &self,
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(ThresholdSchnorrDKGSecret<G>, ThresholdSchnorrDKGCommitment<G>), SignatureError> {
+        check_threshold(threshold, num_participants)?;
+        check_index(index, num_participants)?;
+
+        let coefficients = (0..threshold)
+            .map(|_| <G as Group>::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let commitments = self.commit_coefficients(&coefficients)?;
+        let proof_of_possession = self.sign(
+            &coefficients[0],
+            &Self::possession_message(index, &commitments[0])?,
+            rng,
+        )?;
+
+        let secret = ThresholdSchnorrDKGSecret {
+            index,
+            threshold,
+            num_participants,
+            coefficients,
+        };
+        let commitment = ThresholdSchnorrDKGCommitment {
+            index,
+            commitments,
+            proof_of_possession,
+        };
+        Ok((secret, commitment))
+    }
+
+    /// Verifies the commitments of all participants, and returns the secret shares
+    /// to send privately to each of the other participants.
+    pub fn dkg_distribute(
+        &self,
+        secret: &ThresholdSchnorrDKGSecret<G>,
+        commitments: &[ThresholdSchnorrDKGCommitment<G>],
+    ) -> Result<Vec<ThresholdSchnorrDKGShare<G>>, SignatureError> {
+        self.verify_dkg_commitments(secret, commitments)?;
+
+        Ok((1..=secret.num_participants)
+            .filter(|&receiver| receiver != secret.index)
+            .map(|receiver| ThresholdSchnorrDKGShare {
+                sender: secret.index,
+                receiver,
+                share: evaluate_polynomial(&secret.coefficients, receiver),
+            })
+            .collect())
+    }
+
+    /// Verifies the secret shares received from all other participants against their commitments,
+    /// and returns the participant's share of the group private key.
+    pub fn dkg_finalize(
+        &self,
+        secret: &ThresholdSchnorrDKGSecret<G>,
+        commitments: &[ThresholdSchnorrDKGCommitment<G>],
+        shares: &[ThresholdSchnorrDKGShare<G>],
+    ) -> Result<ThresholdSchnorrKeyShare<G>, SignatureError> {
+        self.verify_dkg_commitments(secret, commitments)?;
+
+        let mut senders = HashSet::with_capacity(shares.len());
+        let mut private_key_share = evaluate_polynomial(&secret.coefficients, secret.index);
+        for share in shares {
+            if share.receiver != secret.index || share.sender == secret.index || !senders.insert(share.sender) {
+                return Err(SignatureError::Message(format!(
+                    "unexpected share from participant {} to participant {}",
+                    share.sender, share.receiver
+                )));
+            }
+
+            let sender_commitments = commitments
+                .iter()
+                .find(|commitment| commitment.index == share.sender)
+                .ok_or_else(|| SignatureError::Message(format!("unknown participant {}", share.sender)))?;
+            if self.generate_public_key(&share.share)?.0
+                != evaluate_commitments(&sender_commitments.commitments, secret.index)
+            {
+                return Err(SignatureError::Message(format!(
+                    "invalid share from participant {}",
+                    share.sender
+                )));
+            }
+
+            private_key_share += &share.share;
+        }
+
+        if senders.len() + 1 != secret.num_participants as usize {
+            return Err(SignatureError::Message(format!(
+                "expected {} shares, found {}",
+                secret.num_participants - 1,
+                senders.len()
+            )));
+        }
+
+        // The group commits to the sum of all secret polynomials.
+        let mut group_commitments = vec![G::zero(); secret.threshold as usize];
+        for commitment in commitments {
+            for (group_commitment, coefficient_commitment) in group_commitments.iter_mut().zip(&commitment.commitments)
+            {
+                *group_commitment += coefficient_commitment;
+            }
+        }
+
+        self.key_share(
+            secret.index,
+            secret.threshold,
+            secret.num_participants,
+            private_key_share,
+            &group_commitments,
+        )
+    }
+
+    /// Splits an existing private key into `num_participants` shares, any `threshold` of which can sign.
+    ///
+    /// Unlike distributed key generation, this requires a trusted dealer who knows the private key,
+    /// such as the holder of an existing account.
+    pub fn split_private_key<R: Rng>(
+        &self,
+        private_key: &<G as Group>::ScalarField,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<Vec<ThresholdSchnorrKeyShare<G>>, SignatureError> {
+        check_threshold(threshold, num_participants)?;
+
+        let mut coefficients = vec![*private_key];
+        coefficients.extend((1..threshold).map(|_| <G as Group>::ScalarField::rand(rng)));
+        let commitments = self.commit_coefficients(&coefficients)?;
+
+        (1..=num_participants)
+            .map(|index| {
+                let private_key_share = evaluate_polynomial(&coefficients, index);
+                self.key_share(index, threshold, num_participants, private_key_share, &commitments)
+            })
+            .collect()
+    }
+
+    /// Samples the nonces of the participant `index` for a single signing session.
+    ///
+    /// Returns the secret nonces, and the commitment to broadcast to the other signers.
+    pub fn commit_nonces<R: Rng>(
+        &self,
+        index: u16,
+        rng: &mut R,
+    ) -> Result<(ThresholdSchnorrNonces<G>, ThresholdSchnorrNonceCommitment<G>), SignatureError> {
+        let nonces = ThresholdSchnorrNonces {
+            index,
+            hiding: <G as Group>::ScalarField::rand(rng),
+            binding: <G as Group>::ScalarField::rand(rng),
+        };
+        let commitment = self.nonce_commitment(&nonces)?;
+        Ok((nonces, commitment))
+    }
+
+    /// Returns the participant's share of the signature on `message`, for the signers
+    /// that committed to their nonces in `commitments`.
+    pub fn sign_share(
+        &self,
+        key_share: &ThresholdSchnorrKeyShare<G>,
+        nonces: ThresholdSchnorrNonces<G>,
+        message: &[u8],
+        commitments: &[ThresholdSchnorrNonceCommitment<G>],
+    ) -> Result<ThresholdSchnorrSignatureShare<G>, SignatureError> {
+        let sign_time = start_timer!(|| "SchnorrSignature::sign_share");
+
+        if nonces.index != key_share.index {
+            return Err(SignatureError::Message(format!(
+                "nonces of participant {} used by participant {}",
+                nonces.index, key_share.index
+            )));
+        }
+        if commitments.len() < key_share.threshold as usize {
+            return Err(SignatureError::Message(format!(
+                "expected at least {} signers, found {}",
+                key_share.threshold,
+                commitments.len()
+            )));
+        }
+
+        let nonce_commitment = self.nonce_commitment(&nonces)?;
+        let position = commitments
+            .iter()
+            .position(|commitment| commitment == &nonce_commitment)
+            .ok_or_else(|| SignatureError::Message(format!("missing commitment of participant {}", nonces.index)))?;
+
+        let (binding_factors, verifier_challenge) = self.signing_challenge(message, commitments)?;
+        let lagrange = Self::signer_lagrange_coefficient(key_share.index, commitments)?;
+
+        // d + e·ρ - λ·x·c, so that the responses sum to k - x·c.
+        let response = nonces.hiding + (nonces.binding * binding_factors[position])
+            - (lagrange * key_share.private_key_share * verifier_challenge);
+
+        end_timer!(sign_time);
+        Ok(ThresholdSchnorrSignatureShare {
+            index: key_share.index,
+            response,
+        })
+    }
+
+    /// Returns `true` if `share` is a valid signature share on `message`, under the verification keys of the participants.
+    pub fn verify_share(
+        &self,
+        verification_keys: &[G],
+        message: &[u8],
+        commitments: &[ThresholdSchnorrNonceCommitment<G>],
+        share: &ThresholdSchnorrSignatureShare<G>,
+    ) -> Result<bool, SignatureError> {
+        let (binding_factors, verifier_challenge) = self.signing_challenge(message, commitments)?;
+
+        let (position, commitment) = match commitments
+            .iter()
+            .enumerate()
+            .find(|(_, commitment)| commitment.index == share.index)
+        {
+            Some(found) => found,
+            None => return Ok(false),
+        };
+        let verification_key = match verification_keys.get((share.index as usize).wrapping_sub(1)) {
+            Some(verification_key) => *verification_key,
+            None => return Ok(false),
+        };
+        let lagrange = Self::signer_lagrange_coefficient(share.index, commitments)?;
+
+        // z·g = D + ρ·E - λ·c·Y
+        let expected = commitment.hiding + (commitment.binding * binding_factors[position])
+            - (verification_key * (lagrange * verifier_challenge));
+        Ok(self.generate_public_key(&share.response)?.0 == expected)
+    }
+
+    /// Verifies the signature shares of all signers, and combines them into a Schnorr signature
+    /// that verifies under the group public key with `SchnorrSignature::verify`.
+    pub fn aggregate_signature_shares(
+        &self,
+        verification_keys: &[G],
+        message: &[u8],
+        commitments: &[ThresholdSchnorrNonceCommitment<G>],
+        shares: &[ThresholdSchnorrSignatureShare<G>],
+    ) -> Result<SchnorrOutput<G>, SignatureError> {
+        let aggregate_time = start_timer!(|| "SchnorrSignature::aggregate_signature_shares");
+
+        if shares.len() != commitments.len()
+            || !commitments
+                .iter()
+                .all(|commitment| shares.iter().any(|share| share.index == commitment.index))
+        {
+            return Err(SignatureError::Message(
+                "signature shares do not match the nonce commitments".into(),
+            ));
+        }
+
+        let mut prover_response = <G as Group>::ScalarField::zero();
+        for share in shares {
+            if !self.verify_share(verification_keys, message, commitments, share)? {
+                return Err(SignatureError::Message(format!(
+                    "invalid signature share from participant {}",
+                    share.index
+                )));
+            }
+            prover_response += &share.response;
+        }
+
+        let (_, verifier_challenge) = self.signing_challenge(message, commitments)?;
+
+        end_timer!(aggregate_time);
+        Ok(SchnorrOutput {
+            prover_response,
+            verifier_challenge,
+        })
+    }
+
+    /// Returns the commitments `a_k · g` to the given coefficients.
+    fn commit_coefficients(&self, coefficients: &[<G as Group>::ScalarField]) -> Result<Vec<G>, SignatureError> {
+        coefficients
+            .iter()
+            .map(|coefficient| Ok(self.generate_public_key(coefficient)?.0))
+            .collect()
+    }
+
+    /// Returns the key share of the participant `index`, given the commitments to the group polynomial.
+    fn key_share(
+        &self,
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        private_key_share: <G as Group>::ScalarField,
+        group_commitments: &[G],
+    ) -> Result<ThresholdSchnorrKeyShare<G>, SignatureError> {
+        let verification_keys = (1..=num_participants)
+            .map(|j| evaluate_commitments(group_commitments, j))
+            .collect::<Vec<_>>();
+
+        if self.generate_public_key(&private_key_share)?.0 != verification_keys[index as usize - 1] {
+            return Err(SignatureError::Message(format!(
+                "inconsistent key share for participant {}",
+                index
+            )));
+        }
+
+        Ok(ThresholdSchnorrKeyShare {
+            index,
+            threshold,
+            private_key_share,
+            verification_keys,
+            public_key: SchnorrPublicKey(group_commitments[0]),
+        })
+    }
+
+    /// Ensures there is exactly one well-formed commitment from each participant.
+    fn verify_dkg_commitments(
+        &self,
+        secret: &ThresholdSchnorrDKGSecret<G>,
+        commitments: &[ThresholdSchnorrDKGCommitment<G>],
+    ) -> Result<(), SignatureError> {
+        if commitments.len() != secret.num_participants as usize {
+            return Err(SignatureError::Message(format!(
+                "expected {} commitments, found {}",
+                secret.num_participants,
+                commitments.len()
+            )));
+        }
+
+        let mut indices = HashSet::with_capacity(commitments.len());
+        for commitment in commitments {
+            check_index(commitment.index, secret.num_participants)?;
+            if !indices.insert(commitment.index) || commitment.commitments.len() != secret.threshold as usize {
+                return Err(SignatureError::Message(format!(
+                    "malformed commitment from participant {}",
+                    commitment.index
+                )));
+            }
+
+            let public_key = SchnorrPublicKey(commitment.commitments[0]);
+            let message = Self::possession_message(commitment.index, &commitment.commitments[0])?;
+            if !self.verify(&public_key, &message, &commitment.proof_of_possession)? {
+                return Err(SignatureError::Message(format!(
+                    "invalid proof of possession from participant {}",
+                    commitment.index
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the message signed by a participant to prove knowledge of its secret.
+    fn possession_message(index: u16, commitment: &G) -> Result<Vec<u8>, SignatureError> {
+        let mut message = THRESHOLD_SCHNORR_POSSESSION_DOMAIN.to_vec();
+        message.extend_from_slice(&index.to_le_bytes());
+        message.extend_from_slice(&to_bytes![commitment]?);
+        Ok(message)
+    }
+
+    /// Returns the commitments to the given nonces.
+    fn nonce_commitment(
+        &self,
+        nonces: &ThresholdSchnorrNonces<G>,
+    ) -> Result<ThresholdSchnorrNonceCommitment<G>, SignatureError> {
+        Ok(ThresholdSchnorrNonceCommitment {
+            index: nonces.index,
+            hiding: self.generate_public_key(&nonces.hiding)?.0,
+            binding: self.generate_public_key(&nonces.binding)?.0,
+        })
+    }
+
+    /// Returns the Lagrange coefficient of the signer `index`, for the signers in `commitments`.
+    fn signer_lagrange_coefficient(
+        index: u16,
+        commitments: &[ThresholdSchnorrNonceCommitment<G>],
+    ) -> Result<<G as Group>::ScalarField, SignatureError> {
+        let indices = commitments
+            .iter()
+            .map(|commitment| commitment.index)
+            .collect::<Vec<_>>();
+        lagrange_coefficient(index, &indices).ok_or_else(|| SignatureError::Message("duplicate signer indices".into()))
+    }
+
+    /// Returns the binding factors of the signers, and the verifier challenge `e := H(salt || R || msg)`
+    /// for the group commitment `R`, exactly as computed by `SchnorrSignature::sign`.
+    ///
+    /// As the challenge must be a canonical scalar, the binding factors are rederived with
+    /// an incremented counter until it is. Every signer computes the same counter.
+    fn signing_challenge(
+        &self,
+        message: &[u8],
+        commitments: &[ThresholdSchnorrNonceCommitment<G>],
+    ) -> Result<(Vec<<G as Group>::ScalarField>, <G as Group>::ScalarField), SignatureError> {
+        let mut indices = HashSet::with_capacity(commitments.len());
+        if commitments.is_empty() || !commitments.iter().all(|commitment| indices.insert(commitment.index)) {
+            return Err(SignatureError::Message("invalid set of signers".into()));
+        }
+
+        let mut encoded_commitments = Vec::new();
+        for commitment in commitments {
+            encoded_commitments.extend_from_slice(&commitment.index.to_le_bytes());
+            encoded_commitments.extend_from_slice(&to_bytes![commitment.hiding, commitment.binding]?);
+        }
+
+        for counter in 0..=u8::MAX {
+            let binding_factors = commitments
+                .iter()
+                .map(|commitment| {
+                    let mut hash_input = THRESHOLD_SCHNORR_BINDING_DOMAIN.to_vec();
+                    hash_input.push(counter);
+                    hash_input.extend_from_slice(&commitment.index.to_le_bytes());
+                    hash_input.extend_from_slice(&(message.len() as u64).to_le_bytes());
+                    hash_input.extend_from_slice(message);
+                    hash_input.extend_from_slice(&encoded_commitments);
+                    self.hash_to_scalar(&hash_input)
+                })
+                .collect::<Vec<_>>();
+
+            // R := sum_i (D_i + ρ_i·E_i)
+            let group_commitment = commitments
+                .iter()
+                .zip(&binding_factors)
+                .fold(G::zero(), |acc, (commitment, binding_factor)| {
+                    acc + commitment.hiding + (commitment.binding * *binding_factor)
+                });
+
+            let mut hash_input = Vec::new();
+            hash_input.extend_from_slice(&self.parameters.salt);
+            hash_input.extend_from_slice(&to_bytes![group_commitment]?);
+            hash_input.extend_from_slice(message);
+
+            if let Some(verifier_challenge) = <G as Group>::ScalarField::from_random_bytes(&D::digest(&hash_input)) {
+                return Ok((binding_factors, verifier_challenge));
+            }
+        }
+
+        Err(SignatureError::Message("failed to derive a verifier challenge".into()))
+    }
+
+    /// Returns two digests of `salt || input` reduced modulo the scalar field, so the bias is negligible.
+    fn hash_to_scalar(&self, input: &[u8]) -> <G as Group>::ScalarField {
+        hash_to_scalar::<_, D>(&self.parameters.salt, input)
+    }
+}
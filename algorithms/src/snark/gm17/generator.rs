@@ -17,7 +17,7 @@
 use super::{r1cs_to_sap::R1CStoSAP, ProvingKey, VerifyingKey};
 use crate::{fft::EvaluationDomain, msm::FixedBaseMSM};
 use snarkvm_curves::traits::{PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_fields::{Field, One, Zero};
 use snarkvm_r1cs::{
     errors::SynthesisError,
     ConstraintSynthesizer,
@@ -208,7 +208,6 @@ where
     let non_zero_a = cfg_into_iter!(0..sap_num_variables)
         .map(|i| (!a[i].is_zero()) as usize)
         .sum();
-    let scalar_bits = E::Fr::size_in_bits();
 
     // Compute G window table
     let g_window_time = start_timer!(|| "Compute G window table");
@@ -224,7 +223,7 @@ where
         // G gamma2 Z t
         + m_raw + 1,
     );
-    let g_table = FixedBaseMSM::get_window_table::<E::G1Projective>(scalar_bits, g_window, g);
+    let g_table = FixedBaseMSM::get_glv_window_table::<E::G1Projective>(g_window, g);
     end_timer!(g_window_time);
 
     // Generate the R1CS proving key
@@ -232,8 +231,7 @@ where
 
     // Compute the A-query
     let a_time = start_timer!(|| "Calculate A");
-    let mut a_query = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
+    let mut a_query = FixedBaseMSM::glv_multi_scalar_mul::<E::G1Projective>(
         g_window,
         &g_table,
         &cfg_iter!(a).map(|a| *a * gamma).collect::<Vec<_>>(),
@@ -254,8 +252,7 @@ where
 
     // Compute the vector G_gamma2_z_t := Z(t) * t^i * gamma^2 * G
     let gamma2_z_t = gamma_z * gamma;
-    let mut g_gamma2_z_t = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
+    let mut g_gamma2_z_t = FixedBaseMSM::glv_multi_scalar_mul::<E::G1Projective>(
         g_window,
         &g_table,
         &cfg_into_iter!(0..m_raw + 1)
@@ -266,8 +263,7 @@ where
 
     // Compute the C_1-query
     let c1_time = start_timer!(|| "Calculate C1");
-    let mut result = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
+    let mut result = FixedBaseMSM::glv_multi_scalar_mul::<E::G1Projective>(
         g_window,
         &g_table,
         &cfg_into_iter!(0..sap_num_variables + 1)
@@ -280,8 +276,7 @@ where
     // Compute the C_2-query
     let c2_time = start_timer!(|| "Calculate C2");
     let double_gamma2_z = (zt * gamma.square()).double();
-    let mut c_query_2 = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-        scalar_bits,
+    let mut c_query_2 = FixedBaseMSM::glv_multi_scalar_mul::<E::G1Projective>(
         g_window,
         &g_table,
         &cfg_into_iter!(0..sap_num_variables + 1)
@@ -294,13 +289,12 @@ where
     // Compute H_gamma window table
     let h_gamma_time = start_timer!(|| "Compute H table");
     let h_gamma_window = FixedBaseMSM::get_mul_window_size(non_zero_a);
-    let h_gamma_table = FixedBaseMSM::get_window_table::<E::G2Projective>(scalar_bits, h_gamma_window, h_gamma.into());
+    let h_gamma_table = FixedBaseMSM::get_glv_window_table::<E::G2Projective>(h_gamma_window, h_gamma.into());
     end_timer!(h_gamma_time);
 
     // Compute the B-query
     let b_time = start_timer!(|| "Calculate B");
-    let mut b_query =
-        FixedBaseMSM::multi_scalar_mul::<E::G2Projective>(scalar_bits, h_gamma_window, &h_gamma_table, &a);
+    let mut b_query = FixedBaseMSM::glv_multi_scalar_mul::<E::G2Projective>(h_gamma_window, &h_gamma_table, &a);
     end_timer!(b_time);
 
     end_timer!(proving_key_time);
@@ -18,6 +18,11 @@
 //!
 //! [`Groth16`]: https://eprint.iacr.org/2016/260.pdf
 
+use crate::{
+    envelope::{envelope_digest, scalar_field_fingerprint, EnvelopeKind, EnvelopeScheme},
+    errors::EnvelopeError,
+    traits::Envelope,
+};
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine};
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{Index, LinearCombination};
@@ -79,6 +84,15 @@ impl<E: PairingEngine> FromBytes for Proof<E> {
     }
 }
 
+impl<E: PairingEngine> Envelope for Proof<E> {
+    const KIND: EnvelopeKind = EnvelopeKind::Proof;
+    const SCHEME: EnvelopeScheme = EnvelopeScheme::Groth16;
+
+    fn curve() -> [u8; 8] {
+        scalar_field_fingerprint::<E::Fr>()
+    }
+}
+
 impl<E: PairingEngine> PartialEq for Proof<E> {
     fn eq(&self, other: &Self) -> bool {
         self.a == other.a && self.b == other.b && self.c == other.c
@@ -193,6 +207,25 @@ impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
     }
 }
 
+impl<E: PairingEngine> Envelope for VerifyingKey<E> {
+    const KIND: EnvelopeKind = EnvelopeKind::VerifyingKey;
+    const SCHEME: EnvelopeScheme = EnvelopeScheme::Groth16;
+
+    fn curve() -> [u8; 8] {
+        scalar_field_fingerprint::<E::Fr>()
+    }
+
+    fn sizes(&self) -> Vec<u64> {
+        vec![self.gamma_abc_g1.len() as u64]
+    }
+
+    fn circuit_id(&self) -> Result<[u8; 32], EnvelopeError> {
+        let mut bytes = vec![];
+        VerifyingKey::write(self, &mut bytes)?;
+        Ok(envelope_digest(&bytes))
+    }
+}
+
 impl<E: PairingEngine> From<ProvingKey<E>> for VerifyingKey<E> {
     fn from(other: ProvingKey<E>) -> Self {
         other.vk
@@ -282,6 +315,29 @@ impl<E: PairingEngine> FromBytes for ProvingKey<E> {
     }
 }
 
+impl<E: PairingEngine> Envelope for ProvingKey<E> {
+    const KIND: EnvelopeKind = EnvelopeKind::ProvingKey;
+    const SCHEME: EnvelopeScheme = EnvelopeScheme::Groth16;
+
+    fn curve() -> [u8; 8] {
+        scalar_field_fingerprint::<E::Fr>()
+    }
+
+    fn sizes(&self) -> Vec<u64> {
+        vec![
+            self.a_query.len() as u64,
+            self.b_g1_query.len() as u64,
+            self.b_g2_query.len() as u64,
+            self.h_query.len() as u64,
+            self.l_query.len() as u64,
+        ]
+    }
+
+    fn circuit_id(&self) -> Result<[u8; 32], EnvelopeError> {
+        self.vk.circuit_id()
+    }
+}
+
 impl<E: PairingEngine> ProvingKey<E> {
     /// Serialize the parameters to bytes.
     pub fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
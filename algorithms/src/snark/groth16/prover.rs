@@ -16,7 +16,7 @@
 
 use super::{push_constraints, r1cs_to_qap::R1CStoQAP, Proof, ProvingKey};
 use crate::{cfg_into_iter, msm::VariableBaseMSM};
-use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_curves::traits::{glv_mul, AffineCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_r1cs::errors::SynthesisError;
 
@@ -24,7 +24,6 @@ use snarkvm_profiler::{end_timer, start_timer};
 use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, Index, LinearCombination, Variable};
 use snarkvm_utilities::rand::UniformRand;
 
-use core::ops::Mul;
 use rand::Rng;
 
 #[cfg(feature = "parallel")]
@@ -179,19 +178,19 @@ where
     // Compute A
     let a_acc_time = start_timer!(|| "Compute A");
     let a_query = &params.a_query;
-    let r_g1 = params.delta_g1.mul(r);
+    let r_g1 = subgroup_mul(params.delta_g1.into_projective(), r);
 
-    let g_a = calculate_coeff(r_g1.into(), a_query, params.vk.alpha_g1, &assignment);
+    let g_a = calculate_coeff(r_g1, a_query, params.vk.alpha_g1, &assignment);
 
     end_timer!(a_acc_time);
 
     // Compute B in G1 if needed
     let g1_b = if r != E::Fr::zero() {
         let b_g1_acc_time = start_timer!(|| "Compute B in G1");
-        let s_g1 = params.delta_g1.mul(s);
+        let s_g1 = subgroup_mul(params.delta_g1.into_projective(), s);
         let b_query = &params.b_g1_query;
 
-        let g1_b = calculate_coeff(s_g1.into(), b_query, params.beta_g1, &assignment);
+        let g1_b = calculate_coeff(s_g1, b_query, params.beta_g1, &assignment);
 
         end_timer!(b_g1_acc_time);
 
@@ -203,8 +202,8 @@ where
     // Compute B in G2
     let b_g2_acc_time = start_timer!(|| "Compute B in G2");
     let b_query = &params.b_g2_query;
-    let s_g2 = params.vk.delta_g2.mul(s);
-    let g2_b = calculate_coeff(s_g2.into(), &b_query, params.vk.beta_g2, &assignment);
+    let s_g2 = subgroup_mul(params.vk.delta_g2.into_projective(), s);
+    let g2_b = calculate_coeff(s_g2, &b_query, params.vk.beta_g2, &assignment);
 
     end_timer!(b_g2_acc_time);
 
@@ -212,14 +211,14 @@ where
     let c_acc_time = start_timer!(|| "Compute C");
 
     let h_query = &params.h_query;
-    let h_acc = VariableBaseMSM::multi_scalar_mul(&h_query, &h_assignment);
+    let h_acc = VariableBaseMSM::multi_scalar_mul_glv(&h_query, &h_assignment);
 
     let l_aux_source = &params.l_query;
-    let l_aux_acc = VariableBaseMSM::multi_scalar_mul(l_aux_source, &aux_assignment);
+    let l_aux_acc = VariableBaseMSM::multi_scalar_mul_glv(l_aux_source, &aux_assignment);
 
-    let s_g_a = g_a.mul(s);
-    let r_g1_b = g1_b.mul(r);
-    let r_s_delta_g1 = params.delta_g1.into_projective().mul(r).mul(s);
+    let s_g_a = subgroup_mul(g_a, s);
+    let r_g1_b = subgroup_mul(g1_b, r);
+    let r_s_delta_g1 = subgroup_mul(params.delta_g1.into_projective(), r * s);
 
     let mut g_c = s_g_a;
     g_c += r_g1_b;
@@ -245,7 +244,7 @@ fn calculate_coeff<G: AffineCurve>(
     assignment: &[<G::ScalarField as PrimeField>::BigInteger],
 ) -> G::Projective {
     let el = query[0];
-    let acc = VariableBaseMSM::multi_scalar_mul(&query[1..], assignment);
+    let acc = VariableBaseMSM::multi_scalar_mul_glv(&query[1..], assignment);
 
     let mut res = initial;
     res.add_assign_mixed(&el);
@@ -254,3 +253,10 @@ fn calculate_coeff<G: AffineCurve>(
 
     res
 }
+
+/// Multiplies `base` by `scalar` with the GLV endomorphism of the curve, if it has one.
+///
+/// The points of the proving key are in the prime order subgroup, and so are their sums.
+fn subgroup_mul<G: ProjectiveCurve>(base: G, scalar: G::ScalarField) -> G {
+    glv_mul(&base, &scalar).unwrap_or_else(|| base.mul(scalar))
+}
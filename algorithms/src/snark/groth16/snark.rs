@@ -24,13 +24,17 @@ use super::{
     ProvingKey,
     VerifyingKey,
 };
-use crate::{errors::SNARKError, traits::SNARK};
+use crate::{
+    envelope::EnvelopeMode,
+    errors::{EnvelopeError, SNARKError},
+    traits::{Envelope, SNARK},
+};
 use snarkvm_curves::traits::PairingEngine;
 use snarkvm_fields::ToConstraintField;
 use snarkvm_r1cs::ConstraintSynthesizer;
 
 use rand::Rng;
-use std::marker::PhantomData;
+use std::{io::Read, marker::PhantomData};
 
 /// Note: V should serialize its contents to `Vec<E::Fr>` in the same order as
 /// during the constraint generation.
@@ -89,4 +93,12 @@ impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::
         end_timer!(verify_time);
         Ok(result)
     }
+
+    fn read_proving_key_unchecked<R: Read>(reader: R) -> Result<Self::ProvingKey, EnvelopeError> {
+        Self::ProvingKey::read_envelope_unchecked(reader, EnvelopeMode::None)
+    }
+
+    fn read_verifying_key<R: Read>(reader: R) -> Result<Self::VerifyingKey, EnvelopeError> {
+        Self::VerifyingKey::read_envelope(reader, EnvelopeMode::None)
+    }
 }
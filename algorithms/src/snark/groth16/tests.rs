@@ -162,3 +162,137 @@ mod serialization {
         assert_eq!(recovered_proof.compressed, false);
     }
 }
+
+mod envelope {
+    use super::*;
+    use crate::{
+        envelope::{EnvelopeKind, EnvelopeMode},
+        errors::EnvelopeError,
+        snark::groth16::{create_random_proof, generate_random_parameters, Groth16, Proof, ProvingKey, VerifyingKey},
+        traits::{Envelope, SNARK},
+    };
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fr},
+        bw6_761::BW6_761,
+    };
+    use snarkvm_utilities::{
+        bytes::ToBytes,
+        rand::{test_rng, UniformRand},
+        to_bytes,
+    };
+
+    #[test]
+    fn test_envelope_roundtrip() {
+        let rng = &mut test_rng();
+
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let proof = create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, &parameters, rng).unwrap();
+
+        let mut buffer = vec![];
+        parameters.write_envelope(&mut buffer, EnvelopeMode::None).unwrap();
+        let (header, recovered) =
+            ProvingKey::<Bls12_377>::read_envelope_with_header(&buffer[..], EnvelopeMode::None).unwrap();
+        let header = header.unwrap();
+        assert_eq!(recovered, parameters);
+        assert_eq!(header.kind, EnvelopeKind::ProvingKey);
+        header.ensure_circuit_id(&parameters.vk.circuit_id().unwrap()).unwrap();
+
+        let mut buffer = vec![];
+        parameters.vk.write_envelope(&mut buffer, EnvelopeMode::None).unwrap();
+        let recovered = VerifyingKey::<Bls12_377>::read_envelope(&buffer[..], EnvelopeMode::None).unwrap();
+        assert_eq!(recovered, parameters.vk);
+
+        let mut buffer = vec![];
+        proof.write_envelope(&mut buffer, EnvelopeMode::None).unwrap();
+        let recovered = Proof::<Bls12_377>::read_envelope(&buffer[..], EnvelopeMode::None).unwrap();
+        assert_eq!(recovered, proof);
+    }
+
+    #[test]
+    fn test_envelope_reads_legacy_serialization() {
+        let rng = &mut test_rng();
+
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+
+        let legacy = to_bytes![parameters].unwrap();
+        let (header, recovered) =
+            ProvingKey::<Bls12_377>::read_envelope_with_header(&legacy[..], EnvelopeMode::None).unwrap();
+        assert!(header.is_none());
+        assert_eq!(recovered, parameters);
+    }
+
+    #[test]
+    fn test_envelope_mismatch() {
+        let rng = &mut test_rng();
+
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+
+        let mut buffer = vec![];
+        parameters.vk.write_envelope(&mut buffer, EnvelopeMode::None).unwrap();
+
+        // Reading a key for another curve fails before deserialization.
+        assert!(matches!(
+            VerifyingKey::<BW6_761>::read_envelope(&buffer[..], EnvelopeMode::None),
+            Err(EnvelopeError::CurveMismatch(..))
+        ));
+
+        // Reading a verifying key as a proving key fails.
+        assert!(matches!(
+            ProvingKey::<Bls12_377>::read_envelope(&buffer[..], EnvelopeMode::None),
+            Err(EnvelopeError::KindMismatch(
+                EnvelopeKind::ProvingKey,
+                EnvelopeKind::VerifyingKey
+            ))
+        ));
+
+        // Reading a corrupted payload fails.
+        let last = buffer.len() - 1;
+        buffer[last] ^= 1;
+        assert!(matches!(
+            VerifyingKey::<Bls12_377>::read_envelope(&buffer[..], EnvelopeMode::None),
+            Err(EnvelopeError::ChecksumMismatch(..))
+        ));
+
+        // Reading a truncated payload fails.
+        buffer.truncate(last);
+        assert!(VerifyingKey::<Bls12_377>::read_envelope(&buffer[..], EnvelopeMode::None).is_err());
+    }
+
+    #[test]
+    fn test_snark_reads_envelope_and_legacy_keys() {
+        type TestSNARK = Groth16<Bls12_377, MySillyCircuit<Fr>, [Fr]>;
+
+        let rng = &mut test_rng();
+
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+
+        let mut buffer = vec![];
+        parameters.write_envelope(&mut buffer, EnvelopeMode::None).unwrap();
+        assert_eq!(TestSNARK::read_proving_key_unchecked(&buffer[..]).unwrap(), parameters);
+        let legacy = to_bytes![parameters].unwrap();
+        assert_eq!(TestSNARK::read_proving_key_unchecked(&legacy[..]).unwrap(), parameters);
+
+        let mut buffer = vec![];
+        parameters.vk.write_envelope(&mut buffer, EnvelopeMode::None).unwrap();
+        assert_eq!(TestSNARK::read_verifying_key(&buffer[..]).unwrap(), parameters.vk);
+        let legacy = to_bytes![parameters.vk].unwrap();
+        assert_eq!(TestSNARK::read_verifying_key(&legacy[..]).unwrap(), parameters.vk);
+
+        // Loading the proving key file in place of the verifying key gives a clear error.
+        let mut buffer = vec![];
+        parameters.write_envelope(&mut buffer, EnvelopeMode::None).unwrap();
+        assert!(matches!(
+            TestSNARK::read_verifying_key(&buffer[..]),
+            Err(EnvelopeError::KindMismatch(
+                EnvelopeKind::VerifyingKey,
+                EnvelopeKind::ProvingKey
+            ))
+        ));
+    }
+}
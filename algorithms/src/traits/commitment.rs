@@ -22,11 +22,12 @@ use snarkvm_utilities::{
 
 use rand::Rng;
 use std::{fmt::Debug, hash::Hash};
+use zeroize::Zeroize;
 
 pub trait CommitmentScheme: Sized + Clone + From<<Self as CommitmentScheme>::Parameters> {
     type Output: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + Sync + Send;
     type Parameters: Clone + Debug + Eq + ToBytes + FromBytes;
-    type Randomness: Clone + Debug + Default + Eq + UniformRand + ToBytes + FromBytes;
+    type Randomness: Clone + Debug + Default + Eq + UniformRand + ToBytes + FromBytes + Zeroize;
 
     fn setup<R: Rng>(r: &mut R) -> Self;
 
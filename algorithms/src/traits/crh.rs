@@ -27,6 +27,11 @@ pub trait CRHParameters: Clone + Debug + ToBytes + FromBytes + Eq {
     fn setup<R: Rng>(r: &mut R) -> Self;
 }
 
+/// Parameters for hash functions that do not require a setup, such as SHA-256.
+impl CRHParameters for () {
+    fn setup<R: Rng>(_: &mut R) -> Self {}
+}
+
 pub trait CRH: Clone + From<<Self as CRH>::Parameters> {
     type Output: Clone + Debug + Display + ToBytes + FromBytes + Eq + Hash + Default + Send + Sync + Copy;
     type Parameters: CRHParameters;
@@ -22,10 +22,11 @@ use snarkvm_utilities::{
 
 use rand::Rng;
 use std::{fmt::Debug, hash::Hash};
+use zeroize::Zeroize;
 
 pub trait EncryptionScheme: Sized + Clone + From<<Self as EncryptionScheme>::Parameters> + SignatureScheme {
     type Parameters: Clone + Debug + Eq + ToBytes + FromBytes;
-    type PrivateKey: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + UniformRand;
+    type PrivateKey: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + UniformRand + Zeroize;
     type PublicKey: Clone + Debug + Default + Eq + ToBytes + FromBytes;
     type Text: Clone + Debug + Default + Eq + ToBytes + FromBytes;
     type Randomness: Clone + Debug + Default + Eq + Hash + ToBytes + FromBytes + UniformRand;
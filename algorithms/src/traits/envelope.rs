@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    envelope::{
+        hex,
+        DigestReader,
+        DigestWriter,
+        EnvelopeHeader,
+        EnvelopeKind,
+        EnvelopeMode,
+        EnvelopeScheme,
+        ENVELOPE_MAGIC,
+        ENVELOPE_VERSION,
+    },
+    errors::EnvelopeError,
+};
+use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+
+use std::io::{self, Read, Write};
+
+/// An object that can be stored in a self-describing envelope.
+pub trait Envelope: ToBytes + FromBytes {
+    /// The kind of this object.
+    const KIND: EnvelopeKind;
+    /// The scheme this object belongs to.
+    const SCHEME: EnvelopeScheme;
+
+    /// Returns the fingerprint of the scalar field this object is defined over.
+    fn curve() -> [u8; 8];
+
+    /// Returns the scheme-specific sizes of this object.
+    fn sizes(&self) -> Vec<u64> {
+        Vec::new()
+    }
+
+    /// Returns the identifier of the circuit this object belongs to,
+    /// or zero if this object is not circuit-specific.
+    fn circuit_id(&self) -> Result<[u8; 32], EnvelopeError> {
+        Ok([0u8; 32])
+    }
+
+    /// Writes this object for the given mode, preceded by its envelope header.
+    ///
+    /// The object is serialized twice, first to compute the payload size and checksum
+    /// for the header and then into `writer`, so the payload is never held in memory.
+    fn write_envelope<W: Write>(&self, mut writer: W, mode: EnvelopeMode) -> Result<(), EnvelopeError> {
+        let mut payload = DigestWriter::new(io::sink());
+        self.write(&mut payload)?;
+        let (payload_size, checksum) = payload.finalize();
+
+        let header = EnvelopeHeader {
+            version: ENVELOPE_VERSION,
+            kind: Self::KIND,
+            scheme: Self::SCHEME,
+            mode,
+            curve: Self::curve(),
+            circuit_id: self.circuit_id()?,
+            sizes: self.sizes(),
+            payload_size,
+            checksum,
+        };
+
+        header.write(&mut writer)?;
+        self.write(&mut writer)?;
+        Ok(())
+    }
+
+    /// Reads an object written with `write_envelope` for the given mode, or a raw serialized object.
+    fn read_envelope<R: Read>(reader: R, mode: EnvelopeMode) -> Result<Self, EnvelopeError> {
+        Ok(Self::read_envelope_with_header(reader, mode)?.1)
+    }
+
+    /// Reads an object like `read_envelope`, without performing the validity checks of `read_unchecked`.
+    /// The header and checksum of an envelope are still checked.
+    /// This must only be used on data that comes from a trusted source.
+    fn read_envelope_unchecked<R: Read>(reader: R, mode: EnvelopeMode) -> Result<Self, EnvelopeError> {
+        Ok(read_envelope_with_header(reader, mode, false)?.1)
+    }
+
+    /// Reads an object written with `write_envelope` for the given mode, or a raw serialized object,
+    /// along with its envelope header. The header is `None` for raw serialized objects.
+    fn read_envelope_with_header<R: Read>(
+        reader: R,
+        mode: EnvelopeMode,
+    ) -> Result<(Option<EnvelopeHeader>, Self), EnvelopeError> {
+        read_envelope_with_header(reader, mode, true)
+    }
+}
+
+/// Reads an object and its envelope header, with or without the validity checks of the object.
+fn read_envelope_with_header<T: Envelope, R: Read>(
+    mut reader: R,
+    mode: EnvelopeMode,
+    checked: bool,
+) -> Result<(Option<EnvelopeHeader>, T), EnvelopeError> {
+    let read = |reader: &mut dyn Read| match checked {
+        true => T::read(reader),
+        false => T::read_unchecked(reader),
+    };
+
+    // Objects written before the envelope format are read as they are.
+    let mut magic = [0u8; 4];
+    let mut num_read = 0;
+    while num_read < magic.len() {
+        match reader.read(&mut magic[num_read..])? {
+            0 => break,
+            n => num_read += n,
+        }
+    }
+    if magic[..num_read] != ENVELOPE_MAGIC[..] {
+        return Ok((None, read(&mut (&magic[..num_read]).chain(reader))?));
+    }
+
+    let header = EnvelopeHeader::read_after_magic(&mut reader)?;
+    if header.kind != T::KIND {
+        return Err(EnvelopeError::KindMismatch(T::KIND, header.kind));
+    }
+    if header.scheme != T::SCHEME {
+        return Err(EnvelopeError::SchemeMismatch(T::SCHEME, header.scheme));
+    }
+    if header.curve != T::curve() {
+        return Err(EnvelopeError::CurveMismatch(hex(&T::curve()), hex(&header.curve)));
+    }
+    header.ensure_mode(mode)?;
+
+    // The payload is deserialized as it is read, and checked against the header once it is consumed.
+    let mut payload = DigestReader::new((&mut reader).take(header.payload_size));
+    let object = read(&mut payload);
+    let (payload_size, checksum) = payload.finalize()?;
+    if payload_size != header.payload_size {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "envelope payload is truncated").into());
+    }
+    if checksum != header.checksum {
+        return Err(EnvelopeError::ChecksumMismatch(hex(&header.checksum), hex(&checksum)));
+    }
+
+    let object = object?;
+    if object.sizes() != header.sizes {
+        return Err(EnvelopeError::SizeMismatch(header.sizes, object.sizes()));
+    }
+
+    Ok((Some(header), object))
+}
@@ -0,0 +1,27 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::traits::AffineCurve;
+
+pub trait HashToCurve: AffineCurve {
+    /// Returns a uniformly random point in the prime-order subgroup for the given message
+    /// and domain separation tag, as in `hash_to_curve` from RFC 9380.
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self;
+
+    /// Returns a point in the prime-order subgroup for the given message and domain separation tag,
+    /// as in `encode_to_curve` from RFC 9380. The output distribution is not uniform.
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self;
+}
@@ -23,6 +23,12 @@ pub use crh::*;
 pub mod encryption;
 pub use encryption::*;
 
+pub mod envelope;
+pub use envelope::*;
+
+pub mod hash_to_curve;
+pub use hash_to_curve::*;
+
 pub mod merkle_tree;
 pub use merkle_tree::*;
 
@@ -18,11 +18,12 @@ use crate::errors::PRFError;
 use snarkvm_utilities::bytes::{FromBytes, ToBytes};
 
 use std::{fmt::Debug, hash::Hash};
+use zeroize::Zeroize;
 
 pub trait PRF {
     type Input: FromBytes + Default;
     type Output: ToBytes + Eq + Clone + Default + Hash;
-    type Seed: FromBytes + ToBytes + PartialEq + Eq + Clone + Default + Debug;
+    type Seed: FromBytes + ToBytes + PartialEq + Eq + Clone + Default + Debug + Zeroize;
 
     fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Result<Self::Output, PRFError>;
 }
@@ -22,6 +22,7 @@ use snarkvm_utilities::{
 
 use rand::Rng;
 use std::{fmt::Debug, hash::Hash};
+use zeroize::Zeroize;
 
 pub trait SignatureScheme: Sized + Clone + From<<Self as SignatureScheme>::Parameters> {
     type Parameters: Clone + Debug + ToBytes + FromBytes + Eq + Send + Sync;
@@ -36,7 +37,7 @@ pub trait SignatureScheme: Sized + Clone + From<<Self as SignatureScheme>::Param
         + Sync
         + CanonicalSerialize
         + CanonicalDeserialize;
-    type PrivateKey: Clone + Debug + Default + ToBytes + FromBytes + PartialEq + Eq;
+    type PrivateKey: Clone + Debug + Default + ToBytes + FromBytes + PartialEq + Eq + Zeroize;
     type Output: Clone + Debug + Default + ToBytes + FromBytes + Send + Sync;
 
     fn setup<R: Rng>(rng: &mut R) -> Result<Self, SignatureError>;
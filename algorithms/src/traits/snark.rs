@@ -14,11 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::SNARKError;
+use crate::errors::{EnvelopeError, SNARKError};
 use snarkvm_utilities::bytes::{FromBytes, ToBytes};
 
 use rand::Rng;
-use std::fmt::Debug;
+use std::{fmt::Debug, io::Read};
 
 pub trait SNARK {
     type AllocatedCircuit;
@@ -45,4 +45,16 @@ pub trait SNARK {
         input: &Self::VerifierInput,
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError>;
+
+    /// Reads a proving key from a trusted source without performing validity checks.
+    /// Schemes with an envelope format read keys stored in an envelope or as raw serialized keys.
+    fn read_proving_key_unchecked<R: Read>(reader: R) -> Result<Self::ProvingKey, EnvelopeError> {
+        Ok(Self::ProvingKey::read_unchecked(reader)?)
+    }
+
+    /// Reads a verifying key.
+    /// Schemes with an envelope format read keys stored in an envelope or as raw serialized keys.
+    fn read_verifying_key<R: Read>(reader: R) -> Result<Self::VerifyingKey, EnvelopeError> {
+        Ok(Self::VerifyingKey::read(reader)?)
+    }
 }
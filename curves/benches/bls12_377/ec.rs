@@ -17,7 +17,7 @@
 pub(crate) mod g1 {
     use snarkvm_curves::{
         bls12_377::{Fr, G1Affine, G1Projective as G1},
-        traits::ProjectiveCurve,
+        traits::{glv_mul, ProjectiveCurve},
         Group,
     };
     use snarkvm_utilities::rand::UniformRand;
@@ -49,6 +49,40 @@ pub(crate) mod g1 {
         });
     }
 
+    pub fn bench_g1_glv_mul(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<(G1, Fr)> = (0..SAMPLES).map(|_| (G1::rand(&mut rng), Fr::rand(&mut rng))).collect();
+
+        let mut count = 0;
+        c.bench_function("bls12_377: g1_glv_mul", |c| {
+            c.iter(|| {
+                let tmp = glv_mul(&v[count].0, &v[count].1);
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
+
+    pub fn bench_g1_glv_decomposition(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<Fr> = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect();
+
+        let mut count = 0;
+        c.bench_function("bls12_377: g1_glv_decomposition", |c| {
+            c.iter(|| {
+                let tmp = G1::glv_decomposition(&v[count]);
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
+
     pub fn bench_g1_add_assign(c: &mut Criterion) {
         const SAMPLES: usize = 1000;
 
@@ -23,6 +23,8 @@ criterion_group!(
     bls12_377_ec,
     bls12_377::ec::g1::bench_g1_rand,
     bls12_377::ec::g1::bench_g1_mul_assign,
+    bls12_377::ec::g1::bench_g1_glv_mul,
+    bls12_377::ec::g1::bench_g1_glv_decomposition,
     bls12_377::ec::g1::bench_g1_add_assign,
     bls12_377::ec::g1::bench_g1_add_assign_mixed,
     bls12_377::ec::g1::bench_g1_double,
@@ -103,6 +105,8 @@ criterion_group!(
     bw6_761_ec,
     bw6_761::ec::g1::bench_g1_rand,
     bw6_761::ec::g1::bench_g1_mul_assign,
+    bw6_761::ec::g1::bench_g1_glv_mul,
+    bw6_761::ec::g1::bench_g1_glv_decomposition,
     bw6_761::ec::g1::bench_g1_add_assign,
     bw6_761::ec::g1::bench_g1_add_assign_mixed,
     bw6_761::ec::g1::bench_g1_double,
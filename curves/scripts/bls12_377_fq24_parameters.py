@@ -0,0 +1,160 @@
+# Generates `curves/src/bls12_377/tests/fq24_parameters.rs`, the test-only BLS24-style tower over BLS12-377:
+#
+#   Fq4 = Fq2[v] / (v^2 - u),
+#   Fq12 = Fq4[w] / (w^3 - v),
+#   Fq24 = Fq12[z] / (z^2 - w),
+#
+# where Fq2 = Fq[u] / (u^2 + 5) is the quadratic extension of BLS12-377, so that z^12 = u.
+# Each Frobenius coefficient is a power of u computed from the characteristic, written in Montgomery form.
+#
+# Usage: python3 bls12_377_fq24_parameters.py > ../src/bls12_377/tests/fq24_parameters.rs
+
+FQ = 258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177
+FQ2_NONRESIDUE = FQ - 5
+# The Montgomery constant R = 2^384 mod q.
+R = pow(2, 384, FQ)
+
+LICENSE = """// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+"""
+
+
+def fq2_mul(a, b):
+    c0 = (a[0] * b[0] + FQ2_NONRESIDUE * a[1] * b[1]) % FQ
+    c1 = (a[0] * b[1] + a[1] * b[0]) % FQ
+    return (c0, c1)
+
+
+def fq2_pow(a, exponent):
+    result = (1, 0)
+    for bit in bin(exponent)[2:]:
+        result = fq2_mul(result, result)
+        if bit == "1":
+            result = fq2_mul(result, a)
+    return result
+
+
+U = (0, 1)
+
+
+def frobenius_coefficient(power, numerator, denominator):
+    # u^((numerator * q^power - numerator) / denominator)
+    exponent, remainder = divmod(numerator * FQ ** power - numerator, denominator)
+    assert remainder == 0
+    return fq2_pow(U, exponent)
+
+
+def fq(value, indent):
+    limbs = [(value * R % FQ) >> (64 * i) & (2 ** 64 - 1) for i in range(6)]
+    if value == 0:
+        return "field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]))"
+    pad = " " * indent
+    lines = ["field!(", pad + "    Fq,", pad + "    BigInteger384(["]
+    lines += [pad + "        0x%x," % limb for limb in limbs]
+    lines += [pad + "    ])", pad + ")"]
+    return "\n".join(lines)
+
+
+def fq2(value, indent):
+    pad = " " * indent
+    return "\n".join(
+        [
+            "field!(",
+            pad + "    Fq2,",
+            pad + "    " + fq(value[0], indent + 4) + ",",
+            pad + "    " + fq(value[1], indent + 4) + ",",
+            pad + ")",
+        ]
+    )
+
+
+def fq4(c0, c1, indent):
+    pad = " " * indent
+    return "\n".join(
+        [
+            "field!(",
+            pad + "    Fq4,",
+            pad + "    " + fq2(c0, indent + 4) + ",",
+            pad + "    " + fq2(c1, indent + 4) + ",",
+            pad + ")",
+        ]
+    )
+
+
+def coefficients(name, count, numerator, denominator):
+    lines = ["    const %s: [Fq2; %d] = [" % (name, count)]
+    for power in range(count):
+        if numerator == 1:
+            comment = "U^(((q^%d) - 1) / %d)" % (power, denominator)
+        else:
+            comment = "U^((%d * (q^%d) - %d) / %d)" % (numerator, power, numerator, denominator)
+        lines.append("        // " + comment)
+        lines.append("        " + fq2(frobenius_coefficient(power, numerator, denominator), 8) + ",")
+    lines.append("    ];")
+    return "\n".join(lines)
+
+
+def main():
+    out = [LICENSE]
+    out.append("//! Generated by `curves/scripts/bls12_377_fq24_parameters.py`. Do not edit.")
+    out.append("")
+    out.append("use crate::bls12_377::{Fq, Fq2, Fq2Parameters};")
+    out.append("use snarkvm_fields::{")
+    out.append("    field,")
+    out.append("    fp24_2over3over2over2::{Fp24, Fp24Parameters},")
+    out.append("    Fp4,")
+    out.append("    Fp4Parameters,")
+    out.append("};")
+    out.append("use snarkvm_utilities::biginteger::BigInteger384;")
+    out.append("")
+    out.append("/// Fq4 = Fq2[v]/(v^2 - u), a quadratic extension of `Fq2` by the nonresidue of `Fq6`.")
+    out.append("#[derive(Clone, Copy)]")
+    out.append("pub(super) struct Fq4Parameters;")
+    out.append("")
+    out.append("pub(super) type Fq4 = Fp4<Fq4Parameters>;")
+    out.append("")
+    out.append("impl Fp4Parameters for Fq4Parameters {")
+    out.append("    type Fp2Params = Fq2Parameters;")
+    out.append("")
+    out.append("    /// NONRESIDUE = U")
+    out.append("    const NONRESIDUE: Fq2 = " + fq2(U, 4) + ";")
+    out.append("")
+    out.append(coefficients("FROBENIUS_COEFF_FP4_C1", 4, 1, 2))
+    out.append("}")
+    out.append("")
+    out.append("/// Fq24 = Fq12[z]/(z^2 - w), where Fq12 = Fq4[w]/(w^3 - v), so that z^12 = u.")
+    out.append("#[derive(Clone, Copy)]")
+    out.append("pub(super) struct Fq24Parameters;")
+    out.append("")
+    out.append("pub(super) type Fq24 = Fp24<Fq24Parameters>;")
+    out.append("")
+    out.append("impl Fp24Parameters for Fq24Parameters {")
+    out.append("    type Fp4Params = Fq4Parameters;")
+    out.append("")
+    out.append("    /// NONRESIDUE = V")
+    out.append("    const NONRESIDUE: Fq4 = " + fq4((0, 0), (1, 0), 4) + ";")
+    out.append("")
+    out.append(coefficients("FROBENIUS_COEFF_FP12_C1", 12, 1, 6))
+    out.append("")
+    out.append(coefficients("FROBENIUS_COEFF_FP12_C2", 12, 2, 6))
+    out.append("")
+    out.append(coefficients("FROBENIUS_COEFF_FP24_C1", 24, 1, 12))
+    out.append("}")
+    print("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -19,7 +19,7 @@ use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
     bls12_377::{Fq, Fr},
-    traits::{ModelParameters, SWModelParameters},
+    traits::{GLVParameters, ModelParameters, SWModelParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -60,6 +60,75 @@ impl SWModelParameters for Bls12_377G1Parameters {
             1114629510922847535,
         ])
     );
+    /// GLV_PARAMETERS, with BETA a primitive cube root of unity in Fq and LAMBDA = 91893752504881257701523279626832445440
+    const GLV_PARAMETERS: Option<GLVParameters<Fq, Fr>> = Some(GLVParameters {
+        // BETA = 80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945
+        beta: field!(
+            Fq,
+            BigInteger384([
+                0xdacd106da5847973,
+                0xd8fe2454bac2a79a,
+                0x1ada4fd6fd832edc,
+                0xfb9868449d150908,
+                0xd63eb8aeea32285e,
+                0x167d6a36f873fd0,
+            ])
+        ),
+        // LAMBDA = 91893752504881257701523279626832445440
+        lambda: field!(
+            Fr,
+            BigInteger256([
+                0xae8012cd506fe7e2,
+                0x8adb5f3b1ec9d536,
+                0x15b65fe3a66fe319,
+                0x91f331343200452,
+            ])
+        ),
+        // A1 = 1
+        a1: field!(
+            Fr,
+            BigInteger256([
+                0x7d1c7ffffffffff3,
+                0x7257f50f6ffffff2,
+                0x16d81575512c0fee,
+                0xd4bda322bbb9a9d,
+            ])
+        ),
+        // B1 = 91893752504881257701523279626832445441
+        b1: field!(
+            Fr,
+            BigInteger256([
+                0x218b12cd506fe7d4,
+                0xa388dd4bbec9d528,
+                0xcbda283a9b644306,
+                0x3bfa7e6d4aef998,
+            ])
+        ),
+        // A2 = 91893752504881257701523279626832445440
+        a2: field!(
+            Fr,
+            BigInteger256([
+                0xae8012cd506fe7e2,
+                0x8adb5f3b1ec9d536,
+                0x15b65fe3a66fe319,
+                0x91f331343200452,
+            ])
+        ),
+        // B2 = -1
+        b2: field!(
+            Fr,
+            BigInteger256([
+                0x8cf500000000000e,
+                0xe75281ef6000000e,
+                0x49dc37a90b0ba012,
+                0x55f8b2c6e710ab9,
+            ])
+        ),
+        // G1 = 14
+        g1: &[0xe, 0x0, 0x0, 0x0],
+        // G2 = 1260064869275694167304791240164355610668
+        g2: &[0x7f72ed32af90182c, 0xb3f7aa969fd37160, 0x3, 0x0],
+    });
 
     #[inline(always)]
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
@@ -45,7 +45,14 @@ use crate::{
 };
 use snarkvm_fields::{
     fp6_3over2::Fp6Parameters,
-    tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+    tests_field::{
+        field_flags_serialization_test,
+        field_serialization_test,
+        field_test,
+        frobenius_test,
+        primefield_test,
+        sqrt_field_test,
+    },
     FftField,
     FftParameters,
     Field,
@@ -60,6 +67,7 @@ use snarkvm_fields::{
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger384},
     rand::UniformRand,
+    serialize::{CanonicalSerialize, ConstantSerializedSize, EdwardsFlags, SWFlags},
 };
 
 use rand::SeedableRng;
@@ -69,6 +77,9 @@ use std::{
     ops::{AddAssign, MulAssign, SubAssign},
 };
 
+mod fq24_parameters;
+use fq24_parameters::{Fq24, Fq4};
+
 pub(crate) const ITERATIONS: usize = 5;
 
 #[test]
@@ -95,6 +106,20 @@ fn test_bls12_377_fq() {
     }
 }
 
+#[test]
+fn test_bls12_377_flags_serialization() {
+    // The flags fit in the spare bits of the BLS12-377 fields, so points serialize to the same bytes
+    // as before flags could spill into an extra byte.
+    field_flags_serialization_test::<Fq, _>(SWFlags::PositiveY);
+    field_flags_serialization_test::<Fq, _>(SWFlags::Infinity);
+    field_flags_serialization_test::<Fr, _>(EdwardsFlags::PositiveY);
+
+    let g1 = G1Affine::prime_subgroup_generator();
+    let g2 = G2Affine::prime_subgroup_generator();
+    assert_eq!(g1.serialized_size(), Fq::SERIALIZED_SIZE);
+    assert_eq!(g2.serialized_size(), 2 * Fq::SERIALIZED_SIZE);
+}
+
 #[test]
 fn test_bls12_377_fq2() {
     for _ in 0..ITERATIONS {
@@ -129,6 +154,29 @@ fn test_bls12_377_fq12() {
     field_serialization_test::<Fq12>();
 }
 
+#[test]
+fn test_bls12_377_fq4() {
+    for _ in 0..ITERATIONS {
+        let a: Fq4 = rand::random();
+        let b: Fq4 = rand::random();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq4, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq4>();
+}
+
+#[test]
+fn test_bls12_377_fq24() {
+    for _ in 0..ITERATIONS {
+        let a: Fq24 = rand::random();
+        let b: Fq24 = rand::random();
+        field_test(a, b);
+    }
+    frobenius_test::<Fq24, _>(Fq::characteristic(), 25);
+    field_serialization_test::<Fq24>();
+}
+
 #[test]
 fn test_fq_repr_from() {
     assert_eq!(BigInteger384::from(100), BigInteger384([100, 0, 0, 0, 0, 0]));
@@ -406,6 +454,96 @@ fn test_fq_root_of_unity() {
     assert!(Fq::multiplicative_generator().sqrt().is_none());
 }
 
+mod derived {
+    use super::*;
+    use snarkvm_utilities::biginteger::BigInteger256;
+
+    #[derive(snarkvm_utilities::PrimeFieldParams)]
+    #[modulus = "8444461749428370424248824938781546531375899335154063827935233455917409239041"]
+    #[generator = "22"]
+    #[small_subgroup_base = "3"]
+    #[small_subgroup_base_adicity = "1"]
+    struct FrParameters;
+
+    #[derive(snarkvm_utilities::PrimeFieldParams)]
+    #[modulus = "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177"]
+    #[generator = "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458172"]
+    struct FqParameters;
+
+    macro_rules! assert_field_parameters_eq {
+        ($expected:ty, $derived:ty) => {
+            assert_eq!(<$expected>::MODULUS, <$derived>::MODULUS);
+            assert_eq!(<$expected>::MODULUS_BITS, <$derived>::MODULUS_BITS);
+            assert_eq!(<$expected>::CAPACITY, <$derived>::CAPACITY);
+            assert_eq!(<$expected>::REPR_SHAVE_BITS, <$derived>::REPR_SHAVE_BITS);
+            assert_eq!(<$expected>::R, <$derived>::R);
+            assert_eq!(<$expected>::R2, <$derived>::R2);
+            assert_eq!(<$expected>::INV, <$derived>::INV);
+            assert_eq!(<$expected>::GENERATOR, <$derived>::GENERATOR);
+            assert_eq!(
+                <$expected>::MODULUS_MINUS_ONE_DIV_TWO,
+                <$derived>::MODULUS_MINUS_ONE_DIV_TWO
+            );
+            assert_eq!(<$expected>::T, <$derived>::T);
+            assert_eq!(<$expected>::T_MINUS_ONE_DIV_TWO, <$derived>::T_MINUS_ONE_DIV_TWO);
+            assert_eq!(<$expected>::TWO_ADICITY, <$derived>::TWO_ADICITY);
+            assert_eq!(<$expected>::SMALL_SUBGROUP_BASE, <$derived>::SMALL_SUBGROUP_BASE);
+            assert_eq!(
+                <$expected>::SMALL_SUBGROUP_BASE_ADICITY,
+                <$derived>::SMALL_SUBGROUP_BASE_ADICITY
+            );
+        };
+    }
+
+    #[test]
+    fn test_derived_fr_parameters() {
+        assert_field_parameters_eq!(crate::bls12_377::FrParameters, FrParameters);
+
+        // The hardcoded Fr roots of unity are not the powers of GENERATOR (see the TODO in fr.rs),
+        // so check the derived roots against their defining properties instead.
+        assert_eq!(
+            FrParameters::TWO_ADIC_ROOT_OF_UNITY,
+            BigInteger256([
+                12646347781564978760,
+                6783048705277173164,
+                268534165941069093,
+                1121515446318641358
+            ])
+        );
+        let two_adic_root_of_unity = Fr::two_adic_root_of_unity();
+        let mut root = two_adic_root_of_unity;
+        for _ in 0..(FrParameters::TWO_ADICITY - 1) {
+            root.square_in_place();
+        }
+        assert_eq!(root, -Fr::one());
+
+        let large_subgroup_root_of_unity = Fr::large_subgroup_root_of_unity().unwrap();
+        assert_eq!(large_subgroup_root_of_unity.pow([3u64]), two_adic_root_of_unity);
+        assert_ne!(large_subgroup_root_of_unity, two_adic_root_of_unity);
+
+        for _ in 0..ITERATIONS {
+            let a: Fr = rand::random();
+            let b: Fr = rand::random();
+            field_test(a, b);
+            sqrt_field_test(b);
+        }
+        primefield_test::<Fr>();
+    }
+
+    #[test]
+    fn test_derived_fq_parameters() {
+        assert_field_parameters_eq!(crate::bls12_377::FqParameters, FqParameters);
+        assert_eq!(
+            crate::bls12_377::FqParameters::TWO_ADIC_ROOT_OF_UNITY,
+            FqParameters::TWO_ADIC_ROOT_OF_UNITY
+        );
+        assert_eq!(
+            crate::bls12_377::FqParameters::LARGE_SUBGROUP_ROOT_OF_UNITY,
+            FqParameters::LARGE_SUBGROUP_ROOT_OF_UNITY
+        );
+    }
+}
+
 #[test]
 fn test_fq_ordering() {
     // BigInteger384's ordering is well-tested, but we still need to make sure the
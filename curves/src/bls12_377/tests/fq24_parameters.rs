@@ -0,0 +1,932 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generated by `curves/scripts/bls12_377_fq24_parameters.py`. Do not edit.
+
+use crate::bls12_377::{Fq, Fq2, Fq2Parameters};
+use snarkvm_fields::{
+    field,
+    fp24_2over3over2over2::{Fp24, Fp24Parameters},
+    Fp4,
+    Fp4Parameters,
+};
+use snarkvm_utilities::biginteger::BigInteger384;
+
+/// Fq4 = Fq2[v]/(v^2 - u), a quadratic extension of `Fq2` by the nonresidue of `Fq6`.
+#[derive(Clone, Copy)]
+pub(super) struct Fq4Parameters;
+
+pub(super) type Fq4 = Fp4<Fq4Parameters>;
+
+impl Fp4Parameters for Fq4Parameters {
+    type Fp2Params = Fq2Parameters;
+
+    /// NONRESIDUE = U
+    const NONRESIDUE: Fq2 = field!(
+        Fq2,
+        field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        field!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a,
+            ])
+        ),
+    );
+
+    const FROBENIUS_COEFF_FP4_C1: [Fq2; 4] = [
+        // U^(((q^0) - 1) / 2)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^1) - 1) / 2)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x982c13d9d084771f,
+                    0xfd49de0c6da34a32,
+                    0x61a530d183ab0e53,
+                    0xdf8fe44106dd9879,
+                    0x40f29b58d88472bc,
+                    0x158723199046d5d,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^2) - 1) / 2)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x120d3e434c71c50,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^3) - 1) / 2)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xecdcac262f7b88e2,
+                    0x19c17f37c25cb5cd,
+                    0xbd4e315e365e39ac,
+                    0x3a92f5b1fa177b15,
+                    0x85486a67941cd67e,
+                    0x55c8147ec0a38d,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+}
+
+/// Fq24 = Fq12[z]/(z^2 - w), where Fq12 = Fq4[w]/(w^3 - v), so that z^12 = u.
+#[derive(Clone, Copy)]
+pub(super) struct Fq24Parameters;
+
+pub(super) type Fq24 = Fp24<Fq24Parameters>;
+
+impl Fp24Parameters for Fq24Parameters {
+    type Fp4Params = Fq4Parameters;
+
+    /// NONRESIDUE = V
+    const NONRESIDUE: Fq4 = field!(
+        Fq4,
+        field!(
+            Fq2,
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    );
+
+    const FROBENIUS_COEFF_FP12_C1: [Fq2; 12] = [
+        // U^(((q^0) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^1) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x6ec47a04a3f7ca9e,
+                    0xa42e0cb968c1fa44,
+                    0x578d5187fbd2bd23,
+                    0x930eeb0ac79dd4bd,
+                    0xa24883de1e09a9ee,
+                    0xdaa7058067d46f,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^2) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5892506da58478da,
+                    0x133366940ac2a74b,
+                    0x9b64a150cdf726cf,
+                    0x5cc426090a9c587e,
+                    0x5cf848adfdcd640c,
+                    0x4702bf3ac02380,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^3) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x982c13d9d084771f,
+                    0xfd49de0c6da34a32,
+                    0x61a530d183ab0e53,
+                    0xdf8fe44106dd9879,
+                    0x40f29b58d88472bc,
+                    0x158723199046d5d,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^4) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xdacd106da5847973,
+                    0xd8fe2454bac2a79a,
+                    0x1ada4fd6fd832edc,
+                    0xfb9868449d150908,
+                    0xd63eb8aeea32285e,
+                    0x167d6a36f873fd0,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^5) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x296799d52c8cac81,
+                    0x591bd15304e14fee,
+                    0xa17df4987d85130,
+                    0x4c80f9363f3fc3bc,
+                    0x9eaa177aba7ac8ce,
+                    0x7dcb2c189c98ed,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^6) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x120d3e434c71c50,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^7) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x164445fb5c083563,
+                    0x72dd508ac73e05bc,
+                    0xc76610a7be368adc,
+                    0x8713eee839573ed1,
+                    0x23f281e24e979f4c,
+                    0xd39340975d3c7b,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^8) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2c766f925a7b8727,
+                    0x3d7f6b0253d58b5,
+                    0x838ec0deec122131,
+                    0xbd5eb3e9f658bb10,
+                    0x6942bd126ed3e52e,
+                    0x1673786dd04ed6a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^9) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xecdcac262f7b88e2,
+                    0x19c17f37c25cb5cd,
+                    0xbd4e315e365e39ac,
+                    0x3a92f5b1fa177b15,
+                    0x85486a67941cd67e,
+                    0x55c8147ec0a38d,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^10) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xaa3baf925a7b868e,
+                    0x3e0d38ef753d5865,
+                    0x4191258bc861923,
+                    0x1e8a71ae63e00a87,
+                    0xeffc4d11826f20dc,
+                    0x4663a2a83dd119,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^11) - 1) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5ba1262ad3735380,
+                    0xbdef8bf12b1eb012,
+                    0x14db82e63230f6cf,
+                    0xcda1e0bcc1b54fd3,
+                    0x2790ee45b226806c,
+                    0x1306f19ff2877fd,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+
+    const FROBENIUS_COEFF_FP12_C2: [Fq2; 12] = [
+        // U^((2 * (q^0) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^1) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5892506da58478da,
+                    0x133366940ac2a74b,
+                    0x9b64a150cdf726cf,
+                    0x5cc426090a9c587e,
+                    0x5cf848adfdcd640c,
+                    0x4702bf3ac02380,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^2) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xdacd106da5847973,
+                    0xd8fe2454bac2a79a,
+                    0x1ada4fd6fd832edc,
+                    0xfb9868449d150908,
+                    0xd63eb8aeea32285e,
+                    0x167d6a36f873fd0,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^3) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x120d3e434c71c50,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^4) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2c766f925a7b8727,
+                    0x3d7f6b0253d58b5,
+                    0x838ec0deec122131,
+                    0xbd5eb3e9f658bb10,
+                    0x6942bd126ed3e52e,
+                    0x1673786dd04ed6a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^5) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xaa3baf925a7b868e,
+                    0x3e0d38ef753d5865,
+                    0x4191258bc861923,
+                    0x1e8a71ae63e00a87,
+                    0xeffc4d11826f20dc,
+                    0x4663a2a83dd119,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^6) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^7) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5892506da58478da,
+                    0x133366940ac2a74b,
+                    0x9b64a150cdf726cf,
+                    0x5cc426090a9c587e,
+                    0x5cf848adfdcd640c,
+                    0x4702bf3ac02380,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^8) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xdacd106da5847973,
+                    0xd8fe2454bac2a79a,
+                    0x1ada4fd6fd832edc,
+                    0xfb9868449d150908,
+                    0xd63eb8aeea32285e,
+                    0x167d6a36f873fd0,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^9) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x120d3e434c71c50,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^10) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2c766f925a7b8727,
+                    0x3d7f6b0253d58b5,
+                    0x838ec0deec122131,
+                    0xbd5eb3e9f658bb10,
+                    0x6942bd126ed3e52e,
+                    0x1673786dd04ed6a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^((2 * (q^11) - 2) / 6)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xaa3baf925a7b868e,
+                    0x3e0d38ef753d5865,
+                    0x4191258bc861923,
+                    0x1e8a71ae63e00a87,
+                    0xeffc4d11826f20dc,
+                    0x4663a2a83dd119,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+
+    const FROBENIUS_COEFF_FP24_C1: [Fq2; 24] = [
+        // U^(((q^0) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2cdffffffffff68,
+                    0x51409f837fffffb1,
+                    0x9f7db3a98a7d3ff2,
+                    0x7b4e97b76e7c6305,
+                    0x4cf495bf803c84e8,
+                    0x8d6661e2fdf49a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^1) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x249f370e10e6e812,
+                    0x57b271366e5c9e,
+                    0xd2dfa87d436449f9,
+                    0x601c4eac52cb1dd3,
+                    0xbc5a8fec13b91fe6,
+                    0xcbbacb57863256,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^2) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x6ec47a04a3f7ca9e,
+                    0xa42e0cb968c1fa44,
+                    0x578d5187fbd2bd23,
+                    0x930eeb0ac79dd4bd,
+                    0xa24883de1e09a9ee,
+                    0xdaa7058067d46f,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^3) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x1dbc570b239f21ee,
+                    0x528a320870911b45,
+                    0x4f0e34a56bad6f8d,
+                    0xbf3d6d8ad20a225c,
+                    0x948799b3dcb95688,
+                    0x7868308f981f96,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^4) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5892506da58478da,
+                    0x133366940ac2a74b,
+                    0x9b64a150cdf726cf,
+                    0x5cc426090a9c587e,
+                    0x5cf848adfdcd640c,
+                    0x4702bf3ac02380,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^5) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x3e8861aaeba4479e,
+                    0x4213b78e95e6cbd5,
+                    0x9870ab561870c6c8,
+                    0x9a9cbe291a8da7a5,
+                    0xd0802141aefa0921,
+                    0x11ab0813526dca7,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^6) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x982c13d9d084771f,
+                    0xfd49de0c6da34a32,
+                    0x61a530d183ab0e53,
+                    0xdf8fe44106dd9879,
+                    0x40f29b58d88472bc,
+                    0x158723199046d5d,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^7) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xae67468c2165475c,
+                    0x93039d26c0d5caae,
+                    0x60e3695b93880169,
+                    0x4ae9b7e7dfc9c088,
+                    0x1f23804e41acc94,
+                    0x3553968c63e370,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^8) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xdacd106da5847973,
+                    0xd8fe2454bac2a79a,
+                    0x1ada4fd6fd832edc,
+                    0xfb9868449d150908,
+                    0xd63eb8aeea32285e,
+                    0x167d6a36f873fd0,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^9) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x19e92a9cdabd5f8c,
+                    0x41bc051d5f786f37,
+                    0xc59102d8d50c7ccf,
+                    0x3a806f7cc7c289d1,
+                    0x142591559b40e93b,
+                    0x4ef5b5dda0aa51,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^10) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x296799d52c8cac81,
+                    0x591bd15304e14fee,
+                    0xa17df4987d85130,
+                    0x4c80f9363f3fc3bc,
+                    0x9eaa177aba7ac8ce,
+                    0x7dcb2c189c98ed,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^11) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x15b3af80fdc6256f,
+                    0x5784c8628044af6a,
+                    0x30c896e5e1e3d9dc,
+                    0xa5cf24500eb4b1bb,
+                    0x33a5a4117402bf46,
+                    0x16b25ac1490d4c4,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^12) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x823ac00000000099,
+                    0xc5cabdc0b000004f,
+                    0x7f75ae862f8c080d,
+                    0x9ed4423b9278b089,
+                    0x79467000ec64c452,
+                    0x120d3e434c71c50,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^13) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x606988f1ef1917ef,
+                    0x16b3aad2f991a362,
+                    0x4c13b9b276a4fe07,
+                    0xba068b46ae29f5bb,
+                    0x9e075d458e82954,
+                    0xe27f7ac03ede94,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^14) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x164445fb5c083563,
+                    0x72dd508ac73e05bc,
+                    0xc76610a7be368adc,
+                    0x8713eee839573ed1,
+                    0x23f281e24e979f4c,
+                    0xd39340975d3c7b,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^15) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x674c68f4dc60de13,
+                    0xc4812b3bbf6ee4bb,
+                    0xcfe52d8a4e5bd872,
+                    0x5ae56c682eeaf132,
+                    0x31b36c0c8fe7f2b2,
+                    0x135d215882cf154,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^16) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x2c766f925a7b8727,
+                    0x3d7f6b0253d58b5,
+                    0x838ec0deec122131,
+                    0xbd5eb3e9f658bb10,
+                    0x6942bd126ed3e52e,
+                    0x1673786dd04ed6a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^17) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x46805e55145bb863,
+                    0xd4f7a5b59a19342b,
+                    0x8682b6d9a1988137,
+                    0x7f861bc9e6676be9,
+                    0xf5bae47ebda74019,
+                    0x9389c4e29e3442,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^18) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xecdcac262f7b88e2,
+                    0x19c17f37c25cb5cd,
+                    0xbd4e315e365e39ac,
+                    0x3a92f5b1fa177b15,
+                    0x85486a67941cd67e,
+                    0x55c8147ec0a38d,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^19) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xd6a17973de9ab8a5,
+                    0x8407c01d6f2a3551,
+                    0xbe0ff8d426814696,
+                    0xcf39220b212b5306,
+                    0xc448cdbb88867ca6,
+                    0x178e6af8b612d7a,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^20) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0xaa3baf925a7b868e,
+                    0x3e0d38ef753d5865,
+                    0x4191258bc861923,
+                    0x1e8a71ae63e00a87,
+                    0xeffc4d11826f20dc,
+                    0x4663a2a83dd119,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^21) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x6b1f95632542a075,
+                    0xd54f5826d08790c9,
+                    0x59625f56e4fccb30,
+                    0xdfa26a76393289bd,
+                    0xb215746ad1605fff,
+                    0x15f44903a246699,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^22) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x5ba1262ad3735380,
+                    0xbdef8bf12b1eb012,
+                    0x14db82e63230f6cf,
+                    0xcda1e0bcc1b54fd3,
+                    0x2790ee45b226806c,
+                    0x1306f19ff2877fd,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+        // U^(((q^23) - 1) / 12)
+        field!(
+            Fq2,
+            field!(
+                Fq,
+                BigInteger384([
+                    0x6f55107f0239da92,
+                    0xbf8694e1afbb5096,
+                    0xee2acb49d8256e23,
+                    0x7453b5a2f24061d3,
+                    0x929561aef89e89f4,
+                    0x43149a03343c26,
+                ])
+            ),
+            field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+        ),
+    ];
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// BLS12-381 scalar field.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// q = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+/// R = 10920338887063814464675503992315976177888879664585288394250266608035967270910 # Montgomery R
+/// s = 32
+/// o = q - 1
+/// F = GF(q)
+/// g = F(7)
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen: ", g * R % q)
+/// print("Gen: ", into_chunks(g * R % q, 64, 4))
+/// print("2-adic gen: ", into_chunks(g2 * R % q, 64, 4))
+/// ```
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    /// LARGE_SUBGROUP_ROOT_OF_UNITY = (2-adic gen)^(3^-1 mod 2^32) * GENERATOR^((r - 1) / 3)
+    /// Chosen so that LARGE_SUBGROUP_ROOT_OF_UNITY^3 = TWO_ADIC_ROOT_OF_UNITY.
+    /// Encoded in Montgomery form, so the value is
+    /// (27190014779860110908007675333221278800259766657973320723066279455788162251031 * R) % r
+    #[rustfmt::skip]
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
+        0xc3bd1fc0baafea0c,
+        0x15e3d3605ecb5af5,
+        0xac35740580d62e80,
+        0x5a86e0353b85f530,
+    ]));
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(1);
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^t =
+    /// 10238227357739495823651030575849232062558860180284477541189508159991286009131
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xb9b58d8c5f0e466a,
+        0x5b1b4c801819d7ec,
+        0xaf53ae352a31e64,
+        0x5bf3adda19e9b27b,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 7
+    /// Encoded in Montgomery form, so the value is
+    /// (7 * R) % r = 24006497034320510773280787438025867407531605151569380937148207556313189711857
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0xefffffff1,
+        0x17e363d300189c0f,
+        0xff9c57876f8457b0,
+        0x351332208fc5a8c4,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 18446744069414584319u64;
+    /// MODULUS = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xffffffff00000001,
+        0x53bda402fffe5bfe,
+        0x3339d80809a1d805,
+        0x73eda753299d7d48,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (r - 1) / 2 =
+    /// 26217937587563095239723870254092982918845276250263818911301829349969290592256
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x7fffffff80000000,
+        0xa9ded2017fff2dff,
+        0x199cec0404d0ec02,
+        0x39f6d3a994cebea4,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x1fffffffe,
+        0x5884b7fa00034802,
+        0x998c4fefecbc4ff5,
+        0x1824b159acc5056f,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xc999e990f3f29c6d,
+        0x2b6cedcb87925c23,
+        0x5d314967254398f,
+        0x748d9d99f59ff11,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 12208678567578594777604504606729831043093128246378069236549469339647
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xfffe5bfeffffffff,
+        0x9a1d80553bda402,
+        0x299d7d483339d808,
+        0x73eda753,
+    ]);
+    /// (t - 1) / 2 =
+    /// 6104339283789297388802252303364915521546564123189034618274734669823
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x7fff2dff7fffffff,
+        0x4d0ec02a9ded201,
+        0x94cebea4199cec04,
+        0x39f6d3a9,
+    ]);
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, Zero};
+use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
+
+use crate::{
+    bls12_381::{Fq, Fr},
+    traits::{ModelParameters, SWModelParameters},
+};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Bls12_381G1Parameters;
+
+impl ModelParameters for Bls12_381G1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Bls12_381G1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0]));
+    /// COEFF_B = 4
+    const COEFF_B: Fq = field!(
+        Fq,
+        BigInteger384([
+            0xaa270000000cfff3,
+            0x53cc0032fc34000a,
+            0x478fe97a6b0a807f,
+            0xb1d37ebee6ba24d7,
+            0x8ec9733bbf78ab2f,
+            0x9d645513d83de7e,
+        ])
+    );
+    /// COFACTOR = (x - 1)^2 / 3 = 76329603384216526031706109802092473003
+    const COFACTOR: &'static [u64] = &[0x8c00aaab0000aaab, 0x396c8c005555e156];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    /// = 52435875175126190458656871551744051925719901746859129887267498875565241663483
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([
+            0x40229a33c46652b,
+            0xfff4aeddbe10862,
+            0x2442d96fe6ff2893,
+            0x471e70efea875ef8,
+        ])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G1_GENERATOR_X =
+/// 3685416753713387016781088315183077757961620795782546409894578378688607592378376318836054947676345821548104185464507
+pub const G1_GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger384([
+        0x5cb38790fd530c16,
+        0x7817fc679976fff5,
+        0x154f95c7143ba1c1,
+        0xf0ae6acdf3d0e747,
+        0xedce6ecc21dbf440,
+        0x120177419e0bfb75,
+    ])
+);
+
+/// G1_GENERATOR_Y =
+/// 1339506544944476473020471379941921221584933875938349620426543736416511423956333506472724655353366534992391756441569
+pub const G1_GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger384([
+        0xbaac93d50ce72271,
+        0x8c22631a7918fd8e,
+        0xdd595f13570725ce,
+        0x51ac582950405194,
+        0xe1c8c3fad0059c0,
+        0xbbc3efc5008a26a,
+    ])
+);
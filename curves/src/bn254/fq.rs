@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// BN254 base field.
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 1;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^t =
+    /// 21888242871839275222246405745257275088696311157297823662689037894645226208582
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x68c3488912edefaa,
+        0x8d087f6872aabf4f,
+        0x51e1a24709081231,
+        0x2259d6b14729c0fa,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 3
+    /// Encoded in Montgomery form, so the value is
+    /// (3 * R) % q = 19052624634359457937016868847204597229365286637454337178037183604060995791063
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x7a17caa950ad28d7,
+        0x1f6ac17ae15521b9,
+        0x334bea4e696bd284,
+        0x2a1f6744ce179d8e,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 9786893198990664585u64;
+    /// MODULUS = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 254;
+    /// (q - 1) / 2 =
+    /// 10944121435919637611123202872628637544348155578648911831344518947322613104291
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0xd35d438dc58f0d9d,
+        0xa78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0xe0a77c19a07df2f,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xf32cfc5b538afa89,
+        0xb5e71911d44501fb,
+        0x47ab1eff0a417ff6,
+        0x6d89f71cab8351f,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 2;
+    // T and T_MINUS_ONE_DIV_TWO, where q - 1 = 2^s * t
+
+    /// t = (q - 1) / 2^s =
+    /// 10944121435919637611123202872628637544348155578648911831344518947322613104291
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+    /// (t - 1) / 2 =
+    /// 5472060717959818805561601436314318772174077789324455915672259473661306552145
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x4f082305b61f3f51,
+        0x65e05aa45a1c72a3,
+        0x6e14116da0605617,
+        0xc19139cb84c680a,
+    ]);
+}
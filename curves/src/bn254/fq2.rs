@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+
+use snarkvm_fields::{field, Fp2, Fp2Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+use crate::bn254::Fq;
+
+pub type Fq2 = Fp2<Fq2Parameters>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fq2Parameters;
+
+impl Fp2Parameters for Fq2Parameters {
+    type Fp = Fq;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: [Fq; 2] = [
+        // NONRESIDUE**(((q^0) - 1) / 2)
+        field!(
+            Fq,
+            BigInteger([
+                0xd35d438dc58f0d9d,
+                0xa78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0xe0a77c19a07df2f,
+            ])
+        ),
+        // NONRESIDUE**(((q^1) - 1) / 2)
+        field!(
+            Fq,
+            BigInteger([
+                0x68c3488912edefaa,
+                0x8d087f6872aabf4f,
+                0x51e1a24709081231,
+                0x2259d6b14729c0fa,
+            ])
+        ),
+    ];
+    /// NONRESIDUE = -1
+    const NONRESIDUE: Fq = field!(
+        Fq,
+        BigInteger([
+            0x68c3488912edefaa,
+            0x8d087f6872aabf4f,
+            0x51e1a24709081231,
+            0x2259d6b14729c0fa,
+        ])
+    );
+    /// QUADRATIC_NONRESIDUE = U + 2
+    const QUADRATIC_NONRESIDUE: (Fq, Fq) = (
+        field!(
+            Fq,
+            BigInteger([
+                0xa6ba871b8b1e1b3a,
+                0x14f1d651eb8e167b,
+                0xccdd46def0f28c58,
+                0x1c14ef83340fbe5e,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger([
+                0xd35d438dc58f0d9d,
+                0xa78eb28f5c70b3d,
+                0x666ea36f7879462c,
+                0xe0a77c19a07df2f,
+            ])
+        ),
+    );
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue(fe: &Self::Fp) -> Self::Fp {
+        -(*fe)
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// BN254 scalar field.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// q = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+/// R = 6350874878119819312338956282401532410528162663560392320966563075034087161851 # Montgomery R
+/// s = 28
+/// o = q - 1
+/// F = GF(q)
+/// g = F(5)
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen: ", g * R % q)
+/// print("Gen: ", into_chunks(g * R % q, 64, 4))
+/// print("2-adic gen: ", into_chunks(g2 * R % q, 64, 4))
+/// ```
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    /// LARGE_SUBGROUP_ROOT_OF_UNITY = (2-adic gen)^(9^-1 mod 2^28) * GENERATOR^((r - 1) / 9)
+    /// Chosen so that LARGE_SUBGROUP_ROOT_OF_UNITY^9 = TWO_ADIC_ROOT_OF_UNITY.
+    /// Encoded in Montgomery form, so the value is
+    /// (8909971109873282615683849498946744434716403163743542989120725480554883684026 * R) % r
+    #[rustfmt::skip]
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
+        0xa51bf4724b832917,
+        0x39b4cff7218db8c9,
+        0x6f652fb60f6e4c2c,
+        0x99f0ab385a6d1b4,
+    ]));
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(2);
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 28;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^t =
+    /// 19103219067921713944291392827692070036145651957329286315305642004821462161904
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x636e735580d13d9c,
+        0xa22bf3742445ffd6,
+        0x56452ac01eb203d8,
+        0x1860ef942963f9e7,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 5
+    /// Encoded in Montgomery form, so the value is
+    /// (5 * R) % r = 9866131518759821339448375666750386964092448917385927261134611188594627313638
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x1b0d0ef99fffffe6,
+        0xeaba68a3a32a913f,
+        0x47d8eb76d8dd0689,
+        0x15d0085520f5bbc3,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 14042775128853446655u64;
+    /// MODULUS = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x43e1f593f0000001,
+        0x2833e84879b97091,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 254;
+    /// (r - 1) / 2 =
+    /// 10944121435919637611123202872628637544274182200208017171849102093287904247808
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xa1f0fac9f8000000,
+        0x9419f4243cdcb848,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0xe0a77c19a07df2f,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0x1bb8e645ae216da7,
+        0x53fe3ab1e35c59e3,
+        0x8c49833d53bb8085,
+        0x216d0b17f4e44a5,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 2;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 81540058820840996586704275553141814055101440848469862132140264610111
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x9b9709143e1f593f,
+        0x181585d2833e8487,
+        0x131a029b85045b68,
+        0x30644e72e,
+    ]);
+    /// (t - 1) / 2 =
+    /// 40770029410420498293352137776570907027550720424234931066070132305055
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xcdcb848a1f0fac9f,
+        0xc0ac2e9419f4243,
+        0x98d014dc2822db4,
+        0x183227397,
+    ]);
+}
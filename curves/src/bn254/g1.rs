@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    bn254::{Fq, Fr},
+    traits::{ModelParameters, SWModelParameters},
+};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Bn254G1Parameters;
+
+impl ModelParameters for Bn254G1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Bn254G1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// COEFF_B = 3
+    const COEFF_B: Fq = field!(
+        Fq,
+        BigInteger256([
+            0x7a17caa950ad28d7,
+            0x1f6ac17ae15521b9,
+            0x334bea4e696bd284,
+            0x2a1f6744ce179d8e,
+        ])
+    );
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    /// = 1
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([
+            0xac96341c4ffffffb,
+            0x36fc76959f60cd29,
+            0x666ea36f7879462e,
+            0xe0a77c19a07df2f,
+        ])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G1_GENERATOR_X =
+/// 1
+pub const G1_GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger256([
+        0xd35d438dc58f0d9d,
+        0xa78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0xe0a77c19a07df2f,
+    ])
+);
+
+/// G1_GENERATOR_Y =
+/// 2
+pub const G1_GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger256([
+        0xa6ba871b8b1e1b3a,
+        0x14f1d651eb8e167b,
+        0xccdd46def0f28c58,
+        0x1c14ef83340fbe5e,
+    ])
+);
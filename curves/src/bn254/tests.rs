@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bn254::{
+        g1::Bn254G1Parameters,
+        g2::Bn254G2Parameters,
+        Bn254,
+        Bn254Parameters,
+        Fq,
+        Fq12,
+        Fq2,
+        Fq2Parameters,
+        Fq6,
+        Fq6Parameters,
+        FqParameters,
+        Fr,
+        FrParameters,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+    },
+    templates::{bn::mul_by_char, short_weierstrass::tests::sw_tests},
+    traits::{tests_curve::curve_tests, tests_group::group_test, AffineCurve, PairingEngine, ProjectiveCurve},
+};
+use snarkvm_fields::{
+    fp6_3over2::Fp6Parameters,
+    tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+    Field,
+    FieldParameters,
+    Fp2Parameters,
+    One,
+    PrimeField,
+    SquareRootField,
+};
+use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand};
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_bn254_fr() {
+    for _ in 0..ITERATIONS {
+        let a: Fr = rand::random();
+        let b: Fr = rand::random();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        field_serialization_test::<Fr>();
+    }
+}
+
+#[test]
+fn test_bn254_fq() {
+    for _ in 0..ITERATIONS {
+        let a: Fq = rand::random();
+        let b: Fq = rand::random();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        field_serialization_test::<Fq>();
+    }
+}
+
+#[test]
+fn test_bn254_fq2() {
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = rand::random();
+        let b: Fq2 = rand::random();
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq2>();
+}
+
+#[test]
+fn test_bn254_fq6() {
+    for _ in 0..ITERATIONS {
+        let g: Fq6 = rand::random();
+        let h: Fq6 = rand::random();
+        field_test(g, h);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq6>();
+}
+
+#[test]
+fn test_bn254_fq12() {
+    for _ in 0..ITERATIONS {
+        let g: Fq12 = rand::random();
+        let h: Fq12 = rand::random();
+        field_test(g, h);
+    }
+    frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
+    field_serialization_test::<Fq12>();
+}
+
+#[test]
+fn test_num_bits() {
+    assert_eq!(FrParameters::MODULUS_BITS, 254);
+    assert_eq!(FrParameters::CAPACITY, 253);
+    assert_eq!(FqParameters::MODULUS_BITS, 254);
+    assert_eq!(FqParameters::CAPACITY, 253);
+}
+
+#[test]
+fn test_fq2_nonresidue() {
+    assert_eq!(Fq2Parameters::NONRESIDUE, -Fq::one());
+
+    let quadratic_non_residue = Fq2::new(
+        Fq2Parameters::QUADRATIC_NONRESIDUE.0,
+        Fq2Parameters::QUADRATIC_NONRESIDUE.1,
+    );
+    assert!(quadratic_non_residue.legendre().is_qnr());
+}
+
+#[test]
+fn test_fq6_mul_nonresidue() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let nqr = Fq2::new(Fq::from(9u64), Fq::one());
+
+    for _ in 0..1000 {
+        let a = Fq2::rand(&mut rng);
+        assert_eq!(Fq6Parameters::mul_fp2_by_nonresidue(&a), a * nqr);
+    }
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    curve_tests::<G1Projective>();
+    sw_tests::<Bn254G1Parameters>();
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let a: G1Projective = rand::random();
+    let b: G1Projective = rand::random();
+    group_test(a, b);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+    sw_tests::<Bn254G2Parameters>();
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let a: G2Projective = rand::random();
+    let b: G2Projective = rand::random();
+    group_test(a, b);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_mul_by_char() {
+    // On G2, the Frobenius endomorphism acts as multiplication by q.
+    let a: G2Projective = rand::random();
+    let a = a.into_affine();
+    let psi_a = mul_by_char::<Bn254Parameters>(a);
+    assert!(psi_a.is_on_curve());
+    assert_eq!(
+        psi_a.into_projective(),
+        a.mul_bits(BitIteratorBE::new(Fq::characteristic()))
+    );
+}
+
+#[test]
+fn test_bilinearity() {
+    let a: G1Projective = rand::random();
+    let b: G2Projective = rand::random();
+    let s: Fr = rand::random();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = Bn254::pairing(sa, b);
+    let ans2 = Bn254::pairing(a, sb);
+    let ans3 = Bn254::pairing(a, b).pow(s.into_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq12::one());
+    assert_ne!(ans2, Fq12::one());
+    assert_ne!(ans3, Fq12::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{FftParameters, FieldParameters, Fp768, Fp768Parameters};
+use snarkvm_fields::{FftParameters, FieldParameters, Fp768, Fp768Parameters, PoseidonMDSParameters};
 use snarkvm_utilities::biginteger::BigInteger768 as BigInteger;
 
 pub type Fq = Fp768<FqParameters>;
@@ -179,3 +179,144 @@ impl FieldParameters for FqParameters {
         0x48ba093ee0f382,
     ]);
 }
+
+impl PoseidonMDSParameters for FqParameters {
+    const POSEIDON_ALPHA: u64 = 5;
+    const POSEIDON_FULL_ROUNDS: u32 = 8;
+    /// The Cauchy matrix `MDS[i][j] = 1 / (i + j + 3)`, encoded in Montgomery form.
+    const POSEIDON_MDS: [[BigInteger; 3]; 3] = [
+        [
+            BigInteger([
+                17948064239970495737u64,
+                948255120894013262u64,
+                11017622412259774746u64,
+                12578288672050682529u64,
+                11160243486691966157u64,
+                17245218835794583481u64,
+                16499228877691933956u64,
+                103039060119408913u64,
+                13075006851496569941u64,
+                16712926834881976938u64,
+                2947577087722600478u64,
+                62279191753993265u64,
+            ]),
+            BigInteger([
+                9054487050828374424u64,
+                10392717116296913754u64,
+                3254301241091298245u64,
+                11295878325229259195u64,
+                1707330205353258446u64,
+                10510288978485217383u64,
+                3082459063332132610u64,
+                7274337652523729543u64,
+                17543266143078080943u64,
+                9199350678197670445u64,
+                3047804446073956935u64,
+                26238646619925922u64,
+            ]),
+            BigInteger([
+                3554240825920789216u64,
+                935476063553710357u64,
+                13671487437098769566u64,
+                1658005030699586709u64,
+                16123259423250248050u64,
+                15786928812271994552u64,
+                9844664880149526734u64,
+                5819470122018983634u64,
+                6655915284978644108u64,
+                18427526986783867326u64,
+                13506290001084896517u64,
+                20990917295940737u64,
+            ]),
+        ],
+        [
+            BigInteger([
+                9054487050828374424u64,
+                10392717116296913754u64,
+                3254301241091298245u64,
+                11295878325229259195u64,
+                1707330205353258446u64,
+                10510288978485217383u64,
+                3082459063332132610u64,
+                7274337652523729543u64,
+                17543266143078080943u64,
+                9199350678197670445u64,
+                3047804446073956935u64,
+                26238646619925922u64,
+            ]),
+            BigInteger([
+                3554240825920789216u64,
+                935476063553710357u64,
+                13671487437098769566u64,
+                1658005030699586709u64,
+                16123259423250248050u64,
+                15786928812271994552u64,
+                9844664880149526734u64,
+                5819470122018983634u64,
+                6655915284978644108u64,
+                18427526986783867326u64,
+                13506290001084896517u64,
+                20990917295940737u64,
+            ]),
+            BigInteger([
+                17787154378284242626u64,
+                18004564156613302248u64,
+                15526642342336953000u64,
+                2564820693642846668u64,
+                459082488967863806u64,
+                13469859714618732197u64,
+                8386795555010051076u64,
+                4104146888900910357u64,
+                9510225490546529611u64,
+                15027152313368612985u64,
+                18246289357006838702u64,
+                72081090268134685u64,
+            ]),
+        ],
+        [
+            BigInteger([
+                3554240825920789216u64,
+                935476063553710357u64,
+                13671487437098769566u64,
+                1658005030699586709u64,
+                16123259423250248050u64,
+                15786928812271994552u64,
+                9844664880149526734u64,
+                5819470122018983634u64,
+                6655915284978644108u64,
+                18427526986783867326u64,
+                13506290001084896517u64,
+                20990917295940737u64,
+            ]),
+            BigInteger([
+                17787154378284242626u64,
+                18004564156613302248u64,
+                15526642342336953000u64,
+                2564820693642846668u64,
+                459082488967863806u64,
+                13469859714618732197u64,
+                8386795555010051076u64,
+                4104146888900910357u64,
+                9510225490546529611u64,
+                15027152313368612985u64,
+                18246289357006838702u64,
+                72081090268134685u64,
+            ]),
+            BigInteger([
+                12962525838190084349u64,
+                8312142511972956376u64,
+                4721838176682760605u64,
+                8025944298551657029u64,
+                2147712340909478122u64,
+                2120309765709235316u64,
+                14976845550600636674u64,
+                10585156210742347600u64,
+                8238823518314180205u64,
+                4527433775848054171u64,
+                17074742243632158733u64,
+                26691082180282827u64,
+            ]),
+        ],
+    ];
+    const POSEIDON_PARTIAL_ROUNDS: u32 = 57;
+}
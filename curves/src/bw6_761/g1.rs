@@ -19,7 +19,7 @@ use snarkvm_utilities::biginteger::{BigInteger384, BigInteger768};
 
 use crate::{
     bw6_761::{Fq, Fr},
-    traits::{ModelParameters, SWModelParameters},
+    traits::{GLVParameters, ModelParameters, SWModelParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -76,6 +76,105 @@ impl SWModelParameters for BW6_761G1Parameters {
             71512347676739162,
         ])
     );
+    /// GLV_PARAMETERS, with BETA a primitive cube root of unity in Fq and LAMBDA = 258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231
+    const GLV_PARAMETERS: Option<GLVParameters<Fq, Fr>> = Some(GLVParameters {
+        // BETA = 4922464560225523242118178942575080391082002530232324381063048548642823052024664478336818169867474395270858391911405337707247735739826664939444490469542109391530482826728203582549674992333383150446779312029624171857054392282775648
+        beta: field!(
+            Fq,
+            BigInteger768([
+                0x7f96b51bd840c549,
+                0xd59782096496171f,
+                0x49b046fd9ce14bbc,
+                0x4b6163bba7527a56,
+                0xef6c92fb771d59f1,
+                0x425bedbac1dfdc7,
+                0xd3ac39de759c0ffd,
+                0x9f43ed0e063a81d0,
+                0x5bd7d20b4f9a3ce2,
+                0x411f03c36cf5c3c,
+                0x2d658fd49661c472,
+                0x1100249ae760b93,
+            ])
+        ),
+        // LAMBDA = 258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231
+        lambda: field!(
+            Fr,
+            BigInteger384([
+                0x2c766f925a7b8727,
+                0x3d7f6b0253d58b5,
+                0x838ec0deec122131,
+                0xbd5eb3e9f658bb10,
+                0x6942bd126ed3e52e,
+                0x1673786dd04ed6a,
+            ])
+        ),
+        // A1 = 293634935485640680722085584138834120324914961969255022593
+        a1: field!(
+            Fr,
+            BigInteger384([
+                0x1cae140548e890f6,
+                0xfff8c29d9851c255,
+                0x86b67d922c10033e,
+                0xc05529ebc3688c6d,
+                0x48d8d6810da67b31,
+                0x1a267bfeca0146f,
+            ])
+        ),
+        // B1 = -293634935485640680722085584138834120315328839056164388863
+        b1: field!(
+            Fr,
+            BigInteger384([
+                0x74e339ddb5613b70,
+                0xb9fb95ba7f4cc592,
+                0x455ac137f7424867,
+                0xd79cc4a654e7545b,
+                0x899bf95012409d81,
+                0xb12b68b8940f1b,
+            ])
+        ),
+        // A2 = 293634935485640680722085584138834120315328839056164388863
+        a2: field!(
+            Fr,
+            BigInteger384([
+                0x102586224a9ec491,
+                0x5d0fc789b0b33a6e,
+                0xd998a0f7c2c6ff98,
+                0x4286154cac0dbf33,
+                0x3c9f0c705a60abb9,
+                0xfd0edd5f3101cf,
+            ])
+        ),
+        // B2 = 587269870971281361444171168277668240640243801025419411456
+        b2: field!(
+            Fr,
+            BigInteger384([
+                0xa7cada2793875586,
+                0x45fd2ce31904fcc2,
+                0x415bbc5a34cdbad7,
+                0xe8b865456e813812,
+                0xbf3cdd30fb65ddaf,
+                0xf13c57340c0553,
+            ])
+        ),
+        // G1 = 89458034301964758156995792381393618105150552387511985416827
+        g1: &[
+            0x2289a5d86c78aa7b,
+            0x85f6e7602b7a9d8c,
+            0x4061751dd380c860,
+            0xe,
+            0x0,
+            0x0,
+        ],
+        // G2 = 44729017150982379078497896190696809051845155550103861279600
+        g2: &[
+            0x7ccf39ddb5613b70,
+            0x42fb73b015bd4e9e,
+            0x2030ba8ee9c06430,
+            0x7,
+            0x0,
+            0x0,
+        ],
+    });
 
     #[inline(always)]
     fn mul_by_a(_elem: &Self::BaseField) -> Self::BaseField {
@@ -19,7 +19,7 @@ use snarkvm_utilities::biginteger::{BigInteger384, BigInteger768};
 
 use crate::{
     bw6_761::{Fq, Fr},
-    traits::{ModelParameters, SWModelParameters},
+    traits::{GLVParameters, ModelParameters, SWModelParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -76,6 +76,105 @@ impl SWModelParameters for Bls12_377G2Parameters {
             26723985783783076,
         ])
     );
+    /// GLV_PARAMETERS, with BETA a primitive cube root of unity in Fq and LAMBDA = 258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231
+    const GLV_PARAMETERS: Option<GLVParameters<Fq, Fr>> = Some(GLVParameters {
+        // BETA = 1968985824090209297278610739700577151397666382303825728450741611566800370218827257750865013421937292370006175842381275743914023380727582819905021229583192207421122272650305267822868639090213645505120388400344940985710520836292650
+        beta: field!(
+            Fq,
+            BigInteger768([
+                0x67a04ae427bfb5f8,
+                0x9d32d491eb6a5cff,
+                0x43d03c1cb68051d4,
+                0xb75ca96f69859a5,
+                0x763497f5325ec60,
+                0x48076b5c278dd94d,
+                0x8ca3965ff91efd06,
+                0x1e6077657ea02f5d,
+                0xcdd6c153a8c37724,
+                0x28b5b634e5c22ea4,
+                0x9e01e3efd42e902c,
+                0xe3d6815769a804,
+            ])
+        ),
+        // LAMBDA = 258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231
+        lambda: field!(
+            Fr,
+            BigInteger384([
+                0x2c766f925a7b8727,
+                0x3d7f6b0253d58b5,
+                0x838ec0deec122131,
+                0xbd5eb3e9f658bb10,
+                0x6942bd126ed3e52e,
+                0x1673786dd04ed6a,
+            ])
+        ),
+        // A1 = 293634935485640680722085584138834120324914961969255022593
+        a1: field!(
+            Fr,
+            BigInteger384([
+                0x1cae140548e890f6,
+                0xfff8c29d9851c255,
+                0x86b67d922c10033e,
+                0xc05529ebc3688c6d,
+                0x48d8d6810da67b31,
+                0x1a267bfeca0146f,
+            ])
+        ),
+        // B1 = -293634935485640680722085584138834120315328839056164388863
+        b1: field!(
+            Fr,
+            BigInteger384([
+                0x74e339ddb5613b70,
+                0xb9fb95ba7f4cc592,
+                0x455ac137f7424867,
+                0xd79cc4a654e7545b,
+                0x899bf95012409d81,
+                0xb12b68b8940f1b,
+            ])
+        ),
+        // A2 = 293634935485640680722085584138834120315328839056164388863
+        a2: field!(
+            Fr,
+            BigInteger384([
+                0x102586224a9ec491,
+                0x5d0fc789b0b33a6e,
+                0xd998a0f7c2c6ff98,
+                0x4286154cac0dbf33,
+                0x3c9f0c705a60abb9,
+                0xfd0edd5f3101cf,
+            ])
+        ),
+        // B2 = 587269870971281361444171168277668240640243801025419411456
+        b2: field!(
+            Fr,
+            BigInteger384([
+                0xa7cada2793875586,
+                0x45fd2ce31904fcc2,
+                0x415bbc5a34cdbad7,
+                0xe8b865456e813812,
+                0xbf3cdd30fb65ddaf,
+                0xf13c57340c0553,
+            ])
+        ),
+        // G1 = 89458034301964758156995792381393618105150552387511985416827
+        g1: &[
+            0x2289a5d86c78aa7b,
+            0x85f6e7602b7a9d8c,
+            0x4061751dd380c860,
+            0xe,
+            0x0,
+            0x0,
+        ],
+        // G2 = 44729017150982379078497896190696809051845155550103861279600
+        g2: &[
+            0x7ccf39ddb5613b70,
+            0x42fb73b015bd4e9e,
+            0x2030ba8ee9c06430,
+            0x7,
+            0x0,
+            0x0,
+        ],
+    });
 
     #[inline(always)]
     fn mul_by_a(_elem: &Self::BaseField) -> Self::BaseField {
@@ -32,11 +32,20 @@ use crate::{
     traits::{tests_curve::curve_tests, tests_group::group_test, AffineCurve, PairingEngine},
 };
 use snarkvm_fields::{
-    tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+    tests_field::{
+        field_flags_serialization_test,
+        field_serialization_test,
+        field_test,
+        frobenius_test,
+        primefield_test,
+        sqrt_field_test,
+    },
     Field,
     One,
+    PoseidonMDSField,
     PrimeField,
 };
+use snarkvm_utilities::serialize::{CanonicalSerialize, ConstantSerializedSize, SWFlags};
 
 #[test]
 fn test_bw6_761_fr() {
@@ -57,6 +66,29 @@ fn test_bw6_761_fq() {
     field_serialization_test::<Fq>();
 }
 
+#[test]
+fn test_bw6_761_flags_serialization() {
+    // The flags fit in the spare bits of the BW6-761 base field, so points serialize to the same bytes
+    // as before flags could spill into an extra byte.
+    field_flags_serialization_test::<Fq, _>(SWFlags::PositiveY);
+    field_flags_serialization_test::<Fq, _>(SWFlags::Infinity);
+
+    let g1 = G1Affine::prime_subgroup_generator();
+    let g2 = G2Affine::prime_subgroup_generator();
+    assert_eq!(g1.serialized_size(), Fq::SERIALIZED_SIZE);
+    assert_eq!(g2.serialized_size(), Fq::SERIALIZED_SIZE);
+}
+
+#[test]
+fn test_bw6_761_fq_poseidon_mds() {
+    let mds = Fq::poseidon_mds_matrix();
+    for (i, row) in mds.iter().enumerate() {
+        for (j, entry) in row.iter().enumerate() {
+            assert_eq!(*entry * Fq::from((i + j + 3) as u64), Fq::one());
+        }
+    }
+}
+
 #[test]
 fn test_bw6_761_fq3() {
     let a: Fq3 = rand::random();
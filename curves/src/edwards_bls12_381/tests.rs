@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    edwards_bls12_381::*,
+    templates::twisted_edwards_extended::tests::{edwards_test, montgomery_conversion_test},
+    traits::{
+        tests_curve::curve_tests,
+        tests_group::group_test,
+        AffineCurve,
+        Group,
+        MontgomeryModelParameters,
+        ProjectiveCurve,
+    },
+};
+use snarkvm_fields::{
+    tests_field::{field_serialization_test, field_test, primefield_test},
+    Field,
+    One,
+};
+
+#[test]
+fn test_edwards_bls12_381_fr() {
+    let a: Fr = rand::random();
+    let b: Fr = rand::random();
+    field_test(a, b);
+    primefield_test::<Fr>();
+    field_serialization_test::<Fr>();
+}
+
+#[test]
+fn test_edwards_bls12_381_fq() {
+    let a: Fq = rand::random();
+    let b: Fq = rand::random();
+    field_test(a, b);
+    primefield_test::<Fq>();
+    field_serialization_test::<Fq>();
+}
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<EdwardsProjective>();
+    edwards_test::<EdwardsParameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    for _i in 0..10 {
+        let a = rand::random();
+        let b = rand::random();
+        group_test::<EdwardsProjective>(a, b);
+    }
+}
+
+#[test]
+fn test_affine_group() {
+    for _i in 0..10 {
+        let a: EdwardsAffine = rand::random();
+        let b: EdwardsAffine = rand::random();
+        group_test::<EdwardsAffine>(a, b);
+    }
+}
+
+#[test]
+fn test_generator() {
+    let generator = EdwardsAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_conversion() {
+    let a: EdwardsAffine = rand::random();
+    let b: EdwardsAffine = rand::random();
+    let a_b = {
+        use crate::traits::Group;
+        (a + b).double().double()
+    };
+    let a_b2 = (a.into_projective() + b.into_projective()).double().double();
+    assert_eq!(a_b, a_b2.into_affine());
+    assert_eq!(a_b.into_projective(), a_b2);
+}
+
+#[test]
+fn test_montgomery_conversion() {
+    montgomery_conversion_test::<EdwardsParameters>();
+}
+
+#[test]
+#[allow(clippy::many_single_char_names)]
+fn test_edwards_to_montgomery_point() {
+    let a: EdwardsAffine = rand::random();
+    let (x, y) = (a.x, a.y);
+
+    // Montgomery element (u, v)
+    let (u, v) = {
+        let numerator = Fq::one() + y;
+        let denominator = Fq::one() - y;
+
+        let u = numerator * (denominator.inverse().unwrap());
+        let v = numerator * ((denominator * x).inverse().unwrap());
+        (u, v)
+    };
+
+    // Ensure (u, v) is a valid Montgomery element
+    {
+        const A: Fq = <EdwardsParameters as MontgomeryModelParameters>::COEFF_A;
+        const B: Fq = <EdwardsParameters as MontgomeryModelParameters>::COEFF_B;
+
+        // Enforce B * v^2 == u^3 + A * u^2 + u
+        let v2 = v.square();
+        let u2 = u.square();
+        let u3 = u2 * u;
+        assert_eq!(B * v2, u3 + (A * u2) + u);
+    }
+
+    // Edwards element (x, y)
+    let (x_reconstructed, y_reconstructed) = {
+        let x = u * v.inverse().unwrap();
+
+        let numerator = u - Fq::one();
+        let denominator = u + Fq::one();
+        let y = numerator * denominator.inverse().unwrap();
+
+        (x, y)
+    };
+
+    assert_eq!(x, x_reconstructed);
+    assert_eq!(y, y_reconstructed);
+}
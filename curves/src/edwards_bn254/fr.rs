@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// Baby Jubjub scalar field.
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 4;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^t =
+    /// 660854635938548466034658205324789272997681163813030924457091119852551226483
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x1721ada8d4d27255,
+        0xcda0f5264e0e35bb,
+        0x961a936922086fe6,
+        0x1ab00857387dd52,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 31
+    /// Encoded in Montgomery form, so the value is
+    /// (31 * R) % r = 2618965734140831085070793765289188304666407134975808411702372741892512781265
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x3c284f376f3993d1,
+        0x8bc9d93705cf8b8,
+        0x239d5fcbd9538f3e,
+        0x5ca4836185b994b,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 5993417742769255919u64;
+    /// MODULUS = 2736030358979909402780800718157159386076813972158567259200215660948447373041
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x677297dc392126f1,
+        0xab3eedb83920ee0a,
+        0x370a08b6d0302b0b,
+        0x60c89ce5c263405,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 251;
+    /// (r - 1) / 2 =
+    /// 1368015179489954701390400359078579693038406986079283629600107830474223686520
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x33b94bee1c909378,
+        0xd59f76dc1c907705,
+        0x9b85045b68181585,
+        0x30644e72e131a02,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x73315dea08f9c76,
+        0xe7acffc6a098f24b,
+        0xf85a9201d818f015,
+        0x1f16424e1bb7724,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0x35e44abee7ecb21e,
+        0x74646cacf5f84ec4,
+        0xe472df203faa158f,
+        0x445b524f1ba50a8,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 5;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 171001897436244337673800044884822461629800873259910453700013478809277960815
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xa677297dc392126f,
+        0xbab3eedb83920ee0,
+        0x5370a08b6d0302b0,
+        0x60c89ce5c26340,
+    ]);
+    /// (t - 1) / 2 =
+    /// 85500948718122168836900022442411230814900436629955226850006739404638980407
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x533b94bee1c90937,
+        0x5d59f76dc1c90770,
+        0x29b85045b6818158,
+        0x30644e72e131a0,
+    ]);
+}
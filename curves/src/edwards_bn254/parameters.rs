@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    edwards_bn254::{Fq, Fr},
+    errors::GroupError,
+    templates::twisted_edwards_extended::{GroupAffine, GroupProjective},
+    traits::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+};
+use snarkvm_fields::field;
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use std::str::FromStr;
+
+pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
+pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct EdwardsParameters;
+
+impl ModelParameters for EdwardsParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TEModelParameters for EdwardsParameters {
+    type MontgomeryModelParameters = EdwardsParameters;
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+    /// COEFF_A = 168700
+    const COEFF_A: Fq = field!(
+        Fq,
+        BigInteger256([
+            0x95accf61fff261e0,
+            0x24780d659df7d378,
+            0xe0ac11b07e906ae8,
+            0xf35db2216d3def3,
+        ])
+    );
+    /// COEFF_D = 168696
+    const COEFF_D: Fq = field!(
+        Fq,
+        BigInteger256([
+            0x2735f484aff261f5,
+            0x70ba1b579a2e0f63,
+            0xff41c9a91e2caa8c,
+            0x7704a8e8fe6025f,
+        ])
+    );
+    /// COFACTOR = 8
+    const COFACTOR: &'static [u64] = &[8];
+    /// COFACTOR_INV =
+    /// 2394026564107420727433200628387514462817212225638746351800188703329891451411
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([
+            0xfac308b2e25a3d4b,
+            0xa7c55b66e25b59cb,
+            0xeccdd46def0f28c5,
+            0x1c14ef83340fbe5,
+        ])
+    );
+}
+
+impl MontgomeryModelParameters for EdwardsParameters {
+    type TEModelParameters = EdwardsParameters;
+
+    /// COEFF_A = 2 * (a + d) / (a - d) = 0x292FA
+    const COEFF_A: Fq = field!(
+        Fq,
+        BigInteger256([
+            0x80625cbd4ff261eb,
+            0xdeb30882d8efa9b6,
+            0xcc1f10880f1f36e8,
+            0x23853a11c3f5c0be,
+        ])
+    );
+    /// COEFF_B = 4 / (a - d) = 0x1
+    const COEFF_B: Fq = field!(
+        Fq,
+        BigInteger256([
+            0xac96341c4ffffffb,
+            0x36fc76959f60cd29,
+            0x666ea36f7879462e,
+            0xe0a77c19a07df2f,
+        ])
+    );
+}
+
+impl FromStr for EdwardsAffine {
+    type Err = GroupError;
+
+    fn from_str(mut s: &str) -> Result<Self, Self::Err> {
+        s = s.trim();
+        if s.is_empty() {
+            return Err(GroupError::ParsingEmptyString);
+        }
+        if s.len() < 3 {
+            return Err(GroupError::InvalidString);
+        }
+        if !(s.starts_with('(') && s.ends_with(')')) {
+            return Err(GroupError::InvalidString);
+        }
+        let mut point = Vec::new();
+        for substr in s.split(|c| c == '(' || c == ')' || c == ',' || c == ' ') {
+            if !substr.is_empty() {
+                point.push(Fq::from_str(substr)?);
+            }
+        }
+        if point.len() != 2 {
+            return Err(GroupError::InvalidGroupElement);
+        }
+        let point = EdwardsAffine::new(point[0], point[1]);
+
+        if !point.is_on_curve() {
+            Err(GroupError::InvalidGroupElement)
+        } else {
+            Ok(point)
+        }
+    }
+}
+
+/// GENERATOR_X =
+/// 5299619240641551281634865583518297030282874472190772894086521144482721001553
+const GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger256([
+        0xa8fc7bc1a89fa86,
+        0xa7d9d786e9e48627,
+        0xee6158b465bea369,
+        0x14a0ff6d2f874519,
+    ])
+);
+
+/// GENERATOR_Y =
+/// 16950150798460657717958625567821834550301663161624707787222815936182638968203
+const GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger256([
+        0xb83342d20d0201aa,
+        0x2ffef2f7cdcfeac7,
+        0xbfa79a9425a6e625,
+        0xdfb859dc3a44b70,
+    ])
+);
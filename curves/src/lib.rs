@@ -53,15 +53,25 @@ extern crate thiserror;
 
 pub mod bls12_377;
 
+pub mod bls12_381;
+
+pub mod bn254;
+
 pub mod bw6_761;
 
 pub mod edwards_bls12;
 
+pub mod edwards_bls12_381;
+
+pub mod edwards_bn254;
+
 pub mod edwards_sw6;
 
 pub mod errors;
 pub use errors::*;
 
+pub mod pallas;
+
 #[cfg(feature = "sw6")]
 #[deprecated(since = "0.8.0", note = "Please use the `bw6_761` module instead")]
 pub mod sw6;
@@ -71,3 +81,5 @@ pub mod templates;
 #[cfg_attr(test, macro_use)]
 pub mod traits;
 pub use traits::*;
+
+pub mod vesta;
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// The base field of the Pallas curve, which is the scalar field of the Vesta curve.
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^t =
+    /// 19814229590243028906643993866117402072516588566294623396325693409366934201135
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xa28db849bad6dbf0,
+        0x9083cd03d3b539df,
+        0xfba6b9ca9dc8448e,
+        0x3ec928747b89c6da,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 5
+    /// Encoded in Montgomery form, so the value is
+    /// (5 * R) % q = 28948022309329048855892746252171976962451850171313166594149061516916263223277
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0xa1a55e68ffffffed,
+        0x74c2a54b4f4982f3,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 11037532056220336127u64;
+    /// MODULUS = 28948022309329048855892746252171976963363056481941560715954676764349967630337
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x992d30ed00000001,
+        0x224698fc094cf91b,
+        0x0,
+        0x4000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (q - 1) / 2 =
+    /// 14474011154664524427946373126085988481681528240970780357977338382174983815168
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xcc96987680000000,
+        0x11234c7e04a67c8d,
+        0x0,
+        0x2000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x34786d38fffffffd,
+        0x992c350be41914ad,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0x8c78ecb30000000f,
+        0xd7d30dbd8b0de0e7,
+        0x7797a99bc3c95d18,
+        0x96d41af7b9cb714,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where q - 1 = 2^s * t
+
+    /// t = (q - 1) / 2^s =
+    /// 6739986666787659948666753771754907668419893943225396963757154709741
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x94cf91b992d30ed,
+        0x224698fc,
+        0x0,
+        0x40000000,
+    ]);
+    /// (t - 1) / 2 =
+    /// 3369993333393829974333376885877453834209946971612698481878577354870
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x4a67c8dcc969876,
+        0x11234c7e,
+        0x0,
+        0x20000000,
+    ]);
+}
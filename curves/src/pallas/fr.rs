@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// The scalar field of the Pallas curve, which is the base field of the Vesta curve.
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^t =
+    /// 20761624379169977859705911634190121761503565370703356079647768903521299517535
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0x218077428c9942de,
+        0xcc49578921b60494,
+        0xac2e5d27b2efbee2,
+        0xb79fa897f2db056,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 5
+    /// Encoded in Montgomery form, so the value is
+    /// (5 * R) % r = 28948022309329048855892746252171976962451850171311519983372807820091752185837
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x96bc8c8cffffffed,
+        0x74c2a54b49f7778e,
+        0xfffffffffffffffd,
+        0x3fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 10108024940646105087u64;
+    /// MODULUS = 28948022309329048855892746252171976963363056481941647379679742748393362948097
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0x8c46eb2100000001,
+        0x224698fc0994a8dd,
+        0x0,
+        0x4000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    /// (r - 1) / 2 =
+    /// 14474011154664524427946373126085988481681528240970823689839871374196681474048
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xc623759080000000,
+        0x11234c7e04ca546e,
+        0x0,
+        0x2000000000000000,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x5b2b3e9cfffffffd,
+        0x992c350be3420567,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0xfc9678ff0000000f,
+        0x67bb433d891a16e3,
+        0x7fae231004ccf590,
+        0x96d41af7ccfdaa9,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 6739986666787659948666753771754907668419893943225417141728043264801
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x994a8dd8c46eb21,
+        0x224698fc,
+        0x0,
+        0x40000000,
+    ]);
+    /// (t - 1) / 2 =
+    /// 3369993333393829974333376885877453834209946971612708570864021632400
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x4ca546ec6237590,
+        0x11234c7e,
+        0x0,
+        0x20000000,
+    ]);
+}
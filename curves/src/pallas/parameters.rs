@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    pallas::{Fq, Fr},
+    templates::short_weierstrass::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    traits::{ModelParameters, SWModelParameters},
+};
+
+pub type PallasAffine = GroupAffine<PallasParameters>;
+pub type PallasProjective = GroupProjective<PallasParameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct PallasParameters;
+
+impl ModelParameters for PallasParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for PallasParameters {
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// COEFF_B = 5
+    const COEFF_B: Fq = field!(
+        Fq,
+        BigInteger256([
+            0xa1a55e68ffffffed,
+            0x74c2a54b4f4982f3,
+            0xfffffffffffffffd,
+            0x3fffffffffffffff,
+        ])
+    );
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    /// = 1
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([
+            0x5b2b3e9cfffffffd,
+            0x992c350be3420567,
+            0xffffffffffffffff,
+            0x3fffffffffffffff,
+        ])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// GENERATOR_X = -1 =
+/// 28948022309329048855892746252171976963363056481941560715954676764349967630336
+pub const GENERATOR_X: Fq = field!(Fq, BigInteger256([0x64b4c3b400000004, 0x891a63f02533e46e, 0x0, 0x0,]));
+
+/// GENERATOR_Y =
+/// 2
+pub const GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger256([
+        0xcfc3a984fffffff9,
+        0x1011d11bbee5303e,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ])
+);
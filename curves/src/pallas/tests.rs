@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    pallas::{Fq, FqParameters, Fr, FrParameters, PallasAffine, PallasParameters, PallasProjective},
+    templates::short_weierstrass::tests::sw_tests,
+    traits::{tests_curve::curve_tests, tests_group::group_test, AffineCurve},
+};
+use snarkvm_fields::{
+    tests_field::{field_serialization_test, field_test, primefield_test, sqrt_field_test},
+    FftParameters,
+    FieldParameters,
+};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_pallas_fr() {
+    for _ in 0..ITERATIONS {
+        let a: Fr = rand::random();
+        let b: Fr = rand::random();
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+        field_serialization_test::<Fr>();
+    }
+}
+
+#[test]
+fn test_pallas_fq() {
+    for _ in 0..ITERATIONS {
+        let a: Fq = rand::random();
+        let b: Fq = rand::random();
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+        field_serialization_test::<Fq>();
+    }
+}
+
+#[test]
+fn test_num_bits() {
+    assert_eq!(FrParameters::MODULUS_BITS, 255);
+    assert_eq!(FrParameters::CAPACITY, 254);
+    assert_eq!(FqParameters::MODULUS_BITS, 255);
+    assert_eq!(FqParameters::CAPACITY, 254);
+}
+
+#[test]
+fn test_two_adicity() {
+    assert_eq!(FrParameters::TWO_ADICITY, 32);
+    assert_eq!(FqParameters::TWO_ADICITY, 32);
+}
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<PallasProjective>();
+    sw_tests::<PallasParameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let a: PallasProjective = rand::random();
+    let b: PallasProjective = rand::random();
+    group_test(a, b);
+}
+
+#[test]
+fn test_generator() {
+    let generator = PallasAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
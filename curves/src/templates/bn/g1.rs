@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bn::BnParameters,
+        short_weierstrass::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    traits::pairing_engine::AffineCurve,
+};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{bytes::ToBytes, errors::SerializationError, serialize::*};
+
+use std::io::{Result as IoResult, Write};
+
+pub type G1Affine<P> = GroupAffine<<P as BnParameters>::G1Parameters>;
+pub type G1Projective<P> = GroupProjective<<P as BnParameters>::G1Parameters>;
+
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(
+    Clone(bound = "P: BnParameters"),
+    Debug(bound = "P: BnParameters"),
+    PartialEq(bound = "P: BnParameters"),
+    Eq(bound = "P: BnParameters")
+)]
+pub struct G1Prepared<P: BnParameters>(pub G1Affine<P>);
+
+impl<P: BnParameters> G1Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn from_affine(p: G1Affine<P>) -> Self {
+        G1Prepared(p)
+    }
+}
+
+impl<P: BnParameters> Default for G1Prepared<P> {
+    fn default() -> Self {
+        G1Prepared(G1Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+impl<P: BnParameters> ToBytes for G1Prepared<P> {
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bn::{BnParameters, TwistType},
+        short_weierstrass::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    traits::{AffineCurve, SWModelParameters},
+};
+use snarkvm_fields::{Field, Fp2, One, Zero};
+use snarkvm_utilities::{bytes::ToBytes, errors::SerializationError, serialize::*};
+
+use std::io::{Result as IoResult, Write};
+
+pub type G2Affine<P> = GroupAffine<<P as BnParameters>::G2Parameters>;
+pub type G2Projective<P> = GroupProjective<<P as BnParameters>::G2Parameters>;
+type CoeffTriplet<T> = (Fp2<T>, Fp2<T>, Fp2<T>);
+
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(
+    Clone(bound = "P: BnParameters"),
+    Debug(bound = "P: BnParameters"),
+    PartialEq(bound = "P: BnParameters"),
+    Eq(bound = "P: BnParameters")
+)]
+pub struct G2Prepared<P: BnParameters> {
+    // Stores the coefficients of the line evaluations as calculated in
+    // https://eprint.iacr.org/2013/722.pdf
+    pub ell_coeffs: Vec<CoeffTriplet<P::Fp2Params>>,
+    pub infinity: bool,
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: BnParameters"),
+    Copy(bound = "P: BnParameters"),
+    Debug(bound = "P: BnParameters")
+)]
+struct G2HomProjective<P: BnParameters> {
+    x: Fp2<P::Fp2Params>,
+    y: Fp2<P::Fp2Params>,
+    z: Fp2<P::Fp2Params>,
+}
+
+impl<P: BnParameters> Default for G2Prepared<P> {
+    fn default() -> Self {
+        Self::from_affine(G2Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+impl<P: BnParameters> ToBytes for G2Prepared<P> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        for coeff in &self.ell_coeffs {
+            coeff.0.write(&mut writer)?;
+            coeff.1.write(&mut writer)?;
+            coeff.2.write(&mut writer)?;
+        }
+        self.infinity.write(writer)
+    }
+}
+
+impl<P: BnParameters> G2Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine<P>) -> Self {
+        let two_inv = P::Fp::one().double().inverse().unwrap();
+        if q.is_zero() {
+            return Self {
+                ell_coeffs: vec![],
+                infinity: true,
+            };
+        }
+
+        let mut r = G2HomProjective {
+            x: q.x,
+            y: q.y,
+            z: Fp2::one(),
+        };
+
+        let neg_q = -q;
+        let mut ell_coeffs = Vec::with_capacity(P::ATE_LOOP_COUNT.len() * 2);
+
+        for i in (1..P::ATE_LOOP_COUNT.len()).rev() {
+            ell_coeffs.push(doubling_step::<P>(&mut r, &two_inv));
+
+            match P::ATE_LOOP_COUNT[i - 1] {
+                1 => ell_coeffs.push(addition_step::<P>(&mut r, &q)),
+                -1 => ell_coeffs.push(addition_step::<P>(&mut r, &neg_q)),
+                _ => continue,
+            }
+        }
+
+        let q1 = mul_by_char::<P>(q);
+        let mut q2 = mul_by_char::<P>(q1);
+
+        if P::X_IS_NEGATIVE {
+            r.y = -r.y;
+        }
+
+        q2.y = -q2.y;
+
+        ell_coeffs.push(addition_step::<P>(&mut r, &q1));
+        ell_coeffs.push(addition_step::<P>(&mut r, &q2));
+
+        Self {
+            ell_coeffs,
+            infinity: false,
+        }
+    }
+}
+
+/// Returns the image of `q` under the Frobenius endomorphism, computed on the twist.
+pub fn mul_by_char<P: BnParameters>(q: G2Affine<P>) -> G2Affine<P> {
+    let mut s = q;
+    s.x.frobenius_map(1);
+    s.x *= &P::TWIST_MUL_BY_Q_X;
+    s.y.frobenius_map(1);
+    s.y *= &P::TWIST_MUL_BY_Q_Y;
+    s
+}
+
+#[allow(clippy::many_single_char_names)]
+fn doubling_step<B: BnParameters>(r: &mut G2HomProjective<B>, two_inv: &B::Fp) -> CoeffTriplet<B::Fp2Params> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+
+    let mut a = r.x * r.y;
+    a.mul_by_fp(two_inv);
+    let b = r.y.square();
+    let c = r.z.square();
+    let e = B::G2Parameters::COEFF_B * (c.double() + c);
+    let f = e.double() + e;
+    let mut g = b + f;
+    g.mul_by_fp(two_inv);
+    let h = (r.y + r.z).square() - (b + c);
+    let i = e - b;
+    let j = r.x.square();
+    let e_square = e.square();
+
+    r.x = a * (b - f);
+    r.y = g.square() - (e_square.double() + e_square);
+    r.z = b * h;
+    match B::TWIST_TYPE {
+        TwistType::M => (i, j.double() + j, -h),
+        TwistType::D => (-h, j.double() + j, i),
+    }
+}
+
+#[allow(clippy::many_single_char_names)]
+fn addition_step<B: BnParameters>(r: &mut G2HomProjective<B>, q: &G2Affine<B>) -> CoeffTriplet<B::Fp2Params> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+    let theta = r.y - (q.y * r.z);
+    let lambda = r.x - (q.x * r.z);
+    let c = theta.square();
+    let d = lambda.square();
+    let e = lambda * d;
+    let f = r.z * c;
+    let g = r.x * d;
+    let h = e + f - g.double();
+    r.x = lambda * h;
+    r.y = theta * (g - h) - (e * r.y);
+    r.z *= &e;
+    let j = theta * q.x - (lambda * q.y);
+
+    match B::TWIST_TYPE {
+        TwistType::M => (j, -theta, lambda),
+        TwistType::D => (lambda, -theta, j),
+    }
+}
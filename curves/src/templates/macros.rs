@@ -66,11 +66,34 @@ macro_rules! impl_sw_curve_serializer {
                 let el: GroupAffine<P> = CanonicalDeserialize::deserialize_uncompressed(reader)?;
                 Ok(el.into())
             }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
+                let el: GroupAffine<P> = CanonicalDeserialize::deserialize_unchecked(reader)?;
+                Ok(el.into())
+            }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_uncompressed_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
+                let el: GroupAffine<P> = CanonicalDeserialize::deserialize_uncompressed_unchecked(reader)?;
+                Ok(el.into())
+            }
         }
 
         impl<P: $params> ConstantSerializedSize for GroupProjective<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
-            const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::serialized_size_with_flags::<
+                P::BaseField,
+                snarkvm_utilities::serialize::SWFlags,
+            >();
+            const UNCOMPRESSED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE
+                + snarkvm_utilities::serialize::serialized_size_with_flags::<
+                    P::BaseField,
+                    snarkvm_utilities::serialize::SWFlags,
+                >();
         }
 
         impl<P: $params> CanonicalSerialize for GroupAffine<P> {
@@ -92,7 +115,7 @@ macro_rules! impl_sw_curve_serializer {
 
             #[inline]
             fn serialized_size(&self) -> usize {
-                Self::SERIALIZED_SIZE
+                self.x.serialized_size_with_flags::<snarkvm_utilities::serialize::SWFlags>()
             }
 
             #[allow(unused_qualifications)]
@@ -113,13 +136,20 @@ macro_rules! impl_sw_curve_serializer {
 
             #[inline]
             fn uncompressed_size(&self) -> usize {
-                Self::UNCOMPRESSED_SIZE
+                self.x.serialized_size() + self.y.serialized_size_with_flags::<snarkvm_utilities::serialize::SWFlags>()
             }
         }
 
         impl<P: $params> ConstantSerializedSize for GroupAffine<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
-            const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::serialized_size_with_flags::<
+                P::BaseField,
+                snarkvm_utilities::serialize::SWFlags,
+            >();
+            const UNCOMPRESSED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE
+                + snarkvm_utilities::serialize::serialized_size_with_flags::<
+                    P::BaseField,
+                    snarkvm_utilities::serialize::SWFlags,
+                >();
         }
 
         impl<P: $params> CanonicalDeserialize for GroupAffine<P> {
@@ -127,13 +157,8 @@ macro_rules! impl_sw_curve_serializer {
             fn deserialize<R: snarkvm_utilities::io::Read>(
                 reader: &mut R,
             ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
-                let (x, flags): (P::BaseField, snarkvm_utilities::serialize::SWFlags) =
-                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
-                if flags.is_infinity() {
-                    Ok(Self::zero())
-                } else {
-                    let p = GroupAffine::<P>::from_x_coordinate(x, flags.is_positive().unwrap())
-                        .ok_or(snarkvm_utilities::errors::SerializationError::InvalidData)?;
+                let p = Self::deserialize_unchecked(reader)?;
+                if !p.is_zero() {
                     if !snarkvm_utilities::PROCESSING_SNARK_PARAMS
                         .with(|p| p.load(std::sync::atomic::Ordering::Relaxed))
                     {
@@ -144,23 +169,45 @@ macro_rules! impl_sw_curve_serializer {
                         snarkvm_utilities::SNARK_PARAMS_AFFINE_COUNT
                             .with(|p| p.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
                     }
-                    Ok(p)
                 }
+                Ok(p)
             }
 
             #[allow(unused_qualifications)]
             fn deserialize_uncompressed<R: snarkvm_utilities::io::Read>(
                 reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
+                let p = Self::deserialize_uncompressed_unchecked(reader)?;
+                if !p.is_on_curve() || !p.is_in_correct_subgroup_assuming_on_curve() {
+                    return Err(snarkvm_utilities::errors::SerializationError::InvalidData);
+                }
+                Ok(p)
+            }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
+                let (x, flags): (P::BaseField, snarkvm_utilities::serialize::SWFlags) =
+                    CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+                if flags.is_infinity() {
+                    Ok(Self::zero())
+                } else {
+                    // Recovering y from x already ensures the point is on the curve.
+                    GroupAffine::<P>::from_x_coordinate(x, flags.is_positive().unwrap())
+                        .ok_or(snarkvm_utilities::errors::SerializationError::InvalidData)
+                }
+            }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_uncompressed_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
             ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
                 let x: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
                 let (y, flags): (P::BaseField, snarkvm_utilities::serialize::SWFlags) =
                     CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
 
-                let p = GroupAffine::<P>::new(x, y, flags.is_infinity());
-                if !p.is_in_correct_subgroup_assuming_on_curve() {
-                    return Err(snarkvm_utilities::errors::SerializationError::InvalidData);
-                }
-                Ok(p)
+                Ok(GroupAffine::<P>::new(x, y, flags.is_infinity()))
             }
         }
     };
@@ -199,7 +246,10 @@ macro_rules! impl_edwards_curve_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for GroupProjective<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::serialized_size_with_flags::<
+                P::BaseField,
+                snarkvm_utilities::serialize::EdwardsFlags,
+            >();
             const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
         }
 
@@ -219,6 +269,22 @@ macro_rules! impl_edwards_curve_serializer {
                 let el: GroupAffine<P> = CanonicalDeserialize::deserialize_uncompressed(reader)?;
                 Ok(el.into())
             }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
+                let el: GroupAffine<P> = CanonicalDeserialize::deserialize_unchecked(reader)?;
+                Ok(el.into())
+            }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_uncompressed_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
+                let el: GroupAffine<P> = CanonicalDeserialize::deserialize_uncompressed_unchecked(reader)?;
+                Ok(el.into())
+            }
         }
 
         impl<P: $params> CanonicalSerialize for GroupAffine<P> {
@@ -240,7 +306,7 @@ macro_rules! impl_edwards_curve_serializer {
 
             #[inline]
             fn serialized_size(&self) -> usize {
-                Self::SERIALIZED_SIZE
+                self.x.serialized_size_with_flags::<snarkvm_utilities::serialize::EdwardsFlags>()
             }
 
             #[allow(unused_qualifications)]
@@ -261,7 +327,10 @@ macro_rules! impl_edwards_curve_serializer {
         }
 
         impl<P: $params> ConstantSerializedSize for GroupAffine<P> {
-            const SERIALIZED_SIZE: usize = <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+            const SERIALIZED_SIZE: usize = snarkvm_utilities::serialize::serialized_size_with_flags::<
+                P::BaseField,
+                snarkvm_utilities::serialize::EdwardsFlags,
+            >();
             const UNCOMPRESSED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
         }
 
@@ -269,33 +338,48 @@ macro_rules! impl_edwards_curve_serializer {
             #[allow(unused_qualifications)]
             fn deserialize<R: snarkvm_utilities::io::Read>(
                 reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
+                let p = Self::deserialize_unchecked(reader)?;
+                if !p.is_in_correct_subgroup_assuming_on_curve() {
+                    return Err(snarkvm_utilities::errors::SerializationError::InvalidData);
+                }
+                Ok(p)
+            }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_uncompressed<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
+            ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
+                let p = Self::deserialize_uncompressed_unchecked(reader)?;
+                if !p.is_on_curve() || !p.is_in_correct_subgroup_assuming_on_curve() {
+                    return Err(snarkvm_utilities::errors::SerializationError::InvalidData);
+                }
+                Ok(p)
+            }
+
+            #[allow(unused_qualifications)]
+            fn deserialize_unchecked<R: snarkvm_utilities::io::Read>(
+                reader: &mut R,
             ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
                 let (x, flags): (P::BaseField, snarkvm_utilities::serialize::EdwardsFlags) =
                     CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
                 if x == P::BaseField::zero() {
                     Ok(Self::zero())
                 } else {
-                    let p = GroupAffine::<P>::from_x_coordinate(x, flags.is_positive())
-                        .ok_or(snarkvm_utilities::errors::SerializationError::InvalidData)?;
-                    if !p.is_in_correct_subgroup_assuming_on_curve() {
-                        return Err(snarkvm_utilities::errors::SerializationError::InvalidData);
-                    }
-                    Ok(p)
+                    // Recovering y from x already ensures the point is on the curve.
+                    GroupAffine::<P>::from_x_coordinate(x, flags.is_positive())
+                        .ok_or(snarkvm_utilities::errors::SerializationError::InvalidData)
                 }
             }
 
             #[allow(unused_qualifications)]
-            fn deserialize_uncompressed<R: snarkvm_utilities::io::Read>(
+            fn deserialize_uncompressed_unchecked<R: snarkvm_utilities::io::Read>(
                 reader: &mut R,
             ) -> Result<Self, snarkvm_utilities::errors::SerializationError> {
                 let x: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
                 let y: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
 
-                let p = GroupAffine::<P>::new(x, y);
-                if !p.is_in_correct_subgroup_assuming_on_curve() {
-                    return Err(snarkvm_utilities::errors::SerializationError::InvalidData);
-                }
-                Ok(p)
+                Ok(GroupAffine::<P>::new(x, y))
             }
         }
     };
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod bls12;
+pub mod bn;
 pub mod bw6;
 pub mod short_weierstrass;
 pub mod to_field_vec;
@@ -23,7 +23,7 @@ pub mod tests;
 macro_rules! impl_sw_from_random_bytes {
     () => {
         fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-            P::BaseField::from_random_bytes_with_flags(bytes).and_then(|(x, flags)| {
+            P::BaseField::from_random_bytes_with_flags::<SWFlags>(bytes).and_then(|(x, flags)| {
                 let infinity_flag_mask = SWFlags::Infinity.u8_bitmask();
                 let positive_flag_mask = SWFlags::PositiveY.u8_bitmask();
                 // if x is valid and is zero and only the infinity flag is set, then parse this
@@ -17,9 +17,19 @@
 use crate::{
     impl_sw_curve_serializer,
     impl_sw_from_random_bytes,
-    traits::{AffineCurve, Group, ProjectiveCurve, SWModelParameters as Parameters},
+    traits::{AffineCurve, GLVDecomposition, Group, ProjectiveCurve, SWModelParameters as Parameters},
+};
+use snarkvm_fields::{
+    batch_inversion,
+    impl_additive_ops_from_ref,
+    Choice,
+    ConditionallySelectable,
+    Field,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
 };
-use snarkvm_fields::{impl_additive_ops_from_ref, Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     bytes::{FromBytes, ToBytes},
@@ -186,6 +196,71 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
             y2 == x3b
         }
     }
+
+    #[inline]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        P::GLV_PARAMETERS.map(|params| match self.infinity {
+            true => *self,
+            false => Self::new(params.beta * self.x, self.y, false),
+        })
+    }
+
+    #[inline]
+    fn glv_decomposition(scalar: &Self::ScalarField) -> Option<GLVDecomposition<Self::ScalarField>> {
+        P::GLV_PARAMETERS.map(|params| params.decompose(scalar))
+    }
+
+    /// Adds each pair of points with the affine formulas, computing all slopes with a single inversion.
+    fn batch_add_assign(bases: &mut [Self], others: &[Self]) {
+        assert_eq!(bases.len(), others.len());
+
+        // Compute the denominators of the slopes, leaving zero for additions without a slope.
+        let mut denominators = bases
+            .iter()
+            .zip(others)
+            .map(|(base, other)| {
+                if base.is_zero() || other.is_zero() {
+                    P::BaseField::zero()
+                } else if base.x == other.x {
+                    // Doubling, or the sum is zero.
+                    if base.y == other.y {
+                        base.y.double()
+                    } else {
+                        P::BaseField::zero()
+                    }
+                } else {
+                    other.x - base.x
+                }
+            })
+            .collect::<Vec<_>>();
+        batch_inversion(&mut denominators);
+
+        for ((base, other), inverse) in bases.iter_mut().zip(others).zip(denominators) {
+            if other.is_zero() {
+                continue;
+            } else if base.is_zero() {
+                *base = *other;
+                continue;
+            } else if inverse.is_zero() {
+                // The sum is zero, or the point has order two.
+                *base += other;
+                continue;
+            }
+
+            let lambda = if base.x == other.x {
+                // (3x^2 + a) / 2y
+                let x_squared = base.x.square();
+                (x_squared.double() + x_squared + P::mul_by_a(&base.x)) * inverse
+            } else {
+                // (y_2 - y_1) / (x_2 - x_1)
+                (other.y - base.y) * inverse
+            };
+
+            let x = lambda.square() - base.x - other.x;
+            let y = lambda * (base.x - x) - base.y;
+            *base = Self::new(x, y, false);
+        }
+    }
 }
 
 impl<P: Parameters> Group for GroupAffine<P> {
@@ -206,6 +281,17 @@ impl<P: Parameters> Group for GroupAffine<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for GroupAffine<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            u8::conditional_select(&(a.infinity as u8), &(b.infinity as u8), choice) == 1,
+        )
+    }
+}
+
 impl<P: Parameters> Neg for GroupAffine<P> {
     type Output = Self;
 
@@ -524,6 +610,16 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
     fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
         P::empirical_recommended_wnaf_for_num_scalars(num_scalars)
     }
+
+    #[inline]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        P::GLV_PARAMETERS.map(|params| Self::new(params.beta * self.x, self.y, self.z))
+    }
+
+    #[inline]
+    fn glv_decomposition(scalar: &Self::ScalarField) -> Option<GLVDecomposition<Self::ScalarField>> {
+        P::GLV_PARAMETERS.map(|params| params.decompose(scalar))
+    }
 }
 
 impl<P: Parameters> Group for GroupProjective<P> {
@@ -574,6 +670,17 @@ impl<P: Parameters> Group for GroupProjective<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Neg for GroupProjective<P> {
     type Output = Self;
 
@@ -19,14 +19,18 @@ use super::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
 use snarkvm_utilities::{
     io::Cursor,
     rand::UniformRand,
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Flags, SWFlags, Validate},
 };
 
 use crate::traits::{
+    glv_mul,
+    glv_scalar_bits,
     pairing_engine::{AffineCurve, ProjectiveCurve},
+    Group,
     SWModelParameters,
 };
-use snarkvm_fields::Zero;
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger, BitIteratorBE};
 
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
@@ -36,6 +40,7 @@ pub const ITERATIONS: usize = 10;
 pub fn sw_tests<P: SWModelParameters>() {
     sw_curve_serialization_test::<P>();
     sw_from_random_bytes::<P>();
+    sw_glv_test::<P>();
 }
 
 pub fn sw_curve_serialization_test<P: SWModelParameters>() {
@@ -118,6 +123,33 @@ pub fn sw_curve_serialization_test<P: SWModelParameters>() {
             let b = GroupAffine::<P>::deserialize_uncompressed(&mut cursor).unwrap();
             assert_eq!(a, b);
         }
+
+        {
+            for compress in [Compress::Yes, Compress::No] {
+                for validate in [Validate::Yes, Validate::No] {
+                    let mut serialized = vec![0; a.serialized_size_with_mode(compress)];
+                    let mut cursor = Cursor::new(&mut serialized[..]);
+                    a.serialize_with_mode(&mut cursor, compress).unwrap();
+                    let mut cursor = Cursor::new(&serialized[..]);
+                    let b = GroupAffine::<P>::deserialize_with_mode(&mut cursor, compress, validate).unwrap();
+                    assert_eq!(a, b);
+                }
+            }
+        }
+
+        {
+            // A point that is not on the curve is only accepted when validation is skipped.
+            let mut c = a;
+            c.y += &P::BaseField::one();
+            let mut serialized = vec![0; c.uncompressed_size()];
+            let mut cursor = Cursor::new(&mut serialized[..]);
+            c.serialize_uncompressed(&mut cursor).unwrap();
+            let mut cursor = Cursor::new(&serialized[..]);
+            GroupAffine::<P>::deserialize_with_mode(&mut cursor, Compress::No, Validate::Yes).unwrap_err();
+            let mut cursor = Cursor::new(&serialized[..]);
+            let d = GroupAffine::<P>::deserialize_with_mode(&mut cursor, Compress::No, Validate::No).unwrap();
+            assert_eq!(c, d);
+        }
     }
 }
 
@@ -138,6 +170,82 @@ pub fn sw_from_random_bytes<P: SWModelParameters>() {
             let p1 = GroupAffine::<P>::deserialize(&mut cursor).unwrap();
             let p2 = GroupAffine::<P>::from_random_bytes(&serialized).unwrap();
             assert_eq!(p1, p2);
+
+            // The infinity flag is only honored for a zero x-coordinate, and only when it is the sole flag set.
+            serialized[buf_size - 1] |= SWFlags::Infinity.u8_bitmask();
+            let p3 = GroupAffine::<P>::from_random_bytes(&serialized).unwrap();
+            assert_eq!(p1, p3);
+        }
+    }
+}
+
+pub fn sw_glv_test<P: SWModelParameters>() {
+    let params = match P::GLV_PARAMETERS {
+        Some(params) => params,
+        None => {
+            assert!(GroupAffine::<P>::zero().glv_endomorphism().is_none());
+            return;
         }
+    };
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = GroupProjective::<P>::rand(&mut rng);
+        // Ensure the point is not normalized.
+        let a = a.double() - a;
+
+        // The endomorphism acts as multiplication by lambda.
+        let expected = a.into_affine().mul_bits(BitIteratorBE::new(params.lambda.into_repr()));
+        assert_eq!(a.glv_endomorphism().unwrap(), expected);
+        assert_eq!(a.into_affine().glv_endomorphism().unwrap().into_projective(), expected);
+
+        let scalars = [
+            P::ScalarField::zero(),
+            P::ScalarField::one(),
+            -P::ScalarField::one(),
+            params.lambda,
+            P::ScalarField::rand(&mut rng),
+        ];
+        for scalar in &scalars {
+            // The decomposition is short and recombines to the scalar.
+            let decomposition = GroupProjective::<P>::glv_decomposition(scalar).unwrap();
+            assert!(decomposition.k1.num_bits() as usize <= glv_scalar_bits::<P::ScalarField>());
+            assert!(decomposition.k2.num_bits() as usize <= glv_scalar_bits::<P::ScalarField>());
+
+            let mut k1 = P::ScalarField::from_repr(decomposition.k1).unwrap();
+            let mut k2 = P::ScalarField::from_repr(decomposition.k2).unwrap();
+            if decomposition.k1_neg {
+                k1 = -k1;
+            }
+            if decomposition.k2_neg {
+                k2 = -k2;
+            }
+            assert_eq!(k1 + k2 * params.lambda, *scalar);
+
+            // The endomorphism-accelerated multiplication agrees with double-and-add.
+            let expected = a.into_affine().mul_bits(BitIteratorBE::new(scalar.into_repr()));
+            assert_eq!(glv_mul(&a, scalar).unwrap(), expected);
+        }
+    }
+
+    assert!(GroupAffine::<P>::zero().glv_endomorphism().unwrap().is_zero());
+    assert!(glv_mul(&GroupProjective::<P>::zero(), &P::ScalarField::rand(&mut rng))
+        .unwrap()
+        .is_zero());
+
+    // Scalar multiplication does not assume that the point is in the prime order subgroup.
+    let mut num_samples = 0;
+    while num_samples < ITERATIONS {
+        let point = match GroupAffine::<P>::from_x_coordinate(P::BaseField::rand(&mut rng), false) {
+            Some(point) if !point.is_in_correct_subgroup_assuming_on_curve() => point,
+            _ => continue,
+        };
+        num_samples += 1;
+
+        let scalar = P::ScalarField::rand(&mut rng);
+        let expected = point.mul_bits(BitIteratorBE::new(scalar.into_repr()));
+        assert_eq!(point.into_projective() * scalar, expected);
+        assert_eq!(point * scalar, expected.into_affine());
     }
 }
@@ -24,7 +24,16 @@ use crate::{
         TEModelParameters as Parameters,
     },
 };
-use snarkvm_fields::{impl_additive_ops_from_ref, Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{
+    impl_additive_ops_from_ref,
+    Choice,
+    ConditionallySelectable,
+    Field,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     bytes::{FromBytes, ToBytes},
@@ -152,7 +161,7 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
 
     // Copied from https://github.com/scipr-lab/zexe/blob/4b3f08c6c0a08c5392ed8aa3fd3c32f28da402c4/algebra-core/src/curves/models/twisted_edwards_extended.rs#L144-L156.
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        let x = P::BaseField::from_random_bytes_with_flags(bytes);
+        let x = P::BaseField::from_random_bytes_with_flags::<EdwardsFlags>(bytes);
         if let Some((x, flags)) = x {
             let parsed_flags = EdwardsFlags::from_u8(flags);
             if x.is_zero() {
@@ -231,6 +240,16 @@ impl<P: Parameters> Group for GroupAffine<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for GroupAffine<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Neg for GroupAffine<P> {
     type Output = Self;
 
@@ -567,6 +586,18 @@ impl<P: Parameters> Group for GroupProjective<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Neg for GroupProjective<P> {
     type Output = Self;
 
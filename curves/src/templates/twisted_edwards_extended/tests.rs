@@ -20,7 +20,7 @@ use snarkvm_utilities::{
     bytes::ToBytes,
     io::Cursor,
     rand::UniformRand,
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
     to_bytes,
 };
 
@@ -118,6 +118,33 @@ pub fn edwards_curve_serialization_test<P: TEModelParameters>() {
             let b = GroupAffine::<P>::deserialize_uncompressed(&mut cursor).unwrap();
             assert_eq!(a, b);
         }
+
+        {
+            for compress in [Compress::Yes, Compress::No] {
+                for validate in [Validate::Yes, Validate::No] {
+                    let mut serialized = vec![0; a.serialized_size_with_mode(compress)];
+                    let mut cursor = Cursor::new(&mut serialized[..]);
+                    a.serialize_with_mode(&mut cursor, compress).unwrap();
+                    let mut cursor = Cursor::new(&serialized[..]);
+                    let b = GroupAffine::<P>::deserialize_with_mode(&mut cursor, compress, validate).unwrap();
+                    assert_eq!(a, b);
+                }
+            }
+        }
+
+        {
+            // A point that is not on the curve is only accepted when validation is skipped.
+            let mut c = a;
+            c.y += &P::BaseField::one();
+            let mut serialized = vec![0; c.uncompressed_size()];
+            let mut cursor = Cursor::new(&mut serialized[..]);
+            c.serialize_uncompressed(&mut cursor).unwrap();
+            let mut cursor = Cursor::new(&serialized[..]);
+            GroupAffine::<P>::deserialize_with_mode(&mut cursor, Compress::No, Validate::Yes).unwrap_err();
+            let mut cursor = Cursor::new(&serialized[..]);
+            let d = GroupAffine::<P>::deserialize_with_mode(&mut cursor, Compress::No, Validate::No).unwrap();
+            assert_eq!(c, d);
+        }
     }
 }
 
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::ProjectiveCurve;
+use snarkvm_fields::{Field, FieldParameters, PrimeField};
+use snarkvm_utilities::biginteger::{arithmetic::mac_with_carry, BigInteger};
+
+/// The parameters of the GLV endomorphism `(x, y) -> (beta * x, y)` of a short Weierstrass curve,
+/// which acts on the prime order subgroup as multiplication by `lambda`.
+///
+/// The lattice basis `(a1, b1), (a2, b2)` spans the solutions of `a + b * lambda = 0 (mod r)`,
+/// and is oriented such that `g1 = round(2^m * b2 / det)` and `g2 = round(-2^m * b1 / det)`
+/// are non-negative, where `m` is the bit size of the scalar field representation.
+#[derive(Copy, Clone, Debug)]
+pub struct GLVParameters<F: Field, S: PrimeField> {
+    pub beta: F,
+    pub lambda: S,
+    pub a1: S,
+    pub b1: S,
+    pub a2: S,
+    pub b2: S,
+    pub g1: &'static [u64],
+    pub g2: &'static [u64],
+}
+
+/// A decomposition of a scalar `k` into `k1 + k2 * lambda`, where both halves are given
+/// as a sign and an absolute value of roughly half the bit size of the scalar field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GLVDecomposition<S: PrimeField> {
+    pub k1_neg: bool,
+    pub k1: S::BigInteger,
+    pub k2_neg: bool,
+    pub k2: S::BigInteger,
+}
+
+impl<F: Field, S: PrimeField> GLVParameters<F, S> {
+    /// Returns the decomposition of the given scalar into two halves `k1 + k2 * lambda`.
+    pub fn decompose(&self, scalar: &S) -> GLVDecomposition<S> {
+        let k = scalar.into_repr();
+
+        // c1 = round(k * b2 / det), c2 = round(-k * b1 / det).
+        let c1 = S::from_repr(mul_shift_round::<S>(&k, self.g1)).expect("GLV rounding is below the modulus");
+        let c2 = S::from_repr(mul_shift_round::<S>(&k, self.g2)).expect("GLV rounding is below the modulus");
+
+        // (k1, k2) = (k, 0) - c1 * (a1, b1) - c2 * (a2, b2).
+        let k1 = *scalar - c1 * self.a1 - c2 * self.a2;
+        let k2 = -(c1 * self.b1 + c2 * self.b2);
+
+        let (k1_neg, k1) = to_signed(k1);
+        let (k2_neg, k2) = to_signed(k2);
+        debug_assert!(k1.num_bits() as usize <= glv_scalar_bits::<S>());
+        debug_assert!(k2.num_bits() as usize <= glv_scalar_bits::<S>());
+
+        GLVDecomposition { k1_neg, k1, k2_neg, k2 }
+    }
+}
+
+/// Returns an upper bound on the bit size of the halves of a GLV decomposition.
+pub fn glv_scalar_bits<S: PrimeField>() -> usize {
+    S::size_in_bits() / 2 + 3
+}
+
+/// Multiplies `base` by `scalar` using the GLV endomorphism of the curve, if it has one.
+///
+/// The result is only correct for points in the prime order subgroup.
+pub fn glv_mul<G: ProjectiveCurve>(base: &G, scalar: &G::ScalarField) -> Option<G> {
+    let decomposition = G::glv_decomposition(scalar)?;
+    let endomorphism = base.glv_endomorphism()?;
+
+    let p1 = if decomposition.k1_neg { -*base } else { *base };
+    let p2 = if decomposition.k2_neg {
+        -endomorphism
+    } else {
+        endomorphism
+    };
+    let table = G::batch_normalization_into_affine(vec![p1, p2, p1 + p2]);
+
+    let (k1, k2) = (decomposition.k1, decomposition.k2);
+    let num_bits = std::cmp::max(k1.num_bits(), k2.num_bits()) as usize;
+
+    // Shamir's trick: a joint double-and-add over both halves.
+    let mut result = G::zero();
+    for i in (0..num_bits).rev() {
+        result.double_in_place();
+        match (k1.get_bit(i), k2.get_bit(i)) {
+            (true, false) => result.add_assign_mixed(&table[0]),
+            (false, true) => result.add_assign_mixed(&table[1]),
+            (true, true) => result.add_assign_mixed(&table[2]),
+            (false, false) => (),
+        }
+    }
+    Some(result)
+}
+
+/// Returns `round(k * g / 2^m)`, where `m` is the bit size of the representation of `k`.
+fn mul_shift_round<S: PrimeField>(k: &S::BigInteger, g: &[u64]) -> S::BigInteger {
+    let k = k.as_ref();
+    let n = k.len();
+    assert_eq!(g.len(), n);
+
+    let mut product = vec![0u64; 2 * n];
+    for (i, k_i) in k.iter().enumerate() {
+        let mut carry = 0;
+        for (j, g_j) in g.iter().enumerate() {
+            product[i + j] = mac_with_carry(product[i + j], *k_i, *g_j, &mut carry);
+        }
+        product[i + n] = carry;
+    }
+
+    let mut result = S::BigInteger::default();
+    result.as_mut().copy_from_slice(&product[n..]);
+    if product[n - 1] >> 63 == 1 {
+        result.add_nocarry(&S::BigInteger::from(1));
+    }
+    result
+}
+
+/// Returns the sign and the absolute value of the given field element,
+/// interpreting elements above `(r - 1) / 2` as negative.
+fn to_signed<S: PrimeField>(element: S) -> (bool, S::BigInteger) {
+    let repr = element.into_repr();
+    match repr > S::Parameters::MODULUS_MINUS_ONE_DIV_TWO {
+        true => (true, (-element).into_repr()),
+        false => (false, repr),
+    }
+}
@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{PrimeField, SquareRootField};
+use snarkvm_fields::{Choice, ConditionallySelectable, PrimeField, SquareRootField};
 use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
     bytes::{FromBytes, ToBytes},
     rand::UniformRand,
 };
@@ -54,6 +55,7 @@ pub trait Group:
     + for<'a> Sub<&'a Self, Output = Self>
     + for<'a> AddAssign<&'a Self>
     + for<'a> SubAssign<&'a Self>
+    + ConditionallySelectable
 {
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInteger>;
 
@@ -63,4 +65,25 @@ pub trait Group:
 
     /// Sets `self := self + self`.
     fn double_in_place(&mut self);
+
+    /// Returns `self * scalar` using a Montgomery ladder.
+    ///
+    /// The ladder performs one addition and one doubling for every bit of the scalar
+    /// representation, and swaps its operands with `conditional_swap`.
+    /// This is not constant time, as the group and field arithmetic branch on their inputs.
+    #[must_use]
+    fn mul_ladder(&self, scalar: &Self::ScalarField) -> Self {
+        let mut r0 = Self::zero();
+        let mut r1 = *self;
+
+        for bit in BitIteratorBE::new(scalar.into_repr()) {
+            let choice = Choice::from(bit as u8);
+
+            Self::conditional_swap(&mut r0, &mut r1, choice);
+            r1 += &r0;
+            r0.double_in_place();
+            Self::conditional_swap(&mut r0, &mut r1, choice);
+        }
+        r0
+    }
 }
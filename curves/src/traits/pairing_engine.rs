@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::Group;
+use crate::traits::{GLVDecomposition, GLVParameters, Group};
 use snarkvm_fields::{Field, PrimeField, SquareRootField};
 use snarkvm_utilities::{biginteger::BigInteger, bytes::ToBytes, serialize::*, BitIteratorBE};
 
@@ -146,6 +146,20 @@ pub trait ProjectiveCurve:
     /// inclusive.
     #[must_use]
     fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize;
+
+    /// Returns the image of this element under the GLV endomorphism,
+    /// if the curve has an efficiently computable endomorphism.
+    #[must_use]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        None
+    }
+
+    /// Returns the GLV decomposition of the given scalar,
+    /// if the curve has an efficiently computable endomorphism.
+    #[must_use]
+    fn glv_decomposition(_scalar: &Self::ScalarField) -> Option<GLVDecomposition<Self::ScalarField>> {
+        None
+    }
 }
 
 /// Affine representation of an elliptic curve point guaranteed to be
@@ -222,6 +236,40 @@ pub trait AffineCurve:
 
     /// Checks that the current point is on the elliptic curve.
     fn is_on_curve(&self) -> bool;
+
+    /// Returns the image of this element under the GLV endomorphism,
+    /// if the curve has an efficiently computable endomorphism.
+    #[must_use]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        None
+    }
+
+    /// Returns the GLV decomposition of the given scalar,
+    /// if the curve has an efficiently computable endomorphism.
+    #[must_use]
+    fn glv_decomposition(_scalar: &Self::ScalarField) -> Option<GLVDecomposition<Self::ScalarField>> {
+        None
+    }
+
+    /// Sets `bases[i] += others[i]` for each `i`, sharing a single field inversion across all additions.
+    fn batch_add_assign(bases: &mut [Self], others: &[Self]) {
+        assert_eq!(bases.len(), others.len());
+
+        let mut sums = bases
+            .iter()
+            .zip(others)
+            .map(|(base, other)| {
+                let mut sum = base.into_projective();
+                sum.add_assign_mixed(other);
+                sum
+            })
+            .collect::<Vec<_>>();
+        Self::Projective::batch_normalization(&mut sums);
+
+        for (base, sum) in bases.iter_mut().zip(sums) {
+            *base = sum.into();
+        }
+    }
 }
 
 pub trait PairingCurve: AffineCurve {
@@ -250,6 +298,8 @@ pub trait SWModelParameters: ModelParameters {
     const COFACTOR: &'static [u64];
     const COFACTOR_INV: Self::ScalarField;
     const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField);
+    /// The parameters of the GLV endomorphism, if the curve has one.
+    const GLV_PARAMETERS: Option<GLVParameters<Self::BaseField, Self::ScalarField>> = None;
 
     #[inline(always)]
     fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
@@ -16,7 +16,10 @@
 
 use crate::traits::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::Zero;
-use snarkvm_utilities::rand::UniformRand;
+use snarkvm_utilities::{
+    rand::UniformRand,
+    serialize::{CanonicalSerialize, ConstantSerializedSize},
+};
 
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
@@ -228,6 +231,62 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     }
 }
 
+fn random_batch_addition_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let a = (0..ITERATIONS)
+        .map(|_| G::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    let b = (0..ITERATIONS)
+        .map(|_| G::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    // Include the edge cases of zero, doubling and negation.
+    let mut bases = a.clone();
+    bases.extend_from_slice(&[G::Affine::zero(), a[0], a[1], a[2], G::Affine::zero()]);
+    let mut others = b;
+    others.extend_from_slice(&[a[0], G::Affine::zero(), a[1], -a[2], G::Affine::zero()]);
+
+    let expected = bases
+        .iter()
+        .zip(&others)
+        .map(|(base, other)| base.into_projective() + other.into_projective())
+        .collect::<Vec<_>>();
+    G::Affine::batch_add_assign(&mut bases, &others);
+
+    for (sum, expected) in bases.iter().zip(expected) {
+        assert_eq!(sum.into_projective(), expected);
+    }
+}
+
+fn serialized_size_test<G: ProjectiveCurve>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for a in [G::zero(), G::rand(&mut rng)] {
+        let mut serialized = vec![];
+        a.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), G::SERIALIZED_SIZE);
+        assert_eq!(serialized.len(), a.serialized_size());
+
+        let mut serialized = vec![];
+        a.serialize_uncompressed(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), G::UNCOMPRESSED_SIZE);
+        assert_eq!(serialized.len(), a.uncompressed_size());
+
+        let a = a.into_affine();
+
+        let mut serialized = vec![];
+        a.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), G::Affine::SERIALIZED_SIZE);
+        assert_eq!(serialized.len(), a.serialized_size());
+
+        let mut serialized = vec![];
+        a.serialize_uncompressed(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), G::Affine::UNCOMPRESSED_SIZE);
+        assert_eq!(serialized.len(), a.uncompressed_size());
+    }
+}
+
 pub fn curve_tests<G: ProjectiveCurve>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -282,4 +341,6 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_doubling_test::<G>();
     random_negation_test::<G>();
     random_transformation_test::<G>();
+    random_batch_addition_test::<G>();
+    serialized_size_test::<G>();
 }
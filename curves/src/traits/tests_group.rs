@@ -90,4 +90,13 @@ pub fn group_test<G: Group>(a: G, mut b: G) {
         a.mul(fr_rand1 * fr_rand2),
         "(a * r1) * r2 != a * (r1 * r2)"
     );
+
+    // Check that the conditional select and the ladder agree with their branching counterparts
+    assert_eq!(G::conditional_select(&a, &b, 0u8.into()), a);
+    assert_eq!(G::conditional_select(&a, &b, 1u8.into()), b);
+    assert_eq!(a.mul_ladder(&fr_zero), zero);
+    assert_eq!(a.mul_ladder(&fr_one), a);
+    assert_eq!(a.mul_ladder(&fr_two), a_two);
+    assert_eq!(a.mul_ladder(&fr_rand1), a_rand1);
+    assert_eq!(zero.mul_ladder(&fr_rand2), zero);
 }
@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::short_weierstrass::tests::sw_tests,
+    traits::{tests_curve::curve_tests, tests_group::group_test, AffineCurve},
+    vesta::{VestaAffine, VestaParameters, VestaProjective},
+};
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<VestaProjective>();
+    sw_tests::<VestaParameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let a: VestaProjective = rand::random();
+    let b: VestaProjective = rand::random();
+    group_test(a, b);
+}
+
+#[test]
+fn test_generator() {
+    let generator = VestaAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
@@ -21,6 +21,32 @@ use syn::*;
 
 use quote::{quote, ToTokens};
 
+mod prime_field_params;
+
+/// Derives `FftParameters`, `FieldParameters` and the matching `FpNNNParameters` trait for a
+/// struct named `<Field>Parameters`, and defines `<Field>` as the Montgomery field type.
+///
+/// The modulus and the multiplicative generator are given in decimal, or in hexadecimal
+/// with a `0x` prefix, and every other constant is computed from them at compile time.
+/// A small subgroup for mixed-radix FFTs may be given with the optional
+/// `small_subgroup_base` and `small_subgroup_base_adicity` attributes.
+///
+/// ```ignore
+/// #[derive(PrimeFieldParams)]
+/// #[modulus = "8444461749428370424248824938781546531375899335154063827935233455917409239041"]
+/// #[generator = "22"]
+/// pub struct FrParameters;
+/// ```
+#[proc_macro_error]
+#[proc_macro_derive(
+    PrimeFieldParams,
+    attributes(modulus, generator, small_subgroup_base, small_subgroup_base_adicity)
+)]
+pub fn derive_prime_field_params(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(prime_field_params::impl_prime_field_params(&ast))
+}
+
 #[proc_macro_derive(CanonicalSerialize)]
 pub fn derive_canonical_serialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
@@ -157,26 +183,54 @@ pub fn derive_canonical_deserialize(input: proc_macro::TokenStream) -> proc_macr
     proc_macro::TokenStream::from(impl_canonical_deserialize(&ast))
 }
 
-/// Returns two TokenStreams, one for the compressed deserialize, one for the
-/// uncompressed.
-fn impl_deserialize_field(ty: &Type) -> (TokenStream, TokenStream) {
+/// Returns the TokenStream deserializing a field of type `ty` with the given
+/// `CanonicalDeserialize` method.
+fn impl_deserialize_field(ty: &Type, method: &Ident) -> TokenStream {
     // Check if type is a tuple.
     match ty {
         Type::Tuple(tuple) => {
-            let (compressed_fields, uncompressed_fields): (Vec<_>, Vec<_>) = tuple
+            let fields: Vec<_> = tuple
                 .elems
                 .iter()
-                .map(|elem_ty| impl_deserialize_field(elem_ty))
-                .unzip();
-            (
-                quote! { (#(#compressed_fields)*), },
-                quote! { (#(#uncompressed_fields)*), },
-            )
+                .map(|elem_ty| impl_deserialize_field(elem_ty, method))
+                .collect();
+            quote! { (#(#fields)*), }
         }
-        _ => (
-            quote! { CanonicalDeserialize::deserialize(reader)?, },
-            quote! { CanonicalDeserialize::deserialize_uncompressed(reader)?, },
-        ),
+        _ => quote! { CanonicalDeserialize::#method(reader)?, },
+    }
+}
+
+/// Returns the body of the given `CanonicalDeserialize` method, which deserializes
+/// each field of the struct with that same method.
+fn impl_deserialize_body(name: &Ident, data_struct: &DataStruct, method: &Ident) -> TokenStream {
+    let mut tuple = false;
+    let mut field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
+    for field in data_struct.fields.iter() {
+        match &field.ident {
+            None => {
+                tuple = true;
+                field_cases.push(impl_deserialize_field(&field.ty, method));
+            }
+            // struct field without len_type
+            Some(ident) => {
+                let field = impl_deserialize_field(&field.ty, method);
+                field_cases.push(quote! { #ident: #field });
+            }
+        }
+    }
+
+    if tuple {
+        quote!({
+            Ok(#name (
+                #(#field_cases)*
+            ))
+        })
+    } else {
+        quote!({
+            Ok(#name {
+                #(#field_cases)*
+            })
+        })
     }
 }
 
@@ -185,58 +239,17 @@ fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream {
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let deserialize_body;
-    let deserialize_uncompressed_body;
-
-    match ast.data {
-        Data::Struct(ref data_struct) => {
-            let mut tuple = false;
-            let mut compressed_field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
-            let mut uncompressed_field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
-            for field in data_struct.fields.iter() {
-                match &field.ident {
-                    None => {
-                        tuple = true;
-                        let (compressed, uncompressed) = impl_deserialize_field(&field.ty);
-                        compressed_field_cases.push(compressed);
-                        uncompressed_field_cases.push(uncompressed);
-                    }
-                    // struct field without len_type
-                    Some(ident) => {
-                        let (compressed_field, uncompressed_field) = impl_deserialize_field(&field.ty);
-                        compressed_field_cases.push(quote! { #ident: #compressed_field });
-                        uncompressed_field_cases.push(quote! { #ident: #uncompressed_field });
-                    }
-                }
-            }
-
-            if tuple {
-                deserialize_body = quote!({
-                    Ok(#name (
-                        #(#compressed_field_cases)*
-                    ))
-                });
-                deserialize_uncompressed_body = quote!({
-                    Ok(#name (
-                        #(#uncompressed_field_cases)*
-                    ))
-                });
-            } else {
-                deserialize_body = quote!({
-                    Ok(#name {
-                        #(#compressed_field_cases)*
-                    })
-                });
-                deserialize_uncompressed_body = quote!({
-                    Ok(#name {
-                        #(#uncompressed_field_cases)*
-                    })
-                });
-            }
-        }
+    let data_struct = match ast.data {
+        Data::Struct(ref data_struct) => data_struct,
         _ => panic!("Deserialize can only be derived for structs, {} is not a Struct", name),
     };
 
+    let body = |method: &str| impl_deserialize_body(name, data_struct, &Ident::new(method, Span::call_site()));
+    let deserialize_body = body("deserialize");
+    let deserialize_uncompressed_body = body("deserialize_uncompressed");
+    let deserialize_unchecked_body = body("deserialize_unchecked");
+    let deserialize_uncompressed_unchecked_body = body("deserialize_uncompressed_unchecked");
+
     let gen = quote! {
         impl #impl_generics CanonicalDeserialize for #name #ty_generics #where_clause {
             #[allow(unused_mut,unused_variables)]
@@ -247,6 +260,14 @@ fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream {
             fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
                 #deserialize_uncompressed_body
             }
+            #[allow(unused_mut,unused_variables)]
+            fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+                #deserialize_unchecked_body
+            }
+            #[allow(unused_mut,unused_variables)]
+            fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+                #deserialize_uncompressed_unchecked_body
+            }
         }
     };
     gen
@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use num_bigint::BigUint;
+use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro_crate::crate_name;
+use proc_macro_error::{abort, abort_call_site};
+use quote::quote;
+use syn::{DeriveInput, Lit, Meta};
+
+/// The Montgomery field types in `snarkvm-fields`, indexed by their number of limbs.
+const FIELD_TYPES: [(usize, &str, &str); 5] = [
+    (4, "Fp256", "BigInteger256"),
+    (5, "Fp320", "BigInteger320"),
+    (6, "Fp384", "BigInteger384"),
+    (12, "Fp768", "BigInteger768"),
+    (13, "Fp832", "BigInteger832"),
+];
+
+pub(crate) fn impl_prime_field_params(ast: &DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+
+    let field_name = match name.to_string().strip_suffix("Parameters") {
+        Some(field_name) if !field_name.is_empty() => Ident::new(field_name, name.span()),
+        _ => abort!(name, "PrimeFieldParams requires a struct named `<Field>Parameters`"),
+    };
+
+    let modulus = parse_integer_attribute(ast, "modulus")
+        .unwrap_or_else(|| abort_call_site!("PrimeFieldParams requires a #[modulus = \"...\"] attribute"));
+    let generator = parse_integer_attribute(ast, "generator")
+        .unwrap_or_else(|| abort_call_site!("PrimeFieldParams requires a #[generator = \"...\"] attribute"));
+    let small_subgroup_base = parse_integer_attribute(ast, "small_subgroup_base");
+    let small_subgroup_base_adicity = parse_integer_attribute(ast, "small_subgroup_base_adicity");
+
+    let one = BigUint::from(1u64);
+    let two = BigUint::from(2u64);
+    if modulus <= two || !is_probable_prime(&modulus) {
+        abort_call_site!("The modulus must be an odd prime");
+    }
+    let modulus_minus_one = &modulus - &one;
+
+    let modulus_bits = modulus.bits() as usize;
+    let num_limbs = modulus.to_u64_digits().len();
+    let (field_type, biginteger_type) = match FIELD_TYPES.iter().find(|(limbs, ..)| *limbs == num_limbs) {
+        Some((_, field_type, biginteger_type)) => (
+            Ident::new(field_type, Span::call_site()),
+            Ident::new(biginteger_type, Span::call_site()),
+        ),
+        None => abort_call_site!("There is no field type for a {}-bit modulus", modulus_bits),
+    };
+
+    // The generator must be a quadratic nonresidue.
+    if generator.modpow(&(&modulus_minus_one >> 1), &modulus) != modulus_minus_one {
+        abort_call_site!("The generator must be a quadratic nonresidue");
+    }
+
+    // Montgomery constants, for R = 2^(64 * num_limbs).
+    let r = (&one << (64 * num_limbs)) % &modulus;
+    let r2 = (&r * &r) % &modulus;
+    let inv = montgomery_inv(modulus.to_u64_digits()[0]);
+    let to_montgomery = |value: &BigUint| (value * &r) % &modulus;
+
+    // MODULUS - 1 = 2^s * t, for odd t.
+    let two_adicity = modulus_minus_one.trailing_zeros().unwrap_or(0) as u32;
+    let t = &modulus_minus_one >> two_adicity;
+    let two_adic_root_of_unity = generator.modpow(&t, &modulus);
+
+    let large_subgroup = match (small_subgroup_base, small_subgroup_base_adicity) {
+        (Some(base), Some(adicity)) => {
+            let base_u32 = to_u32(&base, "small_subgroup_base");
+            let adicity_u32 = to_u32(&adicity, "small_subgroup_base_adicity");
+            let subgroup_order = base.pow(adicity_u32);
+            if (&t % &subgroup_order) != BigUint::from(0u64) {
+                abort_call_site!("MODULUS - 1 is not divisible by small_subgroup_base^small_subgroup_base_adicity");
+            }
+            let root = generator.modpow(&(&t / &subgroup_order), &modulus);
+
+            // The root must have order exactly 2^TWO_ADICITY * base^adicity. Its 2-part is full because the
+            // generator is a quadratic nonresidue, so only the base-part needs to be checked.
+            let two_adic_order = &one << two_adicity;
+            if adicity_u32 > 0 && root.modpow(&(&two_adic_order * &subgroup_order / &base), &modulus) == one {
+                abort_call_site!("The generator must not be a small_subgroup_base-th power residue");
+            }
+            Some((base_u32, adicity_u32, root))
+        }
+        (None, None) => None,
+        _ => abort_call_site!("small_subgroup_base and small_subgroup_base_adicity must be given together"),
+    };
+
+    let fields = crate_path("snarkvm-fields");
+    let utilities = crate_path("snarkvm-utilities");
+    let biginteger = |value: &BigUint| {
+        let mut limbs = value.to_u64_digits();
+        limbs.resize(num_limbs, 0);
+        quote! { #utilities::biginteger::#biginteger_type([#(#limbs),*]) }
+    };
+
+    let modulus_bits = modulus_bits as u32;
+    let repr_shave_bits = (64 * num_limbs) as u32 - modulus_bits;
+    let modulus_tokens = biginteger(&modulus);
+    let r_tokens = biginteger(&r);
+    let r2_tokens = biginteger(&r2);
+    let generator_tokens = biginteger(&to_montgomery(&generator));
+    let t_tokens = biginteger(&t);
+    let t_minus_one_div_two_tokens = biginteger(&(&t >> 1));
+    let modulus_minus_one_div_two_tokens = biginteger(&(&modulus_minus_one >> 1));
+    let two_adic_root_of_unity_tokens = biginteger(&to_montgomery(&two_adic_root_of_unity));
+
+    let large_subgroup_tokens = large_subgroup.map(|(base, adicity, root)| {
+        let root_tokens = biginteger(&to_montgomery(&root));
+        quote! {
+            const SMALL_SUBGROUP_BASE: Option<u32> = Some(#base);
+            const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(#adicity);
+            const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self::BigInteger> = Some(#root_tokens);
+        }
+    });
+
+    let field_parameters_trait = Ident::new(&format!("{}Parameters", field_type), Span::call_site());
+
+    quote! {
+        #vis type #field_name = #fields::#field_type<#name>;
+
+        impl #fields::#field_parameters_trait for #name {}
+
+        impl #fields::FftParameters for #name {
+            type BigInteger = #utilities::biginteger::#biginteger_type;
+
+            const TWO_ADICITY: u32 = #two_adicity;
+            const TWO_ADIC_ROOT_OF_UNITY: Self::BigInteger = #two_adic_root_of_unity_tokens;
+            #large_subgroup_tokens
+        }
+
+        impl #fields::FieldParameters for #name {
+            const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+            const GENERATOR: Self::BigInteger = #generator_tokens;
+            const INV: u64 = #inv;
+            const MODULUS: Self::BigInteger = #modulus_tokens;
+            const MODULUS_BITS: u32 = #modulus_bits;
+            const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInteger = #modulus_minus_one_div_two_tokens;
+            const R: Self::BigInteger = #r_tokens;
+            const R2: Self::BigInteger = #r2_tokens;
+            const REPR_SHAVE_BITS: u32 = #repr_shave_bits;
+            const T: Self::BigInteger = #t_tokens;
+            const T_MINUS_ONE_DIV_TWO: Self::BigInteger = #t_minus_one_div_two_tokens;
+        }
+    }
+}
+
+/// Returns the value of the `#[name = "..."]` attribute, given in decimal or in hexadecimal with a `0x` prefix.
+fn parse_integer_attribute(ast: &DeriveInput, name: &str) -> Option<BigUint> {
+    let attribute = ast.attrs.iter().find(|attribute| attribute.path.is_ident(name))?;
+
+    let value = match attribute.parse_meta() {
+        Ok(Meta::NameValue(meta)) => match meta.lit {
+            Lit::Str(value) => value,
+            lit => abort!(lit, "Expected a string literal for #[{}]", name),
+        },
+        _ => abort!(attribute, "Expected an attribute of the form #[{} = \"...\"]", name),
+    };
+
+    let string = value.value();
+    let parsed = match string.strip_prefix("0x") {
+        Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+        None => BigUint::parse_bytes(string.as_bytes(), 10),
+    };
+    Some(parsed.unwrap_or_else(|| abort!(value, "Invalid integer for #[{}]", name)))
+}
+
+/// Returns `true` if the odd number `n > 2` passes the Miller-Rabin test for the first 20 prime bases.
+fn is_probable_prime(n: &BigUint) -> bool {
+    const BASES: [u64; 20] = [
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+    ];
+
+    let one = BigUint::from(1u64);
+    if n.to_u64_digits()[0] & 1 == 0 {
+        return false;
+    }
+    let n_minus_one = n - &one;
+
+    // n - 1 = 2^s * d, for odd d.
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+
+    'bases: for base in BASES.iter() {
+        let base = BigUint::from(*base);
+        if &base % n == BigUint::from(0u64) {
+            // The base is a multiple of n, which is only possible when n is one of the bases.
+            return *n == base;
+        }
+
+        let mut x = base.modpow(&d, n);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = (&x * &x) % n;
+            if x == n_minus_one {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Returns -(m^{-1}) mod 2^64, for the least significant limb `m` of an odd modulus.
+fn montgomery_inv(m: u64) -> u64 {
+    let mut inv = 1u64;
+    for _ in 0..63 {
+        inv = inv.wrapping_mul(inv);
+        inv = inv.wrapping_mul(m);
+    }
+    inv.wrapping_neg()
+}
+
+fn to_u32(value: &BigUint, name: &str) -> u32 {
+    match value.to_u32_digits().as_slice() {
+        [] => 0,
+        [value] => *value,
+        _ => abort_call_site!("#[{}] must fit in a u32", name),
+    }
+}
+
+/// Returns the path to the given crate, as it is named by the crate invoking the derive.
+fn crate_path(name: &str) -> Ident {
+    let name = crate_name(name).unwrap_or_else(|_| "crate".to_string());
+    Ident::new(&name, Span::call_site())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_probable_prime() {
+        for prime in [3u64, 5, 71, 73, 65537, 2305843009213693951].iter() {
+            assert!(is_probable_prime(&BigUint::from(*prime)));
+        }
+        // 561 and 3215031751 are Carmichael numbers, and 3215031751 is a strong pseudoprime to bases 2, 3, 5 and 7.
+        for composite in [9u64, 15, 561, 3215031751, 2305843009213693953].iter() {
+            assert!(!is_probable_prime(&BigUint::from(*composite)));
+        }
+
+        let bls12_377_fr = BigUint::parse_bytes(
+            b"8444461749428370424248824938781546531375899335154063827935233455917409239041",
+            10,
+        )
+        .unwrap();
+        assert!(is_probable_prime(&bls12_377_fr));
+        assert!(!is_probable_prime(&(&bls12_377_fr * &bls12_377_fr)));
+    }
+
+    #[test]
+    fn test_montgomery_inv() {
+        let modulus = 0x0a11800000000001u64;
+        assert_eq!(modulus.wrapping_mul(montgomery_inv(modulus)), u64::MAX);
+    }
+}
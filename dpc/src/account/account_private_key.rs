@@ -24,8 +24,9 @@ use snarkvm_utilities::{bytes_to_bits, to_bytes, FromBytes, ToBytes};
 use base58::{FromBase58, ToBase58};
 use rand::Rng;
 use std::{fmt, str::FromStr};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-#[derive(Derivative)]
+#[derive(Derivative, Zeroize, ZeroizeOnDrop)]
 #[derivative(
     Clone(bound = "C: DPCComponents"),
     Default(bound = "C: DPCComponents"),
@@ -38,9 +39,11 @@ pub struct AccountPrivateKey<C: DPCComponents> {
     pub sk_sig: <C::AccountSignature as SignatureScheme>::PrivateKey,
     pub sk_prf: <C::PRF as PRF>::Seed,
     pub r_pk: <C::AccountCommitment as CommitmentScheme>::Randomness,
+    #[zeroize(skip)]
     pub r_pk_counter: u16,
     // This dummy flag is set to true for use in the `inner_snark` setup.
     #[derivative(Default(value = "true"))]
+    #[zeroize(skip)]
     pub is_dummy: bool,
 }
 
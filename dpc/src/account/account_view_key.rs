@@ -24,8 +24,9 @@ use std::{
     io::{Read, Result as IoResult, Write},
     str::FromStr,
 };
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-#[derive(Derivative)]
+#[derive(Derivative, Zeroize, ZeroizeOnDrop)]
 #[derivative(
     Default(bound = "C: DPCComponents"),
     Clone(bound = "C: DPCComponents"),
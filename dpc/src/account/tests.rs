@@ -19,6 +19,12 @@ use crate::{
     testnet1::{instantiated::Components, parameters::SystemParameters},
     traits::account::AccountScheme,
 };
+use snarkvm_algorithms::traits::{EncryptionScheme, SignatureScheme};
+use snarkvm_curves::{
+    edwards_bls12::{EdwardsAffine, EdwardsProjective},
+    traits::Group,
+};
+use snarkvm_utilities::{rand::UniformRand, to_bytes, ToBytes};
 
 use rand::thread_rng;
 use std::str::FromStr;
@@ -68,3 +74,161 @@ pub fn test_address_from_str() {
     assert!(address.is_ok());
     assert_eq!(address_string, address.unwrap().to_string());
 }
+
+#[test]
+fn test_threshold_account() {
+    let rng = &mut thread_rng();
+    let parameters = SystemParameters::<Components>::load().unwrap();
+    let signature = &parameters.account_signature;
+    let encryption = &parameters.account_encryption;
+
+    let account = Account::<Components>::new(signature, &parameters.account_commitment, encryption, rng).unwrap();
+
+    // Any 2 of 3 custodians can spend, without reconstructing the signature private key.
+    let message = b"Hi, I am a threshold account signature!";
+    let pk_sig = signature.generate_public_key(&account.private_key.sk_sig).unwrap();
+    let key_shares = signature
+        .split_private_key(&account.private_key.sk_sig, 2, 3, rng)
+        .unwrap();
+    let signers = [&key_shares[0], &key_shares[2]];
+
+    let (nonces, commitments): (Vec<_>, Vec<_>) = signers
+        .iter()
+        .map(|key_share| signature.commit_nonces(key_share.index, rng).unwrap())
+        .unzip();
+    let shares = signers
+        .iter()
+        .zip(nonces)
+        .map(|(key_share, nonces)| signature.sign_share(key_share, nonces, message, &commitments).unwrap())
+        .collect::<Vec<_>>();
+    let output = signature
+        .aggregate_signature_shares(&key_shares[0].verification_keys, message, &commitments, &shares)
+        .unwrap();
+
+    let randomness = to_bytes![<EdwardsAffine as Group>::ScalarField::rand(rng)].unwrap();
+    let randomized_pk_sig = signature.randomize_public_key(&pk_sig, &randomness).unwrap();
+    let randomized_output = signature.randomize_signature(&output, &randomness).unwrap();
+    assert!(signature
+        .verify(&randomized_pk_sig, message, &randomized_output)
+        .unwrap());
+
+    // Any 2 of 3 custodians can decrypt, without reconstructing the view key.
+    let view_key =
+        AccountViewKey::<Components>::from_private_key(signature, &parameters.account_commitment, &account.private_key)
+            .unwrap();
+    let plaintext = (0..4).map(|_| EdwardsProjective::rand(rng)).collect::<Vec<_>>();
+    let encryption_randomness = encryption
+        .generate_randomness(&account.address.encryption_key, rng)
+        .unwrap();
+    let ciphertext = encryption
+        .encrypt(&account.address.encryption_key, &encryption_randomness, &plaintext)
+        .unwrap();
+
+    let key_shares = encryption
+        .split_private_key(&view_key.decryption_key, 2, 3, rng)
+        .unwrap();
+    let decryption_shares = [&key_shares[1], &key_shares[2]]
+        .iter()
+        .map(|key_share| encryption.partial_decrypt(key_share, &ciphertext, rng).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        plaintext,
+        encryption
+            .combine_decryption_shares(&key_shares[0].verification_keys, &decryption_shares, &ciphertext)
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_threshold_account_dkg() {
+    let rng = &mut thread_rng();
+    let parameters = SystemParameters::<Components>::load().unwrap();
+    let signature = &parameters.account_signature;
+    let encryption = &parameters.account_encryption;
+    let (threshold, num_participants) = (2, 3);
+
+    // The custodians generate the signature key together, so no one ever holds the private key.
+    let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+        .map(|index| signature.dkg_commit(index, threshold, num_participants, rng).unwrap())
+        .unzip();
+    let shares = secrets
+        .iter()
+        .flat_map(|secret| signature.dkg_distribute(secret, &commitments).unwrap())
+        .collect::<Vec<_>>();
+    let key_shares = secrets
+        .iter()
+        .zip(1..=num_participants)
+        .map(|(secret, index)| {
+            let received = shares
+                .iter()
+                .filter(|share| share.receiver == index)
+                .cloned()
+                .collect::<Vec<_>>();
+            signature.dkg_finalize(secret, &commitments, &received).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let message = b"Hi, I am a threshold account signature!";
+    let signers = [&key_shares[1], &key_shares[2]];
+    let (nonces, nonce_commitments): (Vec<_>, Vec<_>) = signers
+        .iter()
+        .map(|key_share| signature.commit_nonces(key_share.index, rng).unwrap())
+        .unzip();
+    let signature_shares = signers
+        .iter()
+        .zip(nonces)
+        .map(|(key_share, nonces)| {
+            signature
+                .sign_share(key_share, nonces, message, &nonce_commitments)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let output = signature
+        .aggregate_signature_shares(
+            &key_shares[0].verification_keys,
+            message,
+            &nonce_commitments,
+            &signature_shares,
+        )
+        .unwrap();
+    assert!(signature.verify(&key_shares[0].public_key, message, &output).unwrap());
+
+    // Likewise for the decryption key.
+    let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+        .map(|index| encryption.dkg_commit(index, threshold, num_participants, rng).unwrap())
+        .unzip();
+    let shares = secrets
+        .iter()
+        .flat_map(|secret| encryption.dkg_distribute(secret, &commitments).unwrap())
+        .collect::<Vec<_>>();
+    let key_shares = secrets
+        .iter()
+        .zip(1..=num_participants)
+        .map(|(secret, index)| {
+            let received = shares
+                .iter()
+                .filter(|share| share.receiver == index)
+                .cloned()
+                .collect::<Vec<_>>();
+            encryption.dkg_finalize(secret, &commitments, &received).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let encryption_key = key_shares[0].public_key;
+    let plaintext = (0..4).map(|_| EdwardsProjective::rand(rng)).collect::<Vec<_>>();
+    let encryption_randomness = encryption.generate_randomness(&encryption_key, rng).unwrap();
+    let ciphertext = encryption
+        .encrypt(&encryption_key, &encryption_randomness, &plaintext)
+        .unwrap();
+
+    let decryption_shares = [&key_shares[0], &key_shares[1]]
+        .iter()
+        .map(|key_share| encryption.partial_decrypt(key_share, &ciphertext, rng).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        plaintext,
+        encryption
+            .combine_decryption_shares(&key_shares[0].verification_keys, &decryption_shares, &ciphertext)
+            .unwrap()
+    );
+}
@@ -20,6 +20,7 @@ use snarkvm_algorithms::errors::{
     CommitmentError,
     EncodingError,
     EncryptionError,
+    EnvelopeError,
     MerkleError,
     PRFError,
     SNARKError,
@@ -47,6 +48,9 @@ pub enum DPCError {
     #[error("{}", _0)]
     EncryptionError(EncryptionError),
 
+    #[error("{}", _0)]
+    EnvelopeError(EnvelopeError),
+
     #[error("{}", _0)]
     LedgerError(LedgerError),
 
@@ -105,6 +109,12 @@ impl From<EncryptionError> for DPCError {
     }
 }
 
+impl From<EnvelopeError> for DPCError {
+    fn from(error: EnvelopeError) -> Self {
+        DPCError::EnvelopeError(error)
+    }
+}
+
 impl From<LedgerError> for DPCError {
     fn from(error: LedgerError) -> Self {
         DPCError::LedgerError(error)
@@ -22,6 +22,7 @@ use crate::{
         outer_circuit_verifier_input::OuterCircuitVerifierInput,
         program::{NoopCircuit, ProgramLocalData},
         transaction::Transaction,
+        verifiable_encryption::{VerifiableEncryption, VerifiableEncryptionCircuit, VerifiableEncryptionInput},
         LocalData as DPCLocalData,
         Testnet2Components,
         DPC,
@@ -170,7 +171,7 @@ impl Testnet2Components for Components {
         Self::InnerField,
         Self::OuterField,
         Self::PolynomialCommitment,
-        MarlinKZG10Gadget<InnerCurve, OuterCurve, PairingGadget>,
+        MarlinKZG10Gadget<InnerCurve, Self::OuterField, PairingGadget>,
     >;
 }
 
@@ -184,6 +185,17 @@ pub type LocalData = DPCLocalData<Components>;
 pub type MerkleTreeCRH =
     BoweHopwoodPedersenCompressedCRH<EdwardsBls, MERKLE_TREE_CRH_NUM_WINDOWS, MERKLE_TREE_CRH_WINDOW_SIZE>;
 
+pub type VerifiableEncryptionSNARK<P> = MarlinSNARK<
+    <Components as DPCComponents>::InnerField,
+    <Components as DPCComponents>::OuterField,
+    <Components as Testnet2Components>::PolynomialCommitment,
+    <Components as Testnet2Components>::FiatShamirRng,
+    <Components as Testnet2Components>::MarlinMode,
+    VerifiableEncryptionCircuit<Components, P>,
+    VerifiableEncryptionInput<Components>,
+>;
+pub type Testnet2VerifiableEncryption<P> = VerifiableEncryption<Components, P, VerifiableEncryptionSNARK<P>>;
+
 // This is currently unused.
 //
 // use snarkvm_marlin::{FiatShamirAlgebraicSpongeRngVar, PoseidonSpongeVar};
@@ -84,6 +84,9 @@ pub use record::*;
 pub mod transaction;
 pub use transaction::*;
 
+pub mod verifiable_encryption;
+pub use verifiable_encryption::*;
+
 pub mod instantiated;
 
 #[cfg(test)]
@@ -15,7 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::testnet2::Testnet2Components;
-use snarkvm_algorithms::traits::{EncryptionScheme, SNARK};
+use snarkvm_algorithms::{
+    envelope::EnvelopeMode,
+    traits::{EncryptionScheme, Envelope, SNARK},
+};
 use snarkvm_marlin::marlin::UniversalSRS;
 use snarkvm_parameters::{prelude::*, testnet2::*};
 use snarkvm_utilities::bytes::FromBytes;
@@ -90,8 +93,11 @@ pub struct ProgramSNARKUniversalSRS<C: Testnet2Components>(pub UniversalSRS<C::I
 
 impl<C: Testnet2Components> ProgramSNARKUniversalSRS<C> {
     pub fn load() -> IoResult<Self> {
-        let srs: UniversalSRS<C::InnerField, C::PolynomialCommitment> =
-            From::from(FromBytes::read(UniversalSRSParameters::load_bytes()?.as_slice())?);
+        // The SRS is trusted, as the checksum of its bytes was verified when loading them.
+        let srs = UniversalSRS::<C::InnerField, C::PolynomialCommitment>::read_envelope_unchecked(
+            UniversalSRSParameters::load_bytes()?.as_slice(),
+            EnvelopeMode::None,
+        )?;
 
         Ok(Self(srs))
     }
@@ -107,10 +113,11 @@ pub struct NoopProgramSNARKParameters<C: Testnet2Components> {
 impl<C: Testnet2Components> NoopProgramSNARKParameters<C> {
     // TODO (howardwu): Why are we not preparing the VK here?
     pub fn load() -> IoResult<Self> {
-        let proving_key: <C::NoopProgramSNARK as SNARK>::ProvingKey =
-            FromBytes::read(NoopProgramSNARKPKParameters::load_bytes()?.as_slice())?;
+        // The proving key is trusted, as the checksum of its bytes was verified when loading them.
+        let proving_key =
+            C::NoopProgramSNARK::read_proving_key_unchecked(NoopProgramSNARKPKParameters::load_bytes()?.as_slice())?;
         let verification_key =
-            <C::NoopProgramSNARK as SNARK>::VerifyingKey::read(NoopProgramSNARKVKParameters::load_bytes()?.as_slice())?;
+            C::NoopProgramSNARK::read_verifying_key(NoopProgramSNARKVKParameters::load_bytes()?.as_slice())?;
 
         Ok(Self {
             proving_key,
@@ -201,16 +208,17 @@ impl<C: Testnet2Components> PublicParameters<C> {
         let system_parameters = SystemParameters::<C>::load()?;
         let noop_program_snark_parameters = NoopProgramSNARKParameters::<C>::load()?;
 
+        // The proving keys are trusted, as the checksums of their bytes were verified when loading them.
         let inner_snark_parameters = {
             let inner_snark_pk = match verify_only {
                 true => None,
-                false => Some(<C::InnerSNARK as SNARK>::ProvingKey::read(
+                false => Some(C::InnerSNARK::read_proving_key_unchecked(
                     InnerSNARKPKParameters::load_bytes()?.as_slice(),
                 )?),
             };
 
             let inner_snark_vk: <C::InnerSNARK as SNARK>::VerifyingKey =
-                <C::InnerSNARK as SNARK>::VerifyingKey::read(InnerSNARKVKParameters::load_bytes()?.as_slice())?;
+                C::InnerSNARK::read_verifying_key(InnerSNARKVKParameters::load_bytes()?.as_slice())?;
 
             (inner_snark_pk, inner_snark_vk.into())
         };
@@ -218,13 +226,13 @@ impl<C: Testnet2Components> PublicParameters<C> {
         let outer_snark_parameters = {
             let outer_snark_pk = match verify_only {
                 true => None,
-                false => Some(<C::OuterSNARK as SNARK>::ProvingKey::read(
+                false => Some(C::OuterSNARK::read_proving_key_unchecked(
                     OuterSNARKPKParameters::load_bytes()?.as_slice(),
                 )?),
             };
 
             let outer_snark_vk: <C::OuterSNARK as SNARK>::VerifyingKey =
-                <C::OuterSNARK as SNARK>::VerifyingKey::read(OuterSNARKVKParameters::load_bytes()?.as_slice())?;
+                C::OuterSNARK::read_verifying_key(OuterSNARKVKParameters::load_bytes()?.as_slice())?;
 
             (outer_snark_pk, outer_snark_vk.into())
         };
@@ -244,14 +252,14 @@ impl<C: Testnet2Components> PublicParameters<C> {
         let inner_snark_parameters = {
             let inner_snark_pk = None;
             let inner_snark_vk: <C::InnerSNARK as SNARK>::VerifyingKey =
-                <C::InnerSNARK as SNARK>::VerifyingKey::read(InnerSNARKVKParameters::load_bytes()?.as_slice())?;
+                C::InnerSNARK::read_verifying_key(InnerSNARKVKParameters::load_bytes()?.as_slice())?;
             (inner_snark_pk, inner_snark_vk.into())
         };
 
         let outer_snark_parameters = {
             let outer_snark_pk = None;
             let outer_snark_vk: <C::OuterSNARK as SNARK>::VerifyingKey =
-                <C::OuterSNARK as SNARK>::VerifyingKey::read(OuterSNARKVKParameters::load_bytes()?.as_slice())?;
+                C::OuterSNARK::read_verifying_key(OuterSNARKVKParameters::load_bytes()?.as_slice())?;
             (outer_snark_pk, outer_snark_vk.into())
         };
 
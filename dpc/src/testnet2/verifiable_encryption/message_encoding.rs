@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::DPCError;
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::GroupAffine as TEAffine,
+    traits::{AffineCurve, TEModelParameters},
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::biginteger::BigInteger;
+
+use std::marker::PhantomData;
+
+/// The number of low-order bits of a y-coordinate that are reserved for the encoding counter.
+pub const MESSAGE_ENCODING_COUNTER_BITS: usize = 8;
+
+/// The number of group elements used to encode a single message.
+pub const MESSAGE_ENCODING_NUM_CHUNKS: usize = 2;
+
+/// Encodes base field elements as twisted Edwards group elements, such that decoding does not
+/// require a discrete logarithm and can be cheaply checked in a circuit.
+///
+/// A message is split into `MESSAGE_ENCODING_NUM_CHUNKS` chunks of
+/// `ceil(MODULUS_BITS / MESSAGE_ENCODING_NUM_CHUNKS)` bits. Each chunk is shifted into
+/// the high-order bits of a y-coordinate, and the low-order counter bits are incremented until the
+/// y-coordinate corresponds to a point on the curve.
+pub struct MessageEncoding<P: TEModelParameters> {
+    _parameters: PhantomData<P>,
+}
+
+impl<P: TEModelParameters> MessageEncoding<P>
+where
+    P::BaseField: PrimeField,
+{
+    /// Returns the number of message bits that are encoded in each group element.
+    pub fn chunk_size_in_bits() -> usize {
+        <P::BaseField as PrimeField>::size_in_bits().div_ceil(MESSAGE_ENCODING_NUM_CHUNKS)
+    }
+
+    /// Returns the group elements encoding the given message, along with the counters used to find them.
+    pub fn encode(message: &P::BaseField) -> Result<Vec<(TEAffine<P>, u8)>, DPCError> {
+        let chunk_size = Self::chunk_size_in_bits();
+        let shift = P::BaseField::from(1u128 << MESSAGE_ENCODING_COUNTER_BITS);
+
+        let message_bits = message.into_repr().to_bits_le();
+
+        let mut encoding = Vec::with_capacity(MESSAGE_ENCODING_NUM_CHUNKS);
+        for chunk_bits in message_bits.chunks(chunk_size).take(MESSAGE_ENCODING_NUM_CHUNKS) {
+            let chunk = chunk_bits
+                .iter()
+                .rev()
+                .fold(P::BaseField::zero(), |chunk, bit| match bit {
+                    true => chunk.double() + P::BaseField::one(),
+                    false => chunk.double(),
+                });
+
+            let offset = chunk * shift;
+            let element = (0..=u8::MAX)
+                .find_map(|counter| {
+                    let y_coordinate = offset + P::BaseField::from(counter as u128);
+                    TEAffine::<P>::from_y_coordinate(y_coordinate, false).map(|element| (element, counter))
+                })
+                .ok_or_else(|| DPCError::Message("Failed to encode the message as a group element".into()))?;
+
+            encoding.push(element);
+        }
+
+        Ok(encoding)
+    }
+
+    /// Returns the message encoded by the given group elements.
+    pub fn decode(encoding: &[TEAffine<P>]) -> Result<P::BaseField, DPCError> {
+        if encoding.len() != MESSAGE_ENCODING_NUM_CHUNKS {
+            return Err(DPCError::Message(format!(
+                "Expected {} group elements to decode a message, found {}",
+                MESSAGE_ENCODING_NUM_CHUNKS,
+                encoding.len()
+            )));
+        }
+
+        let chunk_size = Self::chunk_size_in_bits();
+        let chunk_shift = P::BaseField::from(2u128).pow([chunk_size as u64]);
+
+        let mut message = P::BaseField::zero();
+        for element in encoding.iter().rev() {
+            let mut chunk = element.y.into_repr();
+            chunk.divn(MESSAGE_ENCODING_COUNTER_BITS as u32);
+
+            if chunk.num_bits() as usize > chunk_size {
+                return Err(DPCError::Message("Invalid message encoding".into()));
+            }
+
+            let chunk =
+                P::BaseField::from_repr(chunk).ok_or_else(|| DPCError::Message("Invalid message encoding".into()))?;
+            message = message * chunk_shift + chunk;
+        }
+
+        Ok(message)
+    }
+}
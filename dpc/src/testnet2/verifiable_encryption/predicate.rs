@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::PrimeField;
+use snarkvm_gadgets::{
+    bits::{Boolean, ToBitsLEGadget},
+    fields::FpGadget,
+    traits::eq::EqGadget,
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+use snarkvm_utilities::biginteger::BigInteger;
+
+/// A statement about the messages of a verifiable encryption, which is proven
+/// to hold without revealing the messages themselves.
+pub trait MessagePredicate<F: PrimeField>: Clone {
+    /// Returns `true` if the given messages satisfy the predicate.
+    fn evaluate(&self, messages: &[F]) -> bool;
+
+    /// Enforces that the given message gadgets satisfy the predicate.
+    fn enforce<CS: ConstraintSystem<F>>(&self, cs: CS, messages: &[FpGadget<F>]) -> Result<(), SynthesisError>;
+}
+
+/// Accepts messages that are each less than `2^num_bits`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageRangePredicate {
+    pub num_bits: usize,
+}
+
+impl MessageRangePredicate {
+    pub fn new(num_bits: usize) -> Self {
+        Self { num_bits }
+    }
+}
+
+impl<F: PrimeField> MessagePredicate<F> for MessageRangePredicate {
+    fn evaluate(&self, messages: &[F]) -> bool {
+        messages
+            .iter()
+            .all(|message| message.into_repr().num_bits() as usize <= self.num_bits)
+    }
+
+    fn enforce<CS: ConstraintSystem<F>>(&self, mut cs: CS, messages: &[FpGadget<F>]) -> Result<(), SynthesisError> {
+        for (i, message) in messages.iter().enumerate() {
+            let message_bits = message.to_bits_le_strict(cs.ns(|| format!("message_{} to_bits_le_strict", i)))?;
+
+            for (j, bit) in message_bits.iter().enumerate().skip(self.num_bits) {
+                bit.enforce_equal(
+                    cs.ns(|| format!("message_{} bit_{} is zero", i, j)),
+                    &Boolean::constant(false),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    testnet2::{instantiated::*, parameters::SystemParameters, verifiable_encryption::*, Testnet2Components},
+    traits::{AccountScheme, DPCComponents},
+    Account,
+    AccountViewKey,
+};
+use snarkvm_curves::edwards_bls12::EdwardsParameters;
+use snarkvm_marlin::marlin::{MarlinSNARK, UniversalSRS};
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+use snarkvm_utilities::{to_bytes, FromBytes, ToBytes, UniformRand};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+type InnerField = <Components as DPCComponents>::InnerField;
+type PolynomialCommitment = <Components as Testnet2Components>::PolynomialCommitment;
+type RangeVerifiableEncryption = Testnet2VerifiableEncryption<MessageRangePredicate>;
+type RangeVerifiableCiphertext = VerifiableCiphertext<Components, VerifiableEncryptionSNARK<MessageRangePredicate>>;
+
+const ITERATIONS: usize = 100;
+
+/// The number of bits a message may have to satisfy the range predicate in these tests.
+const MESSAGE_SIZE_IN_BITS: usize = 64;
+
+fn generate_account<R: Rng>(
+    system_parameters: &SystemParameters<Components>,
+    rng: &mut R,
+) -> (Account<Components>, AccountViewKey<Components>) {
+    let account = Account::new(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &system_parameters.account_encryption,
+        rng,
+    )
+    .unwrap();
+
+    let view_key = AccountViewKey::from_private_key(
+        &system_parameters.account_signature,
+        &system_parameters.account_commitment,
+        &account.private_key,
+    )
+    .unwrap();
+
+    (account, view_key)
+}
+
+fn generate_universal_srs<R: Rng>(rng: &mut R) -> UniversalSRS<InnerField, PolynomialCommitment> {
+    MarlinSNARK::<
+        InnerField,
+        <Components as DPCComponents>::OuterField,
+        PolynomialCommitment,
+        <Components as Testnet2Components>::FiatShamirRng,
+        <Components as Testnet2Components>::MarlinMode,
+    >::universal_setup(50000, 50000, 150000, rng)
+    .unwrap()
+}
+
+#[test]
+fn test_message_encoding() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let message = InnerField::rand(&mut rng);
+
+        let encoding = MessageEncoding::<EdwardsParameters>::encode(&message).unwrap();
+        assert_eq!(MESSAGE_ENCODING_NUM_CHUNKS, encoding.len());
+
+        let elements: Vec<_> = encoding.iter().map(|(element, _)| *element).collect();
+        let candidate_message = MessageEncoding::<EdwardsParameters>::decode(&elements).unwrap();
+        assert_eq!(message, candidate_message);
+    }
+}
+
+#[test]
+fn test_message_range_predicate() {
+    let predicate = MessageRangePredicate::new(MESSAGE_SIZE_IN_BITS);
+
+    assert!(predicate.evaluate(&[InnerField::from(u64::MAX as u128)]));
+    assert!(!predicate.evaluate(&[InnerField::from(u64::MAX as u128 + 1)]));
+}
+
+#[test]
+fn test_verifiable_encryption_circuit() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = Testnet2DPC::generate_system_parameters(&mut rng).unwrap();
+    let (account, _) = generate_account(&system_parameters, &mut rng);
+    let predicate = MessageRangePredicate::new(MESSAGE_SIZE_IN_BITS);
+
+    // Check that the circuit is satisfied for messages that satisfy the predicate.
+    let messages = vec![
+        InnerField::from(rng.gen::<u64>() as u128),
+        InnerField::from(u64::MAX as u128),
+    ];
+    let (_, circuit) = RangeVerifiableEncryption::encrypt_with_circuit(
+        &system_parameters,
+        &predicate,
+        &account.address,
+        &messages,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut cs = TestConstraintSystem::<InnerField>::new();
+    circuit
+        .generate_constraints(&mut cs.ns(|| "verifiable encryption"))
+        .unwrap();
+    if !cs.is_satisfied() {
+        println!("Unsatisfied constraint: {}", cs.which_is_unsatisfied().unwrap());
+    }
+    assert!(cs.is_satisfied());
+
+    // Check that the circuit is not satisfied for messages that violate the predicate.
+    let messages = vec![InnerField::from(rng.gen::<u64>() as u128), InnerField::rand(&mut rng)];
+    let (_, circuit) = RangeVerifiableEncryption::encrypt_with_circuit(
+        &system_parameters,
+        &predicate,
+        &account.address,
+        &messages,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut cs = TestConstraintSystem::<InnerField>::new();
+    circuit
+        .generate_constraints(&mut cs.ns(|| "verifiable encryption"))
+        .unwrap();
+    assert!(!cs.is_satisfied());
+}
+
+#[test]
+fn test_verifiable_encryption() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let system_parameters = Testnet2DPC::generate_system_parameters(&mut rng).unwrap();
+    let universal_srs = generate_universal_srs(&mut rng);
+
+    let (account, view_key) = generate_account(&system_parameters, &mut rng);
+    let (other_account, _) = generate_account(&system_parameters, &mut rng);
+
+    let predicate = MessageRangePredicate::new(MESSAGE_SIZE_IN_BITS);
+    let messages = vec![InnerField::from(rng.gen::<u64>() as u128)];
+
+    let (proving_key, verifying_key) =
+        RangeVerifiableEncryption::setup(&system_parameters, &universal_srs, &predicate, messages.len(), &mut rng)
+            .unwrap();
+
+    let verifiable_ciphertext = RangeVerifiableEncryption::encrypt(
+        &system_parameters,
+        &proving_key,
+        &predicate,
+        &account.address,
+        &messages,
+        &mut rng,
+    )
+    .unwrap();
+
+    // Check that the ciphertext verifies for the recipient, and decrypts to the messages.
+    assert!(RangeVerifiableEncryption::verify(&verifying_key, &account.address, &verifiable_ciphertext).unwrap());
+    assert_eq!(
+        messages,
+        RangeVerifiableEncryption::decrypt(&system_parameters, &view_key, &verifiable_ciphertext).unwrap()
+    );
+
+    // Check that the ciphertext does not verify for a different recipient.
+    assert!(
+        !RangeVerifiableEncryption::verify(&verifying_key, &other_account.address, &verifiable_ciphertext).unwrap()
+    );
+
+    // Check that the ciphertext round-trips through serialization.
+    let verifiable_ciphertext_bytes = to_bytes![verifiable_ciphertext].unwrap();
+    let candidate_verifiable_ciphertext = RangeVerifiableCiphertext::read(&verifiable_ciphertext_bytes[..]).unwrap();
+    assert!(
+        RangeVerifiableEncryption::verify(&verifying_key, &account.address, &candidate_verifiable_ciphertext).unwrap()
+    );
+
+    // Check that messages which do not satisfy the predicate are rejected.
+    let invalid_messages = vec![InnerField::from(u64::MAX as u128 + 1)];
+    assert!(RangeVerifiableEncryption::encrypt(
+        &system_parameters,
+        &proving_key,
+        &predicate,
+        &account.address,
+        &invalid_messages,
+        &mut rng,
+    )
+    .is_err());
+}
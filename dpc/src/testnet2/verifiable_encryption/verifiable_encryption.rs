@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    testnet2::{
+        parameters::SystemParameters,
+        verifiable_encryption::{
+            MessageEncoding,
+            MessagePredicate,
+            VerifiableEncryptionCircuit,
+            MESSAGE_ENCODING_NUM_CHUNKS,
+        },
+        Testnet2Components,
+    },
+    AccountAddress,
+    AccountViewKey,
+    DPCError,
+};
+use snarkvm_algorithms::{
+    encryption::{GroupEncryption, GroupEncryptionParameters, GroupEncryptionPublicKey},
+    traits::{EncryptionScheme, SNARK},
+};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{GroupAffine as TEAffine, GroupProjective as TEProjective},
+    traits::{AffineCurve, ModelParameters, ProjectiveCurve, TEModelParameters},
+};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_marlin::marlin::UniversalSRS;
+use snarkvm_utilities::{to_bytes, variable_length_integer::*, FromBytes, ToBytes};
+
+use blake2::Blake2s;
+use rand::Rng;
+use std::{
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+};
+
+type ScalarField<C> = <<C as Testnet2Components>::EncryptionModelParameters as ModelParameters>::ScalarField;
+type EncryptionGroup<C> = TEProjective<<C as Testnet2Components>::EncryptionModelParameters>;
+type EncryptionGroupAffine<C> = TEAffine<<C as Testnet2Components>::EncryptionModelParameters>;
+type Encryption<C> = GroupEncryption<EncryptionGroup<C>, EncryptionGroupAffine<C>, Blake2s>;
+
+/// The public input of a verifiable encryption proof.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "C: Testnet2Components"),
+    Debug(bound = "C: Testnet2Components"),
+    PartialEq(bound = "C: Testnet2Components"),
+    Eq(bound = "C: Testnet2Components")
+)]
+pub struct VerifiableEncryptionInput<C: Testnet2Components> {
+    pub public_key: EncryptionGroup<C>,
+    pub ciphertext: Vec<EncryptionGroup<C>>,
+}
+
+impl<C: Testnet2Components> ToConstraintField<C::InnerField> for VerifiableEncryptionInput<C>
+where
+    C::EncryptionModelParameters: TEModelParameters<BaseField = C::InnerField>,
+{
+    fn to_field_elements(&self) -> Result<Vec<C::InnerField>, ConstraintFieldError> {
+        let mut v = self.public_key.to_field_elements()?;
+        for ciphertext_element in &self.ciphertext {
+            v.extend_from_slice(&ciphertext_element.to_field_elements()?);
+        }
+        Ok(v)
+    }
+}
+
+/// A ciphertext together with a proof that its messages satisfy a predicate.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "C: Testnet2Components, S: SNARK"),
+    Debug(bound = "C: Testnet2Components, S: SNARK")
+)]
+pub struct VerifiableCiphertext<C: Testnet2Components, S: SNARK> {
+    pub ciphertext: Vec<EncryptionGroup<C>>,
+    pub proof: S::Proof,
+}
+
+impl<C: Testnet2Components, S: SNARK> ToBytes for VerifiableCiphertext<C, S> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        variable_length_integer(self.ciphertext.len() as u64).write(&mut writer)?;
+        for ciphertext_element in &self.ciphertext {
+            ciphertext_element.into_affine().write(&mut writer)?;
+        }
+
+        self.proof.write(&mut writer)
+    }
+}
+
+impl<C: Testnet2Components, S: SNARK> FromBytes for VerifiableCiphertext<C, S> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_ciphertext_elements = read_variable_length_integer(&mut reader)?;
+        let mut ciphertext = Vec::with_capacity(num_ciphertext_elements);
+        for _ in 0..num_ciphertext_elements {
+            let ciphertext_element: EncryptionGroupAffine<C> = FromBytes::read(&mut reader)?;
+            ciphertext.push(ciphertext_element.into_projective());
+        }
+
+        let proof: S::Proof = FromBytes::read(&mut reader)?;
+
+        Ok(Self { ciphertext, proof })
+    }
+}
+
+/// Encrypts field elements to an account address, with a proof that the encrypted
+/// messages satisfy a predicate. This allows an account to selectively disclose
+/// properties of a message to a third party, without constructing a transaction.
+pub struct VerifiableEncryption<C: Testnet2Components, P: MessagePredicate<C::InnerField>, S: SNARK> {
+    _components: PhantomData<C>,
+    _predicate: PhantomData<P>,
+    _snark: PhantomData<S>,
+}
+
+impl<C: Testnet2Components, P: MessagePredicate<C::InnerField>, S: SNARK> VerifiableEncryption<C, P, S>
+where
+    C::EncryptionModelParameters: TEModelParameters<BaseField = C::InnerField>,
+    S: SNARK<AllocatedCircuit = VerifiableEncryptionCircuit<C, P>, VerifierInput = VerifiableEncryptionInput<C>>,
+{
+    /// Generates the proving and verifying keys for encrypting `num_messages` messages under the given predicate.
+    pub fn setup<R: Rng>(
+        system_parameters: &SystemParameters<C>,
+        universal_srs: &UniversalSRS<C::InnerField, C::PolynomialCommitment>,
+        predicate: &P,
+        num_messages: usize,
+        rng: &mut R,
+    ) -> Result<(S::ProvingKey, S::PreparedVerifyingKey), DPCError>
+    where
+        S: SNARK<
+            Circuit = (
+                VerifiableEncryptionCircuit<C, P>,
+                UniversalSRS<C::InnerField, C::PolynomialCommitment>,
+            ),
+        >,
+    {
+        let encryption_parameters = Self::encryption_parameters(system_parameters)?;
+        let circuit = VerifiableEncryptionCircuit::blank(&encryption_parameters, predicate, num_messages)?;
+
+        Ok(S::setup(&(circuit, universal_srs.clone()), rng)?)
+    }
+
+    /// Encrypts the given messages to the account address, and proves that they satisfy the predicate.
+    pub fn encrypt<R: Rng>(
+        system_parameters: &SystemParameters<C>,
+        proving_key: &S::ProvingKey,
+        predicate: &P,
+        address: &AccountAddress<C>,
+        messages: &[C::InnerField],
+        rng: &mut R,
+    ) -> Result<VerifiableCiphertext<C, S>, DPCError> {
+        if !predicate.evaluate(messages) {
+            return Err(DPCError::Message("The messages do not satisfy the predicate".into()));
+        }
+
+        let (ciphertext, circuit) = Self::encrypt_with_circuit(system_parameters, predicate, address, messages, rng)?;
+        let proof = S::prove(proving_key, &circuit, rng)?;
+
+        Ok(VerifiableCiphertext { ciphertext, proof })
+    }
+
+    /// Encrypts the given messages to the account address, and returns the ciphertext
+    /// along with the circuit attesting to its correctness.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn encrypt_with_circuit<R: Rng>(
+        system_parameters: &SystemParameters<C>,
+        predicate: &P,
+        address: &AccountAddress<C>,
+        messages: &[C::InnerField],
+        rng: &mut R,
+    ) -> Result<(Vec<EncryptionGroup<C>>, VerifiableEncryptionCircuit<C, P>), DPCError> {
+        let encryption_parameters = Self::encryption_parameters(system_parameters)?;
+        let encryption = Encryption::<C>::from(encryption_parameters.clone());
+        let public_key = Self::public_key(address)?;
+
+        // Encode the messages as group elements.
+        let mut message_encodings = Vec::with_capacity(messages.len() * MESSAGE_ENCODING_NUM_CHUNKS);
+        for message in messages {
+            message_encodings.extend(MessageEncoding::<C::EncryptionModelParameters>::encode(message)?);
+        }
+        let plaintext: Vec<_> = message_encodings
+            .iter()
+            .map(|(element, _)| element.into_projective())
+            .collect();
+
+        // Encrypt the encoded messages.
+        let randomness = encryption.generate_randomness(&public_key, rng)?;
+        let blinding_exponents = encryption.generate_blinding_exponents(&public_key, &randomness, plaintext.len())?;
+        let ciphertext = encryption.encrypt(&public_key, &randomness, &plaintext)?;
+
+        let circuit = VerifiableEncryptionCircuit::new(
+            &encryption_parameters,
+            predicate,
+            &public_key.0,
+            &ciphertext,
+            messages,
+            &message_encodings,
+            &randomness,
+            &blinding_exponents,
+        );
+
+        Ok((ciphertext, circuit))
+    }
+
+    /// Returns `true` if the ciphertext is encrypted to the account address, and its messages satisfy the predicate.
+    pub fn verify(
+        verifying_key: &S::PreparedVerifyingKey,
+        address: &AccountAddress<C>,
+        verifiable_ciphertext: &VerifiableCiphertext<C, S>,
+    ) -> Result<bool, DPCError> {
+        let input = VerifiableEncryptionInput {
+            public_key: Self::public_key(address)?.0,
+            ciphertext: verifiable_ciphertext.ciphertext.clone(),
+        };
+
+        Ok(S::verify(verifying_key, &input, &verifiable_ciphertext.proof)?)
+    }
+
+    /// Decrypts the messages of the ciphertext with the account view key.
+    pub fn decrypt(
+        system_parameters: &SystemParameters<C>,
+        account_view_key: &AccountViewKey<C>,
+        verifiable_ciphertext: &VerifiableCiphertext<C, S>,
+    ) -> Result<Vec<C::InnerField>, DPCError> {
+        let ciphertext = &verifiable_ciphertext.ciphertext;
+        if ciphertext.len() % MESSAGE_ENCODING_NUM_CHUNKS != 1 {
+            return Err(DPCError::Message(format!(
+                "Invalid verifiable ciphertext length {}",
+                ciphertext.len()
+            )));
+        }
+
+        let encryption = Encryption::<C>::from(Self::encryption_parameters(system_parameters)?);
+        let private_key = ScalarField::<C>::read(&to_bytes![account_view_key.decryption_key]?[..])?;
+
+        let plaintext = encryption.decrypt(&private_key, ciphertext)?;
+
+        plaintext
+            .chunks(MESSAGE_ENCODING_NUM_CHUNKS)
+            .map(|encoding| {
+                let encoding: Vec<_> = encoding.iter().map(|element| element.into_affine()).collect();
+                MessageEncoding::<C::EncryptionModelParameters>::decode(&encoding)
+            })
+            .collect()
+    }
+
+    fn encryption_parameters(
+        system_parameters: &SystemParameters<C>,
+    ) -> Result<GroupEncryptionParameters<EncryptionGroup<C>>, DPCError> {
+        Ok(FromBytes::read(
+            &to_bytes![system_parameters.account_encryption.parameters()]?[..],
+        )?)
+    }
+
+    fn public_key(address: &AccountAddress<C>) -> Result<GroupEncryptionPublicKey<EncryptionGroup<C>>, DPCError> {
+        Ok(FromBytes::read(&to_bytes![address.encryption_key]?[..])?)
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    testnet2::{
+        verifiable_encryption::{
+            MessageEncoding,
+            MessagePredicate,
+            MESSAGE_ENCODING_COUNTER_BITS,
+            MESSAGE_ENCODING_NUM_CHUNKS,
+        },
+        Testnet2Components,
+    },
+    DPCError,
+};
+use snarkvm_algorithms::encryption::{GroupEncryption, GroupEncryptionParameters, GroupEncryptionPublicKey};
+use snarkvm_curves::{
+    templates::twisted_edwards_extended::{GroupAffine as TEAffine, GroupProjective as TEProjective},
+    traits::{AffineCurve, ModelParameters, TEModelParameters},
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_gadgets::{
+    algorithms::encryption::{
+        GroupEncryptionBlindingExponentsGadget,
+        GroupEncryptionCiphertextGadget,
+        GroupEncryptionGadget,
+        GroupEncryptionParametersGadget,
+        GroupEncryptionPlaintextGadget,
+        GroupEncryptionPublicKeyGadget,
+        GroupEncryptionRandomnessGadget,
+    },
+    bits::{Boolean, ToBitsLEGadget},
+    curves::templates::twisted_edwards::AffineGadget,
+    fields::FpGadget,
+    traits::{algorithms::EncryptionGadget, alloc::AllocGadget, eq::EqGadget, fields::FieldGadget},
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+
+use blake2::Blake2s;
+
+type BaseField<C> = <<C as Testnet2Components>::EncryptionModelParameters as ModelParameters>::BaseField;
+type ScalarField<C> = <<C as Testnet2Components>::EncryptionModelParameters as ModelParameters>::ScalarField;
+type EncryptionGroup<C> = TEProjective<<C as Testnet2Components>::EncryptionModelParameters>;
+type EncryptionGroupAffine<C> = TEAffine<<C as Testnet2Components>::EncryptionModelParameters>;
+type EncryptionGroupGadget<C> =
+    AffineGadget<<C as Testnet2Components>::EncryptionModelParameters, BaseField<C>, FpGadget<BaseField<C>>>;
+
+/// Proves that a ciphertext is the encryption of messages satisfying a predicate.
+pub struct VerifiableEncryptionCircuit<C: Testnet2Components, P> {
+    /// Encryption parameters
+    encryption_parameters: GroupEncryptionParameters<EncryptionGroup<C>>,
+    /// Predicate enforced on the messages
+    predicate: P,
+
+    /// Public key the messages are encrypted to
+    public_key: EncryptionGroup<C>,
+    /// Ciphertext of the encoded messages
+    ciphertext: Vec<EncryptionGroup<C>>,
+
+    /// Messages
+    messages: Vec<BaseField<C>>,
+    /// Group element encodings of the messages, along with their counters
+    message_encodings: Vec<(EncryptionGroupAffine<C>, u8)>,
+    /// Encryption randomness
+    randomness: ScalarField<C>,
+    /// Blinding exponents used to encrypt the messages
+    blinding_exponents: Vec<ScalarField<C>>,
+}
+
+impl<C: Testnet2Components, P: MessagePredicate<BaseField<C>>> VerifiableEncryptionCircuit<C, P>
+where
+    BaseField<C>: PrimeField,
+{
+    pub fn blank(
+        encryption_parameters: &GroupEncryptionParameters<EncryptionGroup<C>>,
+        predicate: &P,
+        num_messages: usize,
+    ) -> Result<Self, DPCError> {
+        let messages = vec![BaseField::<C>::zero(); num_messages];
+
+        let mut message_encodings = Vec::with_capacity(num_messages * MESSAGE_ENCODING_NUM_CHUNKS);
+        for message in &messages {
+            message_encodings.extend(MessageEncoding::<C::EncryptionModelParameters>::encode(message)?);
+        }
+
+        let ciphertext = vec![EncryptionGroup::<C>::default(); message_encodings.len() + 1];
+        let blinding_exponents = vec![ScalarField::<C>::one(); message_encodings.len()];
+
+        Ok(Self {
+            encryption_parameters: encryption_parameters.clone(),
+            predicate: predicate.clone(),
+            public_key: EncryptionGroup::<C>::default(),
+            ciphertext,
+            messages,
+            message_encodings,
+            randomness: ScalarField::<C>::default(),
+            blinding_exponents,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        encryption_parameters: &GroupEncryptionParameters<EncryptionGroup<C>>,
+        predicate: &P,
+        public_key: &EncryptionGroup<C>,
+        ciphertext: &[EncryptionGroup<C>],
+        messages: &[BaseField<C>],
+        message_encodings: &[(EncryptionGroupAffine<C>, u8)],
+        randomness: &ScalarField<C>,
+        blinding_exponents: &[ScalarField<C>],
+    ) -> Self {
+        Self {
+            encryption_parameters: encryption_parameters.clone(),
+            predicate: predicate.clone(),
+            public_key: *public_key,
+            ciphertext: ciphertext.to_vec(),
+            messages: messages.to_vec(),
+            message_encodings: message_encodings.to_vec(),
+            randomness: *randomness,
+            blinding_exponents: blinding_exponents.to_vec(),
+        }
+    }
+}
+
+impl<C: Testnet2Components, P: MessagePredicate<C::InnerField>> ConstraintSynthesizer<C::InnerField>
+    for VerifiableEncryptionCircuit<C, P>
+where
+    C::EncryptionModelParameters: TEModelParameters<BaseField = C::InnerField>,
+{
+    fn generate_constraints<CS: ConstraintSystem<C::InnerField>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let chunk_size = MessageEncoding::<C::EncryptionModelParameters>::chunk_size_in_bits();
+
+        // *******************************************************************
+        // Declare the public key and the ciphertext as public inputs
+
+        let public_key_gadget = GroupEncryptionPublicKeyGadget::<_, _, EncryptionGroupGadget<C>>::alloc_input(
+            cs.ns(|| "Allocate the public key"),
+            || Ok(GroupEncryptionPublicKey(self.public_key)),
+        )?;
+
+        let ciphertext_gadget = GroupEncryptionCiphertextGadget::<_, _, EncryptionGroupGadget<C>>::alloc_input(
+            cs.ns(|| "Allocate the ciphertext"),
+            || Ok(&self.ciphertext),
+        )?;
+
+        // *******************************************************************
+        // Check that the messages satisfy the predicate
+
+        let mut message_gadgets = Vec::with_capacity(self.messages.len());
+        for (i, message) in self.messages.iter().enumerate() {
+            let message_gadget = FpGadget::alloc(cs.ns(|| format!("Allocate message {}", i)), || Ok(message))?;
+            message_gadgets.push(message_gadget);
+        }
+
+        self.predicate.enforce(
+            cs.ns(|| "Check that the messages satisfy the predicate"),
+            &message_gadgets,
+        )?;
+
+        // *******************************************************************
+        // Check the group encoding of the messages
+
+        let mut plaintext = Vec::with_capacity(self.message_encodings.len());
+        let mut message_encodings = self.message_encodings.iter();
+
+        for (i, message_gadget) in message_gadgets.iter().enumerate() {
+            let cs = &mut cs.ns(|| format!("Check the group encoding of message {}", i));
+
+            let message_bits = message_gadget.to_bits_le_strict(cs.ns(|| "message to_bits_le_strict"))?;
+
+            for (j, chunk_bits) in message_bits.chunks(chunk_size).enumerate() {
+                let (element, counter) = message_encodings.next().ok_or(SynthesisError::AssignmentMissing)?;
+
+                let mut counter_bits = Vec::with_capacity(MESSAGE_ENCODING_COUNTER_BITS);
+                for k in 0..MESSAGE_ENCODING_COUNTER_BITS {
+                    counter_bits.push(Boolean::alloc(
+                        cs.ns(|| format!("chunk_{} counter_bit_{}", j, k)),
+                        || Ok((counter >> k) & 1 == 1),
+                    )?);
+                }
+
+                // Construct the expected y-coordinate as chunk * 2^COUNTER_BITS + counter.
+                let mut y_coordinate = FpGadget::zero(cs.ns(|| format!("chunk_{} zero", j)))?;
+                let mut coefficient = C::InnerField::one();
+                for (k, bit) in counter_bits.iter().chain(chunk_bits).enumerate() {
+                    y_coordinate = y_coordinate.conditionally_add_constant(
+                        cs.ns(|| format!("chunk_{} add bit_{}", j, k)),
+                        bit,
+                        coefficient,
+                    )?;
+                    coefficient.double_in_place();
+                }
+
+                let element_gadget =
+                    EncryptionGroupGadget::<C>::alloc(cs.ns(|| format!("Allocate chunk_{} group element", j)), || {
+                        Ok(element.into_projective())
+                    })?;
+
+                element_gadget.y.enforce_equal(
+                    cs.ns(|| format!("Check that chunk_{} is encoded in the y-coordinate", j)),
+                    &y_coordinate,
+                )?;
+
+                plaintext.push(element_gadget);
+            }
+        }
+
+        // *******************************************************************
+        // Check that the ciphertext is the encryption of the encoded messages
+
+        let parameters_gadget =
+            GroupEncryptionParametersGadget::alloc(cs.ns(|| "Declare encryption parameters"), || {
+                Ok(&self.encryption_parameters)
+            })?;
+
+        let randomness_gadget =
+            GroupEncryptionRandomnessGadget::alloc(cs.ns(|| "Allocate encryption randomness"), || Ok(self.randomness))?;
+
+        let blinding_exponents_gadget =
+            GroupEncryptionBlindingExponentsGadget::alloc(cs.ns(|| "Allocate encryption blinding exponents"), || {
+                Ok(&self.blinding_exponents)
+            })?;
+
+        let plaintext_gadget = GroupEncryptionPlaintextGadget::new(plaintext);
+
+        let candidate_ciphertext_gadget =
+            <GroupEncryptionGadget<_, _, EncryptionGroupGadget<C>> as EncryptionGadget<
+                GroupEncryption<EncryptionGroup<C>, EncryptionGroupAffine<C>, Blake2s>,
+                _,
+            >>::check_encryption_gadget(
+                cs.ns(|| "Compute the ciphertext"),
+                &parameters_gadget,
+                &randomness_gadget,
+                &public_key_gadget,
+                &plaintext_gadget,
+                &blinding_exponents_gadget,
+            )?;
+
+        candidate_ciphertext_gadget.enforce_equal(
+            cs.ns(|| "Check that the declared and computed ciphertexts are equal"),
+            &ciphertext_gadget,
+        )?;
+
+        Ok(())
+    }
+}
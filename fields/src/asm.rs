@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Montgomery multiplication for x86-64 CPUs with the BMI2 and ADX instruction set extensions,
+//! which provide the `mulx`, `adcx` and `adox` instructions.
+//!
+//! The routines are written in inline assembly, which interleaves two independent carry chains
+//! through the carry and overflow flags with `adcx` and `adox`. They are plain inlinable functions,
+//! used by `Fp384` when the `asm` feature is enabled and the CPU supports them, which is checked
+//! at runtime. Squaring uses the same routine as multiplication. `Fp256` keeps the generic limb
+//! code, which is faster than the assembly for four limbs.
+//! Inline assembly requires Rust 1.59 or later.
+//!
+//! Like the generic limb code, they require the most significant bit of the modulus to be
+//! unset, and return a result in `[0, 2 * modulus)` that the caller must reduce.
+
+use core::arch::asm;
+
+/// Returns `true` if the CPU supports the BMI2 and ADX instruction set extensions.
+#[inline]
+pub(crate) fn has_bmi2_adx() -> bool {
+    cfg!(all(target_feature = "bmi2", target_feature = "adx"))
+        || (std::is_x86_feature_detected!("bmi2") && std::is_x86_feature_detected!("adx"))
+}
+
+// The coarsely integrated operand scanning (CIOS) method, with one row per limb of `b`.
+// Each row computes `t := t + a * b_i` with the products split across both carry chains,
+// saves the high word `t_N`, and then computes `t := (t + k * modulus) / 2^64`, where `k`
+// is chosen so that the division is exact. The row and reduction are expanded limb by limb
+// into a single assembly block, so that the flags are never clobbered between instructions.
+
+/// Expands to the instructions for `t := t + a * rdx`, leaving the high word in `hi`.
+macro_rules! mul_add_limbs {
+    (($t:ident, $o:literal)) => {
+        concat!(
+            "mulx {hi}, {lo}, qword ptr [{x} + ", $o, "]\n",
+            "adox {", stringify!($t), "}, {lo}\n",
+            "mov {lo}, 0\n",
+            "adcx {hi}, {lo}\n",
+            "adox {hi}, {lo}\n",
+        )
+    };
+    (($t:ident, $o:literal), ($next:ident, $next_o:literal) $(, ($ts:ident, $os:literal))*) => {
+        concat!(
+            "mulx {hi}, {lo}, qword ptr [{x} + ", $o, "]\n",
+            "adox {", stringify!($t), "}, {lo}\n",
+            "adcx {", stringify!($next), "}, {hi}\n",
+            mul_add_limbs!(($next, $next_o) $(, ($ts, $os))*),
+        )
+    };
+}
+
+/// Expands to the instructions for the reduction after the low word, restoring the saved high word.
+macro_rules! reduce_limbs {
+    ($prev:ident, ($t:ident, $o:literal)) => {
+        concat!(
+            "mov {", stringify!($prev), "}, {hi}\n",
+            "adcx {", stringify!($prev), "}, {", stringify!($t), "}\n",
+            "mulx {hi}, {lo}, qword ptr [{q} + ", $o, "]\n",
+            "adox {", stringify!($prev), "}, {lo}\n",
+            "mov {lo}, 0\n",
+            "adcx {hi}, {lo}\n",
+            "pop {lo}\n",
+            "adox {hi}, {lo}\n",
+            "mov {", stringify!($t), "}, {hi}\n",
+        )
+    };
+    ($prev:ident, ($t:ident, $o:literal) $(, ($ts:ident, $os:literal))+) => {
+        concat!(
+            "mov {", stringify!($prev), "}, {hi}\n",
+            "adcx {", stringify!($prev), "}, {", stringify!($t), "}\n",
+            "mulx {hi}, {lo}, qword ptr [{q} + ", $o, "]\n",
+            "adox {", stringify!($prev), "}, {lo}\n",
+            reduce_limbs!($t $(, ($ts, $os))+),
+        )
+    };
+}
+
+/// Expands to the instructions for one row of the Montgomery multiplication.
+macro_rules! montgomery_row {
+    ($o:literal, [($t0:ident, $o0:literal) $(, ($ts:ident, $os:literal))*]) => {
+        concat!(
+            "mov rdx, qword ptr [{y} + ", $o, "]\n",
+            "xor {lo}, {lo}\n",
+            mul_add_limbs!(($t0, $o0) $(, ($ts, $os))*),
+            "push {hi}\n",
+            "mov rdx, {inv}\n",
+            "imul rdx, {", stringify!($t0), "}\n",
+            "xor {lo}, {lo}\n",
+            "mulx {hi}, {lo}, qword ptr [{q} + ", $o0, "]\n",
+            "adcx {lo}, {", stringify!($t0), "}\n",
+            reduce_limbs!($t0 $(, ($ts, $os))*),
+        )
+    };
+}
+
+/// Expands to the instructions for the Montgomery multiplication, with one row per limb offset.
+macro_rules! montgomery_mul {
+    ([$($o:literal),+], $limbs:tt) => {
+        concat!($(montgomery_row!($o, $limbs)),+)
+    };
+}
+
+/// Returns `a * b * R^-1` for 6-limb integers, where `inv = -modulus^-1 mod 2^64`.
+///
+/// # Safety
+///
+/// The caller must ensure that the CPU supports BMI2 and ADX, see [`has_bmi2_adx`].
+#[inline(always)]
+pub(crate) unsafe fn mul_6(a: &[u64; 6], b: &[u64; 6], modulus: &[u64; 6], inv: u64) -> [u64; 6] {
+    debug_assert!(modulus[5] >> 63 == 0);
+
+    let (mut t0, mut t1, mut t2, mut t3, mut t4, mut t5) = (0u64, 0u64, 0u64, 0u64, 0u64, 0u64);
+    asm!(
+        montgomery_mul!([0, 8, 16, 24, 32, 40], [(t0, 0), (t1, 8), (t2, 16), (t3, 24), (t4, 32), (t5, 40)]),
+        x = in(reg) a.as_ptr(),
+        y = in(reg) b.as_ptr(),
+        q = in(reg) modulus.as_ptr(),
+        inv = in(reg) inv,
+        t0 = inout(reg) t0,
+        t1 = inout(reg) t1,
+        t2 = inout(reg) t2,
+        t3 = inout(reg) t3,
+        t4 = inout(reg) t4,
+        t5 = inout(reg) t5,
+        lo = out(reg) _,
+        hi = out(reg) _,
+        out("rdx") _,
+        options(readonly),
+    );
+    [t0, t1, t2, t3, t4, t5]
+}
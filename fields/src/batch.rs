@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Field, LegendreSymbol, SquareRootField};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Given a vector of field elements `v`, replaces each nonzero element `v_i` with `1 / v_i`.
+/// Zero elements are left untouched.
+pub fn batch_inversion<F: Field>(v: &mut [F]) {
+    batch_inversion_and_mul(v, &F::one());
+}
+
+/// Given a vector of field elements `v` and a coefficient `coeff`, replaces each
+/// nonzero element `v_i` with `coeff / v_i`. Zero elements are left untouched.
+pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+    #[cfg(feature = "parallel")]
+    {
+        // Divide the vector evenly between all available threads, and batch invert
+        // each chunk in place, at the cost of one inversion per chunk.
+        let num_elements_per_thread = std::cmp::max(v.len() / rayon::current_num_threads(), 1);
+        v.par_chunks_mut(num_elements_per_thread)
+            .for_each(|chunk| serial_batch_inversion_and_mul(chunk, coeff));
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    serial_batch_inversion_and_mul(v, coeff);
+}
+
+/// Given a vector of field elements `v` and a coefficient `coeff`, replaces each
+/// nonzero element `v_i` with `coeff / v_i`, using a single field inversion.
+fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+    // Montgomery’s Trick and Fast Implementation of Masked AES
+    // Genelle, Prouff and Quisquater
+    // Section 3.2
+
+    // First pass: compute [a, ab, abc, ...]
+    let mut prod = Vec::with_capacity(v.len());
+    let mut tmp = F::one();
+    for f in v.iter().filter(|f| !f.is_zero()) {
+        tmp.mul_assign(f);
+        prod.push(tmp);
+    }
+
+    // Invert `tmp`.
+    tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
+
+    // Multiply product by coeff, so all inverses will be scaled by coeff.
+    tmp *= coeff;
+
+    // Second pass: iterate backwards to compute inverses
+    for (f, s) in v
+        .iter_mut()
+        // Backwards
+        .rev()
+        // Ignore normalized elements
+        .filter(|f| !f.is_zero())
+        // Backwards, skip last element, fill in one for last term.
+        .zip(prod.into_iter().rev().skip(1).chain(Some(F::one())))
+    {
+        // tmp := tmp * f; f := tmp * s = 1/f
+        let new_tmp = tmp * *f;
+        *f = tmp * s;
+        tmp = new_tmp;
+    }
+}
+
+/// Returns the Legendre symbol of each element of `v`.
+pub fn batch_legendre<F: SquareRootField>(v: &[F]) -> Vec<LegendreSymbol> {
+    #[cfg(feature = "parallel")]
+    let iter = v.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let iter = v.iter();
+
+    iter.map(|f| f.legendre()).collect()
+}
+
+/// Returns the square root of each element of `v`, if it exists.
+pub fn batch_sqrt<F: SquareRootField>(v: &[F]) -> Vec<Option<F>> {
+    #[cfg(feature = "parallel")]
+    let iter = v.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let iter = v.iter();
+
+    iter.map(|f| f.sqrt()).collect()
+}
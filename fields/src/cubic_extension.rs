@@ -0,0 +1,477 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Choice, ConditionallySelectable, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    div_ceil,
+    errors::SerializationError,
+    rand::UniformRand,
+    serialize::*,
+};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// The parameters of a cubic extension `BaseField[X]/(X^3 - NONRESIDUE)`.
+pub trait CubicExtensionParameters: 'static + Send + Sync + Sized {
+    /// The prime field at the bottom of the tower.
+    type BasePrimeField: PrimeField;
+    /// The field this is an extension of.
+    type BaseField: Field;
+
+    /// The degree of the extension over the base prime field.
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize;
+    /// The name of the extension, used to display its elements.
+    const NAME: &'static str;
+
+    /// Returns `fe * NONRESIDUE`.
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField;
+
+    /// Multiplies the `c1` and `c2` coefficients by the coefficients of the Frobenius automorphism for `power`.
+    fn mul_base_field_by_frob_coeff(c1: &mut Self::BaseField, c2: &mut Self::BaseField, power: usize);
+}
+
+/// An element of a cubic extension, represented by c0 + c1 * v + c2 * v^(2).
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(
+    Default(bound = "P: CubicExtensionParameters"),
+    Hash(bound = "P: CubicExtensionParameters"),
+    Clone(bound = "P: CubicExtensionParameters"),
+    Copy(bound = "P: CubicExtensionParameters"),
+    Debug(bound = "P: CubicExtensionParameters"),
+    PartialEq(bound = "P: CubicExtensionParameters"),
+    Eq(bound = "P: CubicExtensionParameters")
+)]
+pub struct CubicExtension<P: CubicExtensionParameters> {
+    pub c0: P::BaseField,
+    pub c1: P::BaseField,
+    pub c2: P::BaseField,
+    #[derivative(Debug = "ignore")]
+    #[doc(hidden)]
+    pub _parameters: PhantomData<P>,
+}
+
+impl<P: CubicExtensionParameters> CubicExtension<P> {
+    pub fn new(c0: P::BaseField, c1: P::BaseField, c2: P::BaseField) -> Self {
+        CubicExtension {
+            c0,
+            c1,
+            c2,
+            _parameters: PhantomData,
+        }
+    }
+
+    pub fn mul_assign_by_base_field(&mut self, element: &P::BaseField) {
+        self.c0.mul_assign(element);
+        self.c1.mul_assign(element);
+        self.c2.mul_assign(element);
+    }
+}
+
+impl<P: CubicExtensionParameters> Zero for CubicExtension<P> {
+    fn zero() -> Self {
+        Self::new(P::BaseField::zero(), P::BaseField::zero(), P::BaseField::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<P: CubicExtensionParameters> One for CubicExtension<P> {
+    fn one() -> Self {
+        Self::new(P::BaseField::one(), P::BaseField::zero(), P::BaseField::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<P: CubicExtensionParameters> Field for CubicExtension<P> {
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::BaseField::characteristic()
+    }
+
+    fn double(&self) -> Self {
+        let mut result = *self;
+        result.double_in_place();
+        result
+    }
+
+    fn double_in_place(&mut self) {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+        self.c2.double_in_place();
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, u8)> {
+        if bytes.len() < P::DEGREE_OVER_BASE_PRIME_FIELD * div_ceil(P::BasePrimeField::size_in_bits(), 8) {
+            return None;
+        }
+        let split_at = bytes.len() / 3;
+        if let Some(c0) = P::BaseField::from_random_bytes(&bytes[..split_at]) {
+            if let Some(c1) = P::BaseField::from_random_bytes(&bytes[split_at..2 * split_at]) {
+                if let Some((c2, flags)) = P::BaseField::from_random_bytes_with_flags::<F>(&bytes[2 * split_at..]) {
+                    return Some((Self::new(c0, c1, c2), flags));
+                }
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    fn square(&self) -> Self {
+        let mut result = *self;
+        result.square_in_place();
+        result
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
+        // Pairing-Friendly
+        // Fields.pdf; Section 4 (CH-SQR2)
+        let s0 = self.c0.square();
+        let s1 = (self.c0 * self.c1).double();
+        let s2 = (self.c0 - self.c1 + self.c2).square();
+        let s3 = (self.c1 * self.c2).double();
+        let s4 = self.c2.square();
+
+        self.c0 = s0 + P::mul_base_field_by_nonresidue(&s3);
+        self.c1 = s1 + P::mul_base_field_by_nonresidue(&s4);
+        self.c2 = s1 + s2 + s3 - s0 - s4;
+
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // From "High-Speed Software Implementation of the Optimal Ate Pairing over
+            // Barreto-Naehrig
+            // Curves"; Algorithm 17
+            let t0 = self.c0.square();
+            let t1 = self.c1.square();
+            let t2 = self.c2.square();
+            let t3 = self.c0 * self.c1;
+            let t4 = self.c0 * self.c2;
+            let t5 = self.c1 * self.c2;
+
+            let s0 = t0 - P::mul_base_field_by_nonresidue(&t5);
+            let s1 = P::mul_base_field_by_nonresidue(&t2) - t3;
+            let s2 = t1 - t4;
+
+            let a1 = self.c2 * s1;
+            let a2 = self.c1 * s2;
+            let a3 = P::mul_base_field_by_nonresidue(&(a1 + a2));
+            let t6 = self.c0 * s0 + a3;
+
+            t6.inverse().map(|t6| Self::new(t6 * s0, t6 * s1, t6 * s2))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+        self.c2.frobenius_map(power);
+        P::mul_base_field_by_frob_coeff(&mut self.c1, &mut self.c2, power);
+    }
+}
+
+/// `CubicExtension` elements are ordered lexicographically.
+impl<P: CubicExtensionParameters> Ord for CubicExtension<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let c2_cmp = self.c2.cmp(&other.c2);
+        let c1_cmp = self.c1.cmp(&other.c1);
+        let c0_cmp = self.c0.cmp(&other.c0);
+        if c2_cmp == Ordering::Equal {
+            if c1_cmp == Ordering::Equal { c0_cmp } else { c1_cmp }
+        } else {
+            c2_cmp
+        }
+    }
+}
+
+impl<P: CubicExtensionParameters> PartialOrd for CubicExtension<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: CubicExtensionParameters> From<u128> for CubicExtension<P> {
+    fn from(other: u128) -> Self {
+        Self::new(other.into(), P::BaseField::zero(), P::BaseField::zero())
+    }
+}
+
+impl<P: CubicExtensionParameters> From<u64> for CubicExtension<P> {
+    fn from(other: u64) -> Self {
+        Self::new(other.into(), P::BaseField::zero(), P::BaseField::zero())
+    }
+}
+
+impl<P: CubicExtensionParameters> From<u32> for CubicExtension<P> {
+    fn from(other: u32) -> Self {
+        Self::new(other.into(), P::BaseField::zero(), P::BaseField::zero())
+    }
+}
+
+impl<P: CubicExtensionParameters> From<u16> for CubicExtension<P> {
+    fn from(other: u16) -> Self {
+        Self::new(other.into(), P::BaseField::zero(), P::BaseField::zero())
+    }
+}
+
+impl<P: CubicExtensionParameters> From<u8> for CubicExtension<P> {
+    fn from(other: u8) -> Self {
+        Self::new(other.into(), P::BaseField::zero(), P::BaseField::zero())
+    }
+}
+
+impl<P: CubicExtensionParameters> ToBytes for CubicExtension<P> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.c0.write(&mut writer)?;
+        self.c1.write(&mut writer)?;
+        self.c2.write(writer)
+    }
+}
+
+impl<P: CubicExtensionParameters> FromBytes for CubicExtension<P> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let c0 = P::BaseField::read(&mut reader)?;
+        let c1 = P::BaseField::read(&mut reader)?;
+        let c2 = P::BaseField::read(reader)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+}
+
+impl<P: CubicExtensionParameters> ConditionallySelectable for CubicExtension<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.c0, &b.c0, choice),
+            ConditionallySelectable::conditional_select(&a.c1, &b.c1, choice),
+            ConditionallySelectable::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+impl<P: CubicExtensionParameters> zeroize::DefaultIsZeroes for CubicExtension<P> {}
+
+impl<P: CubicExtensionParameters> Neg for CubicExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.c0 = self.c0.neg();
+        self.c1 = self.c1.neg();
+        self.c2 = self.c2.neg();
+        self
+    }
+}
+
+impl<P: CubicExtensionParameters> Distribution<CubicExtension<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CubicExtension<P> {
+        CubicExtension::new(UniformRand::rand(rng), UniformRand::rand(rng), UniformRand::rand(rng))
+    }
+}
+
+impl_additive_ops_from_ref!(CubicExtension, CubicExtensionParameters);
+impl_multiplicative_ops_from_ref!(CubicExtension, CubicExtensionParameters);
+
+impl<'a, P: CubicExtensionParameters> Add<&'a Self> for CubicExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: CubicExtensionParameters> Sub<&'a Self> for CubicExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(other);
+        result
+    }
+}
+
+impl<'a, P: CubicExtensionParameters> Mul<&'a Self> for CubicExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(other);
+        result
+    }
+}
+
+impl<'a, P: CubicExtensionParameters> Div<&'a Self> for CubicExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: CubicExtensionParameters> AddAssign<&'a Self> for CubicExtension<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(other.c0);
+        self.c1.add_assign(other.c1);
+        self.c2.add_assign(other.c2);
+    }
+}
+
+impl<'a, P: CubicExtensionParameters> SubAssign<&'a Self> for CubicExtension<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+        self.c2.sub_assign(&other.c2);
+    }
+}
+
+impl<'a, P: CubicExtensionParameters> MulAssign<&'a Self> for CubicExtension<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        // Devegili OhEig Scott Dahab --- Multiplication and Squaring on
+        // Pairing-Friendly
+        // Fields.pdf; Section 4 (Karatsuba)
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let v2 = self.c2 * other.c2;
+
+        let c0 = P::mul_base_field_by_nonresidue(&((self.c1 + self.c2) * (other.c1 + other.c2) - v1 - v2)) + v0;
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1 + P::mul_base_field_by_nonresidue(&v2);
+        let c2 = (self.c0 + self.c2) * (other.c0 + other.c2) - v0 - v2 + v1;
+
+        self.c0 = c0;
+        self.c1 = c1;
+        self.c2 = c2;
+    }
+}
+
+impl<'a, P: CubicExtensionParameters> DivAssign<&'a Self> for CubicExtension<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl<P: CubicExtensionParameters> std::fmt::Display for CubicExtension<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({} + {} * v + {} * v^2)", P::NAME, self.c0, self.c1, self.c2)
+    }
+}
+
+impl<P: CubicExtensionParameters> CanonicalSerializeWithFlags for CubicExtension<P> {
+    #[inline]
+    fn serialize_with_flags<W: Write, F: Flags>(&self, writer: &mut W, flags: F) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.c0, writer)?;
+        CanonicalSerialize::serialize(&self.c1, writer)?;
+        self.c2.serialize_with_flags(writer, flags)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        self.c0.serialized_size() + self.c1.serialized_size() + self.c2.serialized_size_with_flags::<F>()
+    }
+}
+
+impl<P: CubicExtensionParameters> CanonicalSerialize for CubicExtension<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+}
+
+impl<P: CubicExtensionParameters> ConstantSerializedSize for CubicExtension<P> {
+    const SERIALIZED_SIZE: usize = 3 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+}
+
+impl<P: CubicExtensionParameters> ConstantSerializedSizeWithFlags for CubicExtension<P> {
+    // The flags are serialized with the last coefficient.
+    const SERIALIZED_SIZE_IN_BITS: usize = 2 * 8 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE
+        + <P::BaseField as ConstantSerializedSizeWithFlags>::SERIALIZED_SIZE_IN_BITS;
+}
+
+impl<P: CubicExtensionParameters> CanonicalDeserializeWithFlags for CubicExtension<P> {
+    #[inline]
+    fn deserialize_with_flags<R: Read, F: Flags>(reader: &mut R) -> Result<(Self, F), SerializationError> {
+        let c0: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        let c1: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        let (c2, flags): (P::BaseField, _) = CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+        Ok((Self::new(c0, c1, c2), flags))
+    }
+}
+
+impl<P: CubicExtensionParameters> CanonicalDeserialize for CubicExtension<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let c0: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        let c1: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        let c2: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The tower Fp24 = Fp12[Z]/(Z^2 - w), where Fp12 = Fp4[W]/(W^3 - NONRESIDUE),
+//! as used by the BLS24 family of curves.
+
+use crate::{
+    CubicExtension,
+    CubicExtensionParameters,
+    Fp2,
+    Fp2Parameters,
+    Fp4,
+    Fp4Parameters,
+    QuadraticExtension,
+    QuadraticExtensionParameters,
+};
+
+use std::marker::PhantomData;
+
+pub trait Fp24Parameters: 'static + Send + Sync {
+    type Fp4Params: Fp4Parameters;
+
+    /// The cubic nonresidue in Fp4 used to construct Fp12.
+    const NONRESIDUE: Fp4<Self::Fp4Params>;
+
+    /// Coefficients for the Frobenius automorphism of Fp12.
+    const FROBENIUS_COEFF_FP12_C1: [Fp2<Fp2Params<Self>>; 12];
+    const FROBENIUS_COEFF_FP12_C2: [Fp2<Fp2Params<Self>>; 12];
+
+    /// Coefficients for the Frobenius automorphism of Fp24.
+    const FROBENIUS_COEFF_FP24_C1: [Fp2<Fp2Params<Self>>; 24];
+
+    #[inline(always)]
+    fn mul_fp4_by_nonresidue(fe: &Fp4<Self::Fp4Params>) -> Fp4<Self::Fp4Params> {
+        Self::NONRESIDUE * fe
+    }
+}
+
+type Fp2Params<P> = <<P as Fp24Parameters>::Fp4Params as Fp4Parameters>::Fp2Params;
+
+/// Adapts `Fp24Parameters` to the `CubicExtensionParameters` of Fp12.
+pub struct Fp12ParamsWrapper<P: Fp24Parameters>(PhantomData<P>);
+
+impl<P: Fp24Parameters> CubicExtensionParameters for Fp12ParamsWrapper<P> {
+    type BaseField = Fp4<P::Fp4Params>;
+    type BasePrimeField = <Fp2Params<P> as Fp2Parameters>::Fp;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 12;
+    const NAME: &'static str = "Fp12_3over2over2";
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField {
+        P::mul_fp4_by_nonresidue(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(c1: &mut Self::BaseField, c2: &mut Self::BaseField, power: usize) {
+        c1.mul_assign_by_base_field(&P::FROBENIUS_COEFF_FP12_C1[power % 12]);
+        c2.mul_assign_by_base_field(&P::FROBENIUS_COEFF_FP12_C2[power % 12]);
+    }
+}
+
+/// An element of Fp12, represented by c0 + c1 * w + c2 * w^(2), where w^3 = NONRESIDUE.
+pub type Fp12<P> = CubicExtension<Fp12ParamsWrapper<P>>;
+
+impl<P: Fp24Parameters> Fp12<P> {
+    pub fn mul_by_fp2(&mut self, element: &Fp2<Fp2Params<P>>) {
+        self.c0.mul_assign_by_base_field(element);
+        self.c1.mul_assign_by_base_field(element);
+        self.c2.mul_assign_by_base_field(element);
+    }
+}
+
+/// Adapts `Fp24Parameters` to the `QuadraticExtensionParameters` of Fp24.
+pub struct Fp24ParamsWrapper<P: Fp24Parameters>(PhantomData<P>);
+
+impl<P: Fp24Parameters> QuadraticExtensionParameters for Fp24ParamsWrapper<P> {
+    type BaseField = Fp12<P>;
+    type BasePrimeField = <Fp2Params<P> as Fp2Parameters>::Fp;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 24;
+    const NAME: &'static str = "Fp24";
+
+    /// Multiply by quadratic nonresidue w.
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField {
+        Fp12::new(P::mul_fp4_by_nonresidue(&fe.c2), fe.c0, fe.c1)
+    }
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize) {
+        fe.mul_by_fp2(&P::FROBENIUS_COEFF_FP24_C1[power % 24]);
+    }
+}
+
+/// An element of Fp24, represented by c0 + c1 * z, where z^2 = w.
+pub type Fp24<P> = QuadraticExtension<Fp24ParamsWrapper<P>>;
+
+impl<P: Fp24Parameters> Fp24<P> {
+    pub fn mul_by_fp(&mut self, element: &<Fp2Params<P> as Fp2Parameters>::Fp) {
+        self.c0.c0.mul_by_fp(element);
+        self.c0.c1.mul_by_fp(element);
+        self.c0.c2.mul_by_fp(element);
+        self.c1.c0.mul_by_fp(element);
+        self.c1.c1.mul_by_fp(element);
+        self.c1.c2.mul_by_fp(element);
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Fp2, Fp2Parameters, QuadraticExtension, QuadraticExtensionParameters};
+
+use std::{marker::PhantomData, ops::MulAssign};
+
+pub trait Fp4Parameters: 'static + Send + Sync {
+    type Fp2Params: Fp2Parameters;
+
+    /// The quadratic nonresidue in Fp2 used to construct Fp4.
+    const NONRESIDUE: Fp2<Self::Fp2Params>;
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP4_C1: [Fp2<Self::Fp2Params>; 4];
+
+    #[inline(always)]
+    fn mul_fp2_by_nonresidue(fe: &Fp2<Self::Fp2Params>) -> Fp2<Self::Fp2Params> {
+        Self::NONRESIDUE * fe
+    }
+}
+
+/// Adapts `Fp4Parameters` to `QuadraticExtensionParameters`.
+pub struct Fp4ParamsWrapper<P: Fp4Parameters>(PhantomData<P>);
+
+impl<P: Fp4Parameters> QuadraticExtensionParameters for Fp4ParamsWrapper<P> {
+    type BaseField = Fp2<P::Fp2Params>;
+    type BasePrimeField = <P::Fp2Params as Fp2Parameters>::Fp;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 4;
+    const NAME: &'static str = "Fp4";
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField {
+        P::mul_fp2_by_nonresidue(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize) {
+        fe.mul_assign(&P::FROBENIUS_COEFF_FP4_C1[power % 4]);
+    }
+}
+
+/// An element of Fp4, represented by c0 + c1 * v, where v^2 = NONRESIDUE.
+pub type Fp4<P> = QuadraticExtension<Fp4ParamsWrapper<P>>;
+
+impl<P: Fp4Parameters> Fp4<P> {
+    pub fn mul_by_fp(&mut self, element: &<P::Fp2Params as Fp2Parameters>::Fp) {
+        self.c0.mul_by_fp(element);
+        self.c1.mul_by_fp(element);
+    }
+}
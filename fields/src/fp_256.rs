@@ -394,6 +394,8 @@ impl_prime_field_from_int!(Fp256, u8, Fp256Parameters);
 
 impl_prime_field_standard_sample!(Fp256, Fp256Parameters);
 
+impl_prime_field_conditionally_selectable!(Fp256, Fp256Parameters);
+
 impl_additive_ops_from_ref!(Fp256, Fp256Parameters);
 impl_multiplicative_ops_from_ref!(Fp256, Fp256Parameters);
 
@@ -442,6 +442,8 @@ impl_prime_field_from_int!(Fp320, u8, Fp320Parameters);
 
 impl_prime_field_standard_sample!(Fp320, Fp320Parameters);
 
+impl_prime_field_conditionally_selectable!(Fp320, Fp320Parameters);
+
 impl_additive_ops_from_ref!(Fp320, Fp320Parameters);
 impl_multiplicative_ops_from_ref!(Fp320, Fp320Parameters);
 
@@ -227,6 +227,14 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
 
     #[inline]
     fn square_in_place(&mut self) -> &mut Self {
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        if crate::asm::has_bmi2_adx() {
+            // Safety: the CPU has been checked to support BMI2 and ADX.
+            (self.0).0 = unsafe { crate::asm::mul_6(&(self.0).0, &(self.0).0, &P::MODULUS.0, P::INV) };
+            self.reduce();
+            return self;
+        }
+
         let mut carry = 0;
         let r1 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[1], &mut carry);
         let r2 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[2], &mut carry);
@@ -469,6 +477,8 @@ impl_prime_field_from_int!(Fp384, u8, Fp384Parameters);
 
 impl_prime_field_standard_sample!(Fp384, Fp384Parameters);
 
+impl_prime_field_conditionally_selectable!(Fp384, Fp384Parameters);
+
 impl_additive_ops_from_ref!(Fp384, Fp384Parameters);
 impl_multiplicative_ops_from_ref!(Fp384, Fp384Parameters);
 
@@ -639,6 +649,14 @@ impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
 impl<'a, P: Fp384Parameters> MulAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
+        #[cfg(all(feature = "asm", target_arch = "x86_64"))]
+        if crate::asm::has_bmi2_adx() {
+            // Safety: the CPU has been checked to support BMI2 and ADX.
+            (self.0).0 = unsafe { crate::asm::mul_6(&(self.0).0, &(other.0).0, &P::MODULUS.0, P::INV) };
+            self.reduce();
+            return;
+        }
+
         let mut carry = 0;
         let r0 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[0], &mut carry);
         let r1 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[1], &mut carry);
@@ -804,6 +804,8 @@ impl_prime_field_from_int!(Fp768, u8, Fp768Parameters);
 
 impl_prime_field_standard_sample!(Fp768, Fp768Parameters);
 
+impl_prime_field_conditionally_selectable!(Fp768, Fp768Parameters);
+
 impl_additive_ops_from_ref!(Fp768, Fp768Parameters);
 impl_multiplicative_ops_from_ref!(Fp768, Fp768Parameters);
 
@@ -773,6 +773,8 @@ impl_prime_field_from_int!(Fp832, u8, Fp832Parameters);
 
 impl_prime_field_standard_sample!(Fp832, Fp832Parameters);
 
+impl_prime_field_conditionally_selectable!(Fp832, Fp832Parameters);
+
 impl_additive_ops_from_ref!(Fp832, Fp832Parameters);
 impl_multiplicative_ops_from_ref!(Fp832, Fp832Parameters);
 
@@ -23,6 +23,12 @@ extern crate thiserror;
 #[macro_use]
 mod macros;
 
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+mod asm;
+
+mod batch;
+pub use batch::*;
+
 mod errors;
 pub use errors::*;
 
@@ -51,9 +57,20 @@ pub mod fp6_2over3;
 
 pub mod fp6_3over2;
 
+mod fp4;
+pub use fp4::*;
+
 mod fp12_2over3over2;
 pub use fp12_2over3over2::*;
 
+pub mod fp24_2over3over2over2;
+
+mod quadratic_extension;
+pub use quadratic_extension::*;
+
+mod cubic_extension;
+pub use cubic_extension::*;
+
 mod legendre;
 pub use legendre::*;
 
@@ -88,36 +105,3 @@ impl_prime_field_serializer!(Fp320, Fp320Parameters, 40);
 impl_prime_field_serializer!(Fp384, Fp384Parameters, 48);
 impl_prime_field_serializer!(Fp768, Fp768Parameters, 96);
 impl_prime_field_serializer!(Fp832, Fp832Parameters, 104);
-
-pub fn batch_inversion<F: Field>(v: &mut [F]) {
-    // Montgomery’s Trick and Fast Implementation of Masked AES
-    // Genelle, Prouff and Quisquater
-    // Section 3.2
-
-    // First pass: compute [a, ab, abc, ...]
-    let mut prod = Vec::with_capacity(v.len());
-    let mut tmp = F::one();
-    for f in v.iter().filter(|f| !f.is_zero()) {
-        tmp.mul_assign(f);
-        prod.push(tmp);
-    }
-
-    // Invert `tmp`.
-    tmp = tmp.inverse().unwrap(); // Guaranteed to be nonzero.
-
-    // Second pass: iterate backwards to compute inverses
-    for (f, s) in v
-        .iter_mut()
-        // Backwards
-        .rev()
-        // Ignore normalized elements
-        .filter(|f| !f.is_zero())
-        // Backwards, skip last element, fill in one for last term.
-        .zip(prod.into_iter().rev().skip(1).chain(Some(F::one())))
-    {
-        // tmp := tmp * f; f := tmp * s = 1/f
-        let new_tmp = tmp * *f;
-        *f = tmp * s;
-        tmp = new_tmp;
-    }
-}
@@ -71,6 +71,26 @@ macro_rules! impl_prime_field_standard_sample {
     };
 }
 
+macro_rules! impl_prime_field_conditionally_selectable {
+    ($field: ident, $params: ident) => {
+        impl<P: $params> crate::ConditionallySelectable for $field<P> {
+            /// Selects `a` if `choice` is unset and `b` otherwise, limb by limb,
+            /// without branching on `choice`.
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: crate::Choice) -> Self {
+                let mut result = *a;
+                let limbs = a.0.as_ref().iter().zip(b.0.as_ref());
+                for (limb, (x, y)) in result.0.as_mut().iter_mut().zip(limbs) {
+                    *limb = <u64 as crate::ConditionallySelectable>::conditional_select(x, y, choice);
+                }
+                result
+            }
+        }
+
+        impl<P: $params> zeroize::DefaultIsZeroes for $field<P> {}
+    };
+}
+
 macro_rules! impl_prime_field_from_int {
     ($field: ident, u128, $params: ident) => {
         impl<P: $params> From<u128> for $field<P> {
@@ -165,20 +185,28 @@ macro_rules! impl_prime_field_serializer {
             ) -> Result<(), snarkvm_utilities::errors::SerializationError> {
                 const BYTE_SIZE: usize = $byte_size;
 
-                let (output_bit_size, output_byte_size) =
-                    snarkvm_utilities::serialize::buffer_bit_byte_size($field::<P>::size_in_bits());
-                if F::len() > (output_bit_size - P::MODULUS_BITS as usize) {
+                // Reserve room for the flags, which spills into an extra byte
+                // when the modulus leaves fewer than `F::len()` spare bits.
+                let output_byte_size =
+                    snarkvm_utilities::serialize::buffer_byte_size($field::<P>::size_in_bits() + F::len());
+
+                if output_byte_size > BYTE_SIZE + 1 {
                     return Err(snarkvm_utilities::errors::SerializationError::NotEnoughSpace);
                 }
 
-                let mut bytes = [0u8; BYTE_SIZE];
-                self.write(&mut bytes[..])?;
+                let mut bytes = [0u8; BYTE_SIZE + 1];
+                self.write(&mut bytes[..BYTE_SIZE])?;
 
                 bytes[output_byte_size - 1] |= flags.u8_bitmask();
 
                 writer.write_all(&bytes[..output_byte_size])?;
                 Ok(())
             }
+
+            #[inline]
+            fn serialized_size_with_flags<F: snarkvm_utilities::serialize::Flags>(&self) -> usize {
+                snarkvm_utilities::serialize::buffer_byte_size($field::<P>::size_in_bits() + F::len())
+            }
         }
 
         impl<P: $params> ConstantSerializedSize for $field<P> {
@@ -188,6 +216,10 @@ macro_rules! impl_prime_field_serializer {
             const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
         }
 
+        impl<P: $params> snarkvm_utilities::serialize::ConstantSerializedSizeWithFlags for $field<P> {
+            const SERIALIZED_SIZE_IN_BITS: usize = <$field<P> as crate::PrimeField>::Parameters::MODULUS_BITS as usize;
+        }
+
         impl<P: $params> CanonicalSerialize for $field<P> {
             #[allow(unused_qualifications)]
             #[inline]
@@ -211,18 +243,19 @@ macro_rules! impl_prime_field_serializer {
             ) -> Result<(Self, F), snarkvm_utilities::errors::SerializationError> {
                 const BYTE_SIZE: usize = $byte_size;
 
-                let (output_bit_size, output_byte_size) =
-                    snarkvm_utilities::serialize::buffer_bit_byte_size($field::<P>::size_in_bits());
-                if F::len() > (output_bit_size - P::MODULUS_BITS as usize) {
+                let output_byte_size =
+                    snarkvm_utilities::serialize::buffer_byte_size($field::<P>::size_in_bits() + F::len());
+
+                if output_byte_size > BYTE_SIZE + 1 {
                     return Err(snarkvm_utilities::errors::SerializationError::NotEnoughSpace);
                 }
 
-                let mut masked_bytes = [0; BYTE_SIZE];
+                let mut masked_bytes = [0; BYTE_SIZE + 1];
                 reader.read_exact(&mut masked_bytes[..output_byte_size])?;
 
                 let flags = F::from_u8_remove_flags(&mut masked_bytes[output_byte_size - 1]);
 
-                Ok((Self::read(&masked_bytes[..])?, flags))
+                Ok((Self::read(&masked_bytes[..BYTE_SIZE])?, flags))
             }
         }
 
@@ -303,8 +336,11 @@ macro_rules! impl_prime_field_serializer {
 macro_rules! impl_field_from_random_bytes_with_flags {
     ($limbs: expr) => {
         #[inline]
-        fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)> {
-            let mut result_bytes = [0u8; $limbs * 8];
+        fn from_random_bytes_with_flags<F: snarkvm_utilities::serialize::Flags>(bytes: &[u8]) -> Option<(Self, u8)> {
+            if F::len() > 8 {
+                return None;
+            }
+            let mut result_bytes = [0u8; $limbs * 8 + 1];
             for (result_byte, in_byte) in result_bytes.iter_mut().zip(bytes.iter()) {
                 *result_byte = *in_byte;
             }
@@ -314,7 +350,7 @@ macro_rules! impl_field_from_random_bytes_with_flags {
             let flags_byte_position: usize = 7 - P::REPR_SHAVE_BITS as usize / 8;
             let flags_mask: u8 = ((1 << P::REPR_SHAVE_BITS % 8) - 1) << (8 - P::REPR_SHAVE_BITS % 8);
             // take the last 8 bytes and pass the mask
-            let last_bytes = &mut result_bytes[($limbs - 1) * 8..];
+            let last_bytes = &mut result_bytes[($limbs - 1) * 8..$limbs * 8];
             let mut flags: u8 = 0;
             for (i, (b, m)) in last_bytes.iter_mut().zip(&mask.to_le_bytes()).enumerate() {
                 if i == flags_byte_position {
@@ -322,8 +358,12 @@ macro_rules! impl_field_from_random_bytes_with_flags {
                 }
                 *b &= m;
             }
+            // if the shaven bits cannot hold the flags, they are serialized in the top bits of the next byte
+            if (P::REPR_SHAVE_BITS as usize) < F::len() {
+                flags = result_bytes[$limbs * 8] & (((1u16 << F::len()) - 1) << (8 - F::len())) as u8;
+            }
 
-            <Self as CanonicalDeserialize>::deserialize(&mut &result_bytes[..])
+            <Self as CanonicalDeserialize>::deserialize(&mut &result_bytes[..$limbs * 8])
                 .ok()
                 .map(|f| (f, flags))
         }
@@ -0,0 +1,509 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Choice, ConditionallySelectable, Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    div_ceil,
+    errors::SerializationError,
+    rand::UniformRand,
+    serialize::*,
+};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// The parameters of a quadratic extension `BaseField[X]/(X^2 - NONRESIDUE)`.
+pub trait QuadraticExtensionParameters: 'static + Send + Sync + Sized {
+    /// The prime field at the bottom of the tower.
+    type BasePrimeField: PrimeField;
+    /// The field this is an extension of.
+    type BaseField: Field;
+
+    /// The degree of the extension over the base prime field.
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize;
+    /// The name of the extension, used to display its elements.
+    const NAME: &'static str;
+
+    /// Returns `fe * NONRESIDUE`.
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField;
+
+    /// Multiplies the `c1` coefficient `fe` by the coefficient of the Frobenius automorphism for `power`.
+    fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize);
+}
+
+/// An element of a quadratic extension, represented by c0 + c1 * u.
+#[derive(Derivative, Serialize, Deserialize)]
+#[derivative(
+    Default(bound = "P: QuadraticExtensionParameters"),
+    Hash(bound = "P: QuadraticExtensionParameters"),
+    Clone(bound = "P: QuadraticExtensionParameters"),
+    Copy(bound = "P: QuadraticExtensionParameters"),
+    Debug(bound = "P: QuadraticExtensionParameters"),
+    PartialEq(bound = "P: QuadraticExtensionParameters"),
+    Eq(bound = "P: QuadraticExtensionParameters")
+)]
+pub struct QuadraticExtension<P: QuadraticExtensionParameters> {
+    pub c0: P::BaseField,
+    pub c1: P::BaseField,
+    #[derivative(Debug = "ignore")]
+    #[doc(hidden)]
+    pub _parameters: PhantomData<P>,
+}
+
+impl<P: QuadraticExtensionParameters> QuadraticExtension<P> {
+    pub fn new(c0: P::BaseField, c1: P::BaseField) -> Self {
+        QuadraticExtension {
+            c0,
+            c1,
+            _parameters: PhantomData,
+        }
+    }
+
+    /// Norm of the extension over the base field: Norm(a) = a.c0^2 - NONRESIDUE * a.c1^2
+    pub fn norm(&self) -> P::BaseField {
+        let t0 = self.c0.square();
+        let t1 = P::mul_base_field_by_nonresidue(&self.c1.square());
+        t0 - t1
+    }
+
+    pub fn conjugate(&mut self) {
+        self.c1 = -self.c1;
+    }
+
+    pub fn mul_assign_by_base_field(&mut self, element: &P::BaseField) {
+        self.c0.mul_assign(element);
+        self.c1.mul_assign(element);
+    }
+}
+
+impl<P: QuadraticExtensionParameters> Zero for QuadraticExtension<P> {
+    fn zero() -> Self {
+        Self::new(P::BaseField::zero(), P::BaseField::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+}
+
+impl<P: QuadraticExtensionParameters> One for QuadraticExtension<P> {
+    fn one() -> Self {
+        Self::new(P::BaseField::one(), P::BaseField::zero())
+    }
+
+    fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero()
+    }
+}
+
+impl<P: QuadraticExtensionParameters> Field for QuadraticExtension<P> {
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::BaseField::characteristic()
+    }
+
+    fn double(&self) -> Self {
+        let mut result = *self;
+        result.double_in_place();
+        result
+    }
+
+    fn double_in_place(&mut self) {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+    }
+
+    fn square(&self) -> Self {
+        let mut result = *self;
+        result.square_in_place();
+        result
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, u8)> {
+        if bytes.len() < P::DEGREE_OVER_BASE_PRIME_FIELD * div_ceil(P::BasePrimeField::size_in_bits(), 8) {
+            return None;
+        }
+        let split_at = bytes.len() / 2;
+        if let Some(c0) = P::BaseField::from_random_bytes(&bytes[..split_at]) {
+            if let Some((c1, flags)) = P::BaseField::from_random_bytes_with_flags::<F>(&bytes[split_at..]) {
+                return Some((Self::new(c0, c1), flags));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        // v0 = c0 - c1
+        let mut v0 = self.c0 - self.c1;
+        // v3 = c0 - beta * c1
+        let v3 = self.c0 - P::mul_base_field_by_nonresidue(&self.c1);
+        // v2 = c0 * c1
+        let v2 = self.c0 * self.c1;
+
+        // v0 = (v0 * v3) + v2
+        v0 *= &v3;
+        v0 += &v2;
+
+        self.c1 = v2.double();
+        self.c0 = v0 + P::mul_base_field_by_nonresidue(&v2);
+
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guide to Pairing-based Cryptography, Algorithm 5.19.
+            self.norm().inverse().map(|t| {
+                let c0 = self.c0 * t;
+                let c1 = -(self.c1 * t);
+                Self::new(c0, c1)
+            })
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+        P::mul_base_field_by_frob_coeff(&mut self.c1, power);
+    }
+}
+
+impl<P: QuadraticExtensionParameters> SquareRootField for QuadraticExtension<P>
+where
+    P::BaseField: SquareRootField,
+{
+    fn legendre(&self) -> LegendreSymbol {
+        self.norm().legendre()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        use crate::LegendreSymbol::*;
+        if self.c1.is_zero() {
+            // The square root of c0 lies in the base field if c0 is a square there,
+            // and is otherwise a multiple of u, as c0 / NONRESIDUE is then a square.
+            return match self.c0.sqrt() {
+                Some(c0) => Some(Self::new(c0, P::BaseField::zero())),
+                None => {
+                    let nonresidue = P::mul_base_field_by_nonresidue(&P::BaseField::one());
+                    (self.c0 * nonresidue.inverse()?)
+                        .sqrt()
+                        .map(|c1| Self::new(P::BaseField::zero(), c1))
+                }
+            };
+        }
+        match self.legendre() {
+            // Square root based on the complex method. See
+            // https://eprint.iacr.org/2012/685.pdf (page 15, algorithm 8)
+            Zero => Some(*self),
+            QuadraticNonResidue => None,
+            QuadraticResidue => {
+                let two_inv = P::BaseField::one()
+                    .double()
+                    .inverse()
+                    .expect("Two should always have an inverse");
+                let alpha = self
+                    .norm()
+                    .sqrt()
+                    .expect("We are in the QR case, the norm should have a square root");
+                let mut delta = (alpha + self.c0) * two_inv;
+                if delta.legendre().is_qnr() {
+                    delta -= &alpha;
+                }
+                let c0 = delta.sqrt().expect("Delta must have a square root");
+                let c0_inv = c0.inverse().expect("c0 must have an inverse");
+                Some(Self::new(c0, self.c1 * two_inv * c0_inv))
+            }
+        }
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+/// `QuadraticExtension` elements are ordered lexicographically.
+impl<P: QuadraticExtensionParameters> Ord for QuadraticExtension<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.c1.cmp(&other.c1) {
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Less => Ordering::Less,
+            Ordering::Equal => self.c0.cmp(&other.c0),
+        }
+    }
+}
+
+impl<P: QuadraticExtensionParameters> PartialOrd for QuadraticExtension<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: QuadraticExtensionParameters> From<u128> for QuadraticExtension<P> {
+    fn from(other: u128) -> Self {
+        Self::new(other.into(), P::BaseField::zero())
+    }
+}
+
+impl<P: QuadraticExtensionParameters> From<u64> for QuadraticExtension<P> {
+    fn from(other: u64) -> Self {
+        Self::new(other.into(), P::BaseField::zero())
+    }
+}
+
+impl<P: QuadraticExtensionParameters> From<u32> for QuadraticExtension<P> {
+    fn from(other: u32) -> Self {
+        Self::new(other.into(), P::BaseField::zero())
+    }
+}
+
+impl<P: QuadraticExtensionParameters> From<u16> for QuadraticExtension<P> {
+    fn from(other: u16) -> Self {
+        Self::new(other.into(), P::BaseField::zero())
+    }
+}
+
+impl<P: QuadraticExtensionParameters> From<u8> for QuadraticExtension<P> {
+    fn from(other: u8) -> Self {
+        Self::new(other.into(), P::BaseField::zero())
+    }
+}
+
+impl<P: QuadraticExtensionParameters> ToBytes for QuadraticExtension<P> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.c0.write(&mut writer)?;
+        self.c1.write(writer)
+    }
+}
+
+impl<P: QuadraticExtensionParameters> FromBytes for QuadraticExtension<P> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let c0 = P::BaseField::read(&mut reader)?;
+        let c1 = P::BaseField::read(reader)?;
+        Ok(Self::new(c0, c1))
+    }
+}
+
+impl<P: QuadraticExtensionParameters> ConditionallySelectable for QuadraticExtension<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.c0, &b.c0, choice),
+            ConditionallySelectable::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+impl<P: QuadraticExtensionParameters> zeroize::DefaultIsZeroes for QuadraticExtension<P> {}
+
+impl<P: QuadraticExtensionParameters> Neg for QuadraticExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.c0 = self.c0.neg();
+        self.c1 = self.c1.neg();
+        self
+    }
+}
+
+impl<P: QuadraticExtensionParameters> Distribution<QuadraticExtension<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> QuadraticExtension<P> {
+        QuadraticExtension::new(UniformRand::rand(rng), UniformRand::rand(rng))
+    }
+}
+
+impl_additive_ops_from_ref!(QuadraticExtension, QuadraticExtensionParameters);
+impl_multiplicative_ops_from_ref!(QuadraticExtension, QuadraticExtensionParameters);
+
+impl<'a, P: QuadraticExtensionParameters> Add<&'a Self> for QuadraticExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: QuadraticExtensionParameters> Sub<&'a Self> for QuadraticExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(other);
+        result
+    }
+}
+
+impl<'a, P: QuadraticExtensionParameters> Mul<&'a Self> for QuadraticExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(other);
+        result
+    }
+}
+
+impl<'a, P: QuadraticExtensionParameters> Div<&'a Self> for QuadraticExtension<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: QuadraticExtensionParameters> AddAssign<&'a Self> for QuadraticExtension<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(other.c0);
+        self.c1.add_assign(other.c1);
+    }
+}
+
+impl<'a, P: QuadraticExtensionParameters> SubAssign<&'a Self> for QuadraticExtension<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+    }
+}
+
+impl<'a, P: QuadraticExtensionParameters> MulAssign<&'a Self> for QuadraticExtension<P> {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, other: &Self) {
+        // Karatsuba multiplication;
+        // Guide to Pairing-based cryprography, Algorithm 5.16.
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+
+        self.c1 += &self.c0;
+        self.c1 *= &(other.c0 + other.c1);
+        self.c1 -= &v0;
+        self.c1 -= &v1;
+        self.c0 = v0 + P::mul_base_field_by_nonresidue(&v1);
+    }
+}
+
+impl<'a, P: QuadraticExtensionParameters> DivAssign<&'a Self> for QuadraticExtension<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl<P: QuadraticExtensionParameters> std::fmt::Display for QuadraticExtension<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({} + {} * u)", P::NAME, self.c0, self.c1)
+    }
+}
+
+impl<P: QuadraticExtensionParameters> CanonicalSerializeWithFlags for QuadraticExtension<P> {
+    #[inline]
+    fn serialize_with_flags<W: Write, F: Flags>(&self, writer: &mut W, flags: F) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize(&self.c0, writer)?;
+        self.c1.serialize_with_flags(writer, flags)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        self.c0.serialized_size() + self.c1.serialized_size_with_flags::<F>()
+    }
+}
+
+impl<P: QuadraticExtensionParameters> CanonicalSerialize for QuadraticExtension<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::SERIALIZED_SIZE
+    }
+}
+
+impl<P: QuadraticExtensionParameters> ConstantSerializedSize for QuadraticExtension<P> {
+    const SERIALIZED_SIZE: usize = 2 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE;
+    const UNCOMPRESSED_SIZE: usize = Self::SERIALIZED_SIZE;
+}
+
+impl<P: QuadraticExtensionParameters> ConstantSerializedSizeWithFlags for QuadraticExtension<P> {
+    // The flags are serialized with the last coefficient.
+    const SERIALIZED_SIZE_IN_BITS: usize = 8 * <P::BaseField as ConstantSerializedSize>::SERIALIZED_SIZE
+        + <P::BaseField as ConstantSerializedSizeWithFlags>::SERIALIZED_SIZE_IN_BITS;
+}
+
+impl<P: QuadraticExtensionParameters> CanonicalDeserializeWithFlags for QuadraticExtension<P> {
+    #[inline]
+    fn deserialize_with_flags<R: Read, F: Flags>(reader: &mut R) -> Result<(Self, F), SerializationError> {
+        let c0: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        let (c1, flags): (P::BaseField, _) = CanonicalDeserializeWithFlags::deserialize_with_flags(reader)?;
+        Ok((Self::new(c0, c1), flags))
+    }
+}
+
+impl<P: QuadraticExtensionParameters> CanonicalDeserialize for QuadraticExtension<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let c0: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        let c1: P::BaseField = CanonicalDeserialize::deserialize(reader)?;
+        Ok(Self::new(c0, c1))
+    }
+}
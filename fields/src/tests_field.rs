@@ -14,7 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{traits::FftParameters, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
+use crate::{
+    batch_inversion,
+    batch_inversion_and_mul,
+    batch_legendre,
+    batch_sqrt,
+    traits::FftParameters,
+    FftField,
+    Field,
+    LegendreSymbol,
+    PrimeField,
+    SquareRootField,
+};
 use snarkvm_utilities::{
     io::Cursor,
     serialize::{CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags},
@@ -104,6 +115,27 @@ fn random_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_batch_inversion_tests<F: Field, R: Rng>(rng: &mut R) {
+    for size in [0, 1, 2, 7, 64, 1000] {
+        let mut v: Vec<F> = (0..size).map(|_| F::rand(rng)).collect();
+        if size > 0 {
+            v[size / 2] = F::zero();
+        }
+        let expected: Vec<F> = v.iter().map(|f| f.inverse().unwrap_or_else(F::zero)).collect();
+
+        let mut inverses = v.clone();
+        batch_inversion(&mut inverses);
+        assert_eq!(inverses, expected);
+
+        let coeff = F::rand(rng);
+        let mut scaled = v.clone();
+        batch_inversion_and_mul(&mut scaled, &coeff);
+        for (scaled, expected) in scaled.iter().zip(&expected) {
+            assert_eq!(*scaled, *expected * coeff);
+        }
+    }
+}
+
 fn random_doubling_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         let mut a = F::rand(rng);
@@ -171,6 +203,22 @@ fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_ladder_tests<F: Field, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+
+        assert_eq!(F::conditional_select(&a, &b, 0u8.into()), a);
+        assert_eq!(F::conditional_select(&a, &b, 1u8.into()), b);
+
+        let exp: [u64; 4] = rng.gen();
+        assert_eq!(a.pow_ladder(exp), a.pow(exp));
+    }
+
+    assert_eq!(F::one().pow_ladder([0u64]), F::one());
+    assert_eq!(F::zero().pow_ladder([0u64]), F::one());
+}
+
 fn random_field_tests<F: Field>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -179,9 +227,11 @@ fn random_field_tests<F: Field>() {
     random_subtraction_tests::<F, _>(&mut rng);
     random_multiplication_tests::<F, _>(&mut rng);
     random_inversion_tests::<F, _>(&mut rng);
+    random_batch_inversion_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
+    random_ladder_tests::<F, _>(&mut rng);
 
     assert!(F::zero().is_zero());
     {
@@ -233,6 +283,18 @@ fn random_sqrt_tests<F: SquareRootField>() {
 
         c += &F::one();
     }
+
+    let v: Vec<F> = (0..ITERATIONS)
+        .map(|_| F::rand(&mut rng))
+        .chain(Some(F::zero()))
+        .collect();
+    let legendre = batch_legendre(&v);
+    let sqrt = batch_sqrt(&v);
+    for ((a, legendre), sqrt) in v.iter().zip(legendre).zip(sqrt) {
+        assert_eq!(legendre, a.legendre());
+        assert_eq!(sqrt, a.sqrt());
+        assert_eq!(sqrt.is_some(), !legendre.is_qnr());
+    }
 }
 
 pub fn from_str_test<F: PrimeField>() {
@@ -360,6 +422,17 @@ pub fn fft_field_test<F: PrimeField + FftField>() {
     assert_eq!(two_adic_root_of_unity.pow([1 << two_adicity]), F::one());
     // TODO (howardwu): CRITICAL - Reenable this after BLS12-377 Fr root_of_unity has been fixed.
     // assert_eq!(generator.pow(trace.into_repr().as_ref()), two_adic_root_of_unity);
+
+    // large_subgroup_root_of_unity^(q^k) == two_adic_root_of_unity, and it has order exactly 2^s * q^k
+    if let Some(large_subgroup_root_of_unity) = F::large_subgroup_root_of_unity() {
+        let q = F::FftParameters::SMALL_SUBGROUP_BASE.unwrap() as u64;
+        let q_adicity = F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY.unwrap();
+        let q_part = q.pow(q_adicity);
+        assert_eq!(large_subgroup_root_of_unity.pow([q_part]), two_adic_root_of_unity);
+        assert_ne!(large_subgroup_root_of_unity.pow([q_part / q]).pow([1 << two_adicity]), F::one());
+        assert_eq!(F::get_root_of_unity(1 << two_adicity), Some(two_adic_root_of_unity));
+        assert_eq!(F::get_root_of_unity((q_part as usize) << two_adicity), Some(large_subgroup_root_of_unity));
+    }
 }
 
 pub fn primefield_test<F: PrimeField>() {
@@ -373,6 +446,12 @@ pub fn primefield_test<F: PrimeField>() {
     assert_eq!(F::from_str("2").ok().unwrap(), two);
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        assert_eq!(F::from_str(&a.to_string()).ok().unwrap(), a);
+    }
+
     fft_field_test::<F>();
 }
 
@@ -408,6 +487,26 @@ pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: us
 }
 
 // Taken from https://github.com/scipr-lab/zexe/blob/master/algebra/src/tests/fields.rs#L381
+/// Checks that flags which fit in the spare bits of the field are serialized in place,
+/// so the encoding is the same as without flags, apart from the flag bits.
+pub fn field_flags_serialization_test<F: PrimeField, Fl: Flags>(flags: Fl) {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+
+        let mut expected = vec![0u8; F::SERIALIZED_SIZE];
+        let mut cursor = Cursor::new(&mut expected[..]);
+        CanonicalSerialize::serialize(&a, &mut cursor).unwrap();
+        expected[F::SERIALIZED_SIZE - 1] |= flags.u8_bitmask();
+
+        let mut serialized = vec![];
+        a.serialize_with_flags(&mut serialized, flags).unwrap();
+        assert_eq!(a.serialized_size_with_flags::<Fl>(), F::SERIALIZED_SIZE);
+        assert_eq!(expected, serialized);
+    }
+}
+
 pub fn field_serialization_test<F: Field>() {
     let buf_size = F::SERIALIZED_SIZE;
 
@@ -440,7 +539,7 @@ pub fn field_serialization_test<F: Field>() {
         }
 
         {
-            let mut serialized = vec![0u8; buf_size];
+            let mut serialized = vec![0u8; a.serialized_size_with_flags::<SWFlags>()];
             let mut cursor = Cursor::new(&mut serialized[..]);
             a.serialize_with_flags(&mut cursor, SWFlags::from_y_sign(true)).unwrap();
             let mut cursor = Cursor::new(&serialized[..]);
@@ -453,6 +552,8 @@ pub fn field_serialization_test<F: Field>() {
         #[derive(Default, Clone, Copy, Debug)]
         struct DummyFlags;
         impl Flags for DummyFlags {
+            const BIT_SIZE: usize = 200;
+
             fn u8_bitmask(&self) -> u8 {
                 0
             }
@@ -464,10 +565,6 @@ pub fn field_serialization_test<F: Field>() {
             fn from_u8_remove_flags(_value: &mut u8) -> Self {
                 DummyFlags
             }
-
-            fn len() -> usize {
-                200
-            }
         }
 
         use snarkvm_utilities::errors::SerializationError;
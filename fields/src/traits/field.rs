@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{One, Zero};
+use crate::{ConditionallySelectable, One, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     bytes::{FromBytes, ToBytes},
@@ -25,6 +25,9 @@ use snarkvm_utilities::{
         CanonicalSerialize,
         CanonicalSerializeWithFlags,
         ConstantSerializedSize,
+        ConstantSerializedSizeWithFlags,
+        EmptyFlags,
+        Flags,
     },
 };
 
@@ -35,6 +38,7 @@ use std::{
 };
 
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 /// The interface for a generic field.
 pub trait Field:
@@ -56,6 +60,7 @@ pub trait Field:
     + Zero
     + Sized
     + Hash
+    + Zeroize
     + From<u128>
     + From<u64>
     + From<u32>
@@ -83,11 +88,13 @@ pub trait Field:
     + for<'a> core::iter::Product<&'a Self>
     + CanonicalSerialize
     + ConstantSerializedSize
+    + ConstantSerializedSizeWithFlags
     + CanonicalSerializeWithFlags
     + CanonicalDeserialize
     + CanonicalDeserializeWithFlags
     + Serialize
     + for<'a> Deserialize<'a>
+    + ConditionallySelectable
 {
     /// Returns the characteristic of the field.
     fn characteristic<'a>() -> &'a [u64];
@@ -142,16 +149,38 @@ pub trait Field:
         res
     }
 
+    /// Exponentiates this element by a number represented with `u64` limbs,
+    /// least significant limb first, using a Montgomery ladder.
+    ///
+    /// The ladder performs one multiplication and one squaring for every bit of `exp`,
+    /// including leading zeros, and swaps its operands with `conditional_swap`.
+    /// This is not constant time, as the field arithmetic branches on its inputs.
+    fn pow_ladder<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let mut r0 = Self::one();
+        let mut r1 = *self;
+
+        for i in BitIteratorBE::new(exp) {
+            let choice = (i as u8).into();
+
+            Self::conditional_swap(&mut r0, &mut r1, choice);
+            r1 *= &r0;
+            r0.square_in_place();
+            Self::conditional_swap(&mut r0, &mut r1, choice);
+        }
+        r0
+    }
+
     /// Returns a field element if the set of bytes forms a valid field element,
     /// otherwise returns None. This function is primarily intended for sampling
     /// random field elements from a hash-function or RNG output.
     fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
-        Self::from_random_bytes_with_flags(bytes).map(|f| f.0)
+        Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
     }
 
-    /// Returns a field element with an extra sign bit used for group parsing if
+    /// Returns a field element with the extra bits used for group parsing if
     /// the set of bytes forms a valid field element, otherwise returns
-    /// None. This function is primarily intended for sampling
+    /// None. The bits are read where `serialize_with_flags` writes flags of type `F`.
+    /// This function is primarily intended for sampling
     /// random field elements from a hash-function or RNG output.
-    fn from_random_bytes_with_flags(bytes: &[u8]) -> Option<(Self, u8)>;
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, u8)>;
 }
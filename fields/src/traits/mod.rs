@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod constant_time;
+pub use constant_time::*;
+
 mod fft_field;
 pub use fft_field::*;
 
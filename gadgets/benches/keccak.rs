@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+use snarkvm_gadgets::{
+    algorithms::crh::{KeccakGadget, Sha256Gadget},
+    integers::uint::UInt8,
+};
+use snarkvm_r1cs::{ConstraintCounter, ConstraintSystem, Fr, TestConstraintSystem};
+
+/// The input sizes in bytes, chosen around the Keccak-256 rate of 136 bytes.
+const INPUT_SIZES: [usize; 4] = [32, 64, 136, 512];
+
+macro_rules! create_hash_bench {
+    ($bench_name:ident, $bench_id:expr, $hash:path) => {
+        fn $bench_name(c: &mut Criterion) {
+            let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+            for size in INPUT_SIZES.iter() {
+                let input = (0..*size).map(|_| rng.gen()).collect::<Vec<u8>>();
+
+                // Report the number of constraints for this input size.
+                let mut counter = ConstraintCounter::default();
+                let input_bytes =
+                    UInt8::alloc_vec(ConstraintSystem::<Fr>::ns(&mut counter, || "input"), &input).unwrap();
+                let num_input_constraints = counter.num_constraints;
+                $hash(ConstraintSystem::<Fr>::ns(&mut counter, || "hash"), &input_bytes).unwrap();
+                println!(
+                    "{}::{} bytes: {} constraints",
+                    $bench_id,
+                    size,
+                    counter.num_constraints - num_input_constraints
+                );
+
+                c.bench_function(&format!("{}::{}_bytes", $bench_id, size), |b| {
+                    b.iter(|| {
+                        let mut cs = TestConstraintSystem::<Fr>::new();
+                        let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+                        $hash(cs.ns(|| "hash"), &input_bytes).unwrap()
+                    })
+                });
+            }
+        }
+    };
+}
+
+create_hash_bench!(bench_keccak256, "keccak256", KeccakGadget::keccak256);
+create_hash_bench!(bench_sha3_256, "sha3_256", KeccakGadget::sha3_256);
+create_hash_bench!(bench_sha256, "sha256", Sha256Gadget::sha256);
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = bench_keccak256,
+    bench_sha3_256,
+    bench_sha256,
+);
+
+criterion_main!(benches);
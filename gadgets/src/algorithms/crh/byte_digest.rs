@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::borrow::Borrow;
+
+use snarkvm_algorithms::crh::ByteDigest;
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget},
+        select::CondSelectGadget,
+    },
+};
+
+/// The gadget for a `ByteDigest`, allocated as its 32 bytes.
+#[derive(Clone, Debug)]
+pub struct ByteDigestGadget(pub Vec<UInt8>);
+
+impl PartialEq for ByteDigestGadget {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for ByteDigestGadget {}
+
+impl<F: PrimeField> EqGadget<F> for ByteDigestGadget {}
+
+impl<F: PrimeField> ConditionalEqGadget<F> for ByteDigestGadget {
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        for (i, (a, b)) in self.0.iter().zip(other.0.iter()).enumerate() {
+            a.conditional_enforce_equal(&mut cs.ns(|| format!("byte_digest_equal_{}", i)), b, condition)?;
+        }
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        32 * <UInt8 as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<F: PrimeField> CondSelectGadget<F> for ByteDigestGadget {
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let mut result = Vec::with_capacity(32);
+        for (i, (a, b)) in first.0.iter().zip(second.0.iter()).enumerate() {
+            result.push(UInt8::conditionally_select(
+                cs.ns(|| format!("byte_digest_select_{}", i)),
+                cond,
+                a,
+                b,
+            )?);
+        }
+        Ok(ByteDigestGadget(result))
+    }
+
+    fn cost() -> usize {
+        32 * <UInt8 as CondSelectGadget<F>>::cost()
+    }
+}
+
+impl<F: PrimeField> ToBytesGadget<F> for ByteDigestGadget {
+    #[inline]
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, _cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        Ok(self.0.clone())
+    }
+
+    #[inline]
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.to_bytes(cs)
+    }
+}
+
+impl<F: PrimeField> AllocGadget<ByteDigest, F> for ByteDigestGadget {
+    #[inline]
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<ByteDigest>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(ByteDigestGadget(<UInt8>::alloc_vec(cs, &match value_gen() {
+            Ok(val) => val.borrow().0,
+            Err(_) => [0u8; 32],
+        })?))
+    }
+
+    #[inline]
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<ByteDigest>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(ByteDigestGadget(<UInt8>::alloc_input_vec_le(
+            cs,
+            &match value_gen() {
+                Ok(val) => val.borrow().0,
+                Err(_) => [0u8; 32],
+            },
+        )?))
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::borrow::Borrow;
+
+use snarkvm_algorithms::crh::Keccak256CRH;
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    algorithms::crh::ByteDigestGadget,
+    bits::Boolean,
+    integers::uint::{UInt, UInt64, UInt8},
+    traits::{algorithms::CRHGadget, alloc::AllocGadget, bits::Xor, integers::integer::Integer},
+};
+
+// FIPS 202, Section 3.2.5: the round constants of the iota step.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+// FIPS 202, Section 3.2.2: the rotation offsets of the rho step, indexed by `x + 5 * y`.
+const ROTATION_OFFSETS: [usize; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// The number of bytes absorbed per permutation for a 256-bit digest, `(1600 - 2 * 256) / 8`.
+const RATE_IN_BYTES: usize = 136;
+
+/// Returns the lane index of `(x, y)` in the state.
+#[inline]
+fn index(x: usize, y: usize) -> usize {
+    (x % 5) + 5 * (y % 5)
+}
+
+/// Returns `x <<< by`, which costs no constraints.
+#[inline]
+fn rotl(x: &UInt64, by: usize) -> UInt64 {
+    x.rotr((64 - by) % 64)
+}
+
+/// Applies the Keccak-f[1600] permutation to the 25 lanes of `state`.
+fn keccak_f1600<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    state: &mut [UInt64],
+) -> Result<(), SynthesisError> {
+    assert_eq!(state.len(), 25);
+
+    for (round, round_constant) in ROUND_CONSTANTS.iter().enumerate() {
+        let mut cs = cs.ns(|| format!("round {}", round));
+
+        // Theta
+        let mut c = Vec::with_capacity(5);
+        for x in 0..5 {
+            let mut cs = cs.ns(|| format!("theta column parity {}", x));
+            let mut parity = state[index(x, 0)].clone();
+            for y in 1..5 {
+                parity = parity.xor(cs.ns(|| format!("xor {}", y)), &state[index(x, y)])?;
+            }
+            c.push(parity);
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5].xor(cs.ns(|| format!("theta d {}", x)), &rotl(&c[(x + 1) % 5], 1))?;
+            for y in 0..5 {
+                state[index(x, y)] = state[index(x, y)].xor(cs.ns(|| format!("theta {} {}", x, y)), &d)?;
+            }
+        }
+
+        // Rho and pi
+        let mut b = state.to_vec();
+        for x in 0..5 {
+            for y in 0..5 {
+                b[index(y, 2 * x + 3 * y)] = rotl(&state[index(x, y)], ROTATION_OFFSETS[index(x, y)]);
+            }
+        }
+
+        // Chi
+        for x in 0..5 {
+            for y in 0..5 {
+                let mut cs = cs.ns(|| format!("chi {} {}", x, y));
+
+                let (a, b1, b2) = (&b[index(x, y)], &b[index(x + 1, y)], &b[index(x + 2, y)]);
+                let mut bits = Vec::with_capacity(64);
+                for (i, ((a, b1), b2)) in a.bits.iter().zip(&b1.bits).zip(&b2.bits).enumerate() {
+                    let tmp = Boolean::and(cs.ns(|| format!("!b1 & b2 {}", i)), &b1.not(), b2)?;
+                    bits.push(a.xor(cs.ns(|| format!("a ^ (!b1 & b2) {}", i)), &tmp)?);
+                }
+
+                let value = match (a.value, b1.value, b2.value) {
+                    (Some(a), Some(b1), Some(b2)) => Some(a ^ (!b1 & b2)),
+                    _ => None,
+                };
+                state[index(x, y)] = UInt64::new(bits, value);
+            }
+        }
+
+        // Iota
+        state[0] = state[0].xor(cs.ns(|| "iota"), &UInt64::constant(*round_constant))?;
+    }
+
+    Ok(())
+}
+
+/// The Keccak sponge with a 256-bit output and the given domain separation byte.
+fn keccak_256_sponge<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    input: &[UInt8],
+    domain_separator: u8,
+) -> Result<Vec<UInt8>, SynthesisError> {
+    // Pad the message with the domain separator, zeros, and a final one bit.
+    let mut padded = input.to_vec();
+    let mut padding = vec![0u8; RATE_IN_BYTES - (input.len() % RATE_IN_BYTES)];
+    padding[0] |= domain_separator;
+    *padding.last_mut().unwrap() |= 0x80;
+    padded.extend(padding.into_iter().map(UInt8::constant));
+
+    let mut state = vec![UInt64::constant(0); 25];
+
+    // Absorb each block of little-endian lanes.
+    for (i, block) in padded.chunks(RATE_IN_BYTES).enumerate() {
+        let mut cs = cs.ns(|| format!("block {}", i));
+
+        for (j, lane) in block.chunks(8).enumerate() {
+            let bits = lane.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+            state[j] = state[j].xor(cs.ns(|| format!("absorb lane {}", j)), &UInt64::from_bits_le(&bits))?;
+        }
+
+        keccak_f1600(cs.ns(|| "permutation"), &mut state)?;
+    }
+
+    // Squeeze the first 256 bits, which fit within a single block.
+    Ok(state[..4]
+        .iter()
+        .flat_map(|lane| lane.to_bits_le().chunks(8).map(UInt8::from_bits_le).collect::<Vec<_>>())
+        .collect())
+}
+
+/// The Keccak family of hash functions over the Keccak-f[1600] permutation.
+///
+/// Lanes are little-endian 64-bit words, so the input and output bytes match
+/// `snarkvm_algorithms::crh::keccak256` and `snarkvm_algorithms::crh::sha3_256`.
+pub struct KeccakGadget;
+
+impl KeccakGadget {
+    /// Returns the Keccak-256 digest of `input`, as used by Ethereum.
+    pub fn keccak256<F: PrimeField, CS: ConstraintSystem<F>>(
+        cs: CS,
+        input: &[UInt8],
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        keccak_256_sponge(cs, input, 0x01)
+    }
+
+    /// Returns the SHA3-256 digest of `input`, as standardized in FIPS 202.
+    pub fn sha3_256<F: PrimeField, CS: ConstraintSystem<F>>(
+        cs: CS,
+        input: &[UInt8],
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        keccak_256_sponge(cs, input, 0x06)
+    }
+}
+
+#[derive(Clone)]
+pub struct Keccak256ParametersGadget;
+
+impl<F: PrimeField> AllocGadget<(), F> for Keccak256ParametersGadget {
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<()>, CS: ConstraintSystem<F>>(
+        _: CS,
+        _: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Keccak256ParametersGadget)
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<()>, CS: ConstraintSystem<F>>(
+        _: CS,
+        _: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Keccak256ParametersGadget)
+    }
+}
+
+/// The output of the Keccak-256 CRH gadget.
+pub type Keccak256OutputGadget = ByteDigestGadget;
+
+#[derive(Clone)]
+pub struct Keccak256CRHGadget;
+
+impl<F: PrimeField> CRHGadget<Keccak256CRH, F> for Keccak256CRHGadget {
+    type OutputGadget = Keccak256OutputGadget;
+    type ParametersGadget = Keccak256ParametersGadget;
+
+    fn check_evaluation_gadget<CS: ConstraintSystem<F>>(
+        cs: CS,
+        _: &Self::ParametersGadget,
+        input: Vec<UInt8>,
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        Ok(ByteDigestGadget(KeccakGadget::keccak256(cs, &input)?))
+    }
+}
@@ -17,8 +17,17 @@
 pub mod bowe_hopwood_pedersen;
 pub use bowe_hopwood_pedersen::*;
 
+pub mod byte_digest;
+pub use byte_digest::*;
+
+pub mod keccak;
+pub use keccak::*;
+
 pub mod pedersen;
 pub use pedersen::*;
 
+pub mod sha256;
+pub use sha256::*;
+
 #[cfg(test)]
 pub mod tests;
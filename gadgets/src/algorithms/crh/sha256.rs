@@ -0,0 +1,290 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::borrow::Borrow;
+
+use snarkvm_algorithms::crh::Sha256CRH;
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    algorithms::crh::ByteDigestGadget,
+    bits::Boolean,
+    integers::uint::{UInt, UInt32, UInt8},
+    traits::{algorithms::CRHGadget, alloc::AllocGadget, bits::Xor, integers::integer::Integer},
+};
+
+// FIPS 180-4, Section 4.2.2: the first 32 bits of the fractional parts
+// of the cube roots of the first 64 primes.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+// FIPS 180-4, Section 5.3.3: the first 32 bits of the fractional parts
+// of the square roots of the first 8 primes.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Returns `x >> by`, which costs no constraints.
+fn shr(x: &UInt32, by: usize) -> UInt32 {
+    let bits = x
+        .bits
+        .iter()
+        .skip(by)
+        .cloned()
+        .chain(std::iter::repeat(Boolean::constant(false)))
+        .take(32)
+        .collect();
+
+    UInt32::new(bits, x.value.map(|v| v >> by))
+}
+
+/// Returns `rotr(x, r0) ^ rotr(x, r1) ^ rotr(x, r2)`.
+fn big_sigma<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    x: &UInt32,
+    rotations: [usize; 3],
+) -> Result<UInt32, SynthesisError> {
+    let tmp = x.rotr(rotations[0]).xor(cs.ns(|| "first xor"), &x.rotr(rotations[1]))?;
+    tmp.xor(cs.ns(|| "second xor"), &x.rotr(rotations[2]))
+}
+
+/// Returns `rotr(x, r0) ^ rotr(x, r1) ^ (x >> s)`.
+fn small_sigma<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    x: &UInt32,
+    rotations: [usize; 2],
+    shift: usize,
+) -> Result<UInt32, SynthesisError> {
+    let tmp = x.rotr(rotations[0]).xor(cs.ns(|| "first xor"), &x.rotr(rotations[1]))?;
+    tmp.xor(cs.ns(|| "second xor"), &shr(x, shift))
+}
+
+/// Returns `(e & f) ^ (!e & g)`, computed bitwise as `g ^ (e & (f ^ g))`.
+fn choose<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    e: &UInt32,
+    f: &UInt32,
+    g: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    let f_xor_g = f.xor(cs.ns(|| "f ^ g"), g)?;
+
+    let mut bits = Vec::with_capacity(32);
+    for (i, ((e, f_xor_g), g)) in e.bits.iter().zip(&f_xor_g.bits).zip(&g.bits).enumerate() {
+        let tmp = Boolean::and(cs.ns(|| format!("e & (f ^ g) {}", i)), e, f_xor_g)?;
+        bits.push(tmp.xor(cs.ns(|| format!("g ^ (e & (f ^ g)) {}", i)), g)?);
+    }
+
+    let value = match (e.value, f.value, g.value) {
+        (Some(e), Some(f), Some(g)) => Some((e & f) ^ (!e & g)),
+        _ => None,
+    };
+
+    Ok(UInt32::new(bits, value))
+}
+
+/// Returns `(a & b) ^ (a & c) ^ (b & c)`, computed bitwise as `(a & b) ^ (c & (a ^ b))`.
+fn majority<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    a: &UInt32,
+    b: &UInt32,
+    c: &UInt32,
+) -> Result<UInt32, SynthesisError> {
+    let a_xor_b = a.xor(cs.ns(|| "a ^ b"), b)?;
+
+    let mut bits = Vec::with_capacity(32);
+    for (i, (((a, b), c), a_xor_b)) in a.bits.iter().zip(&b.bits).zip(&c.bits).zip(&a_xor_b.bits).enumerate() {
+        let a_and_b = Boolean::and(cs.ns(|| format!("a & b {}", i)), a, b)?;
+        let c_and_a_xor_b = Boolean::and(cs.ns(|| format!("c & (a ^ b) {}", i)), c, a_xor_b)?;
+        bits.push(a_and_b.xor(cs.ns(|| format!("(a & b) ^ (c & (a ^ b)) {}", i)), &c_and_a_xor_b)?);
+    }
+
+    let value = match (a.value, b.value, c.value) {
+        (Some(a), Some(b), Some(c)) => Some((a & b) ^ (a & c) ^ (b & c)),
+        _ => None,
+    };
+
+    Ok(UInt32::new(bits, value))
+}
+
+/// Applies the SHA-256 compression function to the state `h` with a 512-bit message block.
+#[allow(clippy::many_single_char_names)]
+fn sha256_compression<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    h: &mut [UInt32],
+    block: &[UInt32],
+) -> Result<(), SynthesisError> {
+    assert_eq!(h.len(), 8);
+    assert_eq!(block.len(), 16);
+
+    // Prepare the message schedule.
+    let mut w = block.to_vec();
+    for i in 16..64 {
+        let mut cs = cs.ns(|| format!("message schedule {}", i));
+
+        let s0 = small_sigma(cs.ns(|| "s0"), &w[i - 15], [7, 18], 3)?;
+        let s1 = small_sigma(cs.ns(|| "s1"), &w[i - 2], [17, 19], 10)?;
+        let word = UInt32::addmany(cs.ns(|| "w"), &[w[i - 16].clone(), s0, w[i - 7].clone(), s1])?;
+        w.push(word);
+    }
+
+    let mut a = h[0].clone();
+    let mut b = h[1].clone();
+    let mut c = h[2].clone();
+    let mut d = h[3].clone();
+    let mut e = h[4].clone();
+    let mut f = h[5].clone();
+    let mut g = h[6].clone();
+    let mut hh = h[7].clone();
+
+    for i in 0..64 {
+        let mut cs = cs.ns(|| format!("round {}", i));
+
+        let s1 = big_sigma(cs.ns(|| "S1"), &e, [6, 11, 25])?;
+        let ch = choose(cs.ns(|| "ch"), &e, &f, &g)?;
+        let temp1 = UInt32::addmany(cs.ns(|| "temp1"), &[
+            hh,
+            s1,
+            ch,
+            UInt32::constant(ROUND_CONSTANTS[i]),
+            w[i].clone(),
+        ])?;
+
+        let s0 = big_sigma(cs.ns(|| "S0"), &a, [2, 13, 22])?;
+        let maj = majority(cs.ns(|| "maj"), &a, &b, &c)?;
+
+        hh = g;
+        g = f;
+        f = e;
+        e = UInt32::addmany(cs.ns(|| "d + temp1"), &[d, temp1.clone()])?;
+        d = c;
+        c = b;
+        b = a;
+        a = UInt32::addmany(cs.ns(|| "temp1 + S0 + maj"), &[temp1, s0, maj])?;
+    }
+
+    for (i, word) in [a, b, c, d, e, f, g, hh].iter().enumerate() {
+        h[i] = UInt32::addmany(cs.ns(|| format!("h[{}]", i)), &[h[i].clone(), word.clone()])?;
+    }
+
+    Ok(())
+}
+
+/// The SHA-256 hash function, as specified in FIPS 180-4.
+///
+/// Messages are processed as big-endian 32-bit words, so the input and output
+/// bytes match `snarkvm_algorithms::crh::sha256`.
+pub struct Sha256Gadget;
+
+impl Sha256Gadget {
+    /// Returns the SHA-256 digest of `input`.
+    pub fn sha256<F: PrimeField, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        input: &[UInt8],
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        // Pad the message with a single one bit, zeros, and the 64-bit message length.
+        let bit_length = (input.len() as u64) * 8;
+        let mut padded = input.to_vec();
+        padded.push(UInt8::constant(0x80));
+        while padded.len() % 64 != 56 {
+            padded.push(UInt8::constant(0));
+        }
+        padded.extend(bit_length.to_be_bytes().iter().map(|byte| UInt8::constant(*byte)));
+
+        let mut h = IV.iter().map(|word| UInt32::constant(*word)).collect::<Vec<_>>();
+
+        for (i, block) in padded.chunks(64).enumerate() {
+            let block = block
+                .chunks(4)
+                .map(|bytes| {
+                    let bits = bytes
+                        .iter()
+                        .rev()
+                        .flat_map(|byte| byte.to_bits_le())
+                        .collect::<Vec<_>>();
+                    UInt32::from_bits_le(&bits)
+                })
+                .collect::<Vec<_>>();
+
+            sha256_compression(cs.ns(|| format!("block {}", i)), &mut h, &block)?;
+        }
+
+        Ok(h.iter()
+            .flat_map(|word| {
+                word.to_bits_le()
+                    .chunks(8)
+                    .rev()
+                    .map(UInt8::from_bits_le)
+                    .collect::<Vec<_>>()
+            })
+            .collect())
+    }
+
+    /// Returns the SHA-256 digest of the SHA-256 digest of `input`.
+    pub fn double_sha256<F: PrimeField, CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        input: &[UInt8],
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        let digest = Self::sha256(cs.ns(|| "first sha256"), input)?;
+        Self::sha256(cs.ns(|| "second sha256"), &digest)
+    }
+}
+
+#[derive(Clone)]
+pub struct Sha256ParametersGadget;
+
+impl<F: PrimeField> AllocGadget<(), F> for Sha256ParametersGadget {
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<()>, CS: ConstraintSystem<F>>(
+        _: CS,
+        _: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Sha256ParametersGadget)
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<()>, CS: ConstraintSystem<F>>(
+        _: CS,
+        _: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Sha256ParametersGadget)
+    }
+}
+
+/// The output of the SHA-256 CRH gadget.
+pub type Sha256OutputGadget = ByteDigestGadget;
+
+#[derive(Clone)]
+pub struct Sha256CRHGadget;
+
+impl<F: PrimeField> CRHGadget<Sha256CRH, F> for Sha256CRHGadget {
+    type OutputGadget = Sha256OutputGadget;
+    type ParametersGadget = Sha256ParametersGadget;
+
+    fn check_evaluation_gadget<CS: ConstraintSystem<F>>(
+        cs: CS,
+        _: &Self::ParametersGadget,
+        input: Vec<UInt8>,
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        Ok(ByteDigestGadget(Sha256Gadget::sha256(cs, &input)?))
+    }
+}
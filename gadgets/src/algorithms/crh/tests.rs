@@ -17,7 +17,18 @@
 use rand::{thread_rng, Rng};
 
 use snarkvm_algorithms::{
-    crh::{BoweHopwoodPedersenCRH, BoweHopwoodPedersenCompressedCRH, PedersenCRH, PedersenCompressedCRH},
+    crh::{
+        double_sha256,
+        keccak256,
+        sha256,
+        sha3_256,
+        BoweHopwoodPedersenCRH,
+        BoweHopwoodPedersenCompressedCRH,
+        Keccak256CRH,
+        PedersenCRH,
+        PedersenCompressedCRH,
+        Sha256CRH,
+    },
     traits::{CRHParameters, CRH},
 };
 use snarkvm_curves::{
@@ -31,8 +42,16 @@ use crate::{
     algorithms::crh::{
         BoweHopwoodPedersenCRHGadget,
         BoweHopwoodPedersenCompressedCRHGadget,
+        Keccak256CRHGadget,
+        Keccak256OutputGadget,
+        Keccak256ParametersGadget,
+        KeccakGadget,
         PedersenCRHGadget,
         PedersenCompressedCRHGadget,
+        Sha256CRHGadget,
+        Sha256Gadget,
+        Sha256OutputGadget,
+        Sha256ParametersGadget,
     },
     curves::edwards_bls12::EdwardsBlsGadget,
     integers::uint::UInt8,
@@ -52,6 +71,8 @@ const BHP_WINDOW_SIZE: usize = 48;
 const PEDERSEN_HASH_CONSTRAINTS: usize = 5632;
 const PEDERSEN_HASH_CONSTRAINTS_ON_AFFINE: usize = 6656;
 const BOWE_HOPWOOD_HASH_CONSTRAINTS: usize = 3974;
+const SHA256_HASH_CONSTRAINTS: usize = 103818;
+const KECCAK256_HASH_CONSTRAINTS: usize = 153984;
 
 fn generate_input<F: Field, CS: ConstraintSystem<F>, R: Rng>(
     mut cs: CS,
@@ -230,3 +251,162 @@ mod bowe_hopwood_pedersen_compressed_crh_gadget_on_projective {
         primitive_crh_gadget_test::<Fr, TestCRH, TestCRHGadget>(BOWE_HOPWOOD_HASH_CONSTRAINTS)
     }
 }
+
+mod sha256_crh_gadget {
+    use super::*;
+
+    #[test]
+    fn primitive_gadget_test() {
+        primitive_crh_gadget_test::<Fr, Sha256CRH, Sha256CRHGadget>(SHA256_HASH_CONSTRAINTS)
+    }
+
+    #[test]
+    fn sha256_gadget_test() {
+        let rng = &mut thread_rng();
+
+        // Cover the padding boundaries of a single block and of multiple blocks.
+        for length in [0, 1, 55, 56, 63, 64, 65, 119, 120, 200] {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let input = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+
+            let digest = Sha256Gadget::sha256(cs.ns(|| "sha256"), &input_bytes).unwrap();
+            let double_digest = Sha256Gadget::double_sha256(cs.ns(|| "double_sha256"), &input_bytes).unwrap();
+
+            assert!(cs.is_satisfied());
+
+            let digest = digest.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+            let double_digest = double_digest.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+            assert_eq!(digest, sha256(&input), "sha256 mismatch for input length {}", length);
+            assert_eq!(
+                double_digest,
+                double_sha256(&input),
+                "double_sha256 mismatch for input length {}",
+                length
+            );
+        }
+    }
+
+    #[test]
+    fn sha256_gadget_constant_input_test() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let input = UInt8::constant_vec(b"abc");
+        let digest = Sha256Gadget::sha256(cs.ns(|| "sha256"), &input).unwrap();
+
+        let digest = digest.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+        assert_eq!(digest, sha256(b"abc"));
+        assert_eq!(cs.num_constraints(), 0);
+    }
+
+    #[test]
+    fn sha256_gadget_wrong_output_test() {
+        let rng = &mut thread_rng();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let (input, input_bytes, _mask_bytes) = generate_input(&mut cs, rng);
+
+        let output_gadget = Sha256CRHGadget::check_evaluation_gadget(
+            cs.ns(|| "gadget_evaluation"),
+            &Sha256ParametersGadget,
+            input_bytes,
+        )
+        .unwrap();
+
+        let mut wrong_output = Sha256CRH.hash(&input).unwrap();
+        wrong_output.0[0] ^= 1;
+        let wrong_output_gadget = Sha256OutputGadget::alloc(cs.ns(|| "wrong_output"), || Ok(wrong_output)).unwrap();
+
+        output_gadget
+            .enforce_equal(
+                cs.ns(|| "Check that computed crh matches provided output"),
+                &wrong_output_gadget,
+            )
+            .unwrap();
+
+        assert!(!cs.is_satisfied());
+    }
+}
+
+mod keccak256_crh_gadget {
+    use super::*;
+
+    #[test]
+    fn primitive_gadget_test() {
+        primitive_crh_gadget_test::<Fr, Keccak256CRH, Keccak256CRHGadget>(KECCAK256_HASH_CONSTRAINTS)
+    }
+
+    #[test]
+    fn keccak_gadget_test() {
+        let rng = &mut thread_rng();
+
+        // Cover the padding boundaries around the 136-byte rate.
+        for length in [0, 1, 32, 64, 135, 136, 137, 272, 300] {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+
+            let input = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let input_bytes = UInt8::alloc_vec(cs.ns(|| "input"), &input).unwrap();
+
+            let keccak_digest = KeccakGadget::keccak256(cs.ns(|| "keccak256"), &input_bytes).unwrap();
+            let sha3_digest = KeccakGadget::sha3_256(cs.ns(|| "sha3_256"), &input_bytes).unwrap();
+
+            assert!(cs.is_satisfied());
+
+            let keccak_digest = keccak_digest.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+            let sha3_digest = sha3_digest.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+            assert_eq!(
+                keccak_digest,
+                keccak256(&input),
+                "keccak256 mismatch for input length {}",
+                length
+            );
+            assert_eq!(
+                sha3_digest,
+                sha3_256(&input),
+                "sha3_256 mismatch for input length {}",
+                length
+            );
+        }
+    }
+
+    #[test]
+    fn keccak_gadget_constant_input_test() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let input = UInt8::constant_vec(b"abc");
+        let digest = KeccakGadget::keccak256(cs.ns(|| "keccak256"), &input).unwrap();
+
+        let digest = digest.iter().map(|byte| byte.value.unwrap()).collect::<Vec<_>>();
+        assert_eq!(digest, keccak256(b"abc"));
+        assert_eq!(cs.num_constraints(), 0);
+    }
+
+    #[test]
+    fn keccak_gadget_wrong_output_test() {
+        let rng = &mut thread_rng();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let (input, input_bytes, _mask_bytes) = generate_input(&mut cs, rng);
+
+        let output_gadget = Keccak256CRHGadget::check_evaluation_gadget(
+            cs.ns(|| "gadget_evaluation"),
+            &Keccak256ParametersGadget,
+            input_bytes,
+        )
+        .unwrap();
+
+        let mut wrong_output = Keccak256CRH.hash(&input).unwrap();
+        wrong_output.0[31] ^= 1;
+        let wrong_output_gadget = Keccak256OutputGadget::alloc(cs.ns(|| "wrong_output"), || Ok(wrong_output)).unwrap();
+
+        output_gadget
+            .enforce_equal(
+                cs.ns(|| "Check that computed crh matches provided output"),
+                &wrong_output_gadget,
+            )
+            .unwrap();
+
+        assert!(!cs.is_satisfied());
+    }
+}
@@ -284,6 +284,18 @@ pub struct GroupEncryptionPlaintextGadget<G: Group, F: Field, GG: GroupGadget<G,
     _engine: PhantomData<*const F>,
 }
 
+impl<G: Group, F: Field, GG: GroupGadget<G, F>> GroupEncryptionPlaintextGadget<G, F, GG> {
+    /// Returns a plaintext gadget from group element gadgets that have already been allocated,
+    /// so that callers can constrain the plaintext before it is encrypted.
+    pub fn new(plaintext: Vec<GG>) -> Self {
+        Self {
+            plaintext,
+            _group: PhantomData,
+            _engine: PhantomData,
+        }
+    }
+}
+
 impl<G: Group + ProjectiveCurve, F: Field, GG: GroupGadget<G, F>> AllocGadget<Vec<G>, F>
     for GroupEncryptionPlaintextGadget<G, F, GG>
 {
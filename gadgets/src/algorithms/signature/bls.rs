@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+use snarkvm_algorithms::signature::{BLSOutput, BLSPublicKey};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{
+        alloc::AllocGadget,
+        curves::{GroupGadget, PairingGadget},
+        eq::{ConditionalEqGadget, EqGadget},
+        fields::FieldGadget,
+    },
+};
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P::G2Gadget: Clone"),
+    Debug(bound = "P::G2Gadget: std::fmt::Debug"),
+    PartialEq(bound = "P::G2Gadget: PartialEq"),
+    Eq(bound = "P::G2Gadget: Eq")
+)]
+pub struct BLSPublicKeyGadget<E: PairingEngine, F: Field, P: PairingGadget<E, F>> {
+    pub public_key: P::G2Gadget,
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    _engine: PhantomData<(E, F)>,
+}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> AllocGadget<BLSPublicKey<E>, F>
+    for BLSPublicKeyGadget<E, F, P>
+{
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<BLSPublicKey<E>>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        f: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            public_key: P::G2Gadget::alloc_checked(cs, || f().map(|pk| pk.borrow().0.into_projective()))?,
+            _engine: PhantomData,
+        })
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<BLSPublicKey<E>>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        f: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            public_key: P::G2Gadget::alloc_input(cs, || f().map(|pk| pk.borrow().0.into_projective()))?,
+            _engine: PhantomData,
+        })
+    }
+}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> ConditionalEqGadget<F> for BLSPublicKeyGadget<E, F, P> {
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.public_key.conditional_enforce_equal(
+            &mut cs.ns(|| "conditional_enforce_equal"),
+            &other.public_key,
+            condition,
+        )
+    }
+
+    fn cost() -> usize {
+        <P::G2Gadget as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> EqGadget<F> for BLSPublicKeyGadget<E, F, P> {}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> ToBytesGadget<F> for BLSPublicKeyGadget<E, F, P> {
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.public_key.to_bytes(&mut cs.ns(|| "to_bytes"))
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.public_key.to_bytes_strict(&mut cs.ns(|| "to_bytes_strict"))
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P::G1Gadget: Clone"),
+    Debug(bound = "P::G1Gadget: std::fmt::Debug"),
+    PartialEq(bound = "P::G1Gadget: PartialEq"),
+    Eq(bound = "P::G1Gadget: Eq")
+)]
+pub struct BLSSignatureGadget<E: PairingEngine, F: Field, P: PairingGadget<E, F>> {
+    pub signature: P::G1Gadget,
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    _engine: PhantomData<(E, F)>,
+}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> AllocGadget<BLSOutput<E>, F> for BLSSignatureGadget<E, F, P> {
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<BLSOutput<E>>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        f: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            signature: P::G1Gadget::alloc_checked(cs, || f().map(|s| s.borrow().0.into_projective()))?,
+            _engine: PhantomData,
+        })
+    }
+
+    fn alloc_input<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<BLSOutput<E>>, CS: ConstraintSystem<F>>(
+        cs: CS,
+        f: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            signature: P::G1Gadget::alloc_input(cs, || f().map(|s| s.borrow().0.into_projective()))?,
+            _engine: PhantomData,
+        })
+    }
+}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> ConditionalEqGadget<F> for BLSSignatureGadget<E, F, P> {
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.signature.conditional_enforce_equal(
+            &mut cs.ns(|| "conditional_enforce_equal"),
+            &other.signature,
+            condition,
+        )
+    }
+
+    fn cost() -> usize {
+        <P::G1Gadget as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> EqGadget<F> for BLSSignatureGadget<E, F, P> {}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> ToBytesGadget<F> for BLSSignatureGadget<E, F, P> {
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.signature.to_bytes(&mut cs.ns(|| "to_bytes"))
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.signature.to_bytes_strict(&mut cs.ns(|| "to_bytes_strict"))
+    }
+}
+
+/// Enforces BLS signature verification in a circuit.
+///
+/// Hashing a message to G1 is not practical in-circuit, so the gadget takes
+/// the message hash as a G1 point, as computed by `BLSSignature::hash_message`.
+pub struct BLSVerificationGadget<E: PairingEngine, F: Field, P: PairingGadget<E, F>> {
+    _engine: PhantomData<(E, F, P)>,
+}
+
+impl<E: PairingEngine, F: Field, P: PairingGadget<E, F>> BLSVerificationGadget<E, F, P> {
+    /// Enforces `e(signature, g2) == e(message_hash, public_key)`.
+    pub fn check_verification<CS: ConstraintSystem<F>>(
+        cs: CS,
+        public_key: &BLSPublicKeyGadget<E, F, P>,
+        message_hash: &P::G1Gadget,
+        signature: &BLSSignatureGadget<E, F, P>,
+    ) -> Result<(), SynthesisError> {
+        Self::check_aggregate_verification(cs, &[public_key.clone()], &[message_hash.clone()], signature)
+    }
+
+    /// Enforces `e(signature, g2) == prod_i e(message_hash_i, public_key_i)`.
+    pub fn check_aggregate_verification<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        public_keys: &[BLSPublicKeyGadget<E, F, P>],
+        message_hashes: &[P::G1Gadget],
+        signature: &BLSSignatureGadget<E, F, P>,
+    ) -> Result<(), SynthesisError> {
+        if public_keys.is_empty() || public_keys.len() != message_hashes.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let neg_signature = signature.signature.negate(cs.ns(|| "negate signature"))?;
+
+        let mut g1_prepared = Vec::with_capacity(public_keys.len() + 1);
+        let mut g2_prepared = Vec::with_capacity(public_keys.len() + 1);
+
+        g1_prepared.push(P::prepare_g1(cs.ns(|| "prepare signature"), neg_signature)?);
+        g2_prepared.push(P::G2PreparedGadget::alloc_constant(
+            cs.ns(|| "prepare generator"),
+            || Ok(E::G2Affine::prime_subgroup_generator().prepare()),
+        )?);
+
+        for (i, (public_key, message_hash)) in public_keys.iter().zip(message_hashes).enumerate() {
+            g1_prepared.push(P::prepare_g1(
+                cs.ns(|| format!("prepare message hash {}", i)),
+                message_hash.clone(),
+            )?);
+            g2_prepared.push(P::prepare_g2(
+                cs.ns(|| format!("prepare public key {}", i)),
+                public_key.public_key.clone(),
+            )?);
+        }
+
+        let result = P::product_of_pairings(cs.ns(|| "product of pairings"), &g1_prepared, &g2_prepared)?;
+        let one = P::GTGadget::one(cs.ns(|| "one"))?;
+        result.enforce_equal(cs.ns(|| "enforce verification"), &one)
+    }
+
+    /// Enforces verification of a signature on a single message by many signers.
+    pub fn check_fast_aggregate_verification<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        public_keys: &[BLSPublicKeyGadget<E, F, P>],
+        message_hash: &P::G1Gadget,
+        signature: &BLSSignatureGadget<E, F, P>,
+    ) -> Result<(), SynthesisError> {
+        let (first, rest) = public_keys.split_first().ok_or(SynthesisError::Unsatisfiable)?;
+
+        let mut aggregate_public_key = first.public_key.clone();
+        for (i, public_key) in rest.iter().enumerate() {
+            aggregate_public_key =
+                aggregate_public_key.add(cs.ns(|| format!("aggregate public key {}", i)), &public_key.public_key)?;
+        }
+
+        let aggregate_public_key = BLSPublicKeyGadget {
+            public_key: aggregate_public_key,
+            _engine: PhantomData,
+        };
+        Self::check_verification(
+            cs.ns(|| "check verification"),
+            &aggregate_public_key,
+            message_hash,
+            signature,
+        )
+    }
+}
@@ -60,11 +60,9 @@ fn test_schnorr_signature_randomize_public_key_gadget() {
     let randomized_signature = schnorr_signature
         .randomize_signature(&signature, &random_scalar)
         .unwrap();
-    assert!(
-        schnorr_signature
-            .verify(&randomized_public_key, &message, &randomized_signature)
-            .unwrap()
-    );
+    assert!(schnorr_signature
+        .verify(&randomized_public_key, &message, &randomized_signature)
+        .unwrap());
 
     // Circuit Schnorr randomized public key (candidate)
 
@@ -112,3 +110,103 @@ fn test_schnorr_signature_randomize_public_key_gadget() {
     }
     assert!(cs.is_satisfied());
 }
+
+mod bls {
+    use rand::{thread_rng, Rng};
+
+    use snarkvm_algorithms::{
+        signature::{BLSOutput, BLSPublicKey, BLSSignature},
+        traits::SignatureScheme,
+    };
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fq},
+        traits::AffineCurve,
+    };
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+    use crate::{
+        algorithms::signature::{BLSPublicKeyGadget, BLSSignatureGadget, BLSVerificationGadget},
+        curves::bls12_377::{G1Gadget, PairingGadget},
+        traits::alloc::AllocGadget,
+    };
+
+    type TestBLSSignature = BLSSignature<Bls12_377>;
+    type TestPublicKeyGadget = BLSPublicKeyGadget<Bls12_377, Fq, PairingGadget>;
+    type TestSignatureGadget = BLSSignatureGadget<Bls12_377, Fq, PairingGadget>;
+    type TestVerificationGadget = BLSVerificationGadget<Bls12_377, Fq, PairingGadget>;
+
+    fn verification_is_satisfied(
+        public_keys: &[BLSPublicKey<Bls12_377>],
+        messages: &[&[u8]],
+        signature: &BLSOutput<Bls12_377>,
+    ) -> bool {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+
+        let public_key_gadgets = public_keys
+            .iter()
+            .enumerate()
+            .map(|(i, public_key)| {
+                TestPublicKeyGadget::alloc_input(cs.ns(|| format!("public_key_{}", i)), || Ok(public_key)).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let message_hash_gadgets = messages
+            .iter()
+            .enumerate()
+            .map(|(i, message)| {
+                G1Gadget::alloc_input(cs.ns(|| format!("message_hash_{}", i)), || {
+                    Ok(TestBLSSignature::hash_message(message).into_projective())
+                })
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let signature_gadget = TestSignatureGadget::alloc(cs.ns(|| "signature"), || Ok(signature)).unwrap();
+
+        TestVerificationGadget::check_aggregate_verification(
+            cs.ns(|| "verify"),
+            &public_key_gadgets,
+            &message_hash_gadgets,
+            &signature_gadget,
+        )
+        .unwrap();
+
+        cs.is_satisfied()
+    }
+
+    #[test]
+    fn test_bls_verification_gadget() {
+        let rng = &mut thread_rng();
+        let bls = TestBLSSignature::setup(rng).unwrap();
+
+        let message: [u8; 32] = rng.gen();
+        let private_key = bls.generate_private_key(rng).unwrap();
+        let public_key = bls.generate_public_key(&private_key).unwrap();
+        let signature = bls.sign(&private_key, &message, rng).unwrap();
+
+        assert!(verification_is_satisfied(&[public_key], &[&message], &signature));
+
+        let wrong_message: [u8; 32] = rng.gen();
+        assert!(!verification_is_satisfied(&[public_key], &[&wrong_message], &signature));
+    }
+
+    #[test]
+    fn test_bls_aggregate_verification_gadget() {
+        let rng = &mut thread_rng();
+        let bls = TestBLSSignature::setup(rng).unwrap();
+
+        let messages: Vec<[u8; 32]> = (0..2).map(|_| rng.gen()).collect();
+        let mut public_keys = vec![];
+        let mut signatures = vec![];
+        for message in &messages {
+            let private_key = bls.generate_private_key(rng).unwrap();
+            public_keys.push(bls.generate_public_key(&private_key).unwrap());
+            signatures.push(bls.sign(&private_key, message, rng).unwrap());
+        }
+        let signature = TestBLSSignature::aggregate_signatures(&signatures);
+        let messages = messages.iter().map(|m| &m[..]).collect::<Vec<_>>();
+
+        assert!(verification_is_satisfied(&public_keys, &messages, &signature));
+
+        let reversed = messages.iter().rev().cloned().collect::<Vec<_>>();
+        assert!(!verification_is_satisfied(&public_keys, &reversed, &signature));
+    }
+}
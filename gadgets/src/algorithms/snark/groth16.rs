@@ -716,4 +716,75 @@ mod test {
             assert_eq!(verifier_gadget_constraints, VERIFIER_GADGET_CONSTRAINTS);
         }
     }
+
+    #[test]
+    fn groth16_bw6_761_verifier_test() {
+        use crate::curves::bw6_761::PairingGadget as BW6_761PairingGadget;
+        use snarkvm_curves::bw6_761::{Fq as BW6Fq, Fr as BW6Fr, BW6_761};
+
+        type BW6ProofSystem = Groth16<BW6_761, Bench<BW6Fr>, BW6Fr>;
+        type BW6VerifierGadget = Groth16VerifierGadget<BW6_761, BW6Fq, BW6_761PairingGadget>;
+        type BW6ProofGadget = ProofGadget<BW6_761, BW6Fq, BW6_761PairingGadget>;
+        type BW6VkGadget = VerifyingKeyGadget<BW6_761, BW6Fq, BW6_761PairingGadget>;
+
+        let num_inputs = 4;
+        let num_constraints = num_inputs;
+        let rng = &mut test_rng();
+        let mut inputs: Vec<Option<BW6Fr>> = Vec::with_capacity(num_inputs);
+        for _ in 0..num_inputs {
+            inputs.push(Some(rng.gen()));
+        }
+        let params = {
+            let c = Bench::<BW6Fr> {
+                inputs: vec![None; num_inputs],
+                num_constraints,
+            };
+
+            generate_random_parameters::<BW6_761, _, _>(&c, rng).unwrap()
+        };
+
+        let proof = {
+            let c = Bench {
+                inputs: inputs.clone(),
+                num_constraints,
+            };
+            create_random_proof(&c, &params, rng).unwrap()
+        };
+
+        let mut cs = TestConstraintSystem::<BW6Fq>::new();
+
+        let inputs = inputs.into_iter().map(|input| input.unwrap());
+        let mut input_gadgets = Vec::new();
+
+        {
+            let mut cs = cs.ns(|| "Allocate Input");
+            for (i, input) in inputs.enumerate() {
+                let mut input_bits = BitIteratorBE::new(input.into_repr()).collect::<Vec<_>>();
+                // Input must be in little-endian, but BitIterator outputs in big-endian.
+                input_bits.reverse();
+
+                let input_bits =
+                    Vec::<Boolean>::alloc_input(cs.ns(|| format!("Input {}", i)), || Ok(input_bits)).unwrap();
+                input_gadgets.push(input_bits);
+            }
+        }
+
+        let vk_gadget = BW6VkGadget::alloc_input(cs.ns(|| "Vk"), || Ok(&params.vk)).unwrap();
+        let proof_gadget = BW6ProofGadget::alloc(cs.ns(|| "Proof"), || Ok(proof.clone())).unwrap();
+        <BW6VerifierGadget as SNARKVerifierGadget<BW6ProofSystem, BW6Fq>>::check_verify(
+            cs.ns(|| "Verify"),
+            &vk_gadget,
+            input_gadgets.iter().cloned(),
+            &proof_gadget,
+        )
+        .unwrap();
+        if !cs.is_satisfied() {
+            println!("=========================================================");
+            println!("Unsatisfied constraints:");
+            println!("{:?}", cs.which_is_unsatisfied().unwrap());
+            println!("=========================================================");
+        }
+
+        assert!(cs.is_satisfied());
+    }
 }
@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    curves::templates::bw6::{
+        BW6PairingGadget,
+        G1Gadget as BW6G1Gadget,
+        G1PreparedGadget as BW6G1PreparedGadget,
+        G2Gadget as BW6G2Gadget,
+        G2PreparedGadget as BW6G2PreparedGadget,
+    },
+    fields::{fp6_2over3::Fp6Gadget, Fp3Gadget, FpGadget},
+};
+use snarkvm_curves::bw6_761::{BW6_761Parameters, Fq, Fq3Parameters, Fq6Parameters};
+
+pub type FqGadget = FpGadget<Fq>;
+pub type Fq3Gadget = Fp3Gadget<Fq3Parameters, Fq>;
+pub type Fq6Gadget = Fp6Gadget<Fq6Parameters, Fq>;
+pub type G1Gadget = BW6G1Gadget<BW6_761Parameters>;
+pub type G1PreparedGadget = BW6G1PreparedGadget<BW6_761Parameters>;
+pub type G2Gadget = BW6G2Gadget<BW6_761Parameters>;
+pub type G2PreparedGadget = BW6G2PreparedGadget<BW6_761Parameters>;
+pub type PairingGadget = BW6PairingGadget<BW6_761Parameters>;
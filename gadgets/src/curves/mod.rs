@@ -15,8 +15,15 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod bls12_377;
+pub mod bls12_381;
+pub mod bn254;
+pub mod bw6_761;
 pub mod edwards_bls12;
+pub mod edwards_bls12_381;
+pub mod edwards_bn254;
 pub mod edwards_sw6;
+pub mod pallas;
+pub mod vesta;
 
 #[cfg(test)]
 pub mod tests_curve;
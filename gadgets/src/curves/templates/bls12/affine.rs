@@ -418,6 +418,20 @@ where
 impl<P: SWModelParameters, F: PrimeField, FG: FieldGadget<P::BaseField, F>> AllocGadget<SWProjective<P>, F>
     for AffineGadget<P, F, FG>
 {
+    #[inline]
+    fn alloc_constant<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SWProjective<P>>,
+    {
+        let ge = value_gen()?.borrow().into_affine();
+
+        let x = FG::alloc_constant(&mut cs.ns(|| "x"), || Ok(ge.x))?;
+        let y = FG::alloc_constant(&mut cs.ns(|| "y"), || Ok(ge.y))?;
+
+        Ok(Self::new(x, y, Boolean::Constant(ge.infinity)))
+    }
+
     #[inline]
     fn alloc<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
     where
@@ -17,7 +17,7 @@
 use std::{borrow::Borrow, fmt::Debug};
 
 use snarkvm_curves::templates::bls12::{Bls12Parameters, G2Prepared, TwistType};
-use snarkvm_fields::{batch_inversion, Field, One};
+use snarkvm_fields::{batch_inversion, Field, Fp2, One};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
 use snarkvm_utilities::bititerator::BitIteratorBE;
 
@@ -67,6 +67,29 @@ impl<P: Bls12Parameters> ToBytesGadget<P::Fp> for G2PreparedGadget<P> {
 }
 
 impl<P: Bls12Parameters> G2PreparedGadget<P> {
+    /// Scales each native line coefficient triplet so that the coefficient
+    /// multiplied by `p.y` is one, matching the pair of coefficients used by `ell`.
+    fn normalize_coeffs(
+        projective_coeffs: &[(Fp2<P::Fp2Params>, Fp2<P::Fp2Params>, Fp2<P::Fp2Params>)],
+    ) -> Vec<(Fp2<P::Fp2Params>, Fp2<P::Fp2Params>)> {
+        let mut z_s = projective_coeffs
+            .iter()
+            .map(|(c0, _, c2)| match P::TWIST_TYPE {
+                TwistType::M => *c2,
+                TwistType::D => *c0,
+            })
+            .collect::<Vec<_>>();
+        batch_inversion(&mut z_s);
+        projective_coeffs
+            .iter()
+            .zip(z_s)
+            .map(|((c0, c1, c2), z_inv)| match P::TWIST_TYPE {
+                TwistType::M => (*c0 * z_inv, *c1 * z_inv),
+                TwistType::D => (*c1 * z_inv, *c2 * z_inv),
+            })
+            .collect()
+    }
+
     pub fn from_affine<CS: ConstraintSystem<P::Fp>>(mut cs: CS, q: G2Gadget<P>) -> Result<Self, SynthesisError> {
         let two_inv = P::Fp::one().double().inverse().unwrap();
         let zero = G2Gadget::<P>::zero(cs.ns(|| "zero"))?;
@@ -152,16 +175,7 @@ impl<P: Bls12Parameters> AllocGadget<G2Prepared<P>, <P as Bls12Parameters>::Fp>
         mut cs: CS,
         value_gen: Fn,
     ) -> Result<Self, SynthesisError> {
-        let g2_prep = value_gen().map(|b| {
-            let projective_coeffs = &b.borrow().ell_coeffs;
-            let mut z_s = projective_coeffs.iter().map(|(_, _, z)| *z).collect::<Vec<_>>();
-            batch_inversion(&mut z_s);
-            projective_coeffs
-                .iter()
-                .zip(z_s)
-                .map(|((x, y, _), z_inv)| (*x * z_inv, *y * z_inv))
-                .collect::<Vec<_>>()
-        })?;
+        let g2_prep = value_gen().map(|b| Self::normalize_coeffs(&b.borrow().ell_coeffs))?;
 
         let mut l = Vec::new();
         let mut r = Vec::new();
@@ -188,16 +202,7 @@ impl<P: Bls12Parameters> AllocGadget<G2Prepared<P>, <P as Bls12Parameters>::Fp>
         mut cs: CS,
         value_gen: Fn,
     ) -> Result<Self, SynthesisError> {
-        let g2_prep = value_gen().map(|b| {
-            let projective_coeffs = &b.borrow().ell_coeffs;
-            let mut z_s = projective_coeffs.iter().map(|(_, _, z)| *z).collect::<Vec<_>>();
-            batch_inversion(&mut z_s);
-            projective_coeffs
-                .iter()
-                .zip(z_s)
-                .map(|((x, y, _), z_inv)| (*x * z_inv, *y * z_inv))
-                .collect::<Vec<_>>()
-        })?;
+        let g2_prep = value_gen().map(|b| Self::normalize_coeffs(&b.borrow().ell_coeffs))?;
 
         let mut l = Vec::new();
         let mut r = Vec::new();
@@ -224,16 +229,7 @@ impl<P: Bls12Parameters> AllocGadget<G2Prepared<P>, <P as Bls12Parameters>::Fp>
         mut cs: CS,
         value_gen: Fn,
     ) -> Result<Self, SynthesisError> {
-        let g2_prep = value_gen().map(|b| {
-            let projective_coeffs = &b.borrow().ell_coeffs;
-            let mut z_s = projective_coeffs.iter().map(|(_, _, z)| *z).collect::<Vec<_>>();
-            batch_inversion(&mut z_s);
-            projective_coeffs
-                .iter()
-                .zip(z_s)
-                .map(|((x, y, _), z_inv)| (*x * z_inv, *y * z_inv))
-                .collect::<Vec<_>>()
-        })?;
+        let g2_prep = value_gen().map(|b| Self::normalize_coeffs(&b.borrow().ell_coeffs))?;
 
         let mut l = Vec::new();
         let mut r = Vec::new();
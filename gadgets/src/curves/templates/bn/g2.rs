@@ -0,0 +1,304 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, fmt::Debug};
+
+use snarkvm_curves::templates::bn::{BnParameters, G2Prepared, TwistType};
+use snarkvm_fields::{batch_inversion, Field, Fp2, One};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    curves::templates::bls12::AffineGadget,
+    fields::Fp2Gadget,
+    integers::uint::UInt8,
+    traits::{
+        alloc::AllocGadget,
+        curves::GroupGadget,
+        eq::{ConditionalEqGadget, EqGadget, NEqGadget},
+        fields::FieldGadget,
+    },
+};
+
+pub type G2Gadget<P> = AffineGadget<<P as BnParameters>::G2Parameters, <P as BnParameters>::Fp, Fp2G<P>>;
+
+type Fp2G<P> = Fp2Gadget<<P as BnParameters>::Fp2Params, <P as BnParameters>::Fp>;
+type LCoeff<P> = (Fp2G<P>, Fp2G<P>);
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "Fp2Gadget<P::Fp2Params, P::Fp>: Clone"),
+    Debug(bound = "Fp2Gadget<P::Fp2Params, P::Fp>: Debug"),
+    PartialEq(bound = "Fp2Gadget<P::Fp2Params, P::Fp>: Debug"),
+    Eq(bound = "Fp2Gadget<P::Fp2Params, P::Fp>: Debug")
+)]
+pub struct G2PreparedGadget<P: BnParameters> {
+    pub ell_coeffs: Vec<LCoeff<P>>,
+}
+
+impl<P: BnParameters> ToBytesGadget<P::Fp> for G2PreparedGadget<P> {
+    #[inline]
+    fn to_bytes<CS: ConstraintSystem<P::Fp>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut bytes = Vec::new();
+        for (i, coeffs) in self.ell_coeffs.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("Iteration {}", i));
+            bytes.extend_from_slice(&coeffs.0.to_bytes(&mut cs.ns(|| "c0"))?);
+            bytes.extend_from_slice(&coeffs.1.to_bytes(&mut cs.ns(|| "c1"))?);
+        }
+        Ok(bytes)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<P::Fp>>(&self, cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.to_bytes(cs)
+    }
+}
+
+impl<P: BnParameters> G2PreparedGadget<P> {
+    /// Scales each native line coefficient triplet so that the coefficient
+    /// multiplied by `p.y` is one, matching the pair of coefficients used by `ell`.
+    fn normalize_coeffs(
+        projective_coeffs: &[(Fp2<P::Fp2Params>, Fp2<P::Fp2Params>, Fp2<P::Fp2Params>)],
+    ) -> Vec<(Fp2<P::Fp2Params>, Fp2<P::Fp2Params>)> {
+        let mut z_s = projective_coeffs
+            .iter()
+            .map(|(c0, _, c2)| match P::TWIST_TYPE {
+                TwistType::M => *c2,
+                TwistType::D => *c0,
+            })
+            .collect::<Vec<_>>();
+        batch_inversion(&mut z_s);
+        projective_coeffs
+            .iter()
+            .zip(z_s)
+            .map(|((c0, c1, c2), z_inv)| match P::TWIST_TYPE {
+                TwistType::M => (*c0 * z_inv, *c1 * z_inv),
+                TwistType::D => (*c1 * z_inv, *c2 * z_inv),
+            })
+            .collect()
+    }
+
+    pub fn from_affine<CS: ConstraintSystem<P::Fp>>(mut cs: CS, q: G2Gadget<P>) -> Result<Self, SynthesisError> {
+        let two_inv = P::Fp::one().double().inverse().unwrap();
+        let zero = G2Gadget::<P>::zero(cs.ns(|| "zero"))?;
+        q.enforce_not_equal(cs.ns(|| "enforce not zero"), &zero)?;
+        let mut ell_coeffs = Vec::with_capacity(P::ATE_LOOP_COUNT.len() * 2);
+        let mut r = q.clone();
+        let neg_q = q.negate(cs.ns(|| "negate q"))?;
+
+        for i in (1..P::ATE_LOOP_COUNT.len()).rev() {
+            let mut cs = cs.ns(|| format!("Iteration {}", i));
+            ell_coeffs.push(Self::double(cs.ns(|| "double"), &mut r, &two_inv)?);
+
+            match P::ATE_LOOP_COUNT[i - 1] {
+                1 => ell_coeffs.push(Self::add(cs.ns(|| "add"), &mut r, &q)?),
+                -1 => ell_coeffs.push(Self::add(cs.ns(|| "add neg"), &mut r, &neg_q)?),
+                _ => continue,
+            }
+        }
+
+        let q1 = Self::mul_by_char(cs.ns(|| "q1 = psi(q)"), &q)?;
+        let mut q2 = Self::mul_by_char(cs.ns(|| "q2 = psi(q1)"), &q1)?;
+
+        if P::X_IS_NEGATIVE {
+            r.y.negate_in_place(cs.ns(|| "negate r.y"))?;
+        }
+
+        q2.y.negate_in_place(cs.ns(|| "negate q2.y"))?;
+
+        ell_coeffs.push(Self::add(cs.ns(|| "add q1"), &mut r, &q1)?);
+        ell_coeffs.push(Self::add(cs.ns(|| "add q2"), &mut r, &q2)?);
+
+        Ok(Self { ell_coeffs })
+    }
+
+    /// Returns the image of `q` under the Frobenius endomorphism, computed on the twist.
+    fn mul_by_char<CS: ConstraintSystem<P::Fp>>(mut cs: CS, q: &G2Gadget<P>) -> Result<G2Gadget<P>, SynthesisError> {
+        let x =
+            q.x.frobenius_map(cs.ns(|| "frobenius x"), 1)?
+                .mul_by_constant(cs.ns(|| "mul x by twist"), &P::TWIST_MUL_BY_Q_X)?;
+        let y =
+            q.y.frobenius_map(cs.ns(|| "frobenius y"), 1)?
+                .mul_by_constant(cs.ns(|| "mul y by twist"), &P::TWIST_MUL_BY_Q_Y)?;
+        Ok(G2Gadget::<P>::new(x, y, q.infinity))
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn double<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        r: &mut G2Gadget<P>,
+        two_inv: &P::Fp,
+    ) -> Result<LCoeff<P>, SynthesisError> {
+        let a = r.y.inverse(cs.ns(|| "Inverse"))?;
+        let mut b = r.x.square(cs.ns(|| "square x"))?;
+        let b_tmp = b.clone();
+        b.mul_by_fp_constant_in_place(cs.ns(|| "mul by two_inv"), two_inv)?;
+        b.add_in_place(cs.ns(|| "compute b"), &b_tmp)?;
+
+        let c = a.mul(cs.ns(|| "compute c"), &b)?;
+        let d = r.x.double(cs.ns(|| "compute d"))?;
+        let x3 = c.square(cs.ns(|| "c^2"))?.sub(cs.ns(|| "sub d"), &d)?;
+        let e = c.mul(cs.ns(|| "c*r.x"), &r.x)?.sub(cs.ns(|| "sub r.y"), &r.y)?;
+        let c_x3 = c.mul(cs.ns(|| "c*x_3"), &x3)?;
+        let y3 = e.sub(cs.ns(|| "e = c * x3"), &c_x3)?;
+        let mut f = c;
+        f.negate_in_place(cs.ns(|| "c = -c"))?;
+        r.x = x3;
+        r.y = y3;
+        match P::TWIST_TYPE {
+            TwistType::M => Ok((e, f)),
+            TwistType::D => Ok((f, e)),
+        }
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn add<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        r: &mut G2Gadget<P>,
+        q: &G2Gadget<P>,
+    ) -> Result<LCoeff<P>, SynthesisError> {
+        let a = q.x.sub(cs.ns(|| "q.x - r.x"), &r.x)?.inverse(cs.ns(|| "calc a"))?;
+        let b = q.y.sub(cs.ns(|| "q.y - r.y"), &r.y)?;
+        let c = a.mul(cs.ns(|| "compute c"), &b)?;
+        let d = r.x.add(cs.ns(|| "r.x + q.x"), &q.x)?;
+        let x3 = c.square(cs.ns(|| "c^2"))?.sub(cs.ns(|| "sub d"), &d)?;
+
+        let e =
+            r.x.sub(cs.ns(|| "r.x - x3"), &x3)?
+                .mul(cs.ns(|| "c * (r.x - x3)"), &c)?;
+        let y3 = e.sub(cs.ns(|| "calc y3"), &r.y)?;
+        let g = c.mul(cs.ns(|| "c*r.x"), &r.x)?.sub(cs.ns(|| "calc g"), &r.y)?;
+        let mut f = c;
+        f.negate_in_place(cs.ns(|| "c = -c"))?;
+        r.x = x3;
+        r.y = y3;
+        match P::TWIST_TYPE {
+            TwistType::M => Ok((g, f)),
+            TwistType::D => Ok((f, g)),
+        }
+    }
+}
+
+impl<P: BnParameters> AllocGadget<G2Prepared<P>, <P as BnParameters>::Fp> for G2PreparedGadget<P> {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<G2Prepared<P>>,
+        CS: ConstraintSystem<<P as BnParameters>::Fp>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let g2_prep = value_gen().map(|b| Self::normalize_coeffs(&b.borrow().ell_coeffs))?;
+
+        let mut l = Vec::new();
+        let mut r = Vec::new();
+
+        for (i, (l_elem, _)) in g2_prep.iter().enumerate() {
+            let elem = Fp2G::<P>::alloc_constant(cs.ns(|| format!("l_{}", i)), || Ok(l_elem))?;
+            l.push(elem);
+        }
+
+        for (i, (_, r_elem)) in g2_prep.iter().enumerate() {
+            let elem = Fp2G::<P>::alloc_constant(cs.ns(|| format!("r_{}", i)), || Ok(r_elem))?;
+            r.push(elem);
+        }
+
+        let ell_coeffs = l.into_iter().zip(r).collect();
+        Ok(Self { ell_coeffs })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<G2Prepared<P>>,
+        CS: ConstraintSystem<<P as BnParameters>::Fp>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let g2_prep = value_gen().map(|b| Self::normalize_coeffs(&b.borrow().ell_coeffs))?;
+
+        let mut l = Vec::new();
+        let mut r = Vec::new();
+
+        for (i, (l_elem, _)) in g2_prep.iter().enumerate() {
+            let elem = Fp2G::<P>::alloc(cs.ns(|| format!("l_{}", i)), || Ok(l_elem))?;
+            l.push(elem);
+        }
+
+        for (i, (_, r_elem)) in g2_prep.iter().enumerate() {
+            let elem = Fp2G::<P>::alloc(cs.ns(|| format!("r_{}", i)), || Ok(r_elem))?;
+            r.push(elem);
+        }
+
+        let ell_coeffs = l.into_iter().zip(r).collect();
+        Ok(Self { ell_coeffs })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<G2Prepared<P>>,
+        CS: ConstraintSystem<<P as BnParameters>::Fp>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let g2_prep = value_gen().map(|b| Self::normalize_coeffs(&b.borrow().ell_coeffs))?;
+
+        let mut l = Vec::new();
+        let mut r = Vec::new();
+
+        for (i, (l_elem, _)) in g2_prep.iter().enumerate() {
+            let elem = Fp2G::<P>::alloc_input(cs.ns(|| format!("l_{}", i)), || Ok(l_elem))?;
+            l.push(elem);
+        }
+
+        for (i, (_, r_elem)) in g2_prep.iter().enumerate() {
+            let elem = Fp2G::<P>::alloc_input(cs.ns(|| format!("r_{}", i)), || Ok(r_elem))?;
+            r.push(elem);
+        }
+
+        let ell_coeffs = l.into_iter().zip(r).collect();
+        Ok(Self { ell_coeffs })
+    }
+}
+
+impl<P: BnParameters> EqGadget<<P as BnParameters>::Fp> for G2PreparedGadget<P> {}
+
+impl<P: BnParameters> ConditionalEqGadget<<P as BnParameters>::Fp> for G2PreparedGadget<P> {
+    fn conditional_enforce_equal<CS: ConstraintSystem<P::Fp>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        for (i, (l_coeff, other_l_coeff)) in self.ell_coeffs.iter().zip(&other.ell_coeffs).enumerate() {
+            l_coeff.0.conditional_enforce_equal(
+                cs.ns(|| format!("enforce_equal_l_coeff_0_{}", i)),
+                &other_l_coeff.0,
+                condition,
+            )?;
+            l_coeff.1.conditional_enforce_equal(
+                cs.ns(|| format!("enforce_equal_l_coeff_1_{}", i)),
+                &other_l_coeff.1,
+                condition,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        unimplemented!()
+    }
+}
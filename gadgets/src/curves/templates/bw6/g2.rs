@@ -0,0 +1,330 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, fmt::Debug};
+
+use snarkvm_curves::{
+    templates::bw6::{BW6Parameters, G2Prepared, TwistType},
+    traits::SWModelParameters,
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
+use crate::{
+    bits::{Boolean, ToBytesGadget},
+    curves::templates::bls12::AffineGadget,
+    fields::FpGadget,
+    integers::uint::UInt8,
+    traits::{
+        alloc::AllocGadget,
+        curves::GroupGadget,
+        eq::{ConditionalEqGadget, EqGadget, NEqGadget},
+        fields::FieldGadget,
+    },
+};
+
+pub type G2Gadget<P> = AffineGadget<<P as BW6Parameters>::G2Parameters, <P as BW6Parameters>::Fp, FpG<P>>;
+
+type FpG<P> = FpGadget<<P as BW6Parameters>::Fp>;
+type LCoeff<P> = (FpG<P>, FpG<P>, FpG<P>);
+
+/// Allocates the given line coefficients using the given allocation method of `FpGadget`.
+macro_rules! alloc_ell_coeffs {
+    ($cs: expr, $coeffs: expr, $name: expr, $alloc: ident) => {
+        $coeffs
+            .iter()
+            .enumerate()
+            .map(|(i, (c0, c1, c2))| {
+                let c0 = FpG::<P>::$alloc($cs.ns(|| format!("{}_{}_c0", $name, i)), || Ok(c0))?;
+                let c1 = FpG::<P>::$alloc($cs.ns(|| format!("{}_{}_c1", $name, i)), || Ok(c1))?;
+                let c2 = FpG::<P>::$alloc($cs.ns(|| format!("{}_{}_c2", $name, i)), || Ok(c2))?;
+                Ok((c0, c1, c2))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()
+    };
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "FpGadget<P::Fp>: Clone"),
+    Debug(bound = "FpGadget<P::Fp>: Debug"),
+    PartialEq(bound = "FpGadget<P::Fp>: Debug"),
+    Eq(bound = "FpGadget<P::Fp>: Debug")
+)]
+pub struct G2PreparedGadget<P: BW6Parameters> {
+    pub ell_coeffs_1: Vec<LCoeff<P>>,
+    pub ell_coeffs_2: Vec<LCoeff<P>>,
+}
+
+/// A point of G2 in homogeneous projective coordinates.
+struct G2HomProjectiveGadget<P: BW6Parameters> {
+    x: FpG<P>,
+    y: FpG<P>,
+    z: FpG<P>,
+}
+
+impl<P: BW6Parameters> ToBytesGadget<P::Fp> for G2PreparedGadget<P> {
+    #[inline]
+    fn to_bytes<CS: ConstraintSystem<P::Fp>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut bytes = Vec::new();
+        for (i, coeffs) in self.ell_coeffs_1.iter().chain(&self.ell_coeffs_2).enumerate() {
+            let mut cs = cs.ns(|| format!("Iteration {}", i));
+            bytes.extend_from_slice(&coeffs.0.to_bytes(&mut cs.ns(|| "c0"))?);
+            bytes.extend_from_slice(&coeffs.1.to_bytes(&mut cs.ns(|| "c1"))?);
+            bytes.extend_from_slice(&coeffs.2.to_bytes(&mut cs.ns(|| "c2"))?);
+        }
+        Ok(bytes)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<P::Fp>>(&self, cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        self.to_bytes(cs)
+    }
+}
+
+impl<P: BW6Parameters> G2PreparedGadget<P> {
+    pub fn from_affine<CS: ConstraintSystem<P::Fp>>(mut cs: CS, q: G2Gadget<P>) -> Result<Self, SynthesisError> {
+        let zero = G2Gadget::<P>::zero(cs.ns(|| "zero"))?;
+        q.enforce_not_equal(cs.ns(|| "enforce not zero"), &zero)?;
+
+        // f_{u+1,Q}(P)
+        let bit_iterator = BitIteratorBE::new(P::ATE_LOOP_COUNT_1);
+        let mut ell_coeffs_1 = Vec::with_capacity(bit_iterator.len());
+        let mut r = G2HomProjectiveGadget::<P>::from_affine(cs.ns(|| "r_1"), &q)?;
+
+        for (j, i) in bit_iterator.skip(1).enumerate() {
+            let mut cs = cs.ns(|| format!("Loop 1 iteration {}", j));
+            ell_coeffs_1.push(Self::double(cs.ns(|| "double"), &mut r)?);
+
+            if i {
+                ell_coeffs_1.push(Self::add(cs.ns(|| "add"), &mut r, &q)?);
+            }
+        }
+
+        // f_{u^3-u^2-u,Q}(P)
+        let mut ell_coeffs_2 = Vec::with_capacity(P::ATE_LOOP_COUNT_2.len());
+        let mut r = G2HomProjectiveGadget::<P>::from_affine(cs.ns(|| "r_2"), &q)?;
+        let neg_q = q.negate(cs.ns(|| "negate q"))?;
+
+        for i in (1..P::ATE_LOOP_COUNT_2.len()).rev() {
+            let mut cs = cs.ns(|| format!("Loop 2 iteration {}", i));
+            ell_coeffs_2.push(Self::double(cs.ns(|| "double"), &mut r)?);
+
+            match P::ATE_LOOP_COUNT_2[i - 1] {
+                1 => ell_coeffs_2.push(Self::add(cs.ns(|| "add q"), &mut r, &q)?),
+                -1 => ell_coeffs_2.push(Self::add(cs.ns(|| "add -q"), &mut r, &neg_q)?),
+                _ => continue,
+            }
+        }
+
+        Ok(Self {
+            ell_coeffs_1,
+            ell_coeffs_2,
+        })
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn double<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        r: &mut G2HomProjectiveGadget<P>,
+    ) -> Result<LCoeff<P>, SynthesisError> {
+        // Formula for line function when working with
+        // homogeneous projective coordinates, as described in https://eprint.iacr.org/2013/722.pdf.
+        let a = r.x.mul(cs.ns(|| "a"), &r.y)?;
+        let b = r.y.square(cs.ns(|| "b"))?;
+        let b4 = b.double(cs.ns(|| "2b"))?.double(cs.ns(|| "4b"))?;
+        let c = r.z.square(cs.ns(|| "c"))?;
+        let e = c
+            .mul_by_constant(cs.ns(|| "3c"), &P::Fp::from(3u64))?
+            .mul_by_constant(cs.ns(|| "e"), &P::G2Parameters::COEFF_B)?;
+        let f = e.mul_by_constant(cs.ns(|| "f"), &P::Fp::from(3u64))?;
+        let g = b.add(cs.ns(|| "g"), &f)?;
+        let h = {
+            let b_plus_c = b.add(cs.ns(|| "b + c"), &c)?;
+            r.y.add(cs.ns(|| "y + z"), &r.z)?
+                .square(cs.ns(|| "(y + z)^2"))?
+                .sub(cs.ns(|| "h"), &b_plus_c)?
+        };
+        let i = e.sub(cs.ns(|| "i"), &b)?;
+        let j = r.x.square(cs.ns(|| "j"))?;
+        let e2_square = e.double(cs.ns(|| "2e"))?.square(cs.ns(|| "(2e)^2"))?;
+
+        r.x = {
+            let b_minus_f = b.sub(cs.ns(|| "b - f"), &f)?;
+            a.double(cs.ns(|| "2a"))?.mul(cs.ns(|| "x3"), &b_minus_f)?
+        };
+        r.y = {
+            let e2_square_3 = e2_square.mul_by_constant(cs.ns(|| "3 * (2e)^2"), &P::Fp::from(3u64))?;
+            g.square(cs.ns(|| "g^2"))?.sub(cs.ns(|| "y3"), &e2_square_3)?
+        };
+        r.z = b4.mul(cs.ns(|| "z3"), &h)?;
+
+        let j3 = j.mul_by_constant(cs.ns(|| "3j"), &P::Fp::from(3u64))?;
+        let neg_h = h.negate(cs.ns(|| "-h"))?;
+        match P::TWIST_TYPE {
+            TwistType::M => Ok((i, j3, neg_h)),
+            TwistType::D => Ok((neg_h, j3, i)),
+        }
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn add<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        r: &mut G2HomProjectiveGadget<P>,
+        q: &G2Gadget<P>,
+    ) -> Result<LCoeff<P>, SynthesisError> {
+        // Formula for line function when working with
+        // homogeneous projective coordinates, as described in https://eprint.iacr.org/2013/722.pdf.
+        let theta = {
+            let qy_rz = q.y.mul(cs.ns(|| "q.y * r.z"), &r.z)?;
+            r.y.sub(cs.ns(|| "theta"), &qy_rz)?
+        };
+        let lambda = {
+            let qx_rz = q.x.mul(cs.ns(|| "q.x * r.z"), &r.z)?;
+            r.x.sub(cs.ns(|| "lambda"), &qx_rz)?
+        };
+        let c = theta.square(cs.ns(|| "c"))?;
+        let d = lambda.square(cs.ns(|| "d"))?;
+        let e = lambda.mul(cs.ns(|| "e"), &d)?;
+        let f = r.z.mul(cs.ns(|| "f"), &c)?;
+        let g = r.x.mul(cs.ns(|| "g"), &d)?;
+        let h = {
+            let g2 = g.double(cs.ns(|| "2g"))?;
+            e.add(cs.ns(|| "e + f"), &f)?.sub(cs.ns(|| "h"), &g2)?
+        };
+
+        r.x = lambda.mul(cs.ns(|| "x3"), &h)?;
+        r.y = {
+            let e_ry = e.mul(cs.ns(|| "e * r.y"), &r.y)?;
+            g.sub(cs.ns(|| "g - h"), &h)?
+                .mul(cs.ns(|| "theta * (g - h)"), &theta)?
+                .sub(cs.ns(|| "y3"), &e_ry)?
+        };
+        r.z = r.z.mul(cs.ns(|| "z3"), &e)?;
+
+        let j = {
+            let lambda_qy = lambda.mul(cs.ns(|| "lambda * q.y"), &q.y)?;
+            theta
+                .mul(cs.ns(|| "theta * q.x"), &q.x)?
+                .sub(cs.ns(|| "j"), &lambda_qy)?
+        };
+        let neg_theta = theta.negate(cs.ns(|| "-theta"))?;
+
+        match P::TWIST_TYPE {
+            TwistType::M => Ok((j, neg_theta, lambda)),
+            TwistType::D => Ok((lambda, neg_theta, j)),
+        }
+    }
+}
+
+impl<P: BW6Parameters> G2HomProjectiveGadget<P> {
+    fn from_affine<CS: ConstraintSystem<P::Fp>>(mut cs: CS, q: &G2Gadget<P>) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            x: q.x.clone(),
+            y: q.y.clone(),
+            z: FpG::<P>::one(cs.ns(|| "z"))?,
+        })
+    }
+}
+
+impl<P: BW6Parameters> AllocGadget<G2Prepared<P>, <P as BW6Parameters>::Fp> for G2PreparedGadget<P> {
+    fn alloc_constant<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<G2Prepared<P>>,
+        CS: ConstraintSystem<<P as BW6Parameters>::Fp>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let g2_prep = value_gen()?;
+        let g2_prep = g2_prep.borrow();
+
+        Ok(Self {
+            ell_coeffs_1: alloc_ell_coeffs!(cs, g2_prep.ell_coeffs_1, "ell_coeffs_1", alloc_constant)?,
+            ell_coeffs_2: alloc_ell_coeffs!(cs, g2_prep.ell_coeffs_2, "ell_coeffs_2", alloc_constant)?,
+        })
+    }
+
+    fn alloc<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<G2Prepared<P>>,
+        CS: ConstraintSystem<<P as BW6Parameters>::Fp>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let g2_prep = value_gen()?;
+        let g2_prep = g2_prep.borrow();
+
+        Ok(Self {
+            ell_coeffs_1: alloc_ell_coeffs!(cs, g2_prep.ell_coeffs_1, "ell_coeffs_1", alloc)?,
+            ell_coeffs_2: alloc_ell_coeffs!(cs, g2_prep.ell_coeffs_2, "ell_coeffs_2", alloc)?,
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<G2Prepared<P>>,
+        CS: ConstraintSystem<<P as BW6Parameters>::Fp>,
+    >(
+        mut cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        let g2_prep = value_gen()?;
+        let g2_prep = g2_prep.borrow();
+
+        Ok(Self {
+            ell_coeffs_1: alloc_ell_coeffs!(cs, g2_prep.ell_coeffs_1, "ell_coeffs_1", alloc_input)?,
+            ell_coeffs_2: alloc_ell_coeffs!(cs, g2_prep.ell_coeffs_2, "ell_coeffs_2", alloc_input)?,
+        })
+    }
+}
+
+impl<P: BW6Parameters> EqGadget<<P as BW6Parameters>::Fp> for G2PreparedGadget<P> {}
+
+impl<P: BW6Parameters> ConditionalEqGadget<<P as BW6Parameters>::Fp> for G2PreparedGadget<P> {
+    fn conditional_enforce_equal<CS: ConstraintSystem<P::Fp>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let coeffs = self.ell_coeffs_1.iter().chain(&self.ell_coeffs_2);
+        let other_coeffs = other.ell_coeffs_1.iter().chain(&other.ell_coeffs_2);
+        for (i, (l_coeff, other_l_coeff)) in coeffs.zip(other_coeffs).enumerate() {
+            l_coeff.0.conditional_enforce_equal(
+                cs.ns(|| format!("enforce_equal_l_coeff_0_{}", i)),
+                &other_l_coeff.0,
+                condition,
+            )?;
+            l_coeff.1.conditional_enforce_equal(
+                cs.ns(|| format!("enforce_equal_l_coeff_1_{}", i)),
+                &other_l_coeff.1,
+                condition,
+            )?;
+            l_coeff.2.conditional_enforce_equal(
+                cs.ns(|| format!("enforce_equal_l_coeff_2_{}", i)),
+                &other_l_coeff.2,
+                condition,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        unimplemented!()
+    }
+}
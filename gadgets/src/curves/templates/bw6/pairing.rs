@@ -0,0 +1,305 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    curves::templates::bw6::{G1Gadget, G1PreparedGadget, G2Gadget, G2PreparedGadget},
+    fields::{fp6_2over3::Fp6Gadget, FpGadget},
+    traits::{curves::PairingGadget, fields::FieldGadget},
+};
+use snarkvm_curves::{
+    templates::bw6::{
+        BW6Parameters,
+        G1Affine,
+        G1Prepared,
+        G1Projective,
+        G2Affine,
+        G2Prepared,
+        G2Projective,
+        TwistType,
+        BW6,
+    },
+    traits::{ModelParameters, PairingCurve},
+};
+use snarkvm_fields::fp6_2over3::Fp6;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
+use std::marker::PhantomData;
+
+pub struct BW6PairingGadget<P: BW6Parameters>(PhantomData<P>);
+
+type FpG<P> = FpGadget<<P as BW6Parameters>::Fp>;
+type Fp6G<P> = Fp6Gadget<<P as BW6Parameters>::Fp6Params, <P as BW6Parameters>::Fp>;
+
+impl<P: BW6Parameters> BW6PairingGadget<P> {
+    // Evaluate the line function at point p.
+    fn ell<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        f: &mut Fp6G<P>,
+        coeffs: &(FpG<P>, FpG<P>, FpG<P>),
+        p: &G1Gadget<P>,
+    ) -> Result<(), SynthesisError> {
+        let c1 = coeffs.1.mul(cs.ns(|| "mul c1"), &p.x)?;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                let c2 = coeffs.2.mul(cs.ns(|| "mul c2"), &p.y)?;
+                *f = f.mul_by_014(cs.ns(|| "sparse mul f"), &coeffs.0, &c1, &c2)?;
+                Ok(())
+            }
+            TwistType::D => {
+                let c0 = coeffs.0.mul(cs.ns(|| "mul c0"), &p.y)?;
+                *f = f.mul_by_034(cs.ns(|| "sparse mul f"), &c0, &c1, &coeffs.2)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn exp_by_x<CS: ConstraintSystem<P::Fp>>(mut cs: CS, f: &Fp6G<P>) -> Result<Fp6G<P>, SynthesisError> {
+        let mut result = f.cyclotomic_exp(cs.ns(|| "exp_by_x"), P::X)?;
+        if P::X_IS_NEGATIVE {
+            result.conjugate_in_place(cs.ns(|| "conjugate"))?;
+        }
+        Ok(result)
+    }
+
+    fn frobenius<CS: ConstraintSystem<P::Fp>>(cs: CS, f: &Fp6G<P>) -> Result<Fp6G<P>, SynthesisError> {
+        f.frobenius_map(cs, 1)
+    }
+
+    fn product<CS: ConstraintSystem<P::Fp>>(mut cs: CS, factors: &[&Fp6G<P>]) -> Result<Fp6G<P>, SynthesisError> {
+        let mut result = factors[0].clone();
+        for (i, factor) in factors.iter().enumerate().skip(1) {
+            result.mul_in_place(cs.ns(|| format!("mul {}", i)), factor)?;
+        }
+        Ok(result)
+    }
+}
+
+impl<P: BW6Parameters> PairingGadget<BW6<P>, P::Fp> for BW6PairingGadget<P>
+where
+    G1Affine<P>: PairingCurve<
+        BaseField = <P::G1Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G1Projective<P>,
+        PairWith = G2Affine<P>,
+        Prepared = G1Prepared<P>,
+        PairingResult = Fp6<P::Fp6Params>,
+    >,
+    G2Affine<P>: PairingCurve<
+        BaseField = <P::G2Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G2Projective<P>,
+        PairWith = G1Affine<P>,
+        Prepared = G2Prepared<P>,
+        PairingResult = Fp6<P::Fp6Params>,
+    >,
+{
+    type G1Gadget = G1Gadget<P>;
+    type G1PreparedGadget = G1PreparedGadget<P>;
+    type G2Gadget = G2Gadget<P>;
+    type G2PreparedGadget = G2PreparedGadget<P>;
+    type GTGadget = Fp6G<P>;
+
+    fn miller_loop<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        ps: &[Self::G1PreparedGadget],
+        qs: &[Self::G2PreparedGadget],
+    ) -> Result<Self::GTGadget, SynthesisError> {
+        // Alg.5 in https://eprint.iacr.org/2020/351.pdf
+
+        let mut pairs_1 = Vec::with_capacity(ps.len());
+        let mut pairs_2 = Vec::with_capacity(ps.len());
+        for (p, q) in ps.iter().zip(qs.iter()) {
+            pairs_1.push((p, q.ell_coeffs_1.iter()));
+            pairs_2.push((p, q.ell_coeffs_2.iter()));
+        }
+
+        // f_{u+1,Q}(P)
+        let mut f_1 = Self::GTGadget::one(cs.ns(|| "one 1"))?;
+
+        for (j, i) in BitIteratorBE::new(P::ATE_LOOP_COUNT_1).skip(1).enumerate() {
+            let mut cs = cs.ns(|| format!("Loop 1 iteration {}", j));
+            f_1.square_in_place(cs.ns(|| "square"))?;
+
+            for (k, &mut (p, ref mut coeffs)) in pairs_1.iter_mut().enumerate() {
+                let cs = cs.ns(|| format!("Double input {}", k));
+                Self::ell(cs, &mut f_1, coeffs.next().unwrap(), &p.0)?;
+            }
+
+            if i {
+                for (k, &mut (p, ref mut coeffs)) in pairs_1.iter_mut().enumerate() {
+                    let cs = cs.ns(|| format!("Addition input {}", k));
+                    Self::ell(cs, &mut f_1, coeffs.next().unwrap(), &p.0)?;
+                }
+            }
+        }
+
+        if P::ATE_LOOP_COUNT_1_IS_NEGATIVE {
+            f_1.conjugate_in_place(cs.ns(|| "f_1 conjugate"))?;
+        }
+
+        // f_{u^3-u^2-u,Q}(P)
+        let mut f_2 = Self::GTGadget::one(cs.ns(|| "one 2"))?;
+
+        for i in (1..P::ATE_LOOP_COUNT_2.len()).rev() {
+            let mut cs = cs.ns(|| format!("Loop 2 iteration {}", i));
+            if i != P::ATE_LOOP_COUNT_2.len() - 1 {
+                f_2.square_in_place(cs.ns(|| "square"))?;
+            }
+
+            for (k, &mut (p, ref mut coeffs)) in pairs_2.iter_mut().enumerate() {
+                let cs = cs.ns(|| format!("Double input {}", k));
+                Self::ell(cs, &mut f_2, coeffs.next().unwrap(), &p.0)?;
+            }
+
+            if P::ATE_LOOP_COUNT_2[i - 1] != 0 {
+                for (k, &mut (p, ref mut coeffs)) in pairs_2.iter_mut().enumerate() {
+                    let cs = cs.ns(|| format!("Addition input {}", k));
+                    Self::ell(cs, &mut f_2, coeffs.next().unwrap(), &p.0)?;
+                }
+            }
+        }
+
+        if P::ATE_LOOP_COUNT_2_IS_NEGATIVE {
+            f_2.conjugate_in_place(cs.ns(|| "f_2 conjugate"))?;
+        }
+
+        f_2.frobenius_map_in_place(cs.ns(|| "f_2 frobenius"), 1)?;
+
+        f_1.mul(cs.ns(|| "f_1 * f_2"), &f_2)
+    }
+
+    fn final_exponentiation<CS: ConstraintSystem<P::Fp>>(
+        mut cs: CS,
+        f: &Self::GTGadget,
+    ) -> Result<Self::GTGadget, SynthesisError> {
+        // Easy part: f^((q^3-1)*(q+1))
+        let f = {
+            let mut cs = cs.ns(|| "easy part");
+            let f_inv = f.inverse(cs.ns(|| "inverse"))?;
+            // f_q3_over_f = f^(q^3-1)
+            let mut f_q3_over_f = f.clone();
+            f_q3_over_f.conjugate_in_place(cs.ns(|| "conjugate"))?;
+            f_q3_over_f.mul_in_place(cs.ns(|| "f^(q^3) * f^(-1)"), &f_inv)?;
+            // alpha = f^((q^3-1) * q)
+            let alpha = Self::frobenius(cs.ns(|| "frobenius"), &f_q3_over_f)?;
+            // beta = f^((q^3-1)*(q+1))
+            alpha.mul(cs.ns(|| "alpha * f^(q^3-1)"), &f_q3_over_f)?
+        };
+
+        // Hard part, from https://eprint.iacr.org/2020/351.pdf, Alg.6
+        // R0(x) := (-103*x^7 + 70*x^6 + 269*x^5 - 197*x^4 - 314*x^3 - 73*x^2 - 263*x - 220)
+        // R1(x) := (103*x^9 - 276*x^8 + 77*x^7 + 492*x^6 - 445*x^5 - 65*x^4 + 452*x^3 - 181*x^2 + 34*x + 229)
+        // f ^ R0(u) * (f ^ q) ^ R1(u) in a 2-NAF multi-exp fashion.
+        let mut cs = cs.ns(|| "hard part");
+
+        // steps 1,2,3
+        let f0 = f;
+        let f0p = Self::frobenius(cs.ns(|| "f0p"), &f0)?;
+        let f1 = Self::exp_by_x(cs.ns(|| "f1"), &f0)?;
+        let f1p = Self::frobenius(cs.ns(|| "f1p"), &f1)?;
+        let f2 = Self::exp_by_x(cs.ns(|| "f2"), &f1)?;
+        let f2p = Self::frobenius(cs.ns(|| "f2p"), &f2)?;
+        let f3 = Self::exp_by_x(cs.ns(|| "f3"), &f2)?;
+        let f3p = Self::frobenius(cs.ns(|| "f3p"), &f3)?;
+        let f4 = Self::exp_by_x(cs.ns(|| "f4"), &f3)?;
+        let f4p = Self::frobenius(cs.ns(|| "f4p"), &f4)?;
+        let f5 = Self::exp_by_x(cs.ns(|| "f5"), &f4)?;
+        let f5p = Self::frobenius(cs.ns(|| "f5p"), &f5)?;
+        let f6 = Self::exp_by_x(cs.ns(|| "f6"), &f5)?;
+        let f6p = Self::frobenius(cs.ns(|| "f6p"), &f6)?;
+        let f7 = Self::exp_by_x(cs.ns(|| "f7"), &f6)?;
+        let f7p = Self::frobenius(cs.ns(|| "f7p"), &f7)?;
+
+        // step 4
+        let f8p = Self::exp_by_x(cs.ns(|| "f8p"), &f7p)?;
+        let f9p = Self::exp_by_x(cs.ns(|| "f9p"), &f8p)?;
+
+        // step 5
+        let f5p_p3 = f5p.unitary_inverse(cs.ns(|| "f5p_p3"))?;
+        let result1 = Self::product(cs.ns(|| "result1"), &[&f3p, &f6p, &f5p_p3])?;
+
+        // step 6
+        let result2 = result1.square(cs.ns(|| "result2"))?;
+        let f4_2p = f4.mul(cs.ns(|| "f4_2p"), &f2p)?;
+        let tmp1_p3 =
+            Self::product(cs.ns(|| "tmp1"), &[&f0, &f1, &f3, &f4_2p, &f8p])?.unitary_inverse(cs.ns(|| "tmp1_p3"))?;
+        let result3 = Self::product(cs.ns(|| "result3"), &[&result2, &f5, &f0p, &tmp1_p3])?;
+
+        // step 7
+        let result4 = result3.square(cs.ns(|| "result4"))?;
+        let f7_p3 = f7.unitary_inverse(cs.ns(|| "f7_p3"))?;
+        let result5 = Self::product(cs.ns(|| "result5"), &[&result4, &f9p, &f7_p3])?;
+
+        // step 8
+        let result6 = result5.square(cs.ns(|| "result6"))?;
+        let f2_4p = f2.mul(cs.ns(|| "f2_4p"), &f4p)?;
+        let f4_2p_5p = f4_2p.mul(cs.ns(|| "f4_2p_5p"), &f5p)?;
+        let tmp2_p3 = Self::product(cs.ns(|| "tmp2"), &[&f2_4p, &f3, &f3p])?.unitary_inverse(cs.ns(|| "tmp2_p3"))?;
+        let result7 = Self::product(cs.ns(|| "result7"), &[&result6, &f4_2p_5p, &f6, &f7p, &tmp2_p3])?;
+
+        // step 9
+        let result8 = result7.square(cs.ns(|| "result8"))?;
+        let tmp3_p3 = f0p.mul(cs.ns(|| "tmp3"), &f9p)?.unitary_inverse(cs.ns(|| "tmp3_p3"))?;
+        let result9 = Self::product(cs.ns(|| "result9"), &[&result8, &f0, &f7, &f1p, &tmp3_p3])?;
+
+        // step 10
+        let result10 = result9.square(cs.ns(|| "result10"))?;
+        let f6p_8p = f6p.mul(cs.ns(|| "f6p_8p"), &f8p)?;
+        let f5_7p = f5.mul(cs.ns(|| "f5_7p"), &f7p)?;
+        let tmp4_p3 = f6p_8p.unitary_inverse(cs.ns(|| "tmp4_p3"))?;
+        let result11 = Self::product(cs.ns(|| "result11"), &[&result10, &f5_7p, &f2p, &tmp4_p3])?;
+
+        // step 11
+        let result12 = result11.square(cs.ns(|| "result12"))?;
+        let f3_6 = f3.mul(cs.ns(|| "f3_6"), &f6)?;
+        let f1_7 = f1.mul(cs.ns(|| "f1_7"), &f7)?;
+        let tmp5_p3 = f1_7.mul(cs.ns(|| "tmp5"), &f2)?.unitary_inverse(cs.ns(|| "tmp5_p3"))?;
+        let result13 = Self::product(cs.ns(|| "result13"), &[&result12, &f3_6, &f9p, &tmp5_p3])?;
+
+        // step 12
+        let result14 = result13.square(cs.ns(|| "result14"))?;
+        let tmp6_p3 =
+            Self::product(cs.ns(|| "tmp6"), &[&f4_2p, &f5_7p, &f6p_8p])?.unitary_inverse(cs.ns(|| "tmp6_p3"))?;
+        let result15 = Self::product(cs.ns(|| "result15"), &[&result14, &f0, &f0p, &f3p, &f5p, &tmp6_p3])?;
+
+        // step 13
+        let result16 = result15.square(cs.ns(|| "result16"))?;
+        let tmp7_p3 = f3_6.unitary_inverse(cs.ns(|| "tmp7_p3"))?;
+        let result17 = Self::product(cs.ns(|| "result17"), &[&result16, &f1p, &tmp7_p3])?;
+
+        // step 14
+        let result18 = result17.square(cs.ns(|| "result18"))?;
+        let tmp8_p3 =
+            Self::product(cs.ns(|| "tmp8"), &[&f2_4p, &f4_2p_5p, &f9p])?.unitary_inverse(cs.ns(|| "tmp8_p3"))?;
+        Self::product(cs.ns(|| "result"), &[&result18, &f1_7, &f5_7p, &f0p, &tmp8_p3])
+    }
+
+    fn prepare_g1<CS: ConstraintSystem<P::Fp>>(
+        cs: CS,
+        p: Self::G1Gadget,
+    ) -> Result<Self::G1PreparedGadget, SynthesisError> {
+        Self::G1PreparedGadget::from_affine(cs, p)
+    }
+
+    fn prepare_g2<CS: ConstraintSystem<P::Fp>>(
+        cs: CS,
+        q: Self::G2Gadget,
+    ) -> Result<Self::G2PreparedGadget, SynthesisError> {
+        Self::G2PreparedGadget::from_affine(cs, q)
+    }
+}
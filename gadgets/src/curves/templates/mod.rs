@@ -15,4 +15,6 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod bls12;
+pub mod bn;
+pub mod bw6;
 pub mod twisted_edwards;
@@ -18,7 +18,7 @@ use std::ops::Mul;
 
 use snarkvm_curves::{
     bls12_377::{Bls12_377, Fq, Fr, G1Projective, G2Projective},
-    traits::{PairingEngine, ProjectiveCurve},
+    traits::{PairingCurve, PairingEngine, ProjectiveCurve},
 };
 use snarkvm_fields::{Field, One, PrimeField};
 use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
@@ -103,3 +103,113 @@ fn bls12_377_gadget_bilinearity_test() {
 
     assert!(cs.is_satisfied(), "cs is not satisfied");
 }
+
+#[test]
+fn bw6_761_gadget_bilinearity_test() {
+    use crate::curves::bw6_761::{
+        G1Gadget as BW6G1Gadget,
+        G1PreparedGadget as BW6G1PreparedGadget,
+        G2Gadget as BW6G2Gadget,
+        G2PreparedGadget as BW6G2PreparedGadget,
+        PairingGadget as BW6PairingGadget,
+    };
+    use snarkvm_curves::bw6_761::{
+        Fq as BW6Fq,
+        Fr as BW6Fr,
+        G1Projective as BW6G1Projective,
+        G2Projective as BW6G2Projective,
+        BW6_761,
+    };
+
+    let mut cs = TestConstraintSystem::<BW6Fq>::new();
+
+    let a = BW6G1Projective::prime_subgroup_generator();
+    let b = BW6G2Projective::prime_subgroup_generator();
+    let s = BW6Fr::one() + BW6Fr::one();
+
+    let sa = a.mul(s);
+    let sb = b.mul(s);
+
+    let a_g = BW6G1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+    let b_g = BW6G2Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+    let sa_g = BW6G1Gadget::alloc(&mut cs.ns(|| "sa"), || Ok(sa)).unwrap();
+
+    let a_prep_g = BW6G1PreparedGadget::from_affine(&mut cs.ns(|| "a_prep"), a_g).unwrap();
+    let b_prep_g = BW6G2PreparedGadget::from_affine(&mut cs.ns(|| "b_prep"), b_g).unwrap();
+
+    let sa_prep_g = BW6G1PreparedGadget::from_affine(&mut cs.ns(|| "sa_prep"), sa_g).unwrap();
+    let sb_prep_g = BW6G2PreparedGadget::alloc(&mut cs.ns(|| "sb_prep"), || Ok(sb.into_affine().prepare())).unwrap();
+
+    let ans1_g = BW6PairingGadget::pairing(cs.ns(|| "pair(sa, b)"), sa_prep_g, b_prep_g).unwrap();
+    let ans1_n = BW6_761::pairing(sa, b);
+
+    let ans2_g = BW6PairingGadget::pairing(cs.ns(|| "pair(a, sb)"), a_prep_g, sb_prep_g).unwrap();
+    let ans2_n = BW6_761::pairing(a, sb);
+
+    assert_eq!(ans1_n, ans2_n, "Failed ans1_native == ans2_native");
+    assert_eq!(ans1_g.get_value().unwrap(), ans1_n, "Failed native test 1");
+    assert_eq!(ans2_g.get_value().unwrap(), ans2_n, "Failed native test 2");
+
+    ans1_g.enforce_equal(&mut cs.ns(|| "ans1 == ans2?"), &ans2_g).unwrap();
+
+    if !cs.is_satisfied() {
+        println!("Unsatisfied: {:?}", cs.which_is_unsatisfied());
+    }
+
+    assert!(cs.is_satisfied(), "cs is not satisfied");
+}
+
+#[test]
+fn bn254_gadget_bilinearity_test() {
+    use crate::curves::bn254::{
+        G1Gadget as BnG1Gadget,
+        G1PreparedGadget as BnG1PreparedGadget,
+        G2Gadget as BnG2Gadget,
+        G2PreparedGadget as BnG2PreparedGadget,
+        PairingGadget as BnPairingGadget,
+    };
+    use snarkvm_curves::bn254::{
+        Bn254,
+        Fq as BnFq,
+        Fr as BnFr,
+        G1Projective as BnG1Projective,
+        G2Projective as BnG2Projective,
+    };
+
+    let mut cs = TestConstraintSystem::<BnFq>::new();
+
+    let a = BnG1Projective::prime_subgroup_generator();
+    let b = BnG2Projective::prime_subgroup_generator();
+    let s = BnFr::one() + BnFr::one();
+
+    let sa = a.mul(s);
+    let sb = b.mul(s);
+
+    let a_g = BnG1Gadget::alloc(&mut cs.ns(|| "a"), || Ok(a)).unwrap();
+    let b_g = BnG2Gadget::alloc(&mut cs.ns(|| "b"), || Ok(b)).unwrap();
+    let sa_g = BnG1Gadget::alloc(&mut cs.ns(|| "sa"), || Ok(sa)).unwrap();
+
+    let a_prep_g = BnG1PreparedGadget::from_affine(&mut cs.ns(|| "a_prep"), a_g).unwrap();
+    let b_prep_g = BnG2PreparedGadget::from_affine(&mut cs.ns(|| "b_prep"), b_g).unwrap();
+
+    let sa_prep_g = BnG1PreparedGadget::from_affine(&mut cs.ns(|| "sa_prep"), sa_g).unwrap();
+    let sb_prep_g = BnG2PreparedGadget::alloc(&mut cs.ns(|| "sb_prep"), || Ok(sb.into_affine().prepare())).unwrap();
+
+    let ans1_g = BnPairingGadget::pairing(cs.ns(|| "pair(sa, b)"), sa_prep_g, b_prep_g).unwrap();
+    let ans1_n = Bn254::pairing(sa, b);
+
+    let ans2_g = BnPairingGadget::pairing(cs.ns(|| "pair(a, sb)"), a_prep_g, sb_prep_g).unwrap();
+    let ans2_n = Bn254::pairing(a, sb);
+
+    assert_eq!(ans1_n, ans2_n, "Failed ans1_native == ans2_native");
+    assert_eq!(ans1_g.get_value().unwrap(), ans1_n, "Failed native test 1");
+    assert_eq!(ans2_g.get_value().unwrap(), ans2_n, "Failed native test 2");
+
+    ans1_g.enforce_equal(&mut cs.ns(|| "ans1 == ans2?"), &ans2_g).unwrap();
+
+    if !cs.is_satisfied() {
+        println!("Unsatisfied: {:?}", cs.which_is_unsatisfied());
+    }
+
+    assert!(cs.is_satisfied(), "cs is not satisfied");
+}
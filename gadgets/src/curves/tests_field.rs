@@ -256,6 +256,41 @@ fn bls12_377_field_gadgets_test() {
     assert!(cs.is_satisfied());
 }
 
+#[test]
+fn bw6_761_field_gadgets_test() {
+    use crate::curves::bw6_761::{Fq3Gadget, Fq6Gadget, FqGadget};
+    use snarkvm_curves::bw6_761::{Fq, Fq3, Fq6};
+
+    let mut cs = TestConstraintSystem::<Fq>::new();
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let a = FqGadget::alloc(&mut cs.ns(|| "generate_a"), || Ok(Fq::rand(&mut rng))).unwrap();
+    let b = FqGadget::alloc(&mut cs.ns(|| "generate_b"), || Ok(Fq::rand(&mut rng))).unwrap();
+    field_test(cs.ns(|| "test_fq"), a, b);
+    if !cs.is_satisfied() {
+        println!("{:?}", cs.which_is_unsatisfied().unwrap());
+    }
+
+    let c = Fq3Gadget::alloc(&mut cs.ns(|| "generate_c"), || Ok(Fq3::rand(&mut rng))).unwrap();
+    let d = Fq3Gadget::alloc(&mut cs.ns(|| "generate_d"), || Ok(Fq3::rand(&mut rng))).unwrap();
+    field_test(cs.ns(|| "test_fq3"), c, d);
+    random_frobenius_tests::<Fq3, _, Fq3Gadget, _>(cs.ns(|| "test_frob_fq3"), 13);
+    if !cs.is_satisfied() {
+        println!("{:?}", cs.which_is_unsatisfied().unwrap());
+    }
+
+    let e = Fq6Gadget::alloc(&mut cs.ns(|| "generate_e"), || Ok(Fq6::rand(&mut rng))).unwrap();
+    let f = Fq6Gadget::alloc(&mut cs.ns(|| "generate_f"), || Ok(Fq6::rand(&mut rng))).unwrap();
+    field_test(cs.ns(|| "test_fq6"), e, f);
+    random_frobenius_tests::<Fq6, _, Fq6Gadget, _>(cs.ns(|| "test_frob_fq6"), 13);
+    if !cs.is_satisfied() {
+        println!("{:?}", cs.which_is_unsatisfied().unwrap());
+    }
+
+    assert!(cs.is_satisfied());
+}
+
 #[test]
 fn edwards_field_gadgets_test() {
     use crate::curves::edwards_bls12::FqGadget;
@@ -0,0 +1,581 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+use snarkvm_fields::{Field, Fp3, Fp3Parameters, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, Assignment, ConstraintSystem, ConstraintVariable};
+
+use crate::{
+    bits::{Boolean, ToBitsBEGadget, ToBitsLEGadget, ToBytesGadget},
+    fields::FpGadget,
+    integers::uint::UInt8,
+    traits::{
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget, NEqGadget},
+        fields::{FieldGadget, ToConstraintFieldGadget},
+        select::{CondSelectGadget, ThreeBitCondNegLookupGadget, TwoBitLookupGadget},
+    },
+};
+
+#[derive(Derivative)]
+#[derivative(Debug(bound = "P: Fp3Parameters, F: PrimeField"))]
+#[must_use]
+pub struct Fp3Gadget<P: Fp3Parameters<Fp = F>, F: PrimeField> {
+    pub c0: FpGadget<F>,
+    pub c1: FpGadget<F>,
+    pub c2: FpGadget<F>,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> Fp3Gadget<P, F> {
+    pub fn new(c0: FpGadget<F>, c1: FpGadget<F>, c2: FpGadget<F>) -> Self {
+        Self {
+            c0,
+            c1,
+            c2,
+            _params: PhantomData,
+        }
+    }
+
+    /// Multiply a FpGadget by cubic nonresidue P::NONRESIDUE.
+    #[inline]
+    pub fn mul_fp_gadget_by_nonresidue<CS: ConstraintSystem<F>>(
+        cs: CS,
+        fe: &FpGadget<F>,
+    ) -> Result<FpGadget<F>, SynthesisError> {
+        fe.mul_by_constant(cs, &P::NONRESIDUE)
+    }
+
+    /// Multiply a Fp3Gadget by an element of fp.
+    #[inline]
+    pub fn mul_by_fp_constant_in_place<CS: ConstraintSystem<F>>(
+        &mut self,
+        mut cs: CS,
+        fe: &P::Fp,
+    ) -> Result<&mut Self, SynthesisError> {
+        self.c0.mul_by_constant_in_place(cs.ns(|| "c0"), fe)?;
+        self.c1.mul_by_constant_in_place(cs.ns(|| "c1"), fe)?;
+        self.c2.mul_by_constant_in_place(cs.ns(|| "c2"), fe)?;
+        Ok(self)
+    }
+
+    /// Multiply a Fp3Gadget by an element of fp.
+    #[inline]
+    pub fn mul_by_fp_constant<CS: ConstraintSystem<F>>(&self, cs: CS, fe: &P::Fp) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        result.mul_by_fp_constant_in_place(cs, fe)?;
+        Ok(result)
+    }
+
+    /// Multiply a Fp3Gadget by a FpGadget.
+    #[inline]
+    pub fn mul_by_fp_gadget<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        fe: &FpGadget<F>,
+    ) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.mul(cs.ns(|| "c0"), fe)?;
+        let c1 = self.c1.mul(cs.ns(|| "c1"), fe)?;
+        let c2 = self.c2.mul(cs.ns(|| "c2"), fe)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> FieldGadget<Fp3<P>, F> for Fp3Gadget<P, F> {
+    type Variable = (ConstraintVariable<F>, ConstraintVariable<F>, ConstraintVariable<F>);
+
+    #[inline]
+    fn get_value(&self) -> Option<Fp3<P>> {
+        match (self.c0.get_value(), self.c1.get_value(), self.c2.get_value()) {
+            (Some(c0), Some(c1), Some(c2)) => Some(Fp3::new(c0, c1, c2)),
+            (..) => None,
+        }
+    }
+
+    #[inline]
+    fn get_variable(&self) -> Self::Variable {
+        (self.c0.get_variable(), self.c1.get_variable(), self.c2.get_variable())
+    }
+
+    #[inline]
+    fn zero<CS: ConstraintSystem<F>>(mut cs: CS) -> Result<Self, SynthesisError> {
+        let c0 = FpGadget::zero(cs.ns(|| "c0"))?;
+        let c1 = FpGadget::zero(cs.ns(|| "c1"))?;
+        let c2 = FpGadget::zero(cs.ns(|| "c2"))?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    #[inline]
+    fn one<CS: ConstraintSystem<F>>(mut cs: CS) -> Result<Self, SynthesisError> {
+        let c0 = FpGadget::one(cs.ns(|| "c0"))?;
+        let c1 = FpGadget::zero(cs.ns(|| "c1"))?;
+        let c2 = FpGadget::zero(cs.ns(|| "c2"))?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    #[inline]
+    fn conditionally_add_constant<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        bit: &Boolean,
+        coeff: Fp3<P>,
+    ) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.conditionally_add_constant(cs.ns(|| "c0"), bit, coeff.c0)?;
+        let c1 = self.c1.conditionally_add_constant(cs.ns(|| "c1"), bit, coeff.c1)?;
+        let c2 = self.c2.conditionally_add_constant(cs.ns(|| "c2"), bit, coeff.c2)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    #[inline]
+    fn add<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.add(&mut cs.ns(|| "add c0"), &other.c0)?;
+        let c1 = self.c1.add(&mut cs.ns(|| "add c1"), &other.c1)?;
+        let c2 = self.c2.add(&mut cs.ns(|| "add c2"), &other.c2)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    #[inline]
+    fn sub<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.sub(&mut cs.ns(|| "sub c0"), &other.c0)?;
+        let c1 = self.c1.sub(&mut cs.ns(|| "sub c1"), &other.c1)?;
+        let c2 = self.c2.sub(&mut cs.ns(|| "sub c2"), &other.c2)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    #[inline]
+    fn double<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        result.double_in_place(cs)?;
+        Ok(result)
+    }
+
+    #[inline]
+    fn double_in_place<CS: ConstraintSystem<F>>(&mut self, mut cs: CS) -> Result<&mut Self, SynthesisError> {
+        self.c0.double_in_place(&mut cs.ns(|| "double c0"))?;
+        self.c1.double_in_place(&mut cs.ns(|| "double c1"))?;
+        self.c2.double_in_place(&mut cs.ns(|| "double c2"))?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn negate<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        result.negate_in_place(cs)?;
+        Ok(result)
+    }
+
+    #[inline]
+    fn negate_in_place<CS: ConstraintSystem<F>>(&mut self, mut cs: CS) -> Result<&mut Self, SynthesisError> {
+        self.c0.negate_in_place(&mut cs.ns(|| "negate c0"))?;
+        self.c1.negate_in_place(&mut cs.ns(|| "negate c1"))?;
+        self.c2.negate_in_place(&mut cs.ns(|| "negate c2"))?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn mul<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        // Karatsuba multiplication for Fp3:
+        //     v0 = A.c0 * B.c0
+        //     v1 = A.c1 * B.c1
+        //     v2 = A.c2 * B.c2
+        //     result.c0 = v0 + non_residue * ((A.c1 + A.c2) * (B.c1 + B.c2) - v1 - v2)
+        //     result.c1 = (A.c0 + A.c1) * (B.c0 + B.c1) - v0 - v1 + non_residue * v2
+        //     result.c2 = (A.c0 + A.c2) * (B.c0 + B.c2) - v0 + v1 - v2
+        // Enforced with 6 constraints.
+        // Reference:
+        // "Multiplication and Squaring on Pairing-Friendly Fields"
+        // Devegili, OhEigeartaigh, Scott, Dahab
+        let mul_cs = &mut cs.ns(|| "mul");
+
+        let v0 = self.c0.mul(mul_cs.ns(|| "v0"), &other.c0)?;
+        let v1 = self.c1.mul(mul_cs.ns(|| "v1"), &other.c1)?;
+        let v2 = self.c2.mul(mul_cs.ns(|| "v2"), &other.c2)?;
+
+        let c0 = {
+            let a1_plus_a2 = self.c1.add(mul_cs.ns(|| "a1 + a2"), &self.c2)?;
+            let b1_plus_b2 = other.c1.add(mul_cs.ns(|| "b1 + b2"), &other.c2)?;
+            a1_plus_a2
+                .mul(mul_cs.ns(|| "(a1 + a2) * (b1 + b2)"), &b1_plus_b2)?
+                .sub(mul_cs.ns(|| "c0 - v1"), &v1)?
+                .sub(mul_cs.ns(|| "c0 - v1 - v2"), &v2)?
+                .mul_by_constant(mul_cs.ns(|| "c0 * non_residue"), &P::NONRESIDUE)?
+                .add(mul_cs.ns(|| "c0 + v0"), &v0)?
+        };
+
+        let c1 = {
+            let a0_plus_a1 = self.c0.add(mul_cs.ns(|| "a0 + a1"), &self.c1)?;
+            let b0_plus_b1 = other.c0.add(mul_cs.ns(|| "b0 + b1"), &other.c1)?;
+            let non_residue_times_v2 = v2.mul_by_constant(mul_cs.ns(|| "non_residue * v2"), &P::NONRESIDUE)?;
+            a0_plus_a1
+                .mul(mul_cs.ns(|| "(a0 + a1) * (b0 + b1)"), &b0_plus_b1)?
+                .sub(mul_cs.ns(|| "c1 - v0"), &v0)?
+                .sub(mul_cs.ns(|| "c1 - v0 - v1"), &v1)?
+                .add(mul_cs.ns(|| "c1 + non_residue * v2"), &non_residue_times_v2)?
+        };
+
+        let c2 = {
+            let a0_plus_a2 = self.c0.add(mul_cs.ns(|| "a0 + a2"), &self.c2)?;
+            let b0_plus_b2 = other.c0.add(mul_cs.ns(|| "b0 + b2"), &other.c2)?;
+            a0_plus_a2
+                .mul(mul_cs.ns(|| "(a0 + a2) * (b0 + b2)"), &b0_plus_b2)?
+                .sub(mul_cs.ns(|| "c2 - v0"), &v0)?
+                .add(mul_cs.ns(|| "c2 - v0 + v1"), &v1)?
+                .sub(mul_cs.ns(|| "c2 - v0 + v1 - v2"), &v2)?
+        };
+
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    #[inline]
+    fn inverse<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        let inverse = Self::alloc(&mut cs.ns(|| "alloc inverse"), || {
+            self.get_value().and_then(|val| val.inverse()).get()
+        })?;
+        let one = Self::one(cs.ns(|| "one"))?;
+        inverse.mul_equals(cs.ns(|| "check inverse"), self, &one)?;
+        Ok(inverse)
+    }
+
+    fn frobenius_map<CS: ConstraintSystem<F>>(&self, cs: CS, power: usize) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        let _ = result.frobenius_map_in_place(cs, power)?;
+        Ok(result)
+    }
+
+    fn frobenius_map_in_place<CS: ConstraintSystem<F>>(
+        &mut self,
+        mut cs: CS,
+        power: usize,
+    ) -> Result<&mut Self, SynthesisError> {
+        self.c1
+            .mul_by_constant_in_place(cs.ns(|| "c1_power"), &P::FROBENIUS_COEFF_FP3_C1[power % 3])?;
+        self.c2
+            .mul_by_constant_in_place(cs.ns(|| "c2_power"), &P::FROBENIUS_COEFF_FP3_C2[power % 3])?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn add_constant<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Fp3<P>) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        let _ = result.add_constant_in_place(cs, other)?;
+        Ok(result)
+    }
+
+    #[inline]
+    fn add_constant_in_place<CS: ConstraintSystem<F>>(
+        &mut self,
+        mut cs: CS,
+        other: &Fp3<P>,
+    ) -> Result<&mut Self, SynthesisError> {
+        self.c0.add_constant_in_place(cs.ns(|| "c0"), &other.c0)?;
+        self.c1.add_constant_in_place(cs.ns(|| "c1"), &other.c1)?;
+        self.c2.add_constant_in_place(cs.ns(|| "c2"), &other.c2)?;
+        Ok(self)
+    }
+
+    fn mul_by_constant<CS: ConstraintSystem<F>>(&self, mut cs: CS, fe: &Fp3<P>) -> Result<Self, SynthesisError> {
+        // Schoolbook multiplication (see mul above).
+        // Doesn't need any constraints; returns linear combinations of
+        // `self`'s variables.
+        //
+        // (The operations below are guaranteed to return linear combinations)
+        let (a0, a1, a2) = (&self.c0, &self.c1, &self.c2);
+        let (b0, b1, b2) = (fe.c0, fe.c1, fe.c2);
+
+        let c0 = {
+            let a0b0 = a0.mul_by_constant(cs.ns(|| "a0b0"), &b0)?;
+            let beta_a1b2 = a1.mul_by_constant(cs.ns(|| "beta * a1b2"), &(b2 * P::NONRESIDUE))?;
+            let beta_a2b1 = a2.mul_by_constant(cs.ns(|| "beta * a2b1"), &(b1 * P::NONRESIDUE))?;
+            a0b0.add(cs.ns(|| "c0 + beta * a1b2"), &beta_a1b2)?
+                .add(cs.ns(|| "c0 + beta * a2b1"), &beta_a2b1)?
+        };
+
+        let c1 = {
+            let a0b1 = a0.mul_by_constant(cs.ns(|| "a0b1"), &b1)?;
+            let a1b0 = a1.mul_by_constant(cs.ns(|| "a1b0"), &b0)?;
+            let beta_a2b2 = a2.mul_by_constant(cs.ns(|| "beta * a2b2"), &(b2 * P::NONRESIDUE))?;
+            a0b1.add(cs.ns(|| "c1 + a1b0"), &a1b0)?
+                .add(cs.ns(|| "c1 + beta * a2b2"), &beta_a2b2)?
+        };
+
+        let c2 = {
+            let a0b2 = a0.mul_by_constant(cs.ns(|| "a0b2"), &b2)?;
+            let a1b1 = a1.mul_by_constant(cs.ns(|| "a1b1"), &b1)?;
+            let a2b0 = a2.mul_by_constant(cs.ns(|| "a2b0"), &b0)?;
+            a0b2.add(cs.ns(|| "c2 + a1b1"), &a1b1)?
+                .add(cs.ns(|| "c2 + a2b0"), &a2b0)?
+        };
+
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    fn cost_of_mul() -> usize {
+        6
+    }
+
+    fn cost_of_inv() -> usize {
+        Self::cost_of_mul() + <Self as EqGadget<F>>::cost()
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> PartialEq for Fp3Gadget<P, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1 && self.c2 == other.c2
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> Eq for Fp3Gadget<P, F> {}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> EqGadget<F> for Fp3Gadget<P, F> {
+    fn is_eq<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        let b0 = self.c0.is_eq(cs.ns(|| "c0_is_eq"), &other.c0)?;
+        let b1 = self.c1.is_eq(cs.ns(|| "c1_is_eq"), &other.c1)?;
+        let b2 = self.c2.is_eq(cs.ns(|| "c2_is_eq"), &other.c2)?;
+        let b0_and_b1 = Boolean::and(cs.ns(|| "b0_and_b1"), &b0, &b1)?;
+        Boolean::and(cs.ns(|| "b0_and_b1_and_b2"), &b0_and_b1, &b2)
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> ConditionalEqGadget<F> for Fp3Gadget<P, F> {
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.c0
+            .conditional_enforce_equal(&mut cs.ns(|| "c0"), &other.c0, condition)?;
+        self.c1
+            .conditional_enforce_equal(&mut cs.ns(|| "c1"), &other.c1, condition)?;
+        self.c2
+            .conditional_enforce_equal(&mut cs.ns(|| "c2"), &other.c2, condition)?;
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        3
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> NEqGadget<F> for Fp3Gadget<P, F> {
+    #[inline]
+    fn enforce_not_equal<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<(), SynthesisError> {
+        self.c0.enforce_not_equal(&mut cs.ns(|| "c0"), &other.c0)?;
+        self.c1.enforce_not_equal(&mut cs.ns(|| "c1"), &other.c1)?;
+        self.c2.enforce_not_equal(&mut cs.ns(|| "c2"), &other.c2)?;
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        3
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> ToBitsBEGadget<F> for Fp3Gadget<P, F> {
+    fn to_bits_be<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut c0 = self.c0.to_bits_be(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bits_be(cs.ns(|| "c1"))?;
+        let mut c2 = self.c2.to_bits_be(cs.ns(|| "c2"))?;
+        c0.append(&mut c1);
+        c0.append(&mut c2);
+        Ok(c0)
+    }
+
+    fn to_bits_be_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut c0 = self.c0.to_bits_be_strict(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bits_be_strict(cs.ns(|| "c1"))?;
+        let mut c2 = self.c2.to_bits_be_strict(cs.ns(|| "c2"))?;
+        c0.append(&mut c1);
+        c0.append(&mut c2);
+        Ok(c0)
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> ToBitsLEGadget<F> for Fp3Gadget<P, F> {
+    fn to_bits_le<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut c0 = self.c0.to_bits_le(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bits_le(cs.ns(|| "c1"))?;
+        let mut c2 = self.c2.to_bits_le(cs.ns(|| "c2"))?;
+        c0.append(&mut c1);
+        c0.append(&mut c2);
+        Ok(c0)
+    }
+
+    fn to_bits_le_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut c0 = self.c0.to_bits_le_strict(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bits_le_strict(cs.ns(|| "c1"))?;
+        let mut c2 = self.c2.to_bits_le_strict(cs.ns(|| "c2"))?;
+        c0.append(&mut c1);
+        c0.append(&mut c2);
+        Ok(c0)
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> ToBytesGadget<F> for Fp3Gadget<P, F> {
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut c0 = self.c0.to_bytes(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bytes(cs.ns(|| "c1"))?;
+        let mut c2 = self.c2.to_bytes(cs.ns(|| "c2"))?;
+        c0.append(&mut c1);
+        c0.append(&mut c2);
+        Ok(c0)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut c0 = self.c0.to_bytes_strict(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bytes_strict(cs.ns(|| "c1"))?;
+        let mut c2 = self.c2.to_bytes_strict(cs.ns(|| "c2"))?;
+        c0.append(&mut c1);
+        c0.append(&mut c2);
+        Ok(c0)
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> Clone for Fp3Gadget<P, F> {
+    fn clone(&self) -> Self {
+        Self::new(self.c0.clone(), self.c1.clone(), self.c2.clone())
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> CondSelectGadget<F> for Fp3Gadget<P, F> {
+    #[inline]
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let c0 = FpGadget::<F>::conditionally_select(&mut cs.ns(|| "c0"), cond, &first.c0, &second.c0)?;
+        let c1 = FpGadget::<F>::conditionally_select(&mut cs.ns(|| "c1"), cond, &first.c1, &second.c1)?;
+        let c2 = FpGadget::<F>::conditionally_select(&mut cs.ns(|| "c2"), cond, &first.c2, &second.c2)?;
+
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    fn cost() -> usize {
+        3
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> TwoBitLookupGadget<F> for Fp3Gadget<P, F> {
+    type TableConstant = Fp3<P>;
+
+    fn two_bit_lookup<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        b: &[Boolean],
+        c: &[Self::TableConstant],
+    ) -> Result<Self, SynthesisError> {
+        let c0s = c.iter().map(|f| f.c0).collect::<Vec<_>>();
+        let c1s = c.iter().map(|f| f.c1).collect::<Vec<_>>();
+        let c2s = c.iter().map(|f| f.c2).collect::<Vec<_>>();
+        let c0 = FpGadget::two_bit_lookup(cs.ns(|| "Lookup c0"), b, &c0s)?;
+        let c1 = FpGadget::two_bit_lookup(cs.ns(|| "Lookup c1"), b, &c1s)?;
+        let c2 = FpGadget::two_bit_lookup(cs.ns(|| "Lookup c2"), b, &c2s)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    fn cost() -> usize {
+        3 * <FpGadget<F> as TwoBitLookupGadget<F>>::cost()
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> ThreeBitCondNegLookupGadget<F> for Fp3Gadget<P, F> {
+    type TableConstant = Fp3<P>;
+
+    fn three_bit_cond_neg_lookup<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        b: &[Boolean],
+        b0b1: &Boolean,
+        c: &[Self::TableConstant],
+    ) -> Result<Self, SynthesisError> {
+        let c0s = c.iter().map(|f| f.c0).collect::<Vec<_>>();
+        let c1s = c.iter().map(|f| f.c1).collect::<Vec<_>>();
+        let c2s = c.iter().map(|f| f.c2).collect::<Vec<_>>();
+        let c0 = FpGadget::three_bit_cond_neg_lookup(cs.ns(|| "Lookup c0"), b, b0b1, &c0s)?;
+        let c1 = FpGadget::three_bit_cond_neg_lookup(cs.ns(|| "Lookup c1"), b, b0b1, &c1s)?;
+        let c2 = FpGadget::three_bit_cond_neg_lookup(cs.ns(|| "Lookup c2"), b, b0b1, &c2s)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    fn cost() -> usize {
+        3 * <FpGadget<F> as ThreeBitCondNegLookupGadget<F>>::cost()
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> AllocGadget<Fp3<P>, F> for Fp3Gadget<P, F> {
+    #[inline]
+    fn alloc<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Fp3<P>>,
+    {
+        let (c0, c1, c2) = match value_gen() {
+            Ok(fe) => {
+                let fe = *fe.borrow();
+                (Ok(fe.c0), Ok(fe.c1), Ok(fe.c2))
+            }
+            Err(_) => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let c0 = FpGadget::alloc(&mut cs.ns(|| "c0"), || c0)?;
+        let c1 = FpGadget::alloc(&mut cs.ns(|| "c1"), || c1)?;
+        let c2 = FpGadget::alloc(&mut cs.ns(|| "c2"), || c2)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    #[inline]
+    fn alloc_input<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Fp3<P>>,
+    {
+        let (c0, c1, c2) = match value_gen() {
+            Ok(fe) => {
+                let fe = *fe.borrow();
+                (Ok(fe.c0), Ok(fe.c1), Ok(fe.c2))
+            }
+            Err(_) => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let c0 = FpGadget::alloc_input(&mut cs.ns(|| "c0"), || c0)?;
+        let c1 = FpGadget::alloc_input(&mut cs.ns(|| "c1"), || c1)?;
+        let c2 = FpGadget::alloc_input(&mut cs.ns(|| "c2"), || c2)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+}
+
+impl<P: Fp3Parameters<Fp = F>, F: PrimeField> ToConstraintFieldGadget<F> for Fp3Gadget<P, F> {
+    fn to_constraint_field<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        let mut res = Vec::new();
+
+        res.extend_from_slice(&self.c0.to_constraint_field(cs.ns(|| "fp3_c0_to_constraint_field"))?);
+        res.extend_from_slice(&self.c1.to_constraint_field(cs.ns(|| "fp3_c1_to_constraint_field"))?);
+        res.extend_from_slice(&self.c2.to_constraint_field(cs.ns(|| "fp3_c2_to_constraint_field"))?);
+
+        Ok(res)
+    }
+}
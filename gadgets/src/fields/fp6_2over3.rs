@@ -0,0 +1,678 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+use snarkvm_fields::{
+    fp6_2over3::{Fp6, Fp6Parameters},
+    Field,
+    Fp3,
+    Fp3Parameters,
+    PrimeField,
+};
+use snarkvm_r1cs::{errors::SynthesisError, Assignment, ConstraintSystem};
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
+use crate::{
+    bits::{Boolean, ToBitsBEGadget, ToBitsLEGadget, ToBytesGadget},
+    fields::FpGadget,
+    integers::uint::UInt8,
+    traits::{
+        alloc::AllocGadget,
+        eq::{ConditionalEqGadget, EqGadget, NEqGadget},
+        fields::{FieldGadget, ToConstraintFieldGadget},
+        select::{CondSelectGadget, ThreeBitCondNegLookupGadget, TwoBitLookupGadget},
+    },
+};
+
+type Fp3Gadget<P, F> = super::fp3::Fp3Gadget<<P as Fp6Parameters>::Fp3Params, F>;
+type Fp3GadgetVariable<P, F> = <Fp3Gadget<P, F> as FieldGadget<Fp3<<P as Fp6Parameters>::Fp3Params>, F>>::Variable;
+
+#[derive(Derivative)]
+#[derivative(Debug(bound = "F: PrimeField"))]
+#[must_use]
+pub struct Fp6Gadget<P, F: PrimeField>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    pub c0: Fp3Gadget<P, F>,
+    pub c1: Fp3Gadget<P, F>,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P, F: PrimeField> Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    #[inline]
+    pub fn new(c0: Fp3Gadget<P, F>, c1: Fp3Gadget<P, F>) -> Self {
+        Self {
+            c0,
+            c1,
+            _params: PhantomData,
+        }
+    }
+
+    /// Multiply by quadratic nonresidue v.
+    #[inline]
+    pub(crate) fn mul_fp3_by_nonresidue<CS: ConstraintSystem<F>>(
+        cs: CS,
+        fe: &Fp3Gadget<P, F>,
+    ) -> Result<Fp3Gadget<P, F>, SynthesisError> {
+        let new_c0 = Fp3Gadget::<P, F>::mul_fp_gadget_by_nonresidue(cs, &fe.c2)?;
+        let new_c1 = fe.c0.clone();
+        let new_c2 = fe.c1.clone();
+        Ok(Fp3Gadget::<P, F>::new(new_c0, new_c1, new_c2))
+    }
+
+    #[inline]
+    pub fn conjugate_in_place<CS: ConstraintSystem<F>>(&mut self, cs: CS) -> Result<&mut Self, SynthesisError> {
+        self.c1.negate_in_place(cs)?;
+        Ok(self)
+    }
+
+    /// Returns the inverse of an element of the cyclotomic subgroup.
+    #[inline]
+    pub fn unitary_inverse<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        result.conjugate_in_place(cs)?;
+        Ok(result)
+    }
+
+    /// Multiplies by an element of the form (c0 = (c0, c1, 0), c1 = (0, c4, 0))
+    #[inline]
+    pub fn mul_by_014<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        c0: &FpGadget<F>,
+        c1: &FpGadget<F>,
+        c4: &FpGadget<F>,
+    ) -> Result<Self, SynthesisError> {
+        let (z0, z1, z2) = (&self.c0.c0, &self.c0.c1, &self.c0.c2);
+        let (z3, z4, z5) = (&self.c1.c0, &self.c1.c1, &self.c1.c2);
+
+        let x0 = c0;
+        let x1 = c1;
+        let x4 = c4;
+        let tmp1 = Fp3Gadget::<P, F>::mul_fp_gadget_by_nonresidue(cs.ns(|| "nonresidue * c1"), x1)?;
+        let tmp2 = Fp3Gadget::<P, F>::mul_fp_gadget_by_nonresidue(cs.ns(|| "nonresidue * c4"), x4)?;
+
+        let new_c0_c0 = sum_of_products(cs.ns(|| "c0.c0"), [(x0, z0), (&tmp1, z2), (&tmp2, z4)])?;
+        let new_c0_c1 = sum_of_products(cs.ns(|| "c0.c1"), [(x0, z1), (x1, z0), (&tmp2, z5)])?;
+        let new_c0_c2 = sum_of_products(cs.ns(|| "c0.c2"), [(x0, z2), (x1, z1), (x4, z3)])?;
+        let new_c1_c0 = sum_of_products(cs.ns(|| "c1.c0"), [(x0, z3), (&tmp1, z5), (&tmp2, z2)])?;
+        let new_c1_c1 = sum_of_products(cs.ns(|| "c1.c1"), [(x0, z4), (x1, z3), (x4, z0)])?;
+        let new_c1_c2 = sum_of_products(cs.ns(|| "c1.c2"), [(x0, z5), (x1, z4), (x4, z1)])?;
+
+        Ok(Self::new(
+            Fp3Gadget::<P, F>::new(new_c0_c0, new_c0_c1, new_c0_c2),
+            Fp3Gadget::<P, F>::new(new_c1_c0, new_c1_c1, new_c1_c2),
+        ))
+    }
+
+    /// Multiplies by an element of the form (c0 = (c0, 0, 0), c1 = (c3, c4, 0))
+    #[inline]
+    pub fn mul_by_034<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        c0: &FpGadget<F>,
+        c3: &FpGadget<F>,
+        c4: &FpGadget<F>,
+    ) -> Result<Self, SynthesisError> {
+        let (z0, z1, z2) = (&self.c0.c0, &self.c0.c1, &self.c0.c2);
+        let (z3, z4, z5) = (&self.c1.c0, &self.c1.c1, &self.c1.c2);
+
+        let x0 = c0;
+        let x3 = c3;
+        let x4 = c4;
+        let tmp1 = Fp3Gadget::<P, F>::mul_fp_gadget_by_nonresidue(cs.ns(|| "nonresidue * c3"), x3)?;
+        let tmp2 = Fp3Gadget::<P, F>::mul_fp_gadget_by_nonresidue(cs.ns(|| "nonresidue * c4"), x4)?;
+
+        let new_c0_c0 = sum_of_products(cs.ns(|| "c0.c0"), [(x0, z0), (&tmp1, z5), (&tmp2, z4)])?;
+        let new_c0_c1 = sum_of_products(cs.ns(|| "c0.c1"), [(x0, z1), (x3, z3), (&tmp2, z5)])?;
+        let new_c0_c2 = sum_of_products(cs.ns(|| "c0.c2"), [(x0, z2), (x3, z4), (x4, z3)])?;
+        let new_c1_c0 = sum_of_products(cs.ns(|| "c1.c0"), [(x0, z3), (x3, z0), (&tmp2, z2)])?;
+        let new_c1_c1 = sum_of_products(cs.ns(|| "c1.c1"), [(x0, z4), (x3, z1), (x4, z0)])?;
+        let new_c1_c2 = sum_of_products(cs.ns(|| "c1.c2"), [(x0, z5), (x3, z2), (x4, z1)])?;
+
+        Ok(Self::new(
+            Fp3Gadget::<P, F>::new(new_c0_c0, new_c0_c1, new_c0_c2),
+            Fp3Gadget::<P, F>::new(new_c1_c0, new_c1_c1, new_c1_c2),
+        ))
+    }
+
+    #[inline]
+    pub fn cyclotomic_exp<CS: ConstraintSystem<F>, S: AsRef<[u64]>>(
+        &self,
+        mut cs: CS,
+        exp: S,
+    ) -> Result<Self, SynthesisError> {
+        let mut res = Self::one(cs.ns(|| "one"))?;
+        let mut found_one = false;
+        for (j, i) in BitIteratorBE::new(exp).enumerate() {
+            if found_one {
+                res.square_in_place(cs.ns(|| format!("res_square_{:?}", j)))?;
+            } else {
+                found_one = i;
+            }
+            if i {
+                res.mul_in_place(cs.ns(|| format!("res_mul2_{:?}", j)), self)?;
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// Returns the sum of the products of the given pairs of gadgets.
+fn sum_of_products<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    terms: [(&FpGadget<F>, &FpGadget<F>); 3],
+) -> Result<FpGadget<F>, SynthesisError> {
+    let mut result = terms[0].0.mul(cs.ns(|| "first"), terms[0].1)?;
+    let second = terms[1].0.mul(cs.ns(|| "second"), terms[1].1)?;
+    let third = terms[2].0.mul(cs.ns(|| "third"), terms[2].1)?;
+    result.add_in_place(cs.ns(|| "add second"), &second)?;
+    result.add_in_place(cs.ns(|| "add third"), &third)?;
+    Ok(result)
+}
+
+impl<P, F: PrimeField> FieldGadget<Fp6<P>, F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    type Variable = (Fp3GadgetVariable<P, F>, Fp3GadgetVariable<P, F>);
+
+    #[inline]
+    fn get_value(&self) -> Option<Fp6<P>> {
+        Some(Fp6::new(self.c0.get_value()?, self.c1.get_value()?))
+    }
+
+    #[inline]
+    fn get_variable(&self) -> Self::Variable {
+        (self.c0.get_variable(), self.c1.get_variable())
+    }
+
+    #[inline]
+    fn zero<CS: ConstraintSystem<F>>(mut cs: CS) -> Result<Self, SynthesisError> {
+        let c0 = Fp3Gadget::<P, F>::zero(cs.ns(|| "c0"))?;
+        let c1 = Fp3Gadget::<P, F>::zero(cs.ns(|| "c1"))?;
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn one<CS: ConstraintSystem<F>>(mut cs: CS) -> Result<Self, SynthesisError> {
+        let c0 = Fp3Gadget::<P, F>::one(cs.ns(|| "c0"))?;
+        let c1 = Fp3Gadget::<P, F>::zero(cs.ns(|| "c1"))?;
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn conditionally_add_constant<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        bit: &Boolean,
+        coeff: Fp6<P>,
+    ) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.conditionally_add_constant(cs.ns(|| "c0"), bit, coeff.c0)?;
+        let c1 = self.c1.conditionally_add_constant(cs.ns(|| "c1"), bit, coeff.c1)?;
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn add<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.add(cs.ns(|| "c0"), &other.c0)?;
+        let c1 = self.c1.add(cs.ns(|| "c1"), &other.c1)?;
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn sub<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.sub(cs.ns(|| "c0"), &other.c0)?;
+        let c1 = self.c1.sub(cs.ns(|| "c1"), &other.c1)?;
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn negate<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.negate(cs.ns(|| "c0"))?;
+        let c1 = self.c1.negate(cs.ns(|| "c1"))?;
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn negate_in_place<CS: ConstraintSystem<F>>(&mut self, mut cs: CS) -> Result<&mut Self, SynthesisError> {
+        self.c0.negate_in_place(cs.ns(|| "c0"))?;
+        self.c1.negate_in_place(cs.ns(|| "c1"))?;
+        Ok(self)
+    }
+
+    #[inline]
+    fn mul<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError> {
+        // Karatsuba multiplication:
+        // v0 = A.c0 * B.c0
+        // v1 = A.c1 * B.c1
+        // result.c0 = v0 + non_residue * v1
+        // result.c1 = (A.c0 + A.c1) * (B.c0 + B.c1) - v0 - v1
+        //
+        // Enforced with 3 Fp3_mul_gadget's that ensure that:
+        // A.c1 * B.c1 = v1
+        // A.c0 * B.c0 = v0
+        // (A.c0+A.c1)*(B.c0+B.c1) = result.c1 + v0 + v1
+
+        let v0 = self.c0.mul(cs.ns(|| "v0"), &other.c0)?;
+        let v1 = self.c1.mul(cs.ns(|| "v1"), &other.c1)?;
+        let c0 = {
+            let non_residue_times_v1 = Self::mul_fp3_by_nonresidue(cs.ns(|| "first mul_by_nr"), &v1)?;
+            v0.add(cs.ns(|| "v0 + beta * v1"), &non_residue_times_v1)?
+        };
+        let c1 = {
+            let a0_plus_a1 = self.c0.add(cs.ns(|| "a0 + a1"), &self.c1)?;
+            let b0_plus_b1 = other.c0.add(cs.ns(|| "b0 + b1"), &other.c1)?;
+            let a0_plus_a1_times_b0_plus_b1 = a0_plus_a1.mul(&mut cs.ns(|| "(a0 + a1) * (b0 + b1)"), &b0_plus_b1)?;
+            a0_plus_a1_times_b0_plus_b1
+                .sub(cs.ns(|| "res - v0"), &v0)?
+                .sub(cs.ns(|| "res - v0 - v1"), &v1)?
+        };
+
+        Ok(Self::new(c0, c1))
+    }
+
+    fn square<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        // Complex multiplication for Fp6:
+        //     v0 = A.c0 * A.c1
+        //     result.c0 = (A.c0 + A.c1) * (A.c0 + non_residue * A.c1) - (1 + non_residue) * v0
+        //     result.c1 = 2 * v0
+        // Reference:
+        //     "Multiplication and Squaring on Pairing-Friendly Fields"
+        //     Devegili, OhEigeartaigh, Scott, Dahab
+
+        let mut v0 = self.c0.mul(cs.ns(|| "v0"), &self.c1)?;
+        let a0_plus_a1 = self.c0.add(cs.ns(|| "a0 + a1"), &self.c1)?;
+
+        let non_residue_a1 = Self::mul_fp3_by_nonresidue(cs.ns(|| "non_residue * a1"), &self.c1)?;
+        let a0_plus_non_residue_a1 = self.c0.add(cs.ns(|| "a0 + non_residue * a1"), &non_residue_a1)?;
+        let one_plus_non_residue_v0 =
+            Self::mul_fp3_by_nonresidue(cs.ns(|| "non_residue * v0"), &v0)?.add(cs.ns(|| "plus v0"), &v0)?;
+
+        let c0 = a0_plus_a1
+            .mul(cs.ns(|| "(a0 + a1) * (a0 + non_residue * a1)"), &a0_plus_non_residue_a1)?
+            .sub(cs.ns(|| "- (1 + non_residue) v0"), &one_plus_non_residue_v0)?;
+
+        v0.double_in_place(cs.ns(|| "2v0"))?;
+        let c1 = v0;
+
+        Ok(Self::new(c0, c1))
+    }
+
+    // 18 constaints, we can probably do better but not sure it's worth it.
+    #[inline]
+    fn inverse<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        let inverse = Self::alloc(&mut cs.ns(|| "alloc inverse"), || {
+            self.get_value().and_then(|val| val.inverse()).get()
+        })?;
+        let one = Self::one(cs.ns(|| "one"))?;
+        inverse.mul_equals(cs.ns(|| "check inverse"), self, &one)?;
+        Ok(inverse)
+    }
+
+    #[inline]
+    fn add_constant<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Fp6<P>) -> Result<Self, SynthesisError> {
+        let c0 = self.c0.add_constant(cs.ns(|| "c0"), &other.c0)?;
+        let c1 = self.c1.add_constant(cs.ns(|| "c1"), &other.c1)?;
+
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn add_constant_in_place<CS: ConstraintSystem<F>>(
+        &mut self,
+        mut cs: CS,
+        other: &Fp6<P>,
+    ) -> Result<&mut Self, SynthesisError> {
+        self.c0.add_constant_in_place(cs.ns(|| "c0"), &other.c0)?;
+        self.c1.add_constant_in_place(cs.ns(|| "c1"), &other.c1)?;
+        Ok(self)
+    }
+
+    fn mul_by_constant<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Fp6<P>) -> Result<Self, SynthesisError> {
+        // Karatsuba multiplication (see mul above).
+        // Doesn't need any constraints; returns linear combinations of
+        // `self`'s variables.
+        //
+        // (The operations below are guaranteed to return linear combinations)
+        let (a0, a1) = (&self.c0, &self.c1);
+        let (b0, b1) = (other.c0, other.c1);
+        let mut v0 = a0.mul_by_constant(&mut cs.ns(|| "v0"), &b0)?;
+        let v1 = a1.mul_by_constant(&mut cs.ns(|| "v1"), &b1)?;
+        let beta_v1 = Self::mul_fp3_by_nonresidue(&mut cs.ns(|| "beta * v1"), &v1)?;
+
+        v0.add_in_place(&mut cs.ns(|| "c0"), &beta_v1)?;
+        let c0 = v0;
+
+        let mut a0b1 = a0.mul_by_constant(&mut cs.ns(|| "a0b1"), &b1)?;
+        let a1b0 = a1.mul_by_constant(&mut cs.ns(|| "a1b0"), &b0)?;
+        a0b1.add_in_place(&mut cs.ns(|| "c1"), &a1b0)?;
+        let c1 = a0b1;
+        Ok(Self::new(c0, c1))
+    }
+
+    fn frobenius_map<CS: ConstraintSystem<F>>(&self, cs: CS, power: usize) -> Result<Self, SynthesisError> {
+        let mut result = self.clone();
+        result.frobenius_map_in_place(cs, power)?;
+        Ok(result)
+    }
+
+    fn frobenius_map_in_place<CS: ConstraintSystem<F>>(
+        &mut self,
+        mut cs: CS,
+        power: usize,
+    ) -> Result<&mut Self, SynthesisError> {
+        self.c0.frobenius_map_in_place(cs.ns(|| "frob_map1"), power)?;
+        self.c1.frobenius_map_in_place(cs.ns(|| "frob_map2"), power)?;
+        self.c1
+            .mul_by_fp_constant_in_place(cs.ns(|| "mul"), &P::FROBENIUS_COEFF_FP6_C1[power % 6])?;
+        Ok(self)
+    }
+
+    fn cost_of_mul() -> usize {
+        3 * Fp3Gadget::<P, F>::cost_of_mul()
+    }
+
+    fn cost_of_inv() -> usize {
+        Self::cost_of_mul() + <Self as EqGadget<F>>::cost()
+    }
+}
+
+impl<P, F: PrimeField> PartialEq for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1
+    }
+}
+
+impl<P, F: PrimeField> Eq for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+}
+
+impl<P, F: PrimeField> EqGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    fn is_eq<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+        let b0 = self.c0.is_eq(cs.ns(|| "c0_is_eq"), &other.c0)?;
+        let b1 = self.c1.is_eq(cs.ns(|| "c1_is_eq"), &other.c1)?;
+        Boolean::and(cs.ns(|| "b0_and_b1"), &b0, &b1)
+    }
+}
+
+impl<P, F: PrimeField> ConditionalEqGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.c0
+            .conditional_enforce_equal(&mut cs.ns(|| "c0"), &other.c0, condition)?;
+        self.c1
+            .conditional_enforce_equal(&mut cs.ns(|| "c1"), &other.c1, condition)?;
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        2 * <Fp3Gadget<P, F> as ConditionalEqGadget<F>>::cost()
+    }
+}
+
+impl<P, F: PrimeField> NEqGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    #[inline]
+    fn enforce_not_equal<CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<(), SynthesisError> {
+        self.c0.enforce_not_equal(&mut cs.ns(|| "c0"), &other.c0)?;
+        self.c1.enforce_not_equal(&mut cs.ns(|| "c1"), &other.c1)?;
+        Ok(())
+    }
+
+    fn cost() -> usize {
+        2 * <Fp3Gadget<P, F> as NEqGadget<F>>::cost()
+    }
+}
+
+impl<P, F: PrimeField> ToBitsBEGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    fn to_bits_be<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut c0 = self.c0.to_bits_be(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bits_be(cs.ns(|| "c1"))?;
+        c0.append(&mut c1);
+        Ok(c0)
+    }
+
+    fn to_bits_be_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut c0 = self.c0.to_bits_be_strict(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bits_be_strict(cs.ns(|| "c1"))?;
+        c0.append(&mut c1);
+        Ok(c0)
+    }
+}
+
+impl<P, F: PrimeField> ToBitsLEGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    fn to_bits_le<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut c0 = self.c0.to_bits_le(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bits_le(cs.ns(|| "c1"))?;
+        c0.append(&mut c1);
+        Ok(c0)
+    }
+
+    fn to_bits_le_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<Boolean>, SynthesisError> {
+        let mut c0 = self.c0.to_bits_le_strict(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bits_le_strict(cs.ns(|| "c1"))?;
+        c0.append(&mut c1);
+        Ok(c0)
+    }
+}
+
+impl<P, F: PrimeField> ToBytesGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    fn to_bytes<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut c0 = self.c0.to_bytes(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bytes(cs.ns(|| "c1"))?;
+        c0.append(&mut c1);
+        Ok(c0)
+    }
+
+    fn to_bytes_strict<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
+        let mut c0 = self.c0.to_bytes_strict(cs.ns(|| "c0"))?;
+        let mut c1 = self.c1.to_bytes_strict(cs.ns(|| "c1"))?;
+        c0.append(&mut c1);
+        Ok(c0)
+    }
+}
+
+impl<P, F: PrimeField> Clone for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.c0.clone(), self.c1.clone())
+    }
+}
+
+impl<P, F: PrimeField> CondSelectGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    #[inline]
+    fn conditionally_select<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let c0 = Fp3Gadget::<P, F>::conditionally_select(&mut cs.ns(|| "c0"), cond, &first.c0, &second.c0)?;
+        let c1 = Fp3Gadget::<P, F>::conditionally_select(&mut cs.ns(|| "c1"), cond, &first.c1, &second.c1)?;
+
+        Ok(Self::new(c0, c1))
+    }
+
+    fn cost() -> usize {
+        2 * <Fp3Gadget<P, F> as CondSelectGadget<F>>::cost()
+    }
+}
+
+impl<P, F: PrimeField> TwoBitLookupGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    type TableConstant = Fp6<P>;
+
+    fn two_bit_lookup<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        b: &[Boolean],
+        c: &[Self::TableConstant],
+    ) -> Result<Self, SynthesisError> {
+        let c0s = c.iter().map(|f| f.c0).collect::<Vec<_>>();
+        let c1s = c.iter().map(|f| f.c1).collect::<Vec<_>>();
+        let c0 = Fp3Gadget::<P, F>::two_bit_lookup(cs.ns(|| "Lookup c0"), b, &c0s)?;
+        let c1 = Fp3Gadget::<P, F>::two_bit_lookup(cs.ns(|| "Lookup c1"), b, &c1s)?;
+        Ok(Self::new(c0, c1))
+    }
+
+    fn cost() -> usize {
+        2 * <Fp3Gadget<P, F> as TwoBitLookupGadget<F>>::cost()
+    }
+}
+
+impl<P, F: PrimeField> ThreeBitCondNegLookupGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    type TableConstant = Fp6<P>;
+
+    fn three_bit_cond_neg_lookup<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        b: &[Boolean],
+        b0b1: &Boolean,
+        c: &[Self::TableConstant],
+    ) -> Result<Self, SynthesisError> {
+        let c0s = c.iter().map(|f| f.c0).collect::<Vec<_>>();
+        let c1s = c.iter().map(|f| f.c1).collect::<Vec<_>>();
+        let c0 = Fp3Gadget::<P, F>::three_bit_cond_neg_lookup(cs.ns(|| "Lookup c0"), b, b0b1, &c0s)?;
+        let c1 = Fp3Gadget::<P, F>::three_bit_cond_neg_lookup(cs.ns(|| "Lookup c1"), b, b0b1, &c1s)?;
+        Ok(Self::new(c0, c1))
+    }
+
+    fn cost() -> usize {
+        2 * <Fp3Gadget<P, F> as ThreeBitCondNegLookupGadget<F>>::cost()
+    }
+}
+
+impl<P, F: PrimeField> AllocGadget<Fp6<P>, F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    #[inline]
+    fn alloc<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Fp6<P>>,
+    {
+        let (c0, c1) = match value_gen() {
+            Ok(fe) => {
+                let fe = *fe.borrow();
+                (Ok(fe.c0), Ok(fe.c1))
+            }
+            Err(_) => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let c0 = Fp3Gadget::<P, F>::alloc(&mut cs.ns(|| "c0"), || c0)?;
+        let c1 = Fp3Gadget::<P, F>::alloc(&mut cs.ns(|| "c1"), || c1)?;
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn alloc_input<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Fp6<P>>,
+    {
+        let (c0, c1) = match value_gen() {
+            Ok(fe) => {
+                let fe = *fe.borrow();
+                (Ok(fe.c0), Ok(fe.c1))
+            }
+            Err(_) => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let c0 = Fp3Gadget::<P, F>::alloc_input(&mut cs.ns(|| "c0"), || c0)?;
+        let c1 = Fp3Gadget::<P, F>::alloc_input(&mut cs.ns(|| "c1"), || c1)?;
+        Ok(Self::new(c0, c1))
+    }
+}
+
+impl<P, F: PrimeField> ToConstraintFieldGadget<F> for Fp6Gadget<P, F>
+where
+    P: Fp6Parameters,
+    P::Fp3Params: Fp3Parameters<Fp = F>,
+{
+    fn to_constraint_field<CS: ConstraintSystem<F>>(&self, mut cs: CS) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+        let mut res = Vec::new();
+
+        res.extend_from_slice(&self.c0.to_constraint_field(cs.ns(|| "fp6_c0_to_constraint_field"))?);
+        res.extend_from_slice(&self.c1.to_constraint_field(cs.ns(|| "fp6_c1_to_constraint_field"))?);
+
+        Ok(res)
+    }
+}
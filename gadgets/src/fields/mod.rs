@@ -20,6 +20,11 @@ pub use fp::*;
 pub mod fp2;
 pub use fp2::*;
 
+pub mod fp3;
+pub use fp3::*;
+
+pub mod fp6_2over3;
+
 pub mod fp6_3over2;
 pub use fp6_3over2::*;
 
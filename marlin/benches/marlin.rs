@@ -18,10 +18,7 @@
 extern crate criterion;
 
 use snarkvm_algorithms::traits::SNARK;
-use snarkvm_curves::{
-    bls12_377::{Bls12_377, Fq, Fr},
-    bw6_761::BW6_761,
-};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
 use snarkvm_fields::Field;
 use snarkvm_gadgets::{curves::bls12_377::PairingGadget as Bls12_377PairingGadget, prelude::*};
 use snarkvm_marlin::{
@@ -51,7 +48,7 @@ type MarlinInst = MarlinCore<Fr, Fq, PC, FS, MarlinRecursiveMode>;
 // Used for Marlin Verification Gadget
 
 type PC = MarlinKZG10<Bls12_377>;
-type PCGadget = MarlinKZG10Gadget<Bls12_377, BW6_761, Bls12_377PairingGadget>;
+type PCGadget = MarlinKZG10Gadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 
 type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq>>;
 type FSG = FiatShamirAlgebraicSpongeRngVar<Fr, Fq, PoseidonSponge<Fq>, PoseidonSpongeVar<Fq>>;
@@ -20,17 +20,13 @@ use crate::{
     ahp::{indexer::Matrix, UnnormalizedBivariateLagrangePoly},
     BTreeMap,
 };
-use snarkvm_algorithms::{
-    cfg_iter_mut,
-    fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain},
-};
+use snarkvm_algorithms::fft::{EvaluationDomain, Evaluations as EvaluationsOnDomain};
 use snarkvm_fields::{batch_inversion, Field, PrimeField};
 use snarkvm_polycommit::LabeledPolynomial;
 use snarkvm_r1cs::{ConstraintSystem, Index as VarIndex};
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
 
 use derivative::Derivative;
-use rayon::prelude::*;
 
 // This function converts a matrix output by Zexe's constraint infrastructure
 // to the one used in this crate.
@@ -167,15 +163,13 @@ pub(crate) fn arithmetize_matrix<F: PrimeField>(
     let mut col_vec = Vec::with_capacity(vec_len);
     let mut val_vec = Vec::with_capacity(vec_len);
 
-    let eq_poly_vals_time = start_timer!(|| "Precomputing eq_poly_vals");
-    let eq_poly_vals: BTreeMap<F, F> = output_domain
-        .elements()
-        .zip(output_domain.batch_eval_unnormalized_bivariate_lagrange_poly_with_same_inputs())
-        .collect();
+    let eq_poly_vals_time = start_timer!(|| "Precomputing inverses of eq_poly_vals");
+    let mut eq_poly_vals = output_domain.batch_eval_unnormalized_bivariate_lagrange_poly_with_same_inputs();
+    batch_inversion(&mut eq_poly_vals);
+    let inverse_eq_poly_vals: BTreeMap<F, F> = output_domain.elements().zip(eq_poly_vals).collect();
     end_timer!(eq_poly_vals_time);
 
     let lde_evals_time = start_timer!(|| "Computing row, col and val evals");
-    let mut inverses = Vec::with_capacity(vec_len);
 
     let mut count = 0;
 
@@ -193,15 +187,11 @@ pub(crate) fn arithmetize_matrix<F: PrimeField>(
             // We are dealing with the transpose of M
             row_vec.push(col_val);
             col_vec.push(row_val);
-            val_vec.push(val);
-            inverses.push(eq_poly_vals[&col_val]);
+            val_vec.push(val * inverse_eq_poly_vals[&col_val]);
 
             count += 1;
         }
     }
-    batch_inversion::<F>(&mut inverses);
-
-    cfg_iter_mut!(val_vec).zip(inverses).for_each(|(v, inv)| *v *= &inv);
     end_timer!(lde_evals_time);
 
     for _ in 0..(interpolation_domain.size() - count) {
@@ -222,11 +222,9 @@ impl<F: PrimeField> AHPForR1CS<F> {
 
         let ratio = domain_h.size() / domain_x.size();
 
-        let mut w_extended = state.private_variables.clone();
-        w_extended.extend(vec![
-            F::zero();
-            domain_h.size() - domain_x.size() - state.private_variables.len()
-        ]);
+        // The witness is not needed after the first round, so it is moved out of the state.
+        let mut w_extended = core::mem::take(&mut state.private_variables);
+        w_extended.resize(domain_h.size() - domain_x.size(), F::zero());
 
         let w_poly_time = start_timer!(|| "Computing w polynomial");
         let w_poly_evals = cfg_into_iter!(0..domain_h.size())
@@ -246,13 +244,13 @@ impl<F: PrimeField> AHPForR1CS<F> {
         end_timer!(w_poly_time);
 
         let z_a_poly_time = start_timer!(|| "Computing z_A polynomial");
-        let z_a = state.z_a.clone().unwrap();
+        let z_a = state.z_a.take().unwrap();
         let z_a_poly = &EvaluationsOnDomain::from_vec_and_domain(z_a, domain_h).interpolate()
             + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * &v_H);
         end_timer!(z_a_poly_time);
 
         let z_b_poly_time = start_timer!(|| "Computing z_B polynomial");
-        let z_b = state.z_b.clone().unwrap();
+        let z_b = state.z_b.take().unwrap();
         let z_b_poly = &EvaluationsOnDomain::from_vec_and_domain(z_b, domain_h).interpolate()
             + &(&Polynomial::from_coefficients_slice(&[F::rand(rng)]) * &v_H);
         end_timer!(z_b_poly_time);
@@ -458,7 +456,10 @@ impl<F: PrimeField> AHPForR1CS<F> {
             }
         };
 
+        // The first round polynomials are not needed by the third round.
         state.w_poly = None;
+        state.mz_polys = None;
+        state.mask_poly = None;
         state.verifier_first_message = Some(*verifier_message);
         end_timer!(round_time);
 
@@ -935,10 +935,7 @@ where
 mod test {
     use core::ops::MulAssign;
 
-    use snarkvm_curves::{
-        bls12_377::{Bls12_377, Fq, Fr},
-        bw6_761::BW6_761,
-    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_fields::{Field, Zero};
     use snarkvm_gadgets::{curves::bls12_377::PairingGadget as Bls12_377PairingGadget, traits::eq::EqGadget};
     use snarkvm_polycommit::{
@@ -980,7 +977,7 @@ mod test {
     type MultiPC = MarlinKZG10<Bls12_377>;
     type MarlinInst = MarlinSNARK<Fr, Fq, MultiPC, FS, MarlinRecursiveMode>;
 
-    type MultiPCVar = MarlinKZG10Gadget<Bls12_377, BW6_761, Bls12_377PairingGadget>;
+    type MultiPCVar = MarlinKZG10Gadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 
     type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq>>;
     type FSG = FiatShamirAlgebraicSpongeRngVar<Fr, Fq, PoseidonSponge<Fq>, PoseidonSpongeVar<Fq>>;
@@ -1109,7 +1106,7 @@ mod test {
         let mut message_gadgets = Vec::new();
 
         for (i, comm) in first_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1241,7 +1238,7 @@ mod test {
         let mut message_gadgets = Vec::new();
 
         for (i, comm) in first_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1292,7 +1289,7 @@ mod test {
         let mut second_round_message_gadgets = Vec::new();
 
         for (i, comm) in second_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_second_round_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1408,7 +1405,7 @@ mod test {
         let mut message_gadgets = Vec::new();
 
         for (i, comm) in first_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1459,7 +1456,7 @@ mod test {
         let mut second_round_message_gadgets = Vec::new();
 
         for (i, comm) in second_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_second_round_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1510,7 +1507,7 @@ mod test {
         let mut third_round_message_gadgets = Vec::new();
 
         for (i, comm) in third_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_third_round_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1628,7 +1625,7 @@ mod test {
         let mut message_gadgets = Vec::new();
 
         for (i, comm) in first_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1679,7 +1676,7 @@ mod test {
         let mut second_round_message_gadgets = Vec::new();
 
         for (i, comm) in second_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_second_round_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1730,7 +1727,7 @@ mod test {
         let mut third_round_message_gadgets = Vec::new();
 
         for (i, comm) in third_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_third_round_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1929,7 +1926,7 @@ mod test {
         let mut message_gadgets = Vec::new();
 
         for (i, comm) in first_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -1980,7 +1977,7 @@ mod test {
         let mut second_round_message_gadgets = Vec::new();
 
         for (i, comm) in second_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_second_round_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -2031,7 +2028,7 @@ mod test {
         let mut third_round_message_gadgets = Vec::new();
 
         for (i, comm) in third_commitments.iter().enumerate() {
-            let commitment_gagdet = CommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let commitment_gagdet = CommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_third_round_comm_{}", i)),
                 || Ok(comm.clone()),
             )
@@ -2184,7 +2181,7 @@ mod test {
             assert_eq!(commitment_native.label(), &commitment_gadget.label);
 
             // Check the commitment values
-            let expected_commitment = LabeledCommitmentVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(
+            let expected_commitment = LabeledCommitmentVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(
                 cs.ns(|| format!("alloc_commitment_{}", i)),
                 || Ok(commitment_native),
             )
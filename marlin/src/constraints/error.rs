@@ -63,6 +63,19 @@ where
             crate::marlin::MarlinError::<E>::PolynomialCommitmentError(err) => Self {
                 error_msg: err.to_string(),
             },
+            crate::marlin::MarlinError::<E>::EmptyBatch => Self {
+                error_msg: String::from("the batch does not contain any circuits"),
+            },
+            crate::marlin::MarlinError::<E>::BatchSizeMismatch(expected, found) => Self {
+                error_msg: format!(
+                    "the batch has {} circuits, but {} instances were given",
+                    expected, found
+                ),
+            },
+            crate::marlin::MarlinError::<E>::MemoryLimitExceeded(current, max) => Self {
+                error_msg: format!("allocated {} bytes, exceeding the limit of {} bytes", current, max),
+            },
+            crate::marlin::MarlinError::<E>::ThreadPoolError(err) => Self { error_msg: err },
         }
     }
 }
@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{batch_inversion, Field, PrimeField};
+use snarkvm_algorithms::fft::SubproductTree;
+use snarkvm_fields::{Field, PrimeField};
 use snarkvm_gadgets::{
     nonnative::NonNativeFieldVar,
     traits::{alloc::AllocGadget, eq::EqGadget, fields::FieldGadget},
@@ -48,8 +49,7 @@ impl<F: Field> VanishingPolynomial<F> {
 
 /// A Lagrange interpolation struct for field elements.
 pub struct LagrangeInterpolator<F: PrimeField> {
-    all_domain_elems: Vec<F>,
-    v_inv_elems: Vec<F>,
+    subproduct_tree: SubproductTree<F>,
     domain_vp: VanishingPolynomial<F>,
     poly_evaluations: Vec<F>,
 }
@@ -57,30 +57,19 @@ pub struct LagrangeInterpolator<F: PrimeField> {
 impl<BaseField: PrimeField> LagrangeInterpolator<BaseField> {
     /// Instantiates a new instance of the `LagrangeInterpolator`.
     pub fn new(domain_generator: BaseField, domain_order: u64, polynomial_evaluations: Vec<BaseField>) -> Self {
-        let num_polynomial_evaluations = polynomial_evaluations.len();
-
-        let mut current_element = domain_generator;
-        let mut all_domain_elems = vec![BaseField::one()];
-        let mut v_inv_elems: Vec<BaseField> = Vec::new();
-
-        for _ in 1..num_polynomial_evaluations {
+        let mut current_element = BaseField::one();
+        let mut all_domain_elems = Vec::with_capacity(domain_order as usize);
+        for _ in 0..domain_order {
             all_domain_elems.push(current_element);
             current_element *= &domain_generator;
         }
 
-        let g_inv = domain_generator.inverse().unwrap();
-        let m = BaseField::from(domain_order as u128);
-        let mut v_inv_i = m;
-        for _ in 0..num_polynomial_evaluations {
-            v_inv_elems.push(v_inv_i);
-            v_inv_i *= &g_inv;
-        }
-
+        // The tree spans the whole domain, so the evaluations missing at the end are treated as zero.
+        let subproduct_tree = SubproductTree::new(&all_domain_elems).unwrap();
         let vp = VanishingPolynomial::new(domain_generator, domain_order);
 
         let lagrange_interpolation: LagrangeInterpolator<BaseField> = LagrangeInterpolator {
-            all_domain_elems,
-            v_inv_elems,
+            subproduct_tree,
             domain_vp: vp,
             poly_evaluations: polynomial_evaluations,
         };
@@ -89,19 +78,11 @@ impl<BaseField: PrimeField> LagrangeInterpolator<BaseField> {
 
     /// Returns the Lagrange coefficients.
     fn compute_lagrange_coefficients(&self, interpolation_point: BaseField) -> Vec<BaseField> {
-        let poly_evaluations_size = self.poly_evaluations.len();
-
-        let vp_t_inv = self.domain_vp.evaluate(&interpolation_point).inverse().unwrap();
-        let mut inverted_lagrange_coeffs: Vec<BaseField> = Vec::with_capacity(self.all_domain_elems.len());
-        for i in 0..poly_evaluations_size {
-            let l = vp_t_inv * self.v_inv_elems[i];
-            let r = self.all_domain_elems[i];
-            inverted_lagrange_coeffs.push(l * (interpolation_point - r));
-        }
-        let lagrange_coeffs = inverted_lagrange_coeffs.as_mut_slice();
-        batch_inversion::<BaseField>(lagrange_coeffs);
-
-        lagrange_coeffs.to_vec()
+        let mut lagrange_coeffs = self
+            .subproduct_tree
+            .evaluate_all_lagrange_coefficients(interpolation_point);
+        lagrange_coeffs.truncate(self.poly_evaluations.len());
+        lagrange_coeffs
     }
 
     /// Returns the interpolated value for a given point.
@@ -184,16 +165,16 @@ impl<TargetField: PrimeField, BaseField: PrimeField> LagrangeInterpolationVar<Ta
             self.vp_t = Some(vp_t.clone());
         }
 
-        for (i, ((all_domain_elem, v_inverse), lagrange_coefficient)) in self
-            .lagrange_interpolator
-            .all_domain_elems
+        let subproduct_tree = &self.lagrange_interpolator.subproduct_tree;
+        for (i, ((domain_elem, vanishing_derivative), lagrange_coefficient)) in subproduct_tree
+            .points()
             .iter()
-            .zip(self.lagrange_interpolator.v_inv_elems.iter())
+            .zip(subproduct_tree.vanishing_derivative_evaluations())
             .zip(lagrange_coeffs.iter())
             .take(num_polynomial_evaluations)
             .enumerate()
         {
-            let domain: TargetField = -*all_domain_elem;
+            let domain: TargetField = -*domain_elem;
 
             let lagrange_coefficient = NonNativeFieldVar::<TargetField, BaseField>::alloc(
                 cs.ns(|| format!("generate_lagrange_coefficient_{}", i)),
@@ -202,8 +183,8 @@ impl<TargetField: PrimeField, BaseField: PrimeField> LagrangeInterpolationVar<Ta
             lagrange_coeffs_fg.push(lagrange_coefficient.clone());
 
             let t_minus_domain = t.add_constant(cs.ns(|| format!("t_minus_domain_{}", i)), &domain)?;
-            let t_minus_domain_div_v =
-                &t_minus_domain.mul_by_constant(cs.ns(|| format!("t_minus_domain_div_v_{}", i)), v_inverse)?;
+            let t_minus_domain_div_v = &t_minus_domain
+                .mul_by_constant(cs.ns(|| format!("t_minus_domain_div_v_{}", i)), vanishing_derivative)?;
             let test_elem = t_minus_domain_div_v.mul(
                 cs.ns(|| format!("t_minus_domain_div_v_mul_lag_coeff_{}", i)),
                 &lagrange_coefficient,
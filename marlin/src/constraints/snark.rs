@@ -16,12 +16,13 @@
 
 use std::{
     fmt::{Debug, Formatter},
+    io::Read,
     marker::PhantomData,
 };
 
 use rand::{CryptoRng, Rng, RngCore};
 
-use snarkvm_algorithms::{SNARKError, SNARK};
+use snarkvm_algorithms::{errors::EnvelopeError, Envelope, SNARKError, SNARK};
 use snarkvm_fields::{PoseidonMDSField, PrimeField, ToConstraintField};
 use snarkvm_gadgets::{
     bits::Boolean,
@@ -215,6 +216,14 @@ where
             Err(e) => Err(SNARKError::from(e)),
         }
     }
+
+    fn read_proving_key_unchecked<R: Read>(reader: R) -> Result<Self::ProvingKey, EnvelopeError> {
+        Self::ProvingKey::read_envelope_unchecked(reader, MM::envelope_mode())
+    }
+
+    fn read_verifying_key<R: Read>(reader: R) -> Result<Self::VerifyingKey, EnvelopeError> {
+        Self::VerifyingKey::read_envelope(reader, MM::envelope_mode())
+    }
 }
 
 /// The Marlin proof system gadget.
@@ -365,10 +374,7 @@ impl<F: PrimeField> ConstraintSynthesizer<F> for MarlinBoundCircuit<F> {
 pub mod test {
     use core::ops::MulAssign;
 
-    use snarkvm_curves::{
-        bls12_377::{Bls12_377, Fq, Fr},
-        bw6_761::BW6_761,
-    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_fields::Field;
     use snarkvm_gadgets::{
         curves::bls12_377::PairingGadget as Bls12_377PairingGadget,
@@ -432,7 +438,7 @@ pub mod test {
     }
 
     type PC = MarlinKZG10<Bls12_377>;
-    type PCGadget = MarlinKZG10Gadget<Bls12_377, BW6_761, Bls12_377PairingGadget>;
+    type PCGadget = MarlinKZG10Gadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 
     type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq>>;
     type FSG = FiatShamirAlgebraicSpongeRngVar<Fr, Fq, PoseidonSponge<Fq>, PoseidonSpongeVar<Fq>>;
@@ -525,6 +531,89 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn marlin_snark_bw6_761_test() {
+        use snarkvm_curves::bw6_761::{Fq as BW6Fq, Fr as BW6Fr, BW6_761};
+        use snarkvm_gadgets::curves::bw6_761::PairingGadget as BW6_761PairingGadget;
+
+        type BW6PC = MarlinKZG10<BW6_761>;
+        type BW6PCGadget = MarlinKZG10Gadget<BW6_761, BW6Fq, BW6_761PairingGadget>;
+
+        type BW6FS = FiatShamirAlgebraicSpongeRng<BW6Fr, BW6Fq, PoseidonSponge<BW6Fq>>;
+        type BW6FSG = FiatShamirAlgebraicSpongeRngVar<BW6Fr, BW6Fq, PoseidonSponge<BW6Fq>, PoseidonSpongeVar<BW6Fq>>;
+
+        type BW6SNARK = MarlinSNARK<BW6Fr, BW6Fq, BW6PC, BW6FS, MarlinRecursiveMode, Circuit<BW6Fr>, Vec<BW6Fr>>;
+        type BW6SNARKGadget = MarlinSNARKGadget<BW6Fr, BW6Fq, BW6PC, BW6FS, MarlinRecursiveMode, BW6PCGadget, BW6FSG>;
+
+        let mut rng = test_rng();
+
+        // Construct the circuit.
+
+        let a = BW6Fr::rand(&mut rng);
+        let b = BW6Fr::rand(&mut rng);
+        let mut c = a;
+        c.mul_assign(&b);
+
+        let circ = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 100,
+            num_variables: 25,
+        };
+
+        // Generate the circuit parameters.
+
+        let (pk, vk) = BW6SNARK::circuit_specific_setup(circ, &mut rng).unwrap();
+
+        // Test native proof and verification.
+
+        let proof = BW6SNARK::prove(&pk, &circ, &mut rng).unwrap();
+
+        assert!(
+            BW6SNARK::verify(&vk.clone().into(), &vec![c], &proof).unwrap(),
+            "The native verification check fails."
+        );
+
+        // Initialize constraint system.
+        let mut cs = TestConstraintSystem::<BW6Fq>::new();
+
+        let input_gadget = <BW6SNARKGadget as SNARKGadget<BW6Fr, BW6Fq, BW6SNARK>>::InputVar::alloc_input(
+            cs.ns(|| "alloc_input_gadget"),
+            || Ok(vec![c]),
+        )
+        .unwrap();
+
+        let proof_gadget =
+            <BW6SNARKGadget as SNARKGadget<BW6Fr, BW6Fq, BW6SNARK>>::ProofVar::alloc(cs.ns(|| "alloc_proof"), || {
+                Ok(proof)
+            })
+            .unwrap();
+
+        let vk_gadget = <BW6SNARKGadget as SNARKGadget<BW6Fr, BW6Fq, BW6SNARK>>::VerifyingKeyVar::alloc(
+            cs.ns(|| "alloc_vk"),
+            || Ok(vk.clone()),
+        )
+        .unwrap();
+
+        let verification_result = <BW6SNARKGadget as SNARKGadget<BW6Fr, BW6Fq, BW6SNARK>>::verify(
+            cs.ns(|| "marlin_verify"),
+            &vk_gadget,
+            &input_gadget,
+            &proof_gadget,
+        )
+        .unwrap();
+
+        verification_result
+            .enforce_equal(cs.ns(|| "enforce_equal_verification"), &Boolean::Constant(true))
+            .unwrap();
+
+        assert!(
+            cs.is_satisfied(),
+            "Constraints not satisfied: {}",
+            cs.which_is_unsatisfied().unwrap()
+        );
+    }
+
     #[test]
     fn marlin_verifier_num_constraints_test() {
         let mut rng = test_rng();
@@ -566,12 +655,14 @@ pub mod test {
         .unwrap();
 
         let input_gadget_constraints = cs.num_constraints();
+        let mut num_constraints = cs.num_constraints();
 
         let proof_gadget =
             <TestSNARKGadget as SNARKGadget<Fr, Fq, TestSNARK>>::ProofVar::alloc(cs.ns(|| "alloc_proof"), || Ok(proof))
                 .unwrap();
 
-        let proof_gadget_constraints = cs.num_constraints() - input_gadget_constraints;
+        let proof_gadget_constraints = cs.num_constraints() - num_constraints;
+        num_constraints = cs.num_constraints();
 
         let vk_gadget =
             <TestSNARKGadget as SNARKGadget<Fr, Fq, TestSNARK>>::VerifyingKeyVar::alloc(cs.ns(|| "alloc_vk"), || {
@@ -579,7 +670,8 @@ pub mod test {
             })
             .unwrap();
 
-        let vk_gadget_constraints = cs.num_constraints() - input_gadget_constraints;
+        let vk_gadget_constraints = cs.num_constraints() - num_constraints;
+        num_constraints = cs.num_constraints();
 
         let verification_result = <TestSNARKGadget as SNARKGadget<Fr, Fq, TestSNARK>>::verify(
             cs.ns(|| "marlin_verify"),
@@ -589,7 +681,7 @@ pub mod test {
         )
         .unwrap();
 
-        let verifier_gadget_constraints = cs.num_constraints() - proof_gadget_constraints;
+        let verifier_gadget_constraints = cs.num_constraints() - num_constraints;
 
         verification_result
             .enforce_equal(cs.ns(|| "enforce_equal_verification"), &Boolean::Constant(true))
@@ -603,8 +695,8 @@ pub mod test {
 
         const INPUT_GADGET_CONSTRAINTS: usize = 383;
         const PROOF_GADGET_CONSTRAINTS: usize = 56;
-        const VK_GADGET_CONSTRAINTS: usize = 136;
-        const VERIFIER_GADGET_CONSTRAINTS: usize = 152885;
+        const VK_GADGET_CONSTRAINTS: usize = 80;
+        const VERIFIER_GADGET_CONSTRAINTS: usize = 152422;
 
         assert_eq!(input_gadget_constraints, INPUT_GADGET_CONSTRAINTS);
         assert_eq!(proof_gadget_constraints, PROOF_GADGET_CONSTRAINTS);
@@ -767,7 +859,7 @@ pub mod multiple_input_tests {
     }
 
     type PC = MarlinKZG10<Bls12_377>;
-    type PCGadget = MarlinKZG10Gadget<Bls12_377, BW6_761, Bls12_377PairingGadget>;
+    type PCGadget = MarlinKZG10Gadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 
     type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq>>;
     type FSG = FiatShamirAlgebraicSpongeRngVar<Fr, Fq, PoseidonSponge<Fq>, PoseidonSpongeVar<Fq>>;
@@ -279,10 +279,7 @@ mod test {
 
     use hashbrown::HashMap;
 
-    use snarkvm_curves::{
-        bls12_377::{Bls12_377, Fq, Fr},
-        bw6_761::BW6_761,
-    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_gadgets::{
         curves::bls12_377::PairingGadget as Bls12_377PairingGadget,
         traits::{alloc::AllocGadget, eq::EqGadget},
@@ -310,7 +307,7 @@ mod test {
     use super::*;
 
     type PC = MarlinKZG10<Bls12_377>;
-    type PCGadget = MarlinKZG10Gadget<Bls12_377, BW6_761, Bls12_377PairingGadget>;
+    type PCGadget = MarlinKZG10Gadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 
     type FS = FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq>>;
     type FSG = FiatShamirAlgebraicSpongeRngVar<Fr, Fq, PoseidonSponge<Fq>, PoseidonSpongeVar<Fq>>;
@@ -377,7 +374,7 @@ mod test {
             ..
         } = proof;
 
-        let commitment_gadgets: Vec<Vec<CommitmentVar<Bls12_377, BW6_761, Bls12_377PairingGadget>>> = commitments
+        let commitment_gadgets: Vec<Vec<CommitmentVar<Bls12_377, Fq, Bls12_377PairingGadget>>> = commitments
             .iter()
             .enumerate()
             .map(|(i, lst)| {
@@ -419,10 +416,8 @@ mod test {
             .collect();
 
         let pc_batch_proof =
-            BatchLCProofVar::<Bls12_377, BW6_761, Bls12_377PairingGadget>::alloc(cs.ns(|| "alloc#proof"), || {
-                Ok(pc_proof)
-            })
-            .unwrap();
+            BatchLCProofVar::<Bls12_377, Fq, Bls12_377PairingGadget>::alloc(cs.ns(|| "alloc#proof"), || Ok(pc_proof))
+                .unwrap();
 
         let mut evaluation_gadgets = HashMap::<String, NonNativeFieldVar<Fr, Fq>>::new();
 
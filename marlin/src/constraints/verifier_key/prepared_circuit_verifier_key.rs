@@ -511,10 +511,7 @@ where
 mod test {
     use core::ops::MulAssign;
 
-    use snarkvm_curves::{
-        bls12_377::{Bls12_377, Fq, Fr},
-        bw6_761::BW6_761,
-    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_gadgets::{curves::bls12_377::PairingGadget as Bls12_377PairingGadget, traits::eq::EqGadget};
     use snarkvm_polycommit::marlin_pc::{marlin_kzg10::MarlinKZG10Gadget, MarlinKZG10};
     use snarkvm_r1cs::TestConstraintSystem;
@@ -536,7 +533,7 @@ mod test {
     type MultiPC = MarlinKZG10<Bls12_377>;
     type MarlinInst = MarlinSNARK<Fr, Fq, MultiPC, FS, MarlinTestnet1Mode>;
 
-    type MultiPCVar = MarlinKZG10Gadget<Bls12_377, BW6_761, Bls12_377PairingGadget>;
+    type MultiPCVar = MarlinKZG10Gadget<Bls12_377, Fq, Bls12_377PairingGadget>;
 
     #[test]
     fn test_alloc() {
@@ -0,0 +1,665 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ahp::{prover::ProverMessage, AHPError, AHPForR1CS, EvaluationsProvider},
+    fiat_shamir::traits::FiatShamirRng,
+    marlin::{
+        compute_vk_hash,
+        BatchProof,
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        MarlinError,
+        MarlinMode,
+        MarlinSNARK,
+        UniversalSRS,
+    },
+    String,
+    ToString,
+    Vec,
+};
+use snarkvm_algorithms::fft::EvaluationDomain;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_gadgets::nonnative::params::OptimizationType;
+use snarkvm_polycommit::{
+    Evaluations,
+    LCTerm,
+    LabeledCommitment,
+    LabeledPolynomial,
+    LinearCombination,
+    PCUniversalParams,
+    PolynomialCommitment,
+    QuerySet,
+};
+use snarkvm_r1cs::{ConstraintSynthesizer, SynthesisError};
+use snarkvm_utilities::{bytes::ToBytes, to_bytes};
+
+use rand_core::RngCore;
+
+impl<
+    TargetField: PrimeField,
+    BaseField: PrimeField,
+    PC: PolynomialCommitment<TargetField>,
+    FS: FiatShamirRng<TargetField, BaseField>,
+    MM: MarlinMode,
+> MarlinSNARK<TargetField, BaseField, PC, FS, MM>
+where
+    PC::VerifierKey: ToConstraintField<BaseField>,
+    PC::Commitment: ToConstraintField<BaseField>,
+{
+    /// The personalization string for batches of circuits.
+    /// Used to personalize the Fiat-Shamir RNG.
+    pub const BATCH_PROTOCOL_NAME: &'static [u8] = b"MARLIN-2019-BATCH";
+
+    /// Trims the universal SRS into a committer key and a verifier key
+    /// that support proving any batch of the given circuits together.
+    /// This is a deterministic algorithm that anyone can rerun.
+    #[allow(clippy::type_complexity)]
+    pub fn batch_setup(
+        universal_srs: &UniversalSRS<TargetField, PC>,
+        circuit_verifying_keys: &[&CircuitVerifyingKey<TargetField, PC>],
+    ) -> Result<(PC::CommitterKey, PC::VerifierKey), MarlinError<PC::Error>> {
+        let max_degree = circuit_verifying_keys
+            .iter()
+            .map(|vk| vk.circuit_info.max_degree())
+            .max()
+            .ok_or(MarlinError::EmptyBatch)?;
+        if universal_srs.max_degree() < max_degree {
+            return Err(MarlinError::IndexTooLarge(universal_srs.max_degree(), max_degree));
+        }
+
+        let mut coefficient_support: Vec<usize> = circuit_verifying_keys
+            .iter()
+            .flat_map(|vk| AHPForR1CS::get_degree_bounds(&vk.circuit_info).to_vec())
+            .collect();
+        coefficient_support.sort_unstable();
+        coefficient_support.dedup();
+
+        // Marlin only needs degree 2 random polynomials.
+        let supported_hiding_bound = 1;
+        PC::trim(
+            universal_srs,
+            max_degree,
+            supported_hiding_bound,
+            Some(&coefficient_support),
+        )
+        .map_err(MarlinError::from_pc_err)
+    }
+
+    /// Create a single zkSNARK asserting that the constraint systems of all given circuits are satisfied.
+    /// The committer key must be obtained from `batch_setup` for (a superset of) the given circuits.
+    pub fn prove_batch<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        committer_key: &PC::CommitterKey,
+        circuit_proving_keys: &[&CircuitProvingKey<TargetField, PC>],
+        circuits: &[&C],
+        zk_rng: &mut R,
+    ) -> Result<BatchProof<TargetField, PC>, MarlinError<PC::Error>> {
+        let prover_time = start_timer!(|| format!("Marlin::BatchProver with {} circuits", circuits.len()));
+        Self::check_batch_size(circuit_proving_keys.len(), circuits.len())?;
+
+        let is_recursion = MM::RECURSION;
+        let hiding = !is_recursion;
+
+        let prover_init_states = circuit_proving_keys
+            .iter()
+            .zip(circuits)
+            .map(|(circuit_proving_key, circuit)| AHPForR1CS::prover_init(&circuit_proving_key.circuit, *circuit))
+            .collect::<Result<Vec<_>, _>>()?;
+        let public_inputs: Vec<_> = prover_init_states.iter().map(|state| state.public_input()).collect();
+
+        let mut fs_rng = FS::new();
+        Self::absorb_batch_statement(
+            &mut fs_rng,
+            circuit_proving_keys.iter().map(|pk| &pk.circuit_verifying_key),
+            &public_inputs,
+        )?;
+
+        // --------------------------------------------------------------------
+        // First round
+
+        let mut first_messages = Vec::with_capacity(circuits.len());
+        let mut first_oracles = Vec::with_capacity(circuits.len());
+        let mut first_commitments = Vec::with_capacity(circuits.len());
+        let mut first_randomnesses = Vec::with_capacity(circuits.len());
+        let mut prover_states = Vec::with_capacity(circuits.len());
+        for prover_init_state in prover_init_states {
+            let (message, oracles, state) = AHPForR1CS::prover_first_round(prover_init_state, zk_rng, hiding)?;
+            let (commitments, randomnesses) =
+                PC::commit(committer_key, oracles.iter(), Some(zk_rng)).map_err(MarlinError::from_pc_err)?;
+            Self::absorb_round(&mut fs_rng, &commitments, &message);
+
+            first_messages.push(message);
+            first_oracles.push(oracles);
+            first_commitments.push(commitments);
+            first_randomnesses.push(randomnesses);
+            prover_states.push(state);
+        }
+
+        let mut verifier_first_messages = Vec::with_capacity(circuits.len());
+        let mut verifier_states = Vec::with_capacity(circuits.len());
+        for circuit_proving_key in circuit_proving_keys {
+            let (message, state) =
+                AHPForR1CS::verifier_first_round(circuit_proving_key.circuit_verifying_key.circuit_info, &mut fs_rng)?;
+            verifier_first_messages.push(message);
+            verifier_states.push(state);
+        }
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+
+        let mut second_messages = Vec::with_capacity(circuits.len());
+        let mut second_oracles = Vec::with_capacity(circuits.len());
+        let mut second_commitments = Vec::with_capacity(circuits.len());
+        let mut second_randomnesses = Vec::with_capacity(circuits.len());
+        let mut next_prover_states = Vec::with_capacity(circuits.len());
+        for (state, verifier_message) in prover_states.into_iter().zip(&verifier_first_messages) {
+            let (message, oracles, state) = AHPForR1CS::prover_second_round(verifier_message, state, zk_rng, hiding);
+            let (commitments, randomnesses) =
+                PC::commit(committer_key, oracles.iter(), Some(zk_rng)).map_err(MarlinError::from_pc_err)?;
+            Self::absorb_round(&mut fs_rng, &commitments, &message);
+
+            second_messages.push(message);
+            second_oracles.push(oracles);
+            second_commitments.push(commitments);
+            second_randomnesses.push(randomnesses);
+            next_prover_states.push(state);
+        }
+        let prover_states = next_prover_states;
+
+        let mut verifier_second_messages = Vec::with_capacity(circuits.len());
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| {
+                let (message, state) = AHPForR1CS::verifier_second_round(state, &mut fs_rng)?;
+                verifier_second_messages.push(message);
+                Ok(state)
+            })
+            .collect::<Result<Vec<_>, AHPError>>()?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+
+        let mut third_messages = Vec::with_capacity(circuits.len());
+        let mut third_oracles = Vec::with_capacity(circuits.len());
+        let mut third_commitments = Vec::with_capacity(circuits.len());
+        let mut third_randomnesses = Vec::with_capacity(circuits.len());
+        for (state, verifier_message) in prover_states.into_iter().zip(&verifier_second_messages) {
+            let (message, oracles) = AHPForR1CS::prover_third_round(verifier_message, state, zk_rng)?;
+            let (commitments, randomnesses) =
+                PC::commit(committer_key, oracles.iter(), Some(zk_rng)).map_err(MarlinError::from_pc_err)?;
+            Self::absorb_round(&mut fs_rng, &commitments, &message);
+
+            third_messages.push(message);
+            third_oracles.push(oracles);
+            third_commitments.push(commitments);
+            third_randomnesses.push(randomnesses);
+        }
+
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::verifier_third_round(state, &mut fs_rng))
+            .collect::<Result<Vec<_>, _>>()?;
+        // --------------------------------------------------------------------
+
+        // Gather the polynomials, commitments and randomness of every circuit,
+        // relabeled so that the circuits can be opened together.
+        let mut polynomials = Vec::new();
+        let mut labeled_commitments = Vec::new();
+        let mut commitment_randomnesses = Vec::new();
+        let mut query_set = QuerySet::new();
+        let mut lc_s = Vec::new();
+        let mut evaluations = Vec::with_capacity(circuits.len());
+        for (i, (circuit_proving_key, verifier_state)) in circuit_proving_keys.iter().zip(verifier_states).enumerate() {
+            let vanishing_polys = if is_recursion {
+                Self::vanishing_polynomials(circuit_proving_key)?
+            } else {
+                vec![]
+            };
+
+            let circuit_polynomials: Vec<_> = circuit_proving_key
+                .circuit
+                .iter()
+                .chain(vanishing_polys.iter())
+                .chain(first_oracles[i].iter())
+                .chain(second_oracles[i].iter())
+                .chain(third_oracles[i].iter())
+                .collect();
+
+            let indexer_polynomials = if is_recursion {
+                AHPForR1CS::<TargetField>::INDEXER_POLYNOMIALS_WITH_VANISHING.to_vec()
+            } else {
+                AHPForR1CS::<TargetField>::INDEXER_POLYNOMIALS.to_vec()
+            };
+            let circuit_commitments = circuit_proving_key
+                .circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip(indexer_polynomials)
+                .map(|(c, l)| LabeledCommitment::new(l.to_string(), c, None))
+                .chain(first_commitments[i].iter().cloned())
+                .chain(second_commitments[i].iter().cloned())
+                .chain(third_commitments[i].iter().cloned());
+
+            labeled_commitments.extend(
+                circuit_commitments.map(|c| {
+                    LabeledCommitment::new(batch_label(i, c.label()), c.commitment().clone(), c.degree_bound())
+                }),
+            );
+            commitment_randomnesses.extend(
+                circuit_proving_key
+                    .circuit_commitment_randomness
+                    .iter()
+                    .chain(&first_randomnesses[i])
+                    .chain(&second_randomnesses[i])
+                    .chain(&third_randomnesses[i])
+                    .cloned(),
+            );
+
+            // Compute the AHP verifier's query set for this circuit.
+            let (circuit_query_set, verifier_state) =
+                AHPForR1CS::verifier_query_set(verifier_state, &mut fs_rng, is_recursion);
+            let circuit_lc_s = AHPForR1CS::construct_linear_combinations(
+                &public_inputs[i],
+                &circuit_polynomials,
+                &verifier_state,
+                is_recursion,
+            )?;
+
+            let mut evaluations_unsorted = Vec::new();
+            for (label, (_point_name, point)) in &circuit_query_set {
+                let lc = circuit_lc_s
+                    .iter()
+                    .find(|lc| &lc.label == label)
+                    .ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                let evaluation = circuit_polynomials.get_lc_eval(lc, *point)?;
+                if !AHPForR1CS::<TargetField>::LC_WITH_ZERO_EVAL.contains(&lc.label.as_ref()) {
+                    evaluations_unsorted.push((label.to_string(), evaluation));
+                }
+            }
+            evaluations_unsorted.sort_by(|a, b| a.0.cmp(&b.0));
+            evaluations.push(evaluations_unsorted.into_iter().map(|x| x.1).collect::<Vec<_>>());
+
+            polynomials.extend(
+                circuit_polynomials
+                    .into_iter()
+                    .map(|p| p.with_label(batch_label(i, p.label()))),
+            );
+            query_set.extend(batch_query_set(i, circuit_query_set));
+            lc_s.extend(circuit_lc_s.iter().map(|lc| batch_linear_combination(i, lc)));
+        }
+
+        for circuit_evaluations in &evaluations {
+            Self::absorb_evaluations(&mut fs_rng, circuit_evaluations);
+        }
+
+        let pc_proof = if is_recursion {
+            let num_open_challenges: usize = 7;
+
+            let mut opening_challenges = Vec::new();
+            opening_challenges.append(&mut fs_rng.squeeze_128_bits_nonnative_field_elements(num_open_challenges)?);
+
+            let opening_challenges_f = |i| opening_challenges[i as usize];
+
+            PC::open_combinations_individual_opening_challenges(
+                committer_key,
+                &lc_s,
+                &polynomials,
+                &labeled_commitments,
+                &query_set,
+                &opening_challenges_f,
+                &commitment_randomnesses,
+            )
+            .map_err(MarlinError::from_pc_err)?
+        } else {
+            let opening_challenge: TargetField = fs_rng.squeeze_128_bits_nonnative_field_elements(1)?[0];
+
+            PC::open_combinations(
+                committer_key,
+                &lc_s,
+                &polynomials,
+                &labeled_commitments,
+                &query_set,
+                opening_challenge,
+                &commitment_randomnesses,
+                Some(zk_rng),
+            )
+            .map_err(MarlinError::from_pc_err)?
+        };
+
+        // Gather commitments and prover messages by circuit.
+        let commitments = first_commitments
+            .into_iter()
+            .zip(second_commitments)
+            .zip(third_commitments)
+            .map(|((first, second), third)| {
+                vec![
+                    first.iter().map(|p| p.commitment()).cloned().collect(),
+                    second.iter().map(|p| p.commitment()).cloned().collect(),
+                    third.iter().map(|p| p.commitment()).cloned().collect(),
+                ]
+            })
+            .collect();
+        let prover_messages = first_messages
+            .into_iter()
+            .zip(second_messages)
+            .zip(third_messages)
+            .map(|((first, second), third)| vec![first, second, third])
+            .collect();
+
+        let proof = BatchProof::new(commitments, evaluations, prover_messages, pc_proof);
+        end_timer!(prover_time);
+
+        Ok(proof)
+    }
+
+    /// Verify that a batch proof asserts that the constraint systems of all given circuits are satisfied.
+    /// The verifier key must be obtained from `batch_setup` for (a superset of) the given circuits.
+    pub fn verify_batch(
+        verifier_key: &PC::VerifierKey,
+        circuit_verifying_keys: &[&CircuitVerifyingKey<TargetField, PC>],
+        public_inputs: &[&[TargetField]],
+        proof: &BatchProof<TargetField, PC>,
+    ) -> Result<bool, MarlinError<PC::Error>> {
+        let verifier_time = start_timer!(|| format!("Marlin::BatchVerify with {} circuits", public_inputs.len()));
+        Self::check_batch_size(circuit_verifying_keys.len(), public_inputs.len())?;
+
+        let num_circuits = circuit_verifying_keys.len();
+        if proof.commitments.len() != num_circuits
+            || proof.evaluations.len() != num_circuits
+            || proof.prover_messages.len() != num_circuits
+            || proof.commitments.iter().any(|commitments| commitments.len() != 3)
+            || proof.prover_messages.iter().any(|messages| messages.len() != 3)
+        {
+            end_timer!(verifier_time);
+            return Ok(false);
+        }
+
+        let is_recursion = MM::RECURSION;
+
+        let public_inputs: Vec<_> = public_inputs
+            .iter()
+            .map(|public_input| {
+                let domain_x = EvaluationDomain::<TargetField>::new(public_input.len() + 1).unwrap();
+                let mut new_input = public_input.to_vec();
+                new_input.resize(
+                    core::cmp::max(public_input.len(), domain_x.size() - 1),
+                    TargetField::zero(),
+                );
+                new_input
+            })
+            .collect();
+
+        let mut fs_rng = FS::new();
+        Self::absorb_batch_statement(&mut fs_rng, circuit_verifying_keys.iter().copied(), &public_inputs)?;
+
+        // First round
+        for (commitments, messages) in proof.commitments.iter().zip(&proof.prover_messages) {
+            Self::absorb_round(&mut fs_rng, &commitments[0], &messages[0]);
+        }
+        let verifier_states = circuit_verifying_keys
+            .iter()
+            .map(|vk| Ok(AHPForR1CS::verifier_first_round(vk.circuit_info, &mut fs_rng)?.1))
+            .collect::<Result<Vec<_>, AHPError>>()?;
+
+        // Second round
+        for (commitments, messages) in proof.commitments.iter().zip(&proof.prover_messages) {
+            Self::absorb_round(&mut fs_rng, &commitments[1], &messages[1]);
+        }
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| Ok(AHPForR1CS::verifier_second_round(state, &mut fs_rng)?.1))
+            .collect::<Result<Vec<_>, AHPError>>()?;
+
+        // Third round
+        for (commitments, messages) in proof.commitments.iter().zip(&proof.prover_messages) {
+            Self::absorb_round(&mut fs_rng, &commitments[2], &messages[2]);
+        }
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::verifier_third_round(state, &mut fs_rng))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Compute the query sets of all circuits before absorbing the evaluations, as the prover does.
+        let query_sets_and_states: Vec<_> = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::verifier_query_set(state, &mut fs_rng, is_recursion))
+            .collect();
+
+        for circuit_evaluations in &proof.evaluations {
+            Self::absorb_evaluations(&mut fs_rng, circuit_evaluations);
+        }
+
+        let mut commitments = Vec::new();
+        let mut query_set = QuerySet::new();
+        let mut evaluations = Evaluations::new();
+        let mut lc_s = Vec::new();
+        for (i, (circuit_verifying_key, (circuit_query_set, verifier_state))) in
+            circuit_verifying_keys.iter().zip(query_sets_and_states).enumerate()
+        {
+            // Collect degree bounds for commitments. Indexed polynomials have *no*
+            // degree bounds because we know the committed index polynomial has the
+            // correct degree.
+            let index_info = circuit_verifying_key.circuit_info;
+            let degree_bounds = vec![None; circuit_verifying_key.circuit_commitments.len()]
+                .into_iter()
+                .chain(AHPForR1CS::prover_first_round_degree_bounds(&index_info))
+                .chain(AHPForR1CS::prover_second_round_degree_bounds(&index_info))
+                .chain(AHPForR1CS::prover_third_round_degree_bounds(&index_info));
+
+            let polynomial_labels: Vec<String> = if is_recursion {
+                AHPForR1CS::<TargetField>::polynomial_labels_with_vanishing().collect()
+            } else {
+                AHPForR1CS::<TargetField>::polynomial_labels().collect()
+            };
+
+            commitments.extend(
+                circuit_verifying_key
+                    .iter()
+                    .chain(proof.commitments[i].iter().flatten())
+                    .cloned()
+                    .zip(polynomial_labels)
+                    .zip(degree_bounds)
+                    .map(|((c, l), d)| LabeledCommitment::new(batch_label(i, &l), c, d)),
+            );
+
+            let mut circuit_evaluations = Evaluations::new();
+            let mut evaluation_labels = Vec::<(String, TargetField)>::new();
+            for (label, (_point_name, q)) in circuit_query_set.iter().cloned() {
+                if AHPForR1CS::<TargetField>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                    circuit_evaluations.insert((label, q), TargetField::zero());
+                } else {
+                    evaluation_labels.push((label, q));
+                }
+            }
+            evaluation_labels.sort_by(|a, b| a.0.cmp(&b.0));
+            for (q, eval) in evaluation_labels.into_iter().zip(&proof.evaluations[i]) {
+                circuit_evaluations.insert(q, *eval);
+            }
+
+            let circuit_lc_s = AHPForR1CS::construct_linear_combinations(
+                &public_inputs[i],
+                &circuit_evaluations,
+                &verifier_state,
+                is_recursion,
+            )?;
+
+            evaluations.extend(
+                circuit_evaluations
+                    .into_iter()
+                    .map(|((label, point), evaluation)| ((batch_label(i, &label), point), evaluation)),
+            );
+            query_set.extend(batch_query_set(i, circuit_query_set));
+            lc_s.extend(circuit_lc_s.iter().map(|lc| batch_linear_combination(i, lc)));
+        }
+
+        let evaluations_are_correct = if is_recursion {
+            let num_open_challenges: usize = 7;
+
+            let mut opening_challenges = Vec::new();
+            opening_challenges.append(&mut fs_rng.squeeze_128_bits_nonnative_field_elements(num_open_challenges)?);
+
+            let opening_challenges_f = |i| opening_challenges[i as usize];
+
+            PC::check_combinations_individual_opening_challenges(
+                verifier_key,
+                &lc_s,
+                &commitments,
+                &query_set,
+                &evaluations,
+                &proof.pc_proof,
+                &opening_challenges_f,
+                &mut fs_rng,
+            )
+            .map_err(MarlinError::from_pc_err)?
+        } else {
+            let opening_challenge: TargetField = fs_rng.squeeze_128_bits_nonnative_field_elements(1)?[0];
+
+            PC::check_combinations(
+                verifier_key,
+                &lc_s,
+                &commitments,
+                &query_set,
+                &evaluations,
+                &proof.pc_proof,
+                opening_challenge,
+                &mut fs_rng,
+            )
+            .map_err(MarlinError::from_pc_err)?
+        };
+
+        end_timer!(verifier_time, || format!(
+            " PC::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Returns an error if the batch is empty or its number of circuits and instances differ.
+    fn check_batch_size(num_circuits: usize, num_instances: usize) -> Result<(), MarlinError<PC::Error>> {
+        match (num_circuits, num_instances) {
+            (0, _) => Err(MarlinError::EmptyBatch),
+            (num_circuits, num_instances) if num_circuits != num_instances => {
+                Err(MarlinError::BatchSizeMismatch(num_circuits, num_instances))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Absorbs the circuit verifying keys and public inputs of a batch into the Fiat-Shamir RNG.
+    fn absorb_batch_statement<'a>(
+        fs_rng: &mut FS,
+        circuit_verifying_keys: impl Iterator<Item = &'a CircuitVerifyingKey<TargetField, PC>>,
+        public_inputs: &[Vec<TargetField>],
+    ) -> Result<(), MarlinError<PC::Error>>
+    where
+        PC: 'a,
+    {
+        let num_circuits = public_inputs.len() as u64;
+        fs_rng.absorb_bytes(&to_bytes![&Self::BATCH_PROTOCOL_NAME, num_circuits].unwrap());
+
+        for (circuit_verifying_key, public_input) in circuit_verifying_keys.zip(public_inputs) {
+            if MM::RECURSION {
+                fs_rng.absorb_native_field_elements(&compute_vk_hash::<TargetField, BaseField, PC, FS>(
+                    circuit_verifying_key,
+                )?);
+                fs_rng.absorb_nonnative_field_elements(public_input, OptimizationType::Weight);
+            } else {
+                fs_rng.absorb_bytes(&to_bytes![circuit_verifying_key, public_input].unwrap());
+            }
+        }
+        Ok(())
+    }
+
+    /// Absorbs the commitments and message of one circuit in a prover round into the Fiat-Shamir RNG.
+    fn absorb_round<T: ToBytes + ToConstraintField<BaseField>>(
+        fs_rng: &mut FS,
+        commitments: &[T],
+        message: &ProverMessage<TargetField>,
+    ) {
+        if MM::RECURSION {
+            fs_rng.absorb_native_field_elements(commitments);
+            if !message.field_elements.is_empty() {
+                fs_rng.absorb_nonnative_field_elements(&message.field_elements, OptimizationType::Weight);
+            }
+        } else {
+            fs_rng.absorb_bytes(&to_bytes![commitments, message].unwrap());
+        }
+    }
+
+    /// Absorbs the evaluations of one circuit into the Fiat-Shamir RNG.
+    fn absorb_evaluations(fs_rng: &mut FS, evaluations: &[TargetField]) {
+        if MM::RECURSION {
+            fs_rng.absorb_nonnative_field_elements(evaluations, OptimizationType::Weight);
+        } else {
+            fs_rng.absorb_bytes(&to_bytes![evaluations].unwrap());
+        }
+    }
+
+    /// Returns the vanishing polynomials committed to by circuits indexed for recursion.
+    fn vanishing_polynomials(
+        circuit_proving_key: &CircuitProvingKey<TargetField, PC>,
+    ) -> Result<Vec<LabeledPolynomial<TargetField>>, MarlinError<PC::Error>> {
+        let domain_h = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_constraints)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_k = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_non_zero)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        Ok(vec![
+            LabeledPolynomial::new(
+                "vanishing_poly_h".to_string(),
+                domain_h.vanishing_polynomial().into(),
+                None,
+                None,
+            ),
+            LabeledPolynomial::new(
+                "vanishing_poly_k".to_string(),
+                domain_k.vanishing_polynomial().into(),
+                None,
+                None,
+            ),
+        ])
+    }
+}
+
+/// Returns the label of a polynomial, linear combination or query point of the `i`-th circuit in a batch.
+fn batch_label(i: usize, label: &str) -> String {
+    format!("circuit_{}::{}", i, label)
+}
+
+/// Relabels the query set of the `i`-th circuit in a batch.
+fn batch_query_set<'a, F: PrimeField>(
+    i: usize,
+    query_set: QuerySet<'a, F>,
+) -> impl Iterator<Item = (String, (String, F))> + 'a {
+    query_set
+        .into_iter()
+        .map(move |(label, (point_name, point))| (batch_label(i, &label), (batch_label(i, &point_name), point)))
+}
+
+/// Relabels a linear combination of the `i`-th circuit in a batch.
+fn batch_linear_combination<F: PrimeField>(i: usize, lc: &LinearCombination<F>) -> LinearCombination<F> {
+    let terms = lc
+        .terms
+        .iter()
+        .map(|(coeff, term)| match term {
+            LCTerm::One => (*coeff, LCTerm::One),
+            LCTerm::PolyLabel(label) => (*coeff, LCTerm::PolyLabel(batch_label(i, label))),
+        })
+        .collect();
+    LinearCombination::new(batch_label(i, &lc.label), terms)
+}
@@ -15,6 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{ahp::indexer::*, marlin::CircuitVerifyingKey, Vec};
+use snarkvm_algorithms::{
+    envelope::{scalar_field_fingerprint, EnvelopeKind, EnvelopeScheme},
+    errors::EnvelopeError,
+    Envelope,
+};
 use snarkvm_fields::PrimeField;
 use snarkvm_polycommit::PolynomialCommitment;
 use snarkvm_utilities::{
@@ -53,4 +58,27 @@ impl<F: PrimeField, PC: PolynomialCommitment<F>> FromBytes for CircuitProvingKey
     fn read<R: Read>(mut reader: R) -> IoResult<Self> {
         CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error("could not deserialize CircuitProvingKey"))
     }
+
+    #[inline]
+    fn read_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
+        CanonicalDeserialize::deserialize_unchecked(&mut reader)
+            .map_err(|_| error("could not deserialize CircuitProvingKey"))
+    }
+}
+
+impl<F: PrimeField, PC: PolynomialCommitment<F>> Envelope for CircuitProvingKey<F, PC> {
+    const KIND: EnvelopeKind = EnvelopeKind::ProvingKey;
+    const SCHEME: EnvelopeScheme = EnvelopeScheme::Marlin;
+
+    fn curve() -> [u8; 8] {
+        scalar_field_fingerprint::<F>()
+    }
+
+    fn sizes(&self) -> Vec<u64> {
+        self.circuit_verifying_key.sizes()
+    }
+
+    fn circuit_id(&self) -> Result<[u8; 32], EnvelopeError> {
+        self.circuit_verifying_key.circuit_id()
+    }
 }
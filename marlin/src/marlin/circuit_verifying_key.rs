@@ -20,6 +20,11 @@ use crate::{
     marlin::{CircuitProvingKey, PreparedCircuitVerifyingKey},
     Vec,
 };
+use snarkvm_algorithms::{
+    envelope::{envelope_digest, scalar_field_fingerprint, EnvelopeKind, EnvelopeScheme},
+    errors::EnvelopeError,
+    Envelope,
+};
 use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_polycommit::PolynomialCommitment;
 use snarkvm_utilities::{
@@ -61,6 +66,29 @@ impl<F: PrimeField, PC: PolynomialCommitment<F>> FromBytes for CircuitVerifyingK
     }
 }
 
+impl<F: PrimeField, PC: PolynomialCommitment<F>> Envelope for CircuitVerifyingKey<F, PC> {
+    const KIND: EnvelopeKind = EnvelopeKind::VerifyingKey;
+    const SCHEME: EnvelopeScheme = EnvelopeScheme::Marlin;
+
+    fn curve() -> [u8; 8] {
+        scalar_field_fingerprint::<F>()
+    }
+
+    fn sizes(&self) -> Vec<u64> {
+        vec![
+            self.circuit_info.num_variables as u64,
+            self.circuit_info.num_constraints as u64,
+            self.circuit_info.num_non_zero as u64,
+        ]
+    }
+
+    fn circuit_id(&self) -> Result<[u8; 32], EnvelopeError> {
+        let mut bytes = vec![];
+        ToBytes::write(self, &mut bytes)?;
+        Ok(envelope_digest(&bytes))
+    }
+}
+
 impl<F: PrimeField, PC: PolynomialCommitment<F>> CircuitVerifyingKey<F, PC> {
     /// Iterate over the commitments to indexed polynomials in `self`.
     pub fn iter(&self) -> impl Iterator<Item = &PC::Commitment> {
@@ -31,6 +31,14 @@ pub enum MarlinError<E> {
     R1CSError(snarkvm_r1cs::SynthesisError),
     /// There was an error in the underlying polynomial commitment.
     PolynomialCommitmentError(E),
+    /// The batch does not contain any circuits.
+    EmptyBatch,
+    /// The number of circuits in the batch does not match the number of instances.
+    BatchSizeMismatch(usize, usize),
+    /// The prover allocated more memory than its configured limit.
+    MemoryLimitExceeded(usize, usize),
+    /// The prover thread pool could not be created.
+    ThreadPoolError(String),
 }
 
 impl<E> From<crate::ahp::AHPError> for MarlinError<E> {
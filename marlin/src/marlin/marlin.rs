@@ -17,7 +17,16 @@
 use crate::{
     ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
     fiat_shamir::traits::FiatShamirRng,
-    marlin::{compute_vk_hash, CircuitProvingKey, CircuitVerifyingKey, MarlinError, MarlinMode, Proof, UniversalSRS},
+    marlin::{
+        compute_vk_hash,
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        MarlinError,
+        MarlinMode,
+        MarlinProverConfig,
+        Proof,
+        UniversalSRS,
+    },
 };
 use snarkvm_algorithms::fft::EvaluationDomain;
 use snarkvm_fields::{PrimeField, ToConstraintField};
@@ -244,14 +253,59 @@ where
         circuit_proving_key: &CircuitProvingKey<TargetField, PC>,
         circuit: &C,
         zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, PC>, MarlinError<PC::Error>> {
+        Self::prove_with_resources(circuit_proving_key, circuit, &MarlinProverConfig::default(), zk_rng)
+    }
+
+    /// Create a zkSNARK asserting that the constraint system is satisfied,
+    /// using the threads and memory specified in the given prover configuration.
+    pub fn prove_with_config<C: ConstraintSynthesizer<TargetField> + Sync, R: RngCore + Send>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, PC>,
+        circuit: &C,
+        config: &MarlinProverConfig,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, PC>, MarlinError<PC::Error>>
+    where
+        CircuitProvingKey<TargetField, PC>: Sync,
+        Proof<TargetField, PC>: Send,
+        PC::Error: Send,
+    {
+        #[cfg(feature = "parallel")]
+        if let Some(num_threads) = config.num_threads {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .map_err(|error| MarlinError::ThreadPoolError(error.to_string()))?;
+            return pool.install(|| Self::prove_with_resources(circuit_proving_key, circuit, config, zk_rng));
+        }
+
+        Self::prove_with_resources(circuit_proving_key, circuit, config, zk_rng)
+    }
+
+    /// Create a zkSNARK on the current thread pool, checking the memory limit of the given configuration.
+    fn prove_with_resources<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, PC>,
+        circuit: &C,
+        config: &MarlinProverConfig,
+        zk_rng: &mut R,
     ) -> Result<Proof<TargetField, PC>, MarlinError<PC::Error>> {
         let prover_time = start_timer!(|| "Marlin::Prover");
+        // Under a memory limit, trade some speed for the memory of the multi-scalar multiplications.
+        let _chunked_msms = config
+            .max_memory
+            .map(|_| snarkvm_polycommit::kzg10::ChunkedMSMGuard::new());
+        let memory_scope = snarkvm_profiler::MemoryScope::new();
+        let check_memory = || match config.exceeded_memory(&memory_scope) {
+            Some((peak_memory, max_memory)) => Err(MarlinError::MemoryLimitExceeded(peak_memory, max_memory)),
+            None => Ok(()),
+        };
         // TODO: Add check that c is in the correct mode.
 
         let is_recursion = MM::RECURSION;
 
         let prover_init_state = AHPForR1CS::prover_init(&circuit_proving_key.circuit, circuit)?;
         let public_input = prover_init_state.public_input();
+        check_memory()?;
 
         let mut fs_rng = FS::new();
 
@@ -279,6 +333,7 @@ where
 
         let (prover_first_message, prover_first_oracles, prover_state) =
             AHPForR1CS::prover_first_round(prover_init_state, zk_rng, hiding)?;
+        check_memory()?;
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
         let (first_commitments, first_commitment_randomnesses) = PC::commit(
@@ -287,7 +342,8 @@ where
             Some(zk_rng),
         )
         .map_err(MarlinError::from_pc_err)?;
-        end_timer!(first_round_comm_time);
+        end_timer!(first_round_comm_time, || memory_scope.info());
+        check_memory()?;
 
         if is_recursion {
             fs_rng.absorb_native_field_elements(&first_commitments);
@@ -307,6 +363,7 @@ where
 
         let (prover_second_message, prover_second_oracles, prover_state) =
             AHPForR1CS::prover_second_round(&verifier_first_message, prover_state, zk_rng, hiding);
+        check_memory()?;
 
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
         let (second_commitments, second_commitment_randomnesses) = PC::commit(
@@ -315,7 +372,8 @@ where
             Some(zk_rng),
         )
         .map_err(MarlinError::from_pc_err)?;
-        end_timer!(second_round_comm_time);
+        end_timer!(second_round_comm_time, || memory_scope.info());
+        check_memory()?;
 
         if is_recursion {
             fs_rng.absorb_native_field_elements(&second_commitments);
@@ -333,6 +391,7 @@ where
         // Third round
         let (prover_third_message, prover_third_oracles) =
             AHPForR1CS::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
+        check_memory()?;
 
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
         let (third_commitments, third_commitment_randomnesses) = PC::commit(
@@ -341,7 +400,8 @@ where
             Some(zk_rng),
         )
         .map_err(MarlinError::from_pc_err)?;
-        end_timer!(third_round_comm_time);
+        end_timer!(third_round_comm_time, || memory_scope.info());
+        check_memory()?;
 
         if is_recursion {
             fs_rng.absorb_native_field_elements(&third_commitments);
@@ -453,6 +513,7 @@ where
         evaluations_unsorted.sort_by(|a, b| a.0.cmp(&b.0));
         let evaluations = evaluations_unsorted.iter().map(|x| x.1).collect::<Vec<TargetField>>();
         end_timer!(eval_time);
+        check_memory()?;
 
         if is_recursion {
             fs_rng.absorb_nonnative_field_elements(&evaluations, OptimizationType::Weight);
@@ -494,12 +555,14 @@ where
             .map_err(MarlinError::from_pc_err)?
         };
 
+        check_memory()?;
+
         // Gather prover messages together.
         let prover_messages = vec![prover_first_message, prover_second_message, prover_third_message];
 
         let proof = Proof::new(commitments, evaluations, prover_messages, pc_proof);
         proof.print_size_info();
-        end_timer!(prover_time);
+        end_timer!(prover_time, || memory_scope.info());
 
         Ok(proof)
     }
@@ -14,6 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+/// Batched proving and verification of several circuits.
+mod batch;
+
+/// The Marlin batch proof.
+mod batch_proof;
+pub use batch_proof::*;
+
 /// The Marlin circuit proving key.
 mod circuit_proving_key;
 pub use circuit_proving_key::*;
@@ -42,6 +49,10 @@ pub use prepared_circuit_verifying_key::*;
 mod proof;
 pub use proof::*;
 
+/// The Marlin prover configuration.
+mod prover_config;
+pub use prover_config::*;
+
 #[cfg(test)]
 pub mod tests;
 
@@ -14,10 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use snarkvm_algorithms::envelope::EnvelopeMode;
+
 /// A trait to specify the Marlin mode.
 pub trait MarlinMode: Clone {
     /// Specifies whether this is for a recursive proof of at least depth-1.
     const RECURSION: bool;
+
+    /// Returns the envelope mode of the keys and proofs generated in this mode.
+    fn envelope_mode() -> EnvelopeMode {
+        match Self::RECURSION {
+            true => EnvelopeMode::Recursive,
+            false => EnvelopeMode::NonRecursive,
+        }
+    }
 }
 
 /// TODO (howardwu): Combine all of the testnet configurations into an environment struct higher up.
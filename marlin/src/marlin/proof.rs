@@ -15,6 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{ahp::prover::ProverMessage, Vec};
+use snarkvm_algorithms::{
+    envelope::{scalar_field_fingerprint, EnvelopeKind, EnvelopeScheme},
+    Envelope,
+};
 use snarkvm_fields::PrimeField;
 use snarkvm_polycommit::{BatchLCProof, PCCommitment, PolynomialCommitment};
 use snarkvm_utilities::{
@@ -134,3 +138,12 @@ impl<F: PrimeField, PC: PolynomialCommitment<F>> FromBytes for Proof<F, PC> {
         CanonicalDeserialize::deserialize(&mut r).map_err(|_| error("could not deserialize Proof"))
     }
 }
+
+impl<F: PrimeField, PC: PolynomialCommitment<F>> Envelope for Proof<F, PC> {
+    const KIND: EnvelopeKind = EnvelopeKind::Proof;
+    const SCHEME: EnvelopeScheme = EnvelopeScheme::Marlin;
+
+    fn curve() -> [u8; 8] {
+        scalar_field_fingerprint::<F>()
+    }
+}
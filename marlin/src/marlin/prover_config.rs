@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// Configures the resources used by the Marlin prover.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MarlinProverConfig {
+    /// The number of threads used to generate the proof.
+    /// If `None`, the proof is generated on the global thread pool.
+    pub num_threads: Option<usize>,
+    /// The maximum number of bytes the process may allocate on the heap while proving.
+    /// The limit is only enforced when `snarkvm_profiler::TrackingAllocator` is the global allocator.
+    /// It is checked against the peak heap usage since proving began after every step of the prover,
+    /// so a step that exceeds the limit aborts the proof as soon as it returns, after its allocations were made.
+    /// The heap usage is that of the whole process, so concurrent provers count towards each other's limit.
+    /// Setting a limit also chunks the multi-scalar multiplications of the KZG10 commitments and openings,
+    /// which lowers their memory at some cost in speed.
+    pub max_memory: Option<usize>,
+}
+
+impl MarlinProverConfig {
+    /// Returns a configuration that generates proofs with the given number of threads.
+    pub fn with_num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Returns a configuration that aborts proving when the heap exceeds the given number of bytes.
+    pub fn with_max_memory(mut self, max_memory: usize) -> Self {
+        self.max_memory = Some(max_memory);
+        self
+    }
+
+    /// Returns the peak heap usage of the given scope if it exceeds the maximum memory of this configuration.
+    pub(crate) fn exceeded_memory(&self, scope: &snarkvm_profiler::MemoryScope) -> Option<(usize, usize)> {
+        match self.max_memory {
+            Some(max_memory) if snarkvm_profiler::is_memory_tracked() => {
+                let peak_memory = scope.peak();
+                if peak_memory > max_memory {
+                    Some((peak_memory, max_memory))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
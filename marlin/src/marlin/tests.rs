@@ -292,3 +292,312 @@ mod marlin_recursion {
     //     println!("Called verifier");
     // }
 }
+
+mod envelope {
+    use super::*;
+    use crate::{
+        fiat_shamir::{FiatShamirAlgebraicSpongeRng, FiatShamirChaChaRng, PoseidonSponge},
+        marlin::{
+            CircuitProvingKey,
+            CircuitVerifyingKey,
+            MarlinMode,
+            MarlinRecursiveMode,
+            MarlinSNARK,
+            MarlinTestnet1Mode,
+        },
+    };
+    use snarkvm_algorithms::{envelope::EnvelopeMode, errors::EnvelopeError, Envelope};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_polycommit::marlin_pc::MarlinKZG10;
+    use snarkvm_utilities::{
+        bytes::ToBytes,
+        rand::{test_rng, UniformRand},
+        to_bytes,
+    };
+
+    use blake2::Blake2s;
+
+    type MultiPC = MarlinKZG10<Bls12_377>;
+    type MarlinInst = MarlinSNARK<Fr, Fq, MultiPC, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinTestnet1Mode>;
+    type MarlinRecursiveInst =
+        MarlinSNARK<Fr, Fq, MultiPC, FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq>>, MarlinRecursiveMode>;
+
+    #[test]
+    fn test_envelope_roundtrip() {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+        let circuit = Circuit {
+            a: Some(Fr::rand(rng)),
+            b: Some(Fr::rand(rng)),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let (_, recursive_index_vk) = MarlinRecursiveInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let mode = MarlinTestnet1Mode::envelope_mode();
+        let recursive_mode = MarlinRecursiveMode::envelope_mode();
+
+        let mut buffer = vec![];
+        index_pk.write_envelope(&mut buffer, mode).unwrap();
+        let (header, recovered) =
+            CircuitProvingKey::<Fr, MultiPC>::read_envelope_with_header(&buffer[..], mode).unwrap();
+        let header = header.unwrap();
+        assert_eq!(header.sizes, vec![
+            index_vk.circuit_info.num_variables as u64,
+            index_vk.circuit_info.num_constraints as u64,
+            index_vk.circuit_info.num_non_zero as u64,
+        ]);
+        assert_eq!(header.mode, EnvelopeMode::NonRecursive);
+        header.ensure_circuit_id(&index_vk.circuit_id().unwrap()).unwrap();
+        assert_eq!(to_bytes![recovered].unwrap(), to_bytes![index_pk].unwrap());
+
+        let mut buffer = vec![];
+        recursive_index_vk.write_envelope(&mut buffer, recursive_mode).unwrap();
+        let (header, _) =
+            CircuitVerifyingKey::<Fr, MultiPC>::read_envelope_with_header(&buffer[..], recursive_mode).unwrap();
+        let header = header.unwrap();
+        assert_eq!(header.mode, EnvelopeMode::Recursive);
+        assert!(matches!(
+            header.ensure_circuit_id(&index_vk.circuit_id().unwrap()),
+            Err(EnvelopeError::CircuitMismatch(..))
+        ));
+
+        // Reading a key in another mode fails before deserialization.
+        assert!(matches!(
+            CircuitVerifyingKey::<Fr, MultiPC>::read_envelope(&buffer[..], mode),
+            Err(EnvelopeError::ModeMismatch(
+                EnvelopeMode::NonRecursive,
+                EnvelopeMode::Recursive
+            ))
+        ));
+
+        // Keys serialized without an envelope remain readable.
+        let legacy = to_bytes![index_vk].unwrap();
+        let (header, recovered) =
+            CircuitVerifyingKey::<Fr, MultiPC>::read_envelope_with_header(&legacy[..], mode).unwrap();
+        assert!(header.is_none());
+        assert_eq!(to_bytes![recovered].unwrap(), legacy);
+    }
+}
+
+mod serialization {
+    use super::*;
+    use crate::{
+        fiat_shamir::FiatShamirChaChaRng,
+        marlin::{CircuitProvingKey, MarlinSNARK, MarlinTestnet1Mode, UniversalSRS},
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_polycommit::marlin_pc::MarlinKZG10;
+    use snarkvm_utilities::{
+        bytes::{FromBytes, ToBytes},
+        rand::{test_rng, UniformRand},
+        serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
+        to_bytes,
+    };
+
+    use blake2::Blake2s;
+
+    type MultiPC = MarlinKZG10<Bls12_377>;
+    type MarlinInst = MarlinSNARK<Fr, Fq, MultiPC, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinTestnet1Mode>;
+
+    #[test]
+    fn test_trusted_key_loading() {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 50,
+            num_variables: 25,
+        };
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // The universal SRS and the proving key load from their byte encodings without validation.
+        let srs_bytes = to_bytes![universal_srs].unwrap();
+        let recovered_srs = <UniversalSRS<Fr, MultiPC> as FromBytes>::read_unchecked(&srs_bytes[..]).unwrap();
+        assert_eq!(to_bytes![recovered_srs].unwrap(), srs_bytes);
+
+        let pk_bytes = to_bytes![index_pk].unwrap();
+        let recovered_pk = CircuitProvingKey::<Fr, MultiPC>::read_unchecked(&pk_bytes[..]).unwrap();
+        assert_eq!(to_bytes![recovered_pk].unwrap(), pk_bytes);
+
+        // A trusted proving key can also be stored uncompressed, so that loading it skips the point decompression.
+        for compress in [Compress::Yes, Compress::No] {
+            let mut buffer = vec![];
+            index_pk.serialize_with_mode(&mut buffer, compress).unwrap();
+            assert_eq!(buffer.len(), index_pk.serialized_size_with_mode(compress));
+
+            for validate in [Validate::Yes, Validate::No] {
+                let recovered_pk =
+                    CircuitProvingKey::<Fr, MultiPC>::deserialize_with_mode(&mut &buffer[..], compress, validate)
+                        .unwrap();
+                let proof = MarlinInst::prove(&recovered_pk, &circuit, rng).unwrap();
+                assert!(MarlinInst::verify(&index_vk, &[a * b, a * b * b], &proof).unwrap());
+            }
+        }
+    }
+}
+
+mod prover_config {
+    use super::*;
+    use crate::{
+        fiat_shamir::FiatShamirChaChaRng,
+        marlin::{MarlinError, MarlinProverConfig, MarlinSNARK, MarlinTestnet1Mode},
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_polycommit::marlin_pc::MarlinKZG10;
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    use blake2::Blake2s;
+
+    #[global_allocator]
+    static ALLOCATOR: snarkvm_profiler::TrackingAllocator = snarkvm_profiler::TrackingAllocator;
+
+    type MultiPC = MarlinKZG10<Bls12_377>;
+    type MarlinInst = MarlinSNARK<Fr, Fq, MultiPC, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinTestnet1Mode>;
+
+    #[test]
+    fn test_prove_with_config() {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 50,
+            num_variables: 25,
+        };
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        for num_threads in 1..=2 {
+            let config = MarlinProverConfig::default().with_num_threads(num_threads);
+            let proof = MarlinInst::prove_with_config(&index_pk, &circuit, &config, rng).unwrap();
+            assert!(MarlinInst::verify(&index_vk, &[a * b, a * b * b], &proof).unwrap());
+        }
+
+        let config = MarlinProverConfig::default().with_max_memory(usize::MAX);
+        let proof = MarlinInst::prove_with_config(&index_pk, &circuit, &config, rng).unwrap();
+        assert!(MarlinInst::verify(&index_vk, &[a * b, a * b * b], &proof).unwrap());
+
+        // Proving allocates on the heap, so its peak heap usage exceeds the heap usage before it.
+        let max_memory = snarkvm_profiler::current_memory();
+        let config = MarlinProverConfig::default().with_max_memory(max_memory);
+        match MarlinInst::prove_with_config(&index_pk, &circuit, &config, rng) {
+            Err(MarlinError::MemoryLimitExceeded(peak_memory, limit)) => {
+                assert_eq!(limit, max_memory);
+                assert!(peak_memory > max_memory);
+            }
+            _ => panic!("Expected the memory limit to be exceeded"),
+        }
+    }
+}
+
+mod batch {
+    use super::*;
+    use crate::{
+        fiat_shamir::{FiatShamirAlgebraicSpongeRng, FiatShamirChaChaRng, PoseidonSponge},
+        marlin::{MarlinError, MarlinRecursiveMode, MarlinSNARK, MarlinTestnet1Mode},
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_polycommit::{marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10};
+    use snarkvm_utilities::rand::{test_rng, UniformRand};
+
+    use blake2::Blake2s;
+
+    type MultiPC = MarlinKZG10<Bls12_377>;
+    type MarlinInst = MarlinSNARK<Fr, Fq, MultiPC, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinTestnet1Mode>;
+
+    type MultiPCSonic = SonicKZG10<Bls12_377>;
+    type MarlinSonicInst = MarlinSNARK<Fr, Fq, MultiPCSonic, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinTestnet1Mode>;
+    type MarlinRecursiveInst =
+        MarlinSNARK<Fr, Fq, MultiPC, FiatShamirAlgebraicSpongeRng<Fr, Fq, PoseidonSponge<Fq>>, MarlinRecursiveMode>;
+
+    macro_rules! impl_batch_test {
+        ($test_name: ident, $marlin_inst: tt) => {
+            #[test]
+            fn $test_name() {
+                let rng = &mut test_rng();
+
+                let universal_srs = $marlin_inst::universal_setup(150, 50, 150, rng).unwrap();
+
+                // Three instances of two different circuits.
+                let sizes = [(25, 25), (100, 25), (25, 25)];
+                let circuits: Vec<_> = sizes
+                    .iter()
+                    .map(|&(num_constraints, num_variables)| Circuit {
+                        a: Some(Fr::rand(rng)),
+                        b: Some(Fr::rand(rng)),
+                        num_constraints,
+                        num_variables,
+                    })
+                    .collect();
+                let public_inputs: Vec<_> = circuits
+                    .iter()
+                    .map(|circuit| {
+                        let c = circuit.a.unwrap() * circuit.b.unwrap();
+                        vec![c, c * circuit.b.unwrap()]
+                    })
+                    .collect();
+
+                let keys: Vec<_> = circuits
+                    .iter()
+                    .map(|circuit| $marlin_inst::circuit_setup(&universal_srs, circuit).unwrap())
+                    .collect();
+                let proving_keys: Vec<_> = keys.iter().map(|(pk, _)| pk).collect();
+                let verifying_keys: Vec<_> = keys.iter().map(|(_, vk)| vk).collect();
+                let (committer_key, verifier_key) = $marlin_inst::batch_setup(&universal_srs, &verifying_keys).unwrap();
+
+                let circuit_refs: Vec<_> = circuits.iter().collect();
+                let proof = $marlin_inst::prove_batch(&committer_key, &proving_keys, &circuit_refs, rng).unwrap();
+                assert_eq!(proof.num_circuits(), 3);
+
+                let inputs: Vec<_> = public_inputs.iter().map(|input| &input[..]).collect();
+                assert!($marlin_inst::verify_batch(&verifier_key, &verifying_keys, &inputs, &proof).unwrap());
+
+                // A wrong public input for any circuit is rejected.
+                let wrong_input = [public_inputs[1][0], public_inputs[1][0]];
+                let mut wrong_inputs = inputs.clone();
+                wrong_inputs[1] = &wrong_input;
+                assert!(!$marlin_inst::verify_batch(&verifier_key, &verifying_keys, &wrong_inputs, &proof).unwrap());
+
+                // Reordering the circuits is rejected.
+                let mut swapped_keys = verifying_keys.clone();
+                swapped_keys.swap(0, 1);
+                let mut swapped_inputs = inputs.clone();
+                swapped_inputs.swap(0, 1);
+                assert!(
+                    !$marlin_inst::verify_batch(&verifier_key, &swapped_keys, &swapped_inputs, &proof).unwrap_or(false)
+                );
+
+                // A proof for a subset of the circuits is rejected.
+                assert!(
+                    !$marlin_inst::verify_batch(&verifier_key, &verifying_keys[..2], &inputs[..2], &proof).unwrap()
+                );
+
+                // Mismatched batches are errors.
+                assert!(matches!(
+                    $marlin_inst::verify_batch(&verifier_key, &verifying_keys, &inputs[..2], &proof),
+                    Err(MarlinError::BatchSizeMismatch(3, 2))
+                ));
+                assert!(matches!(
+                    $marlin_inst::prove_batch::<Circuit<Fr>, _>(&committer_key, &[], &[], rng),
+                    Err(MarlinError::EmptyBatch)
+                ));
+            }
+        };
+    }
+
+    impl_batch_test!(prove_and_verify_batch_marlin_pc, MarlinInst);
+    impl_batch_test!(prove_and_verify_batch_sonic_pc, MarlinSonicInst);
+    impl_batch_test!(prove_and_verify_batch_recursive, MarlinRecursiveInst);
+}
@@ -171,4 +171,9 @@ impl<E: PairingEngine> FromBytes for Parameters<E> {
 
         Ok(ret)
     }
+
+    fn read_unchecked<R: Read>(mut r: R) -> io::Result<Self> {
+        // The deferred validation of affine values is skipped along with all other checks.
+        CanonicalDeserialize::deserialize_unchecked(&mut r).map_err(|_| error("could not deserialize parameters"))
+    }
 }
@@ -16,13 +16,9 @@
 
 use crate::{Arc, String, Vec};
 pub use snarkvm_algorithms::fft::DensePolynomial as Polynomial;
+use snarkvm_algorithms::Envelope;
 use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
-use snarkvm_utilities::{
-    bytes::{FromBytes, ToBytes},
-    error as error_fn,
-    errors::SerializationError,
-    serialize::*,
-};
+use snarkvm_utilities::{bytes::ToBytes, error as error_fn, errors::SerializationError, serialize::*};
 
 use core::{
     borrow::Borrow,
@@ -36,7 +32,7 @@ pub type PolynomialLabel = String;
 
 /// Defines the minimal interface for public params for any polynomial
 /// commitment scheme.
-pub trait PCUniversalParams: CanonicalSerialize + CanonicalDeserialize + Clone + Debug + ToBytes + FromBytes {
+pub trait PCUniversalParams: CanonicalSerialize + CanonicalDeserialize + Clone + Debug + Envelope {
     /// Outputs the maximum degree supported by the committer key.
     fn max_degree(&self) -> usize;
 }
@@ -147,6 +143,16 @@ impl<F: Field> LabeledPolynomial<F> {
         &self.label
     }
 
+    /// Returns a copy of `self` with the given label, sharing the underlying polynomial.
+    pub fn with_label(&self, label: PolynomialLabel) -> Self {
+        Self {
+            label,
+            polynomial: self.polynomial.clone(),
+            degree_bound: self.degree_bound,
+            hiding_bound: self.hiding_bound,
+        }
+    }
+
     /// Retrieve the polynomial from `self`.
     pub fn polynomial(&self) -> &Polynomial<F> {
         &self.polynomial
@@ -390,6 +396,11 @@ macro_rules! impl_bytes {
             fn read<R: Read>(mut reader: R) -> io::Result<Self> {
                 CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error("could not deserialize struct"))
             }
+
+            fn read_unchecked<R: Read>(mut reader: R) -> io::Result<Self> {
+                CanonicalDeserialize::deserialize_unchecked(&mut reader)
+                    .map_err(|_| error("could not deserialize struct"))
+            }
         }
 
         impl<E: PairingEngine> ToBytes for $ty<E> {
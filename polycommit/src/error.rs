@@ -98,6 +98,9 @@ pub enum Error {
 
     /// The commitment was generated incorrectly, tampered with, or doesn't support the polynomial.
     MalformedCommitment(String),
+
+    /// A contribution to the SRS ceremony was invalid.
+    InvalidContribution(String),
 }
 
 impl core::fmt::Display for Error {
@@ -156,6 +159,7 @@ impl core::fmt::Display for Error {
             ),
             Error::IncorrectInputLength(err) => write!(f, "{}", err),
             Error::MalformedCommitment(err) => write!(f, "{}", err),
+            Error::InvalidContribution(err) => write!(f, "invalid ceremony contribution: {}", err),
         }
     }
 }
@@ -0,0 +1,502 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A multi-party ceremony for generating the [`UniversalParams`] of KZG10.
+//!
+//! The ceremony starts from an [`Accumulator`] for the secret `beta = 1`.
+//! Each participant samples a secret `tau`, rescales the `i`-th powers in the
+//! accumulator by `tau^i`, and publishes a [`ContributionProof`] showing that
+//! the new secret is `tau` times the old one for a `tau` they know. As long as
+//! one participant discards their `tau`, nobody knows the final `beta`.
+//!
+//! The [`Transcript`] of contribution proofs is enough to verify that the final
+//! accumulator incorporates every contribution, so intermediate accumulators do
+//! not need to be kept around. The final accumulator is then truncated into
+//! [`UniversalParams`] for the desired maximum degree.
+
+use crate::{impl_bytes, kzg10::UniversalParams, BTreeMap, Error, String, ToString, Vec};
+use snarkvm_algorithms::{cfg_iter, hash_to_curve::hash_to_curve, msm::VariableBaseMSM};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    error,
+    errors::SerializationError,
+    rand::UniformRand,
+    serialize::*,
+    BitIteratorBE,
+};
+
+use blake2::{Blake2s, Digest};
+use core::ops::Mul;
+use rand_core::RngCore;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The personalization string used to derive the generator of `gamma * G`.
+const GAMMA_G_PERSONALIZATION: &str = "KZG10 ceremony gamma_g generator";
+
+/// The personalization string used to derive contribution challenges.
+const CHALLENGE_PERSONALIZATION: &[u8] = b"KZG10 ceremony contribution";
+
+/// `Accumulator` holds the powers of the ceremony secret `beta` after some number of contributions.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct Accumulator<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `degree`.
+    pub powers_of_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, where `i` ranges from 0 to `degree + 1`.
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^{-i} H }`, where `i` ranges from 0 to `degree`,
+    /// or empty if the ceremony does not produce negative powers in G2.
+    pub neg_powers_of_h: Vec<E::G2Affine>,
+    /// The generator of G2.
+    pub h: E::G2Affine,
+    /// \beta times the above generator of G2.
+    pub beta_h: E::G2Affine,
+}
+
+impl_bytes!(Accumulator);
+
+impl<E: PairingEngine> Accumulator<E> {
+    /// Returns the accumulator for `beta = 1`, from which the ceremony starts.
+    pub fn new(max_degree: usize, produce_g2_powers: bool) -> Result<Self, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let gamma_g = hash_to_curve::<E::G1Affine, _>(GAMMA_G_PERSONALIZATION);
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        Ok(Self {
+            powers_of_g: vec![g; max_degree + 1],
+            powers_of_gamma_g: vec![gamma_g; max_degree + 2],
+            neg_powers_of_h: if produce_g2_powers {
+                vec![h; max_degree + 1]
+            } else {
+                vec![]
+            },
+            h,
+            beta_h: h,
+        })
+    }
+
+    /// Returns the maximum degree supported by this accumulator.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len() - 1
+    }
+
+    /// Contributes fresh randomness to this accumulator, returning the new
+    /// accumulator and the proof of a correct contribution.
+    pub fn contribute<R: RngCore>(&self, rng: &mut R) -> Result<(Self, ContributionProof<E>), Error> {
+        let contribute_time = start_timer!(|| format!("KZG10::Ceremony::Contribute with degree {}", self.max_degree()));
+
+        let tau = loop {
+            let tau = E::Fr::rand(rng);
+            if !tau.is_zero() {
+                break tau;
+            }
+        };
+        let tau_inverse = tau.inverse().unwrap();
+
+        let next = Self {
+            powers_of_g: rescale_powers(&self.powers_of_g, tau),
+            powers_of_gamma_g: rescale_powers(&self.powers_of_gamma_g, tau),
+            neg_powers_of_h: rescale_powers(&self.neg_powers_of_h, tau_inverse),
+            h: self.h,
+            beta_h: self.beta_h.mul(tau),
+        };
+
+        let g = self.powers_of_g[0];
+        let tau_g = g.mul(tau);
+        let nonce = E::Fr::rand(rng);
+        let commitment = g.mul(nonce);
+        let challenge = contribution_challenge::<E>(&self.beta_h, &next.beta_h, &tau_g, &commitment)?;
+        let response = nonce + challenge * tau;
+
+        let proof = ContributionProof {
+            tau_g,
+            beta_h: next.beta_h,
+            commitment,
+            response,
+        };
+
+        end_timer!(contribute_time);
+        Ok((next, proof))
+    }
+
+    /// Checks that `next` is the result of a contribution to this accumulator, as attested by `proof`.
+    pub fn verify_contribution<R: RngCore>(
+        &self,
+        next: &Self,
+        proof: &ContributionProof<E>,
+        rng: &mut R,
+    ) -> Result<(), Error> {
+        if self.powers_of_g.len() != next.powers_of_g.len()
+            || self.powers_of_gamma_g.len() != next.powers_of_gamma_g.len()
+            || self.neg_powers_of_h.len() != next.neg_powers_of_h.len()
+        {
+            return Err(invalid("the accumulators have different sizes"));
+        }
+        if self.powers_of_g[0] != next.powers_of_g[0]
+            || self.powers_of_gamma_g[0] != next.powers_of_gamma_g[0]
+            || self.h != next.h
+        {
+            return Err(invalid("the contribution changed the generators"));
+        }
+        if proof.beta_h != next.beta_h {
+            return Err(invalid("the proof does not attest to the new accumulator"));
+        }
+
+        proof.verify(&self.powers_of_g[0], &self.beta_h)?;
+        next.verify(rng)
+    }
+
+    /// Checks that this accumulator consists of consecutive powers of a single secret.
+    pub fn verify<R: RngCore>(&self, rng: &mut R) -> Result<(), Error> {
+        let verify_time = start_timer!(|| format!("KZG10::Ceremony::Verify with degree {}", self.max_degree()));
+
+        if self.powers_of_g.len() < 2
+            || self.powers_of_gamma_g.len() != self.powers_of_g.len() + 1
+            || (!self.neg_powers_of_h.is_empty() && self.neg_powers_of_h.len() != self.powers_of_g.len())
+        {
+            return Err(invalid("the accumulator has inconsistent sizes"));
+        }
+
+        let g = self.powers_of_g[0];
+        if g.is_zero() || self.powers_of_gamma_g[0].is_zero() || self.h.is_zero() || self.beta_h.is_zero() {
+            return Err(invalid("the accumulator contains a degenerate generator"));
+        }
+        if !self.neg_powers_of_h.is_empty() && self.neg_powers_of_h[0] != self.h {
+            return Err(invalid("the negative powers of h do not start at h"));
+        }
+        if self.powers_of_g[1] == g {
+            return Err(invalid("the accumulator secret is one"));
+        }
+
+        // Check that the ratio of every pair of consecutive powers is `beta`, using a random linear combination.
+        let check_ratios = |powers: &[E::G1Affine], rng: &mut R| {
+            let (lhs, rhs) = random_linear_combinations(powers, rng);
+            E::pairing(lhs, self.h) == E::pairing(rhs, self.beta_h)
+        };
+        if !check_ratios(&self.powers_of_g, rng) {
+            return Err(invalid("the powers of g are inconsistent"));
+        }
+        if !check_ratios(&self.powers_of_gamma_g, rng) {
+            return Err(invalid("the powers of gamma_g are inconsistent"));
+        }
+        if !self.neg_powers_of_h.is_empty() {
+            // Check that the ratio of every pair of consecutive negative powers is `beta^{-1}`.
+            let (lhs, rhs) = random_linear_combinations(&self.neg_powers_of_h, rng);
+            if E::pairing(self.powers_of_g[1], lhs) != E::pairing(g, rhs) {
+                return Err(invalid("the negative powers of h are inconsistent"));
+            }
+        }
+
+        end_timer!(verify_time);
+        Ok(())
+    }
+
+    /// Truncates this accumulator into universal parameters supporting the given maximum degree.
+    pub fn into_universal_params(self, max_degree: usize) -> Result<UniversalParams<E>, Error> {
+        if max_degree < 1 {
+            return Err(Error::DegreeIsZero);
+        }
+        if max_degree > self.max_degree() {
+            return Err(Error::TrimmingDegreeTooLarge);
+        }
+
+        let mut powers_of_g = self.powers_of_g;
+        powers_of_g.truncate(max_degree + 1);
+
+        let powers_of_gamma_g = self
+            .powers_of_gamma_g
+            .into_iter()
+            .take(max_degree + 2)
+            .enumerate()
+            .collect();
+
+        let mut prepared_neg_powers_of_h = BTreeMap::new();
+        for (i, power) in self.neg_powers_of_h.into_iter().take(max_degree + 1).enumerate() {
+            prepared_neg_powers_of_h.insert(i, power.prepare());
+        }
+
+        Ok(UniversalParams {
+            powers_of_g,
+            powers_of_gamma_g,
+            h: self.h,
+            beta_h: self.beta_h,
+            prepared_neg_powers_of_h,
+            prepared_h: self.h.prepare(),
+            prepared_beta_h: self.beta_h.prepare(),
+        })
+    }
+}
+
+/// `ContributionProof` attests that a contribution multiplied the ceremony secret by a known `tau`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct ContributionProof<E: PairingEngine> {
+    /// `tau` times the generator of G1.
+    pub tau_g: E::G1Affine,
+    /// The new value of `\beta H`.
+    pub beta_h: E::G2Affine,
+    /// The commitment of the Schnorr proof of knowledge of `tau`.
+    pub commitment: E::G1Affine,
+    /// The response of the Schnorr proof of knowledge of `tau`.
+    pub response: E::Fr,
+}
+
+impl_bytes!(ContributionProof);
+
+impl<E: PairingEngine> ContributionProof<E> {
+    /// Checks that this contribution updates `previous_beta_h` to `self.beta_h` by a known `tau`.
+    pub fn verify(&self, g: &E::G1Affine, previous_beta_h: &E::G2Affine) -> Result<(), Error> {
+        if self.tau_g.is_zero() {
+            return Err(invalid("the contribution is zero"));
+        }
+
+        // Check the proof of knowledge of `tau`.
+        let challenge = contribution_challenge::<E>(previous_beta_h, &self.beta_h, &self.tau_g, &self.commitment)?;
+        if g.mul(self.response)
+            != (self.commitment.into_projective() + self.tau_g.mul(challenge).into_projective()).into_affine()
+        {
+            return Err(invalid("the proof of knowledge of the contribution is invalid"));
+        }
+
+        // Check that the new `beta H` is `tau` times the previous one.
+        if E::pairing(self.tau_g, *previous_beta_h) != E::pairing(*g, self.beta_h) {
+            return Err(invalid("the contribution does not match the new accumulator"));
+        }
+
+        Ok(())
+    }
+}
+
+/// `Transcript` records the proofs of all contributions made to a ceremony.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct Transcript<E: PairingEngine> {
+    /// The maximum degree of the ceremony.
+    pub max_degree: u64,
+    /// Whether the ceremony produces negative powers in G2.
+    pub produce_g2_powers: bool,
+    /// The contribution proofs, in the order the contributions were made.
+    pub contributions: Vec<ContributionProof<E>>,
+}
+
+impl_bytes!(Transcript);
+
+impl<E: PairingEngine> Transcript<E> {
+    /// Starts a new ceremony, returning its empty transcript and initial accumulator.
+    pub fn new(max_degree: usize, produce_g2_powers: bool) -> Result<(Self, Accumulator<E>), Error> {
+        let accumulator = Accumulator::new(max_degree, produce_g2_powers)?;
+        let transcript = Self {
+            max_degree: max_degree as u64,
+            produce_g2_powers,
+            contributions: vec![],
+        };
+        Ok((transcript, accumulator))
+    }
+
+    /// Contributes to the given accumulator and records the contribution in this transcript.
+    pub fn contribute<R: RngCore>(
+        &mut self,
+        accumulator: &Accumulator<E>,
+        rng: &mut R,
+    ) -> Result<Accumulator<E>, Error> {
+        let previous_beta_h = self.contributions.last().map(|c| c.beta_h).unwrap_or(accumulator.h);
+        if previous_beta_h != accumulator.beta_h {
+            return Err(invalid("the accumulator is not the latest in the transcript"));
+        }
+
+        let (next, proof) = accumulator.contribute(rng)?;
+        self.contributions.push(proof);
+        Ok(next)
+    }
+
+    /// Checks that the given accumulator is the result of every contribution in this transcript.
+    pub fn verify<R: RngCore>(&self, accumulator: &Accumulator<E>, rng: &mut R) -> Result<(), Error> {
+        let initial = Accumulator::<E>::new(self.max_degree as usize, self.produce_g2_powers)?;
+        if self.contributions.is_empty() {
+            return Err(invalid("the transcript has no contributions"));
+        }
+        if accumulator.powers_of_g.len() != initial.powers_of_g.len()
+            || accumulator.neg_powers_of_h.len() != initial.neg_powers_of_h.len()
+            || accumulator.powers_of_g[0] != initial.powers_of_g[0]
+            || accumulator.powers_of_gamma_g[0] != initial.powers_of_gamma_g[0]
+            || accumulator.h != initial.h
+        {
+            return Err(invalid("the accumulator does not belong to this ceremony"));
+        }
+
+        let g = initial.powers_of_g[0];
+        let mut beta_h = initial.beta_h;
+        for (i, contribution) in self.contributions.iter().enumerate() {
+            contribution
+                .verify(&g, &beta_h)
+                .map_err(|e| invalid(&format!("contribution {} is invalid: {}", i, e)))?;
+            beta_h = contribution.beta_h;
+        }
+
+        if beta_h != accumulator.beta_h {
+            return Err(invalid("the accumulator does not match the last contribution"));
+        }
+
+        accumulator.verify(rng)
+    }
+}
+
+/// Multiplies the `i`-th element of `powers` by `scalar^i`.
+fn rescale_powers<G: AffineCurve>(powers: &[G], scalar: G::ScalarField) -> Vec<G> {
+    let mut scalars = Vec::with_capacity(powers.len());
+    let mut current = G::ScalarField::one();
+    for _ in 0..powers.len() {
+        scalars.push(current.into_repr());
+        current *= scalar;
+    }
+
+    let rescaled: Vec<_> = cfg_iter!(powers)
+        .zip(scalars)
+        .map(|(power, scalar)| power.mul_bits(BitIteratorBE::new(scalar)))
+        .collect();
+    G::Projective::batch_normalization_into_affine(rescaled)
+}
+
+/// Returns `(sum r_i P_{i+1}, sum r_i P_i)` for random `r_i`.
+fn random_linear_combinations<G: AffineCurve, R: RngCore>(powers: &[G], rng: &mut R) -> (G, G) {
+    let scalars: Vec<_> = (0..powers.len() - 1)
+        .map(|_| G::ScalarField::rand(rng).into_repr())
+        .collect();
+
+    let lhs = VariableBaseMSM::multi_scalar_mul(&powers[1..], &scalars);
+    let rhs = VariableBaseMSM::multi_scalar_mul(&powers[..powers.len() - 1], &scalars);
+    (lhs.into_affine(), rhs.into_affine())
+}
+
+/// Derives the Fiat-Shamir challenge of the proof of knowledge of a contribution.
+fn contribution_challenge<E: PairingEngine>(
+    previous_beta_h: &E::G2Affine,
+    beta_h: &E::G2Affine,
+    tau_g: &E::G1Affine,
+    commitment: &E::G1Affine,
+) -> Result<E::Fr, Error> {
+    let mut input = CHALLENGE_PERSONALIZATION.to_vec();
+    previous_beta_h
+        .write(&mut input)
+        .and_then(|_| beta_h.write(&mut input))
+        .and_then(|_| tau_g.write(&mut input))
+        .and_then(|_| commitment.write(&mut input))
+        .map_err(|e| invalid(&e.to_string()))?;
+
+    for counter in 0u64.. {
+        let mut hasher = Blake2s::new();
+        hasher.update(&input);
+        hasher.update(counter.to_le_bytes());
+        if let Some(challenge) = E::Fr::from_random_bytes(&hasher.finalize()) {
+            return Ok(challenge);
+        }
+    }
+    unreachable!()
+}
+
+fn invalid(message: &str) -> Error {
+    Error::InvalidContribution(String::from(message))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+    use super::*;
+    use crate::kzg10::KZG10;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::{rand::test_rng, to_bytes};
+
+    type KZG_Bls12_377 = KZG10<Bls12_377>;
+
+    #[test]
+    fn test_ceremony() {
+        let rng = &mut test_rng();
+
+        let (mut transcript, mut accumulator) = Transcript::<Bls12_377>::new(16, true).unwrap();
+        for _ in 0..3 {
+            let previous = accumulator.clone();
+            accumulator = transcript.contribute(&accumulator, rng).unwrap();
+            let proof = transcript.contributions.last().unwrap();
+            previous.verify_contribution(&accumulator, proof, rng).unwrap();
+        }
+        transcript.verify(&accumulator, rng).unwrap();
+
+        // The transcript and accumulator survive serialization.
+        let transcript = Transcript::<Bls12_377>::read(&to_bytes![transcript].unwrap()[..]).unwrap();
+        let accumulator = Accumulator::<Bls12_377>::read(&to_bytes![accumulator].unwrap()[..]).unwrap();
+        transcript.verify(&accumulator, rng).unwrap();
+
+        // The truncated parameters can be used to commit and open.
+        let pp = accumulator.into_universal_params(8).unwrap();
+        assert_eq!(pp.powers_of_g.len(), 9);
+        assert_eq!(pp.powers_of_gamma_g.len(), 10);
+        assert_eq!(pp.prepared_neg_powers_of_h.len(), 9);
+
+        let (powers, vk) = KZG_Bls12_377::trim(&pp, 8);
+        let p = crate::Polynomial::<Fr>::rand(8, rng);
+        let hiding_bound = Some(1);
+        let (comm, rand) = KZG_Bls12_377::commit(&powers, &p, hiding_bound, Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let value = p.evaluate(point);
+        let proof = KZG_Bls12_377::open(&powers, &p, point, &rand).unwrap();
+        assert!(KZG_Bls12_377::check(&vk, &comm, point, value, &proof).unwrap());
+    }
+
+    #[test]
+    fn test_ceremony_rejects_invalid_contributions() {
+        let rng = &mut test_rng();
+
+        let (mut transcript, accumulator) = Transcript::<Bls12_377>::new(8, false).unwrap();
+        assert!(transcript.verify(&accumulator, rng).is_err());
+
+        let first = transcript.contribute(&accumulator, rng).unwrap();
+        let second = transcript.contribute(&first, rng).unwrap();
+        transcript.verify(&second, rng).unwrap();
+
+        // An accumulator that skips the last contribution is rejected.
+        assert!(transcript.verify(&first, rng).is_err());
+
+        // Contributing to a stale accumulator is rejected.
+        assert!(transcript.clone().contribute(&first, rng).is_err());
+
+        // A tampered power is rejected.
+        let mut tampered = second.clone();
+        tampered.powers_of_g[3] = tampered.powers_of_g[2];
+        assert!(transcript.verify(&tampered, rng).is_err());
+        assert!(first
+            .verify_contribution(&tampered, &transcript.contributions[1], rng)
+            .is_err());
+
+        // A proof copied from another contribution is rejected.
+        let mut copied = transcript.clone();
+        copied.contributions[1] = copied.contributions[0].clone();
+        assert!(copied.verify(&second, rng).is_err());
+
+        // Truncating beyond the ceremony degree is rejected.
+        assert!(second.into_universal_params(9).is_err());
+    }
+}
@@ -16,6 +16,10 @@
 
 use crate::{impl_bytes, BTreeMap, *};
 use core::ops::{Add, AddAssign};
+use snarkvm_algorithms::{
+    envelope::{scalar_field_fingerprint, EnvelopeKind, EnvelopeScheme},
+    Envelope,
+};
 use snarkvm_curves::{
     traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve},
     Group,
@@ -61,6 +65,19 @@ impl<E: PairingEngine> PCUniversalParams for UniversalParams<E> {
     }
 }
 
+impl<E: PairingEngine> Envelope for UniversalParams<E> {
+    const KIND: EnvelopeKind = EnvelopeKind::UniversalSRS;
+    const SCHEME: EnvelopeScheme = EnvelopeScheme::KZG10;
+
+    fn curve() -> [u8; 8] {
+        scalar_field_fingerprint::<E::Fr>()
+    }
+
+    fn sizes(&self) -> Vec<u64> {
+        vec![self.max_degree() as u64]
+    }
+}
+
 /// `Powers` is used to commit to and create evaluation proofs for a given
 /// polynomial.
 #[derive(Derivative)]
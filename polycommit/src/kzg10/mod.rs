@@ -36,9 +36,52 @@ use rand_core::RngCore;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+pub mod ceremony;
+
 mod data_structures;
 pub use data_structures::*;
 
+/// The number of coefficients converted to big integers at a time in the chunked multi-scalar multiplications
+/// of a commitment or an opening.
+const MSM_CHUNK_SIZE: usize = 1 << 16;
+
+#[cfg(feature = "std")]
+std::thread_local!(static CHUNKED_MSMS: core::cell::Cell<bool> = const { core::cell::Cell::new(false) });
+
+/// Chunks the multi-scalar multiplications of commitments and openings on the current thread until dropped.
+///
+/// Chunked multi-scalar multiplications convert `MSM_CHUNK_SIZE` coefficients to big integers at a time,
+/// instead of all of them at once. This bounds their memory overhead for large polynomials, at some cost in speed.
+/// Without the `std` feature, the multi-scalar multiplications are never chunked.
+#[must_use]
+pub struct ChunkedMSMGuard {
+    #[cfg(feature = "std")]
+    was_chunked: bool,
+}
+
+impl ChunkedMSMGuard {
+    /// Starts chunking the multi-scalar multiplications on the current thread.
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            was_chunked: CHUNKED_MSMS.with(|chunked| chunked.replace(true)),
+        }
+    }
+}
+
+impl Default for ChunkedMSMGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ChunkedMSMGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        CHUNKED_MSMS.with(|chunked| chunked.set(self.was_chunked));
+    }
+}
+
 /// `KZG10` is an implementation of the polynomial commitment scheme of
 /// [Kate, Zaverucha and Goldbgerg][kzg10]
 ///
@@ -73,20 +116,14 @@ impl<E: PairingEngine> KZG10<E> {
 
         let window_size = FixedBaseMSM::get_mul_window_size(max_degree + 1);
 
-        let scalar_bits = E::Fr::size_in_bits();
         let g_time = start_timer!(|| "Generating powers of G");
-        let g_table = FixedBaseMSM::get_window_table(scalar_bits, window_size, g);
-        let powers_of_g =
-            FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(scalar_bits, window_size, &g_table, &powers_of_beta);
+        let g_table = FixedBaseMSM::get_glv_window_table(window_size, g);
+        let powers_of_g = FixedBaseMSM::glv_multi_scalar_mul::<E::G1Projective>(window_size, &g_table, &powers_of_beta);
         end_timer!(g_time);
         let gamma_g_time = start_timer!(|| "Generating powers of gamma * G");
-        let gamma_g_table = FixedBaseMSM::get_window_table(scalar_bits, window_size, gamma_g);
-        let mut powers_of_gamma_g = FixedBaseMSM::multi_scalar_mul::<E::G1Projective>(
-            scalar_bits,
-            window_size,
-            &gamma_g_table,
-            &powers_of_beta,
-        );
+        let gamma_g_table = FixedBaseMSM::get_glv_window_table(window_size, gamma_g);
+        let mut powers_of_gamma_g =
+            FixedBaseMSM::glv_multi_scalar_mul::<E::G1Projective>(window_size, &gamma_g_table, &powers_of_beta);
         // Add an additional power of gamma_g, because we want to be able to support
         // up to D queries.
         powers_of_gamma_g.push(powers_of_gamma_g.last().unwrap().mul(beta));
@@ -107,13 +144,9 @@ impl<E: PairingEngine> KZG10<E> {
                 cur /= &beta;
             }
 
-            let neg_h_table = FixedBaseMSM::get_window_table(scalar_bits, window_size, h);
-            let neg_powers_of_h = FixedBaseMSM::multi_scalar_mul::<E::G2Projective>(
-                scalar_bits,
-                window_size,
-                &neg_h_table,
-                &neg_powers_of_beta,
-            );
+            let neg_h_table = FixedBaseMSM::get_glv_window_table(window_size, h);
+            let neg_powers_of_h =
+                FixedBaseMSM::glv_multi_scalar_mul::<E::G2Projective>(window_size, &neg_h_table, &neg_powers_of_beta);
 
             let affines = E::G2Projective::batch_normalization_into_affine(neg_powers_of_h);
             let mut affines_map = BTreeMap::new();
@@ -164,10 +197,13 @@ impl<E: PairingEngine> KZG10<E> {
             hiding_bound,
         ));
 
-        let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(&polynomial);
+        let num_leading_zeros = skip_leading_zeros(polynomial);
 
         let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-        let mut commitment = VariableBaseMSM::multi_scalar_mul(&powers.powers_of_g[num_leading_zeros..], &plain_coeffs);
+        let mut commitment = msm(
+            &powers.powers_of_g[num_leading_zeros..],
+            &polynomial.coeffs[num_leading_zeros..],
+        );
         end_timer!(msm_time);
 
         let mut randomness = Randomness::empty();
@@ -181,10 +217,8 @@ impl<E: PairingEngine> KZG10<E> {
             end_timer!(sample_random_poly_time);
         }
 
-        let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
         let msm_time = start_timer!(|| "MSM to compute commitment to random poly");
-        let random_commitment =
-            VariableBaseMSM::multi_scalar_mul(&powers.powers_of_gamma_g, random_ints.as_slice()).into_affine();
+        let random_commitment = msm(&powers.powers_of_gamma_g, &randomness.blinding_polynomial.coeffs).into_affine();
         end_timer!(msm_time);
 
         commitment.add_assign_mixed(&random_commitment);
@@ -232,10 +266,13 @@ impl<E: PairingEngine> KZG10<E> {
         hiding_witness_polynomial: Option<&Polynomial<E::Fr>>,
     ) -> Result<Proof<E>, Error> {
         Self::check_degree_is_too_large(witness_polynomial.degree(), powers.size())?;
-        let (num_leading_zeros, witness_coeffs) = skip_leading_zeros_and_convert_to_bigints(&witness_polynomial);
+        let num_leading_zeros = skip_leading_zeros(witness_polynomial);
 
         let witness_comm_time = start_timer!(|| "Computing commitment to witness polynomial");
-        let mut w = VariableBaseMSM::multi_scalar_mul(&powers.powers_of_g[num_leading_zeros..], &witness_coeffs);
+        let mut w = msm(
+            &powers.powers_of_g[num_leading_zeros..],
+            &witness_polynomial.coeffs[num_leading_zeros..],
+        );
         end_timer!(witness_comm_time);
 
         let random_v = if let Some(hiding_witness_polynomial) = hiding_witness_polynomial {
@@ -244,9 +281,8 @@ impl<E: PairingEngine> KZG10<E> {
             let blinding_evaluation = blinding_p.evaluate(point);
             end_timer!(blinding_eval_time);
 
-            let random_witness_coeffs = convert_to_bigints(&hiding_witness_polynomial.coeffs);
             let witness_comm_time = start_timer!(|| "Computing commitment to random witness polynomial");
-            w += &VariableBaseMSM::multi_scalar_mul(&powers.powers_of_gamma_g, &random_witness_coeffs);
+            w += &msm(&powers.powers_of_gamma_g, &hiding_witness_polynomial.coeffs);
             end_timer!(witness_comm_time);
             Some(blinding_evaluation)
         } else {
@@ -419,13 +455,21 @@ impl<E: PairingEngine> KZG10<E> {
     }
 }
 
-fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &Polynomial<F>) -> (usize, Vec<F::BigInteger>) {
-    let mut num_leading_zeros = 0;
-    while p.coeffs[num_leading_zeros].is_zero() && num_leading_zeros < p.coeffs.len() {
-        num_leading_zeros += 1;
+fn skip_leading_zeros<F: PrimeField>(p: &Polynomial<F>) -> usize {
+    p.coeffs.iter().take_while(|coeff| coeff.is_zero()).count()
+}
+
+/// Returns the multi-scalar multiplication of `bases` with `coeffs`,
+/// which is chunked while a [`ChunkedMSMGuard`] is active on the current thread.
+///
+/// The bases are powers from the SRS, which are in the prime order subgroup, so this uses the GLV endomorphism.
+fn msm<G: AffineCurve>(bases: &[G], coeffs: &[G::ScalarField]) -> G::Projective {
+    #[cfg(feature = "std")]
+    if CHUNKED_MSMS.with(|chunked| chunked.get()) {
+        return VariableBaseMSM::multi_scalar_mul_glv_chunked(bases, coeffs, MSM_CHUNK_SIZE);
     }
-    let coeffs = convert_to_bigints(&p.coeffs[num_leading_zeros..]);
-    (num_leading_zeros, coeffs)
+
+    VariableBaseMSM::multi_scalar_mul_glv(bases, &convert_to_bigints(coeffs))
 }
 
 fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
@@ -497,6 +541,25 @@ mod tests {
         assert_eq!(f_comm, f_comm_2);
     }
 
+    #[test]
+    fn chunked_msm_test() {
+        let rng = &mut test_rng();
+        let degree = 20;
+        let pp = KZG_Bls12_377::setup(degree, false, rng).unwrap();
+        let (powers, _) = KZG_Bls12_377::trim(&pp, degree);
+        let p = Polynomial::rand(degree, rng);
+        let point = Fr::rand(rng);
+
+        let (comm, rand) = KZG10::commit(&powers, &p, None, None).unwrap();
+        let proof = KZG10::open(&powers, &p, point, &rand).unwrap();
+
+        let guard = ChunkedMSMGuard::new();
+        assert_eq!(comm, KZG10::commit(&powers, &p, None, None).unwrap().0);
+        assert_eq!(proof, KZG10::open(&powers, &p, point, &rand).unwrap());
+        drop(guard);
+        assert!(!CHUNKED_MSMS.with(|chunked| chunked.get()));
+    }
+
     fn end_to_end_test_template<E: PairingEngine>() -> Result<(), Error> {
         let rng = &mut test_rng();
         for _ in 0..100 {
@@ -607,4 +670,35 @@ mod tests {
         assert!(p.degree() > max_degree);
         assert!(KZG_Bls12_377::check_degree_is_too_large(p.degree(), powers.size()).is_err());
     }
+
+    #[test]
+    fn test_universal_params_envelope() {
+        use snarkvm_algorithms::{
+            envelope::{EnvelopeKind, EnvelopeMode},
+            errors::EnvelopeError,
+            Envelope,
+        };
+        use snarkvm_curves::bw6_761::BW6_761;
+
+        let rng = &mut test_rng();
+
+        let max_degree = 15;
+        let pp = KZG_Bls12_377::setup(max_degree, false, rng).unwrap();
+
+        let mut buffer = vec![];
+        pp.write_envelope(&mut buffer, EnvelopeMode::None).unwrap();
+
+        let (header, recovered) =
+            UniversalParams::<Bls12_377>::read_envelope_with_header(&buffer[..], EnvelopeMode::None).unwrap();
+        assert_eq!(header.unwrap().kind, EnvelopeKind::UniversalSRS);
+        assert_eq!(recovered.powers_of_g, pp.powers_of_g);
+        assert_eq!(recovered.powers_of_gamma_g, pp.powers_of_gamma_g);
+        assert_eq!(recovered.h, pp.h);
+        assert_eq!(recovered.beta_h, pp.beta_h);
+
+        assert!(matches!(
+            UniversalParams::<BW6_761>::read_envelope(&buffer[..], EnvelopeMode::None),
+            Err(EnvelopeError::CurveMismatch(..))
+        ));
+    }
 }
@@ -17,7 +17,7 @@
 use core::borrow::Borrow;
 
 use snarkvm_curves::{traits::AffineCurve, PairingEngine};
-use snarkvm_fields::ToConstraintField;
+use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_gadgets::{
     bits::ToBytesGadget,
     fields::FpGadget,
@@ -33,13 +33,10 @@ use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
 use crate::{marlin_pc::Commitment, Vec};
 
 /// Var for an optionally hiding Marlin-KZG10 commitment.
-pub struct CommitmentVar<
-    TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-> where
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+pub struct CommitmentVar<TargetCurve: PairingEngine, BaseField: PrimeField, PG: PairingGadget<TargetCurve, BaseField>>
+where
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     /// Commitment.
     pub comm: PG::G1Gadget,
@@ -47,13 +44,13 @@ pub struct CommitmentVar<
     pub shifted_comm: Option<PG::G1Gadget>,
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for CommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for CommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -63,19 +60,19 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> AllocGadget<Commitment<TargetCurve>, <BaseCurve as PairingEngine>::Fr>
-    for CommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> AllocGadget<Commitment<TargetCurve>, BaseField>
+    for CommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn alloc_constant<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Commitment<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -106,7 +103,7 @@ where
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Commitment<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -135,7 +132,7 @@ where
     fn alloc_input<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Commitment<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -164,20 +161,19 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> ToConstraintFieldGadget<<BaseCurve as PairingEngine>::Fr>
-    for CommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> ToConstraintFieldGadget<BaseField> for CommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    PG::G1Gadget: ToConstraintFieldGadget<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    PG::G1Gadget: ToConstraintFieldGadget<BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
-    fn to_constraint_field<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    fn to_constraint_field<CS: ConstraintSystem<BaseField>>(
         &self,
         mut cs: CS,
-    ) -> Result<Vec<FpGadget<<BaseCurve as PairingEngine>::Fr>>, SynthesisError> {
+    ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
         let mut res = Vec::new();
 
         let mut comm_gadget = self.comm.to_constraint_field(cs.ns(|| "comm_to_constraint_field"))?;
@@ -197,19 +193,15 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> ToBytesGadget<<BaseCurve as PairingEngine>::Fr>
-    for CommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> ToBytesGadget<BaseField> for CommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
-    fn to_bytes<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
-        &self,
-        mut cs: CS,
-    ) -> Result<Vec<UInt8>, SynthesisError> {
+    fn to_bytes<CS: ConstraintSystem<BaseField>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
         let zero_shifted_comm = PG::G1Gadget::zero(cs.ns(|| "zero"))?;
 
         let mut bytes = Vec::new();
@@ -220,10 +212,7 @@ where
         Ok(bytes)
     }
 
-    fn to_bytes_strict<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
-        &self,
-        cs: CS,
-    ) -> Result<Vec<UInt8>, SynthesisError> {
+    fn to_bytes_strict<CS: ConstraintSystem<BaseField>>(&self, cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
         self.to_bytes(cs)
     }
 }
@@ -17,6 +17,7 @@
 use core::borrow::Borrow;
 
 use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
     fields::FpGadget,
     traits::{alloc::AllocGadget, curves::PairingGadget},
@@ -33,27 +34,27 @@ use crate::{
 /// Gadget for a Marlin-KZG10 commitment, with a string label and degree bound.
 pub struct LabeledCommitmentVar<
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
 > where
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     /// A text label for the commitment.
     pub label: String,
     /// The plain commitment.
-    pub commitment: CommitmentVar<TargetCurve, BaseCurve, PG>,
+    pub commitment: CommitmentVar<TargetCurve, BaseField, PG>,
     /// Optionally, a bound on the polynomial degree.
-    pub degree_bound: Option<FpGadget<<BaseCurve as PairingEngine>::Fr>>,
+    pub degree_bound: Option<FpGadget<BaseField>>,
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for LabeledCommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for LabeledCommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn clone(&self) -> Self {
         LabeledCommitmentVar {
@@ -64,20 +65,19 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG>
-    AllocGadget<LabeledCommitment<Commitment<TargetCurve>>, <BaseCurve as PairingEngine>::Fr>
-    for LabeledCommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> AllocGadget<LabeledCommitment<Commitment<TargetCurve>>, BaseField>
+    for LabeledCommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn alloc_constant<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<LabeledCommitment<Commitment<TargetCurve>>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -91,10 +91,8 @@ where
             let commitment = CommitmentVar::alloc_constant(cs.ns(|| "commitment"), || Ok(commitment))?;
 
             let degree_bound = if let Some(degree_bound) = degree_bound {
-                FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc_constant(cs.ns(|| "degree_bound"), || {
-                    Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(
-                        degree_bound as u128,
-                    ))
+                FpGadget::<BaseField>::alloc_constant(cs.ns(|| "degree_bound"), || {
+                    Ok(<BaseField as From<u128>>::from(degree_bound as u128))
                 })
                 .ok()
             } else {
@@ -112,7 +110,7 @@ where
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<LabeledCommitment<Commitment<TargetCurve>>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -126,10 +124,8 @@ where
             let commitment = CommitmentVar::alloc(cs.ns(|| "commitment"), || Ok(commitment))?;
 
             let degree_bound = if let Some(degree_bound) = degree_bound {
-                FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc(cs.ns(|| "degree_bound"), || {
-                    Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(
-                        degree_bound as u128,
-                    ))
+                FpGadget::<BaseField>::alloc(cs.ns(|| "degree_bound"), || {
+                    Ok(<BaseField as From<u128>>::from(degree_bound as u128))
                 })
                 .ok()
             } else {
@@ -147,7 +143,7 @@ where
     fn alloc_input<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<LabeledCommitment<Commitment<TargetCurve>>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -161,10 +157,8 @@ where
             let commitment = CommitmentVar::alloc_input(cs.ns(|| "commitment"), || Ok(commitment))?;
 
             let degree_bound = if let Some(degree_bound) = degree_bound {
-                FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc_input(cs.ns(|| "degree_bound"), || {
-                    Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(
-                        degree_bound as u128,
-                    ))
+                FpGadget::<BaseField>::alloc_input(cs.ns(|| "degree_bound"), || {
+                    Ok(<BaseField as From<u128>>::from(degree_bound as u128))
                 })
                 .ok()
             } else {
@@ -185,7 +179,6 @@ mod tests {
     use snarkvm_algorithms::fft::DensePolynomial;
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fq, Fr},
-        bw6_761::BW6_761,
         AffineCurve,
     };
     use snarkvm_gadgets::{
@@ -202,7 +195,7 @@ mod tests {
 
     type PC = MarlinKZG10<Bls12_377>;
     type PG = Bls12_377PairingGadget;
-    type BaseCurve = BW6_761;
+    type BaseField = Fq;
 
     const MAX_DEGREE: usize = 383;
     const SUPPORTED_DEGREE: usize = 300;
@@ -229,7 +222,7 @@ mod tests {
 
         for (i, commitment) in commitments.iter().enumerate() {
             let commitment_gadget =
-                LabeledCommitmentVar::<_, BaseCurve, PG>::alloc(cs.ns(|| format!("commitment_{}", i)), || {
+                LabeledCommitmentVar::<_, BaseField, PG>::alloc(cs.ns(|| format!("commitment_{}", i)), || {
                     Ok(commitment)
                 })
                 .unwrap();
@@ -327,7 +320,7 @@ mod tests {
 
         for (i, commitment) in commitments.iter().enumerate() {
             let commitment_gadget =
-                LabeledCommitmentVar::<_, BaseCurve, PG>::alloc(cs.ns(|| format!("commitment_{}", i)), || {
+                LabeledCommitmentVar::<_, BaseField, PG>::alloc(cs.ns(|| format!("commitment_{}", i)), || {
                     Ok(commitment)
                 })
                 .unwrap();
@@ -34,11 +34,11 @@ use crate::{
 /// shifted_comm is not prepared, due to the specific use case.
 pub struct PreparedCommitmentVar<
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
 > where
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     /// Prepared commitment.
     pub prepared_comm: Vec<PG::G1Gadget>,
@@ -46,13 +46,13 @@ pub struct PreparedCommitmentVar<
     pub shifted_comm: Option<PG::G1Gadget>,
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for PreparedCommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for PreparedCommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -62,19 +62,18 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG>
-    PrepareGadget<CommitmentVar<TargetCurve, BaseCurve, PG>, <BaseCurve as PairingEngine>::Fr>
-    for PreparedCommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> PrepareGadget<CommitmentVar<TargetCurve, BaseField, PG>, BaseField>
+    for PreparedCommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
-    fn prepare<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    fn prepare<CS: ConstraintSystem<BaseField>>(
         mut cs: CS,
-        unprepared: &CommitmentVar<TargetCurve, BaseCurve, PG>,
+        unprepared: &CommitmentVar<TargetCurve, BaseField, PG>,
     ) -> Result<Self, SynthesisError> {
         let mut prepared_comm = Vec::<PG::G1Gadget>::new();
         let supported_bits = <<TargetCurve as PairingEngine>::Fr as PrimeField>::size_in_bits();
@@ -92,19 +91,19 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> AllocGadget<PreparedCommitment<TargetCurve>, <BaseCurve as PairingEngine>::Fr>
-    for PreparedCommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> AllocGadget<PreparedCommitment<TargetCurve>, BaseField>
+    for PreparedCommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn alloc_constant<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<PreparedCommitment<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -116,7 +115,7 @@ where
         for (i, comm_elem) in obj.prepared_comm.0.iter().enumerate() {
             prepared_comm.push(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc_constant(
                 cs.ns(|| format!("comm_elem_{}", i)),
                 || {
@@ -130,7 +129,7 @@ where
         let shifted_comm = if obj.shifted_comm.is_some() {
             Some(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc_constant(cs.ns(|| "shifted_comm"), || {
                 Ok(<<TargetCurve as PairingEngine>::G1Projective as From<
                     <TargetCurve as PairingEngine>::G1Affine,
@@ -149,7 +148,7 @@ where
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<PreparedCommitment<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -161,7 +160,7 @@ where
         for (i, comm_elem) in obj.prepared_comm.0.iter().enumerate() {
             prepared_comm.push(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc(cs.ns(|| format!("comm_elem_{}", i)), || {
                 Ok(<<TargetCurve as PairingEngine>::G1Projective as From<
                     <TargetCurve as PairingEngine>::G1Affine,
@@ -172,7 +171,7 @@ where
         let shifted_comm = if obj.shifted_comm.is_some() {
             Some(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc(cs.ns(|| "shifted_comm"), || {
                 Ok(<<TargetCurve as PairingEngine>::G1Projective as From<
                     <TargetCurve as PairingEngine>::G1Affine,
@@ -191,7 +190,7 @@ where
     fn alloc_input<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<PreparedCommitment<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -203,7 +202,7 @@ where
         for (i, comm_elem) in obj.prepared_comm.0.iter().enumerate() {
             prepared_comm.push(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc_input(
                 cs.ns(|| format!("comm_elem_{}", i)),
                 || {
@@ -217,7 +216,7 @@ where
         let shifted_comm = if obj.shifted_comm.is_some() {
             Some(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc_input(cs.ns(|| "shifted_comm"), || {
                 Ok(<<TargetCurve as PairingEngine>::G1Projective as From<
                     <TargetCurve as PairingEngine>::G1Affine,
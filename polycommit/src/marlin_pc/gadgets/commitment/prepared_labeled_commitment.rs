@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{fields::FpGadget, traits::curves::PairingGadget};
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError, ToConstraintField};
 
@@ -27,27 +28,27 @@ use crate::{
 /// Prepared gadget for a Marlin-KZG10 commitment, with a string label and degree bound.
 pub struct PreparedLabeledCommitmentVar<
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
 > where
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     /// A text label for the commitment.
     pub label: String,
     /// The plain commitment.
-    pub prepared_commitment: PreparedCommitmentVar<TargetCurve, BaseCurve, PG>,
+    pub prepared_commitment: PreparedCommitmentVar<TargetCurve, BaseField, PG>,
     /// Optionally, a bound on the polynomial degree.
-    pub degree_bound: Option<FpGadget<<BaseCurve as PairingEngine>::Fr>>,
+    pub degree_bound: Option<FpGadget<BaseField>>,
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for PreparedLabeledCommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for PreparedLabeledCommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -58,19 +59,18 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG>
-    PrepareGadget<LabeledCommitmentVar<TargetCurve, BaseCurve, PG>, <BaseCurve as PairingEngine>::Fr>
-    for PreparedLabeledCommitmentVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> PrepareGadget<LabeledCommitmentVar<TargetCurve, BaseField, PG>, BaseField>
+    for PreparedLabeledCommitmentVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
-    fn prepare<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    fn prepare<CS: ConstraintSystem<BaseField>>(
         cs: CS,
-        unprepared: &LabeledCommitmentVar<TargetCurve, BaseCurve, PG>,
+        unprepared: &LabeledCommitmentVar<TargetCurve, BaseField, PG>,
     ) -> Result<Self, SynthesisError> {
         Ok(Self {
             label: unprepared.label.clone(),
@@ -85,7 +85,6 @@ mod tests {
     use snarkvm_algorithms::fft::DensePolynomial;
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fq, Fr},
-        bw6_761::BW6_761,
         AffineCurve,
     };
     use snarkvm_gadgets::{
@@ -107,7 +106,7 @@ mod tests {
 
     type PC = MarlinKZG10<Bls12_377>;
     type PG = Bls12_377PairingGadget;
-    type BaseCurve = BW6_761;
+    type BaseField = Fq;
 
     const MAX_DEGREE: usize = 383;
     const SUPPORTED_DEGREE: usize = 300;
@@ -135,7 +134,7 @@ mod tests {
         for (i, commitment) in commitments.iter().enumerate() {
             let prepared_commitment = PreparedCommitment::prepare(&commitment.commitment());
             let commitment_gadget =
-                LabeledCommitmentVar::<_, BaseCurve, PG>::alloc(cs.ns(|| format!("commitment_{}", i)), || {
+                LabeledCommitmentVar::<_, BaseField, PG>::alloc(cs.ns(|| format!("commitment_{}", i)), || {
                     Ok(commitment)
                 })
                 .unwrap();
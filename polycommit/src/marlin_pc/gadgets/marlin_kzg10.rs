@@ -16,12 +16,14 @@
 
 use core::{convert::TryInto, marker::PhantomData};
 
-use snarkvm_curves::PairingEngine;
+use snarkvm_curves::{AffineCurve, Group, PairingEngine};
+use snarkvm_fields::{FieldParameters, One, PrimeField, Zero};
 use snarkvm_gadgets::{
     bits::{Boolean, ToBitsLEGadget},
     fields::FpGadget,
     nonnative::{NonNativeFieldMulResultVar, NonNativeFieldVar},
     traits::{
+        alloc::AllocGadget,
         curves::{GroupGadget, PairingGadget},
         eq::EqGadget,
         fields::FieldGadget,
@@ -29,6 +31,7 @@ use snarkvm_gadgets::{
     },
 };
 use snarkvm_r1cs::{ConstraintSystem, SynthesisError, ToConstraintField};
+use snarkvm_utilities::BitIteratorBE;
 
 use crate::{
     marlin_pc::{
@@ -54,74 +57,100 @@ use crate::{
 };
 
 /// Gadget for the Marlin-KZG10 polynomial commitment verifier.
-pub struct MarlinKZG10Gadget<TargetCurve, BaseCurve, PG>
+pub struct MarlinKZG10Gadget<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     _target_curve: PhantomData<TargetCurve>,
-    _base_curve: PhantomData<BaseCurve>,
+    _base_field: PhantomData<BaseField>,
     _pairing_gadget: PhantomData<PG>,
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for MarlinKZG10Gadget<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for MarlinKZG10Gadget<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn clone(&self) -> Self {
         Self {
             _target_curve: PhantomData,
-            _base_curve: PhantomData,
+            _base_field: PhantomData,
             _pairing_gadget: PhantomData,
         }
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> MarlinKZG10Gadget<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> MarlinKZG10Gadget<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
+    /// Returns the G1 point from which the accumulators of the batch check start.
+    ///
+    /// The G1 gadget uses incomplete addition, and its neutral element is encoded as `(0, 1)`.
+    /// When `(0, 1)` lies on the curve (e.g. BLS12-377), that encoding is a point of small order
+    /// and is used as is. Otherwise, this returns a constant G1 point whose order is coprime to
+    /// the order of the pairing groups. The pairing maps either point to one, so their multiples
+    /// do not affect the result of the pairing check.
+    fn g1_accumulator_start<CS: ConstraintSystem<BaseField>>(mut cs: CS) -> Result<PG::G1Gadget, SynthesisError> {
+        let zero_is_on_curve = [false, true].iter().any(|greatest| {
+            <TargetCurve as PairingEngine>::G1Affine::from_x_coordinate(Zero::zero(), *greatest)
+                .map_or(false, |point| point.to_y_coordinate().is_one())
+        });
+        if zero_is_on_curve {
+            return PG::G1Gadget::zero(cs.ns(|| "zero"));
+        }
+
+        let modulus = <<TargetCurve as PairingEngine>::Fr as PrimeField>::Parameters::MODULUS;
+        let start = (1u64..100)
+            .filter_map(|x| <TargetCurve as PairingEngine>::G1Affine::from_x_coordinate(x.into(), false))
+            .map(|point| point.mul_bits(BitIteratorBE::new(modulus)))
+            .find(|point| !point.double().is_zero());
+
+        match start {
+            Some(start) => PG::G1Gadget::alloc_constant(cs.ns(|| "accumulator_start"), || Ok(start)),
+            // If G1 has no cofactor, fall back to the neutral element.
+            None => PG::G1Gadget::zero(cs.ns(|| "zero")),
+        }
+    }
+
     #[allow(clippy::type_complexity, clippy::too_many_arguments)]
-    fn prepared_batch_check_evaluations<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    fn prepared_batch_check_evaluations<CS: ConstraintSystem<BaseField>>(
         mut cs: CS,
         prepared_verification_key: &<Self as PCCheckVar<
             <TargetCurve as PairingEngine>::Fr,
             MarlinKZG10<TargetCurve>,
-            <BaseCurve as PairingEngine>::Fr,
+            BaseField,
         >>::PreparedVerifierKeyVar,
         lc_info: &[(
             String,
             Vec<(
-                Option<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>>,
-                Option<FpGadget<<BaseCurve as PairingEngine>::Fr>>,
-                PreparedCommitmentVar<TargetCurve, BaseCurve, PG>,
+                Option<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>>,
+                Option<FpGadget<BaseField>>,
+                PreparedCommitmentVar<TargetCurve, BaseField, PG>,
                 bool,
             )>,
         )],
-        query_set: &QuerySetVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
-        evaluations: &EvaluationsVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+        query_set: &QuerySetVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
+        evaluations: &EvaluationsVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
         proofs: &[<Self as PCCheckVar<
             <TargetCurve as PairingEngine>::Fr,
             MarlinKZG10<TargetCurve>,
-            <BaseCurve as PairingEngine>::Fr,
+            BaseField,
         >>::ProofVar],
-        opening_challenges: &[NonNativeFieldVar<
-            <TargetCurve as PairingEngine>::Fr,
-            <BaseCurve as PairingEngine>::Fr,
-        >],
+        opening_challenges: &[NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>],
         opening_challenges_bits: &[Vec<Boolean>],
-        batching_rands: &[NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>],
+        batching_rands: &[NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>],
         batching_rands_bits: &[Vec<Boolean>],
     ) -> Result<Boolean, SynthesisError> {
         let mut batching_rands = batching_rands.to_vec();
@@ -132,9 +161,9 @@ where
             (
                 String,
                 Vec<(
-                    Option<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>>,
-                    Option<FpGadget<<BaseCurve as PairingEngine>::Fr>>,
-                    PreparedCommitmentVar<TargetCurve, BaseCurve, PG>,
+                    Option<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>>,
+                    Option<FpGadget<BaseField>>,
+                    PreparedCommitmentVar<TargetCurve, BaseField, PG>,
                     bool,
                 )>,
             ),
@@ -143,7 +172,7 @@ where
         let mut query_to_labels_map: BTreeMap<
             String,
             (
-                NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+                NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
                 BTreeSet<&String>,
             ),
         > = BTreeMap::new();
@@ -161,7 +190,7 @@ where
 
         eprintln!("before PC combining commitments: constraints: {}", cs.num_constraints());
 
-        let zero = PG::G1Gadget::zero(cs.ns(|| format!("g1_zero")))?;
+        let zero = Self::g1_accumulator_start(cs.ns(|| format!("g1_zero")))?;
 
         // Accumulate commitments and evaluations for each query.
         let mut combined_queries = Vec::new();
@@ -170,9 +199,9 @@ where
         for (i, (_, (point, labels))) in query_to_labels_map.into_iter().enumerate() {
             let mut comms_to_combine = Vec::<
                 Vec<(
-                    Option<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>>,
-                    Option<FpGadget<<BaseCurve as PairingEngine>::Fr>>,
-                    PreparedCommitmentVar<TargetCurve, BaseCurve, PG>,
+                    Option<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>>,
+                    Option<FpGadget<BaseField>>,
+                    PreparedCommitmentVar<TargetCurve, BaseField, PG>,
                     bool,
                 )>,
             >::new();
@@ -193,11 +222,8 @@ where
             }
 
             // Accumulate the commitments and evaluations corresponding to `query`.
-            let mut combined_comm = PG::G1Gadget::zero(cs.ns(|| format!("comm_zero_{}", i)))?;
-            let mut combined_eval = NonNativeFieldMulResultVar::<
-                <TargetCurve as PairingEngine>::Fr,
-                <BaseCurve as PairingEngine>::Fr,
-            >::zero();
+            let mut combined_comm = Self::g1_accumulator_start(cs.ns(|| format!("comm_zero_{}", i)))?;
+            let mut combined_eval = NonNativeFieldMulResultVar::<<TargetCurve as PairingEngine>::Fr, BaseField>::zero();
 
             let mut opening_challenges_counter = 0;
 
@@ -212,7 +238,8 @@ where
 
                     if coeff.is_none() {
                         // To combine the commitments, we multiply each by one of the random challenges, and sum.
-                        let mut comm_times_challenge = PG::G1Gadget::zero(cs.ns(|| format!("zero_{}_{}_{}", i, j, k)))?;
+                        let mut comm_times_challenge =
+                            Self::g1_accumulator_start(cs.ns(|| format!("zero_{}_{}_{}", i, j, k)))?;
                         {
                             for (l, (bit, base_power)) in
                                 challenge_bits.iter().zip(comm.prepared_comm.iter()).enumerate()
@@ -264,8 +291,9 @@ where
                                 )
                                 .unwrap();
 
-                            let mut shift_power_times_value =
-                                PG::G1Gadget::zero(cs.ns(|| format!("shift_power_times_value_zero{}_{}_{}", i, j, k)))?;
+                            let mut shift_power_times_value = Self::g1_accumulator_start(
+                                cs.ns(|| format!("shift_power_times_value_zero{}_{}_{}", i, j, k)),
+                            )?;
                             {
                                 for (l, (bit, base_power)) in value_bits.iter().zip(&shift_power).enumerate() {
                                     let mut new_encoded = base_power.clone();
@@ -304,7 +332,8 @@ where
                     } else {
                         assert!(degree_bound.is_none());
 
-                        let mut comm_times_challenge = PG::G1Gadget::zero(cs.ns(|| format!("zero_{}_{}_{}", i, j, k)))?;
+                        let mut comm_times_challenge =
+                            Self::g1_accumulator_start(cs.ns(|| format!("zero_{}_{}_{}", i, j, k)))?;
                         let coeff = coeff.clone().unwrap();
 
                         let challenge_times_coeff = challenge.mul(
@@ -366,17 +395,13 @@ where
 
         // Perform the batch check.
         {
-            let mut total_c = PG::G1Gadget::zero(cs.ns(|| "zero_c"))?;
-            let mut total_w = PG::G1Gadget::zero(cs.ns(|| "zero_w"))?;
-
-            let mut g_multiplier = NonNativeFieldMulResultVar::<
-                <TargetCurve as PairingEngine>::Fr,
-                <BaseCurve as PairingEngine>::Fr,
-            >::zero();
-            let mut g_multiplier_reduced = NonNativeFieldVar::<
-                <TargetCurve as PairingEngine>::Fr,
-                <BaseCurve as PairingEngine>::Fr,
-            >::zero(cs.ns(|| "zero_g_multiplier"))?;
+            let mut total_c = Self::g1_accumulator_start(cs.ns(|| "zero_c"))?;
+            let mut total_w = Self::g1_accumulator_start(cs.ns(|| "zero_w"))?;
+
+            let mut g_multiplier = NonNativeFieldMulResultVar::<<TargetCurve as PairingEngine>::Fr, BaseField>::zero();
+            let mut g_multiplier_reduced = NonNativeFieldVar::<<TargetCurve as PairingEngine>::Fr, BaseField>::zero(
+                cs.ns(|| "zero_g_multiplier"),
+            )?;
             for (i, (((c, z), v), proof)) in combined_comms
                 .iter()
                 .zip(combined_queries)
@@ -443,7 +468,7 @@ where
                 let g_multiplier_reduced = g_multiplier_reduced.add(&mut cs.ns(|| "g_multiplier_reduce"), &reduced)?;
                 let g_multiplier_bits = g_multiplier_reduced.to_bits_le(&mut cs.ns(|| "g_multiplier_to_bits_le"))?;
 
-                let mut g_times_mul = PG::G1Gadget::zero(cs.ns(|| "g_times_mul_zero"))?;
+                let mut g_times_mul = Self::g1_accumulator_start(cs.ns(|| "g_times_mul_zero"))?;
                 {
                     for (i, (bit, base_power)) in g_multiplier_bits
                         .iter()
@@ -488,34 +513,33 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG>
-    PCCheckVar<<TargetCurve as PairingEngine>::Fr, MarlinKZG10<TargetCurve>, <BaseCurve as PairingEngine>::Fr>
-    for MarlinKZG10Gadget<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> PCCheckVar<<TargetCurve as PairingEngine>::Fr, MarlinKZG10<TargetCurve>, BaseField>
+    for MarlinKZG10Gadget<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
-    type BatchLCProofVar = BatchLCProofVar<TargetCurve, BaseCurve, PG>;
-    type CommitmentVar = CommitmentVar<TargetCurve, BaseCurve, PG>;
-    type LabeledCommitmentVar = LabeledCommitmentVar<TargetCurve, BaseCurve, PG>;
-    type PreparedCommitmentVar = PreparedCommitmentVar<TargetCurve, BaseCurve, PG>;
-    type PreparedLabeledCommitmentVar = PreparedLabeledCommitmentVar<TargetCurve, BaseCurve, PG>;
-    type PreparedVerifierKeyVar = PreparedVerifierKeyVar<TargetCurve, BaseCurve, PG>;
-    type ProofVar = ProofVar<TargetCurve, BaseCurve, PG>;
-    type VerifierKeyVar = VerifierKeyVar<TargetCurve, BaseCurve, PG>;
+    type BatchLCProofVar = BatchLCProofVar<TargetCurve, BaseField, PG>;
+    type CommitmentVar = CommitmentVar<TargetCurve, BaseField, PG>;
+    type LabeledCommitmentVar = LabeledCommitmentVar<TargetCurve, BaseField, PG>;
+    type PreparedCommitmentVar = PreparedCommitmentVar<TargetCurve, BaseField, PG>;
+    type PreparedLabeledCommitmentVar = PreparedLabeledCommitmentVar<TargetCurve, BaseField, PG>;
+    type PreparedVerifierKeyVar = PreparedVerifierKeyVar<TargetCurve, BaseField, PG>;
+    type ProofVar = ProofVar<TargetCurve, BaseField, PG>;
+    type VerifierKeyVar = VerifierKeyVar<TargetCurve, BaseField, PG>;
 
     #[allow(clippy::type_complexity)]
-    fn batch_check_evaluations<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    fn batch_check_evaluations<CS: ConstraintSystem<BaseField>>(
         mut cs: CS,
         verification_key: &Self::VerifierKeyVar,
         commitments: &[Self::LabeledCommitmentVar],
-        query_set: &QuerySetVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
-        evaluations: &EvaluationsVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+        query_set: &QuerySetVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
+        evaluations: &EvaluationsVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
         proofs: &[Self::ProofVar],
-        rand_data: &PCCheckRandomDataVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+        rand_data: &PCCheckRandomDataVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
     ) -> Result<Boolean, SynthesisError> {
         let mut batching_rands = rand_data.batching_rands.to_vec();
         let mut batching_rands_bits = rand_data.batching_rands_bits.to_vec();
@@ -524,7 +548,7 @@ where
         let mut query_to_labels_map: BTreeMap<
             <TargetCurve as PairingEngine>::Fr,
             (
-                NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+                NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
                 BTreeSet<&String>,
             ),
         > = BTreeMap::new();
@@ -567,13 +591,10 @@ where
             }
 
             // Accumulate the commitments and evaluations corresponding to `query`.
-            let mut combined_comm = PG::G1Gadget::zero(cs.ns(|| format!("comm_zero_{}", i)))?;
-            let mut combined_eval = NonNativeFieldMulResultVar::<
-                <TargetCurve as PairingEngine>::Fr,
-                <BaseCurve as PairingEngine>::Fr,
-            >::zero();
+            let mut combined_comm = Self::g1_accumulator_start(cs.ns(|| format!("comm_zero_{}", i)))?;
+            let mut combined_eval = NonNativeFieldMulResultVar::<<TargetCurve as PairingEngine>::Fr, BaseField>::zero();
 
-            let zero = PG::G1Gadget::zero(cs.ns(|| format!("g1_zero_{}", i)))?;
+            let zero = Self::g1_accumulator_start(cs.ns(|| format!("g1_zero_{}", i)))?;
 
             let mut opening_challenges_counter = 0;
 
@@ -655,15 +676,12 @@ where
 
         // Perform the batch check.
         {
-            let mut total_c = PG::G1Gadget::zero(cs.ns(|| "zero_c"))?;
-            let mut total_w = PG::G1Gadget::zero(cs.ns(|| "zero_w"))?;
+            let mut total_c = Self::g1_accumulator_start(cs.ns(|| "zero_c"))?;
+            let mut total_w = Self::g1_accumulator_start(cs.ns(|| "zero_w"))?;
 
-            let zero = PG::G1Gadget::zero(cs.ns(|| format!("batch_check_g1_zero")))?;
+            let zero = Self::g1_accumulator_start(cs.ns(|| format!("batch_check_g1_zero")))?;
 
-            let mut g_multiplier = NonNativeFieldMulResultVar::<
-                <TargetCurve as PairingEngine>::Fr,
-                <BaseCurve as PairingEngine>::Fr,
-            >::zero();
+            let mut g_multiplier = NonNativeFieldMulResultVar::<<TargetCurve as PairingEngine>::Fr, BaseField>::zero();
             for (i, (((c, z), v), proof)) in combined_comms
                 .iter()
                 .zip(combined_queries)
@@ -748,18 +766,15 @@ where
     }
 
     #[allow(clippy::type_complexity)]
-    fn prepared_check_combinations<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    fn prepared_check_combinations<CS: ConstraintSystem<BaseField>>(
         mut cs: CS,
         prepared_verification_key: &Self::PreparedVerifierKeyVar,
-        linear_combinations: &[LinearCombinationVar<
-            <TargetCurve as PairingEngine>::Fr,
-            <BaseCurve as PairingEngine>::Fr,
-        >],
+        linear_combinations: &[LinearCombinationVar<<TargetCurve as PairingEngine>::Fr, BaseField>],
         prepared_commitments: &[Self::PreparedLabeledCommitmentVar],
-        query_set: &QuerySetVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
-        evaluations: &EvaluationsVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+        query_set: &QuerySetVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
+        evaluations: &EvaluationsVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
         proof: &Self::BatchLCProofVar,
-        rand_data: &PCCheckRandomDataVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>,
+        rand_data: &PCCheckRandomDataVar<<TargetCurve as PairingEngine>::Fr, BaseField>,
     ) -> Result<Boolean, SynthesisError> {
         let BatchLCProofVar { proofs, .. } = proof;
 
@@ -847,7 +862,7 @@ where
     fn create_labeled_commitment(
         label: String,
         commitment: Self::CommitmentVar,
-        degree_bound: Option<FpGadget<<BaseCurve as PairingEngine>::Fr>>,
+        degree_bound: Option<FpGadget<BaseField>>,
     ) -> Self::LabeledCommitmentVar {
         Self::LabeledCommitmentVar {
             label,
@@ -859,7 +874,7 @@ where
     fn create_prepared_labeled_commitment(
         label: String,
         prepared_commitment: Self::PreparedCommitmentVar,
-        degree_bound: Option<FpGadget<<BaseCurve as PairingEngine>::Fr>>,
+        degree_bound: Option<FpGadget<BaseField>>,
     ) -> Self::PreparedLabeledCommitmentVar {
         Self::PreparedLabeledCommitmentVar {
             label,
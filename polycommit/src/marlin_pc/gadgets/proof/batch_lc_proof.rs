@@ -17,6 +17,7 @@
 use core::borrow::Borrow;
 
 use snarkvm_curves::PairingEngine;
+use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
     nonnative::NonNativeFieldVar,
     traits::{alloc::AllocGadget, curves::PairingGadget},
@@ -32,27 +33,24 @@ use crate::{
 
 /// Gadget for a `BatchLCProof`.
 #[allow(clippy::type_complexity)]
-pub struct BatchLCProofVar<
-    TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-> where
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+pub struct BatchLCProofVar<TargetCurve: PairingEngine, BaseField: PrimeField, PG: PairingGadget<TargetCurve, BaseField>>
+where
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     /// Evaluation proofs.
-    pub proofs: Vec<ProofVar<TargetCurve, BaseCurve, PG>>,
+    pub proofs: Vec<ProofVar<TargetCurve, BaseField, PG>>,
     /// Evaluations required to verify the proof.
-    pub evals: Option<Vec<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>>>,
+    pub evals: Option<Vec<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>>>,
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for BatchLCProofVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for BatchLCProofVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -62,22 +60,20 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG>
-    AllocGadget<
-        BatchLCProof<<TargetCurve as PairingEngine>::Fr, MarlinKZG10<TargetCurve>>,
-        <BaseCurve as PairingEngine>::Fr,
-    > for BatchLCProofVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG>
+    AllocGadget<BatchLCProof<<TargetCurve as PairingEngine>::Fr, MarlinKZG10<TargetCurve>>, BaseField>
+    for BatchLCProofVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn alloc_constant<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<BatchLCProof<<TargetCurve as PairingEngine>::Fr, MarlinKZG10<TargetCurve>>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -86,16 +82,15 @@ where
             let BatchLCProof { proof, evaluations } = proof.borrow().clone();
 
             let proofs: Vec<Proof<_>> = proof.to_vec();
-            let proofs: Vec<ProofVar<TargetCurve, BaseCurve, PG>> = proofs
+            let proofs: Vec<ProofVar<TargetCurve, BaseField, PG>> = proofs
                 .iter()
                 .enumerate()
                 .map(|(i, p)| ProofVar::alloc_constant(cs.ns(|| format!("proof_{}", i)), || Ok(p)).unwrap())
                 .collect();
 
             #[allow(clippy::type_complexity)]
-            let evals: Option<
-                Vec<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>>,
-            > = match evaluations {
+            let evals: Option<Vec<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>>> = match evaluations
+            {
                 None => None,
                 Some(evals_inner) => Some(
                     evals_inner
@@ -115,7 +110,7 @@ where
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<BatchLCProof<<TargetCurve as PairingEngine>::Fr, MarlinKZG10<TargetCurve>>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -124,16 +119,15 @@ where
             let BatchLCProof { proof, evaluations } = proof.borrow().clone();
 
             let proofs: Vec<Proof<_>> = proof.to_vec();
-            let proofs: Vec<ProofVar<TargetCurve, BaseCurve, PG>> = proofs
+            let proofs: Vec<ProofVar<TargetCurve, BaseField, PG>> = proofs
                 .iter()
                 .enumerate()
                 .map(|(i, p)| ProofVar::alloc(cs.ns(|| format!("proof_{}", i)), || Ok(p)).unwrap())
                 .collect();
 
             #[allow(clippy::type_complexity)]
-            let evals: Option<
-                Vec<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>>,
-            > = match evaluations {
+            let evals: Option<Vec<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>>> = match evaluations
+            {
                 None => None,
                 Some(evals_inner) => Some(
                     evals_inner
@@ -153,7 +147,7 @@ where
     fn alloc_input<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<BatchLCProof<<TargetCurve as PairingEngine>::Fr, MarlinKZG10<TargetCurve>>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -162,16 +156,15 @@ where
             let BatchLCProof { proof, evaluations } = proof.borrow().clone();
 
             let proofs: Vec<Proof<_>> = proof.to_vec();
-            let proofs: Vec<ProofVar<TargetCurve, BaseCurve, PG>> = proofs
+            let proofs: Vec<ProofVar<TargetCurve, BaseField, PG>> = proofs
                 .iter()
                 .enumerate()
                 .map(|(i, p)| ProofVar::alloc_input(cs.ns(|| format!("proof_{}", i)), || Ok(p)).unwrap())
                 .collect();
 
             #[allow(clippy::type_complexity)]
-            let evals: Option<
-                Vec<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>>,
-            > = match evaluations {
+            let evals: Option<Vec<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>>> = match evaluations
+            {
                 None => None,
                 Some(evals_inner) => Some(
                     evals_inner
@@ -194,7 +187,6 @@ mod tests {
     use snarkvm_algorithms::fft::DensePolynomial;
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fq, Fr},
-        bw6_761::BW6_761,
         AffineCurve,
     };
     use snarkvm_fields::One;
@@ -208,7 +200,7 @@ mod tests {
 
     type PC = MarlinKZG10<Bls12_377>;
     type PG = Bls12_377PairingGadget;
-    type BaseCurve = BW6_761;
+    type BaseField = Fq;
 
     const MAX_DEGREE: usize = 383;
     const SUPPORTED_DEGREE: usize = 300;
@@ -264,7 +256,7 @@ mod tests {
 
         // Construct batch lc proof gadget.
         let batch_lc_proof_gadget =
-            BatchLCProofVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_batch_lc_proof"), || Ok(batch_lc_proof.clone()))
+            BatchLCProofVar::<_, BaseField, PG>::alloc(cs.ns(|| "alloc_batch_lc_proof"), || Ok(batch_lc_proof.clone()))
                 .unwrap();
 
         // Check that the proofs in the batch proof are equivalent.
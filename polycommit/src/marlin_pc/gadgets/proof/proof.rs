@@ -17,6 +17,7 @@
 use core::borrow::Borrow;
 
 use snarkvm_curves::{AffineCurve, PairingEngine};
+use snarkvm_fields::PrimeField;
 use snarkvm_gadgets::{
     nonnative::NonNativeFieldVar,
     traits::{alloc::AllocGadget, curves::PairingGadget},
@@ -27,27 +28,24 @@ use crate::kzg10::Proof;
 
 /// Gadget for a Marlin-KZG10 proof.
 #[allow(clippy::type_complexity)]
-pub struct ProofVar<
-    TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-> where
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+pub struct ProofVar<TargetCurve: PairingEngine, BaseField: PrimeField, PG: PairingGadget<TargetCurve, BaseField>>
+where
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     /// The commitment to the witness polynomial.
     pub w: PG::G1Gadget,
     /// The evaluation of the random hiding polynomial.
-    pub random_v: Option<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, <BaseCurve as PairingEngine>::Fr>>,
+    pub random_v: Option<NonNativeFieldVar<<TargetCurve as PairingEngine>::Fr, BaseField>>,
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for ProofVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for ProofVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -57,19 +55,18 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> AllocGadget<Proof<TargetCurve>, <BaseCurve as PairingEngine>::Fr>
-    for ProofVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> AllocGadget<Proof<TargetCurve>, BaseField> for ProofVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn alloc_constant<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Proof<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -93,7 +90,7 @@ where
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Proof<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -116,7 +113,7 @@ where
     fn alloc_input<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<Proof<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -143,7 +140,6 @@ mod tests {
     use snarkvm_algorithms::fft::DensePolynomial;
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fq, Fr},
-        bw6_761::BW6_761,
         AffineCurve,
     };
     use snarkvm_gadgets::{curves::bls12_377::PairingGadget as Bls12_377PairingGadget, traits::eq::EqGadget};
@@ -156,7 +152,7 @@ mod tests {
 
     type PC = MarlinKZG10<Bls12_377>;
     type PG = Bls12_377PairingGadget;
-    type BaseCurve = BW6_761;
+    type BaseField = Fq;
 
     const MAX_DEGREE: usize = 383;
     const SUPPORTED_DEGREE: usize = 300;
@@ -197,7 +193,7 @@ mod tests {
         )
         .unwrap();
 
-        let proof_gadget = ProofVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_proof"), || Ok(proof)).unwrap();
+        let proof_gadget = ProofVar::<_, BaseField, PG>::alloc(cs.ns(|| "alloc_proof"), || Ok(proof)).unwrap();
 
         let expected_w_gadget =
             <PG as PairingGadget<_, _>>::G1Gadget::alloc(cs.ns(|| "proof_w"), || Ok(proof.w.into_projective()))
@@ -38,11 +38,11 @@ use crate::{
 #[allow(clippy::type_complexity)]
 pub struct PreparedVerifierKeyVar<
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
 > where
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     /// Generator of G1.
     pub prepared_g: Vec<PG::G1Gadget>,
@@ -51,27 +51,26 @@ pub struct PreparedVerifierKeyVar<
     /// Generator of G1, times first monomial.
     pub prepared_beta_h: PG::G2PreparedGadget,
     /// Used for the shift powers associated with different degree bounds.
-    pub prepared_degree_bounds_and_shift_powers:
-        Option<Vec<(usize, FpGadget<<BaseCurve as PairingEngine>::Fr>, Vec<PG::G1Gadget>)>>,
+    pub prepared_degree_bounds_and_shift_powers: Option<Vec<(usize, FpGadget<BaseField>, Vec<PG::G1Gadget>)>>,
     /// Indicate whether or not it is a constant allocation (which decides whether or not shift powers are precomputed)
     pub constant_allocation: bool,
     /// If not a constant allocation, the original vk is attached (for computing the shift power series)
-    pub origin_vk: Option<VerifierKeyVar<TargetCurve, BaseCurve, PG>>,
+    pub origin_vk: Option<VerifierKeyVar<TargetCurve, BaseField, PG>>,
 }
 
-impl<TargetCurve, BaseCurve, PG> PreparedVerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> PreparedVerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     /// Find the appropriate shift for the degree bound.
-    pub fn get_shift_power<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    pub fn get_shift_power<CS: ConstraintSystem<BaseField>>(
         &self,
         mut cs: CS,
-        bound: &FpGadget<<BaseCurve as PairingEngine>::Fr>,
+        bound: &FpGadget<BaseField>,
     ) -> Option<Vec<PG::G1Gadget>> {
         if self.constant_allocation {
             if self.prepared_degree_bounds_and_shift_powers.is_none() {
@@ -116,19 +115,18 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG>
-    PrepareGadget<VerifierKeyVar<TargetCurve, BaseCurve, PG>, <BaseCurve as PairingEngine>::Fr>
-    for PreparedVerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> PrepareGadget<VerifierKeyVar<TargetCurve, BaseField, PG>, BaseField>
+    for PreparedVerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
-    fn prepare<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    fn prepare<CS: ConstraintSystem<BaseField>>(
         mut cs: CS,
-        unprepared: &VerifierKeyVar<TargetCurve, BaseCurve, PG>,
+        unprepared: &VerifierKeyVar<TargetCurve, BaseField, PG>,
     ) -> Result<Self, SynthesisError> {
         let supported_bits = <<TargetCurve as PairingEngine>::Fr as PrimeField>::size_in_bits();
         let mut prepared_g = Vec::<PG::G1Gadget>::new();
@@ -143,7 +141,7 @@ where
         let prepared_beta_h = PG::prepare_g2(cs.ns(|| "prepared_beta_h"), unprepared.beta_h.clone())?;
 
         let prepared_degree_bounds_and_shift_powers = if unprepared.degree_bounds_and_shift_powers.is_some() {
-            let mut res = Vec::<(usize, FpGadget<<BaseCurve as PairingEngine>::Fr>, Vec<PG::G1Gadget>)>::new();
+            let mut res = Vec::<(usize, FpGadget<BaseField>, Vec<PG::G1Gadget>)>::new();
 
             for (d, d_gadget, shift_power) in unprepared.degree_bounds_and_shift_powers.as_ref().unwrap().iter() {
                 res.push((*d, (*d_gadget).clone(), vec![shift_power.clone()]));
@@ -165,13 +163,13 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for PreparedVerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for PreparedVerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -185,16 +183,16 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> Into<VerifierKeyVar<TargetCurve, BaseCurve, PG>>
-    for PreparedVerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Into<VerifierKeyVar<TargetCurve, BaseField, PG>>
+    for PreparedVerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
-    fn into(self) -> VerifierKeyVar<TargetCurve, BaseCurve, PG> {
+    fn into(self) -> VerifierKeyVar<TargetCurve, BaseField, PG> {
         match self.origin_vk {
             Some(vk) => vk.clone(),
             None => {
@@ -205,19 +203,19 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> AllocGadget<PreparedVerifierKey<TargetCurve>, <BaseCurve as PairingEngine>::Fr>
-    for PreparedVerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> AllocGadget<PreparedVerifierKey<TargetCurve>, BaseField>
+    for PreparedVerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
     fn alloc_constant<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<PreparedVerifierKey<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -228,7 +226,7 @@ where
         for (i, g) in obj.prepared_vk.prepared_g.iter().enumerate() {
             prepared_g.push(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc_constant(cs.ns(|| format!("g_{}", i)), || {
                 Ok(g.into_projective())
             })?);
@@ -239,7 +237,7 @@ where
             PG::G2PreparedGadget::alloc(cs.ns(|| "prepared_beta_h"), || Ok(&obj.prepared_vk.prepared_beta_h))?;
 
         let prepared_degree_bounds_and_shift_powers = if obj.prepared_degree_bounds_and_shift_powers.is_some() {
-            let mut res = Vec::<(usize, FpGadget<<BaseCurve as PairingEngine>::Fr>, Vec<PG::G1Gadget>)>::new();
+            let mut res = Vec::<(usize, FpGadget<BaseField>, Vec<PG::G1Gadget>)>::new();
 
             for (i, (d, shift_power_elems)) in obj
                 .prepared_degree_bounds_and_shift_powers
@@ -252,17 +250,17 @@ where
                 for (j, shift_power_elem) in shift_power_elems.iter().enumerate() {
                     gadgets.push(<PG::G1Gadget as AllocGadget<
                         <TargetCurve as PairingEngine>::G1Projective,
-                        <BaseCurve as PairingEngine>::Fr,
+                        BaseField,
                     >>::alloc_constant(
                         cs.ns(|| format!("alloc_constant_gadget_{}_{}", i, j)),
                         || Ok(shift_power_elem.into_projective()),
                     )?);
                 }
 
-                let d_gadget = FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc_constant(
-                    cs.ns(|| format!("alloc_constant_d_{}", i)),
-                    || Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(*d as u128)),
-                )?;
+                let d_gadget =
+                    FpGadget::<BaseField>::alloc_constant(cs.ns(|| format!("alloc_constant_d_{}", i)), || {
+                        Ok(<BaseField as From<u128>>::from(*d as u128))
+                    })?;
 
                 res.push((*d, d_gadget, gadgets));
             }
@@ -284,7 +282,7 @@ where
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<PreparedVerifierKey<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -295,7 +293,7 @@ where
         for (i, g) in obj.prepared_vk.prepared_g.iter().enumerate() {
             prepared_g.push(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc(cs.ns(|| format!("g_{}", i)), || {
                 Ok(g.into_projective())
             })?);
@@ -305,7 +303,7 @@ where
         let prepared_beta_h = PG::G2PreparedGadget::alloc(cs.ns(|| "beta_h"), || Ok(&obj.prepared_vk.prepared_beta_h))?;
 
         let prepared_degree_bounds_and_shift_powers = if obj.prepared_degree_bounds_and_shift_powers.is_some() {
-            let mut res = Vec::<(usize, FpGadget<<BaseCurve as PairingEngine>::Fr>, Vec<PG::G1Gadget>)>::new();
+            let mut res = Vec::<(usize, FpGadget<BaseField>, Vec<PG::G1Gadget>)>::new();
 
             for (i, (d, shift_power_elems)) in obj
                 .prepared_degree_bounds_and_shift_powers
@@ -318,17 +316,16 @@ where
                 for (j, shift_power_elem) in shift_power_elems.iter().enumerate() {
                     gadgets.push(<PG::G1Gadget as AllocGadget<
                         <TargetCurve as PairingEngine>::G1Projective,
-                        <BaseCurve as PairingEngine>::Fr,
+                        BaseField,
                     >>::alloc(
                         cs.ns(|| format!("alloc_gadget_{}_{}", i, j)),
                         || Ok(shift_power_elem.into_projective()),
                     )?);
                 }
 
-                let d_gadget =
-                    FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc(cs.ns(|| format!("alloc_d_{}", i)), || {
-                        Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(*d as u128))
-                    })?;
+                let d_gadget = FpGadget::<BaseField>::alloc(cs.ns(|| format!("alloc_d_{}", i)), || {
+                    Ok(<BaseField as From<u128>>::from(*d as u128))
+                })?;
 
                 res.push((*d, d_gadget, gadgets));
             }
@@ -350,7 +347,7 @@ where
     fn alloc_input<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<PreparedVerifierKey<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -361,7 +358,7 @@ where
         for (i, g) in obj.prepared_vk.prepared_g.iter().enumerate() {
             prepared_g.push(<PG::G1Gadget as AllocGadget<
                 <TargetCurve as PairingEngine>::G1Projective,
-                <BaseCurve as PairingEngine>::Fr,
+                BaseField,
             >>::alloc_input(cs.ns(|| format!("g_{}", i)), || {
                 Ok(g.into_projective())
             })?);
@@ -372,7 +369,7 @@ where
             PG::G2PreparedGadget::alloc_input(cs.ns(|| "beta_h"), || Ok(&obj.prepared_vk.prepared_beta_h))?;
 
         let prepared_degree_bounds_and_shift_powers = if obj.prepared_degree_bounds_and_shift_powers.is_some() {
-            let mut res = Vec::<(usize, FpGadget<<BaseCurve as PairingEngine>::Fr>, Vec<PG::G1Gadget>)>::new();
+            let mut res = Vec::<(usize, FpGadget<BaseField>, Vec<PG::G1Gadget>)>::new();
 
             for (i, (d, shift_power_elems)) in obj
                 .prepared_degree_bounds_and_shift_powers
@@ -385,17 +382,16 @@ where
                 for (j, shift_power_elem) in shift_power_elems.iter().enumerate() {
                     gadgets.push(<PG::G1Gadget as AllocGadget<
                         <TargetCurve as PairingEngine>::G1Projective,
-                        <BaseCurve as PairingEngine>::Fr,
+                        BaseField,
                     >>::alloc_input(
                         cs.ns(|| format!("alloc_input_gadget_{}_{}", i, j)),
                         || Ok(shift_power_elem.into_projective()),
                     )?);
                 }
 
-                let d_gadget = FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc_input(
-                    cs.ns(|| format!("alloc_input_d_{}", i)),
-                    || Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(*d as u128)),
-                )?;
+                let d_gadget = FpGadget::<BaseField>::alloc_input(cs.ns(|| format!("alloc_input_d_{}", i)), || {
+                    Ok(<BaseField as From<u128>>::from(*d as u128))
+                })?;
 
                 res.push((*d, d_gadget, gadgets));
             }
@@ -419,7 +415,6 @@ where
 mod tests {
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fq},
-        bw6_761::BW6_761,
         ProjectiveCurve,
     };
     use snarkvm_gadgets::{curves::bls12_377::PairingGadget as Bls12_377PairingGadget, traits::eq::EqGadget};
@@ -432,7 +427,7 @@ mod tests {
 
     type PC = MarlinKZG10<Bls12_377>;
     type PG = Bls12_377PairingGadget;
-    type BaseCurve = BW6_761;
+    type BaseField = Fq;
 
     const MAX_DEGREE: usize = 383;
     const SUPPORTED_DEGREE: usize = 300;
@@ -455,7 +450,7 @@ mod tests {
         let prepared_vk = PreparedVerifierKey::prepare(&vk);
 
         // Allocate the prepared vk gadget.
-        let prepared_vk_gadget = PreparedVerifierKeyVar::<_, BaseCurve, PG>::alloc(
+        let prepared_vk_gadget = PreparedVerifierKeyVar::<_, BaseField, PG>::alloc(
             cs.ns(|| "alloc_prepared_vk"),
             || Ok(prepared_vk.clone()),
         )
@@ -553,11 +548,11 @@ mod tests {
         let (_committer_key, vk) = PC::trim(&pp, SUPPORTED_DEGREE, SUPPORTED_HIDING_BOUND, None).unwrap();
 
         // Allocate the vk gadget.
-        let vk_gadget = VerifierKeyVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
+        let vk_gadget = VerifierKeyVar::<_, BaseField, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
 
         // Allocate the prepared vk gadget.
         let prepared_vk = PreparedVerifierKey::prepare(&vk);
-        let expected_prepared_vk_gadget = PreparedVerifierKeyVar::<_, BaseCurve, PG>::alloc(
+        let expected_prepared_vk_gadget = PreparedVerifierKeyVar::<_, BaseField, PG>::alloc(
             cs.ns(|| "alloc_prepared_vk"),
             || Ok(prepared_vk.clone()),
         )
@@ -36,11 +36,8 @@ use crate::{kzg10::VerifierKey as KZG10VerifierKey, marlin_pc::data_structures::
 
 /// Var for the verification key of the Marlin-KZG10 polynomial commitment scheme.
 #[allow(clippy::type_complexity)]
-pub struct VerifierKeyVar<
-    TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-> {
+pub struct VerifierKeyVar<TargetCurve: PairingEngine, BaseField: PrimeField, PG: PairingGadget<TargetCurve, BaseField>>
+{
     /// Generator of G1.
     pub g: PG::G1Gadget,
     /// Generator of G2.
@@ -48,20 +45,20 @@ pub struct VerifierKeyVar<
     /// Generator of G1, times first monomial.
     pub beta_h: PG::G2Gadget,
     /// Used for the shift powers associated with different degree bounds.
-    pub degree_bounds_and_shift_powers: Option<Vec<(usize, FpGadget<<BaseCurve as PairingEngine>::Fr>, PG::G1Gadget)>>,
+    pub degree_bounds_and_shift_powers: Option<Vec<(usize, FpGadget<BaseField>, PG::G1Gadget)>>,
 }
 
-impl<TargetCurve, BaseCurve, PG> VerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> VerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
 {
     /// Find the appropriate shift for the degree bound.
-    pub fn get_shift_power<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    pub fn get_shift_power<CS: ConstraintSystem<BaseField>>(
         &self,
         mut cs: CS,
-        bound: &FpGadget<<BaseCurve as PairingEngine>::Fr>,
+        bound: &FpGadget<BaseField>,
     ) -> Option<PG::G1Gadget> {
         // Search the bound using PIR
         if self.degree_bounds_and_shift_powers.is_none() {
@@ -86,22 +83,19 @@ where
             }
 
             // Sum of the PIR values are equal to one
-            let mut sum = FpGadget::<<BaseCurve as PairingEngine>::Fr>::zero(cs.ns(|| "zero")).unwrap();
-            let one = FpGadget::<<BaseCurve as PairingEngine>::Fr>::one(cs.ns(|| "one")).unwrap();
+            let mut sum = FpGadget::<BaseField>::zero(cs.ns(|| "zero")).unwrap();
+            let one = FpGadget::<BaseField>::one(cs.ns(|| "one")).unwrap();
             for (i, pir_gadget) in pir_vector_gadgets.iter().enumerate() {
-                let temp = FpGadget::<<BaseCurve as PairingEngine>::Fr>::from_boolean(
-                    cs.ns(|| format!("from_boolean_{}", i)),
-                    pir_gadget.clone(),
-                )
-                .unwrap();
+                let temp =
+                    FpGadget::<BaseField>::from_boolean(cs.ns(|| format!("from_boolean_{}", i)), pir_gadget.clone())
+                        .unwrap();
 
                 sum = sum.add(cs.ns(|| format!("sum_add_pir{}", i)), &temp).unwrap();
             }
             sum.enforce_equal(cs.ns(|| "sum_enforce_equal"), &one).unwrap();
 
             // PIR the value
-            let mut found_bound =
-                FpGadget::<<BaseCurve as PairingEngine>::Fr>::zero(cs.ns(|| "found_bound_zero")).unwrap();
+            let mut found_bound = FpGadget::<BaseField>::zero(cs.ns(|| "found_bound_zero")).unwrap();
 
             let mut found_shift_power = PG::G1Gadget::zero(cs.ns(|| "found_shift_power_zero")).unwrap();
 
@@ -110,7 +104,7 @@ where
                 .zip(degree_bounds_and_shift_powers.iter())
                 .enumerate()
             {
-                found_bound = FpGadget::<<BaseCurve as PairingEngine>::Fr>::conditionally_select(
+                found_bound = FpGadget::<BaseField>::conditionally_select(
                     cs.ns(|| format!("found_bound_coond_select{}", i)),
                     pir_gadget,
                     degree,
@@ -136,11 +130,11 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> Clone for VerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> Clone for VerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
 {
     fn clone(&self) -> Self {
         Self {
@@ -152,17 +146,17 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> AllocGadget<VerifierKey<TargetCurve>, <BaseCurve as PairingEngine>::Fr>
-    for VerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> AllocGadget<VerifierKey<TargetCurve>, BaseField>
+    for VerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
 {
     fn alloc_constant<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<VerifierKey<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -181,10 +175,9 @@ where
                 .map(|(i, (s, g))| {
                     (
                         *s,
-                        FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc_constant(
-                            cs.ns(|| format!("degree bound_{}", i)),
-                            || Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(*s as u128)),
-                        )
+                        FpGadget::<BaseField>::alloc_constant(cs.ns(|| format!("degree bound_{}", i)), || {
+                            Ok(<BaseField as From<u128>>::from(*s as u128))
+                        })
                         .unwrap(),
                         PG::G1Gadget::alloc_constant(cs.ns(|| format!("pow_{}", i)), || Ok(g.into_projective()))
                             .unwrap(),
@@ -210,7 +203,7 @@ where
     fn alloc<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<VerifierKey<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -229,10 +222,9 @@ where
                 .map(|(i, (s, g))| {
                     (
                         *s,
-                        FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc(
-                            cs.ns(|| format!("degree bound_{}", i)),
-                            || Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(*s as u128)),
-                        )
+                        FpGadget::<BaseField>::alloc(cs.ns(|| format!("degree bound_{}", i)), || {
+                            Ok(<BaseField as From<u128>>::from(*s as u128))
+                        })
                         .unwrap(),
                         PG::G1Gadget::alloc(cs.ns(|| format!("pow_{}", i)), || Ok(g.into_projective())).unwrap(),
                     )
@@ -257,7 +249,7 @@ where
     fn alloc_input<
         Fn: FnOnce() -> Result<T, SynthesisError>,
         T: Borrow<VerifierKey<TargetCurve>>,
-        CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>,
+        CS: ConstraintSystem<BaseField>,
     >(
         mut cs: CS,
         value_gen: Fn,
@@ -276,10 +268,9 @@ where
                 .map(|(i, (s, g))| {
                     (
                         *s,
-                        FpGadget::<<BaseCurve as PairingEngine>::Fr>::alloc_input(
-                            cs.ns(|| format!("degree bound_{}", i)),
-                            || Ok(<<BaseCurve as PairingEngine>::Fr as From<u128>>::from(*s as u128)),
-                        )
+                        FpGadget::<BaseField>::alloc_input(cs.ns(|| format!("degree bound_{}", i)), || {
+                            Ok(<BaseField as From<u128>>::from(*s as u128))
+                        })
                         .unwrap(),
                         PG::G1Gadget::alloc_input(cs.ns(|| format!("pow_{}", i)), || Ok(g.into_projective())).unwrap(),
                     )
@@ -302,17 +293,13 @@ where
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> ToBytesGadget<<BaseCurve as PairingEngine>::Fr>
-    for VerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> ToBytesGadget<BaseField> for VerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
 {
-    fn to_bytes<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
-        &self,
-        mut cs: CS,
-    ) -> Result<Vec<UInt8>, SynthesisError> {
+    fn to_bytes<CS: ConstraintSystem<BaseField>>(&self, mut cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
         let mut bytes = Vec::new();
 
         bytes.extend_from_slice(&self.g.to_bytes(cs.ns(|| "g_to_bytes"))?);
@@ -330,30 +317,26 @@ where
         Ok(bytes)
     }
 
-    fn to_bytes_strict<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
-        &self,
-        cs: CS,
-    ) -> Result<Vec<UInt8>, SynthesisError> {
+    fn to_bytes_strict<CS: ConstraintSystem<BaseField>>(&self, cs: CS) -> Result<Vec<UInt8>, SynthesisError> {
         self.to_bytes(cs)
     }
 }
 
-impl<TargetCurve, BaseCurve, PG> ToConstraintFieldGadget<<BaseCurve as PairingEngine>::Fr>
-    for VerifierKeyVar<TargetCurve, BaseCurve, PG>
+impl<TargetCurve, BaseField, PG> ToConstraintFieldGadget<BaseField> for VerifierKeyVar<TargetCurve, BaseField, PG>
 where
     TargetCurve: PairingEngine,
-    BaseCurve: PairingEngine,
-    <BaseCurve as PairingEngine>::Fr: PrimeField,
-    PG: PairingGadget<TargetCurve, <BaseCurve as PairingEngine>::Fr>,
-    PG::G1Gadget: ToConstraintFieldGadget<<BaseCurve as PairingEngine>::Fr>,
-    PG::G2Gadget: ToConstraintFieldGadget<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
-    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<<BaseCurve as PairingEngine>::Fr>,
+    BaseField: PrimeField,
+    BaseField: PrimeField,
+    PG: PairingGadget<TargetCurve, BaseField>,
+    PG::G1Gadget: ToConstraintFieldGadget<BaseField>,
+    PG::G2Gadget: ToConstraintFieldGadget<BaseField>,
+    <TargetCurve as PairingEngine>::G1Affine: ToConstraintField<BaseField>,
+    <TargetCurve as PairingEngine>::G2Affine: ToConstraintField<BaseField>,
 {
-    fn to_constraint_field<CS: ConstraintSystem<<BaseCurve as PairingEngine>::Fr>>(
+    fn to_constraint_field<CS: ConstraintSystem<BaseField>>(
         &self,
         mut cs: CS,
-    ) -> Result<Vec<FpGadget<<BaseCurve as PairingEngine>::Fr>>, SynthesisError> {
+    ) -> Result<Vec<FpGadget<BaseField>>, SynthesisError> {
         let mut res = Vec::new();
 
         let mut g_gadget = self.g.to_constraint_field(cs.ns(|| "g_to_constraint_field"))?;
@@ -388,7 +371,6 @@ mod tests {
 
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fq},
-        bw6_761::BW6_761,
         ProjectiveCurve,
     };
     use snarkvm_fields::PrimeField;
@@ -402,7 +384,7 @@ mod tests {
 
     type PC = MarlinKZG10<Bls12_377>;
     type PG = Bls12_377PairingGadget;
-    type BaseCurve = BW6_761;
+    type BaseField = Fq;
 
     const MAX_DEGREE: usize = 383;
     const SUPPORTED_DEGREE: usize = 300;
@@ -423,7 +405,7 @@ mod tests {
         let (_committer_key, vk) = PC::trim(&pp, SUPPORTED_DEGREE, SUPPORTED_HIDING_BOUND, None).unwrap();
 
         // Allocate the vk gadget.
-        let vk_gadget = VerifierKeyVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
+        let vk_gadget = VerifierKeyVar::<_, BaseField, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
 
         // Naive value comparison.
         assert_eq!(vk.vk.g, vk_gadget.g.get_value().unwrap().into_affine());
@@ -497,17 +479,14 @@ mod tests {
         // Establish the bound
         let bound = rng.gen_range(1..=SUPPORTED_DEGREE);
 
-        let bound_field = <BaseCurve as PairingEngine>::Fr::from_repr(
-            <<BaseCurve as PairingEngine>::Fr as PrimeField>::BigInteger::from(bound as u64),
-        )
-        .unwrap();
+        let bound_field = BaseField::from_repr(<BaseField as PrimeField>::BigInteger::from(bound as u64)).unwrap();
         let bound_gadget = FpGadget::alloc(cs.ns(|| "alloc_bound"), || Ok(bound_field)).unwrap();
 
         // Construct the verifying key.
         let (_committer_key, vk) = PC::trim(&pp, SUPPORTED_DEGREE, SUPPORTED_HIDING_BOUND, Some(&vec![bound])).unwrap();
 
         // Allocate the vk gadget.
-        let vk_gadget = VerifierKeyVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
+        let vk_gadget = VerifierKeyVar::<_, BaseField, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
 
         // Fetch the shift power
         let shift_power = vk.get_shift_power(bound).unwrap();
@@ -541,7 +520,7 @@ mod tests {
     //     let (_committer_key, vk) = PC::trim(&pp, SUPPORTED_DEGREE, SUPPORTED_HIDING_BOUND, None).unwrap();
     //
     //     // Allocate the vk gadget.
-    //     let vk_gadget = VerifierKeyVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
+    //     let vk_gadget = VerifierKeyVar::<_, BaseField, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
     //     let _vk_gadget_bytes = vk_gadget.to_bytes(cs.ns(|| "to_bytes")).unwrap();
     //
     //     // TODO (raychu86): Compare with native impl. The native impl serializes the prepared elements as well which the gadgets do not include.
@@ -560,7 +539,7 @@ mod tests {
     // let (_committer_key, vk) = PC::trim(&pp, SUPPORTED_DEGREE, SUPPORTED_HIDING_BOUND, None).unwrap();
     //
     // // Allocate the vk gadget.
-    // let vk_gadget = VerifierKeyVar::<_, BaseCurve, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
+    // let vk_gadget = VerifierKeyVar::<_, BaseField, PG>::alloc(cs.ns(|| "alloc_vk"), || Ok(vk.clone())).unwrap();
     //
     // let vk_field_elements = vk.to_field_elements().unwrap();
     // let vk_field_gadgets = vk_gadget.to_constraint_field().unwrap();
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_algorithms::errors::SNARKError;
+use snarkvm_algorithms::errors::{EnvelopeError, SNARKError};
 use snarkvm_fields::ConstraintFieldError;
 use snarkvm_parameters::errors::ParameterError;
 
@@ -36,6 +36,10 @@ pub enum PoswError {
     #[error(transparent)]
     SnarkError(#[from] SNARKError),
 
+    /// Thrown when the stored keys do not match the SNARK
+    #[error(transparent)]
+    EnvelopeError(#[from] EnvelopeError),
+
     /// Thrown when there's an IO error
     #[error(transparent)]
     IoError(#[from] IoError),
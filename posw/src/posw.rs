@@ -97,7 +97,7 @@ where
     /// Loads the PoSW runner from the locally stored parameters.
     pub fn verify_only() -> Result<Self, PoswError> {
         let params = PoswSNARKVKParameters::load_bytes()?;
-        let vk = S::VerifyingKey::read(&params[..])?;
+        let vk = S::read_verifying_key(&params[..])?;
 
         Ok(Self {
             pk: None,
@@ -108,8 +108,9 @@ where
 
     /// Loads the PoSW runner from the locally stored parameters.
     pub fn load() -> Result<Self, PoswError> {
-        let vk = S::VerifyingKey::read(&PoswSNARKVKParameters::load_bytes()?[..])?;
-        let pk = S::ProvingKey::read(&PoswSNARKPKParameters::load_bytes()?[..])?;
+        let vk = S::read_verifying_key(&PoswSNARKVKParameters::load_bytes()?[..])?;
+        // The proving key is trusted, as the checksum of its bytes was verified when loading them.
+        let pk = S::read_proving_key_unchecked(&PoswSNARKPKParameters::load_bytes()?[..])?;
 
         Ok(Self {
             pk: Some(pk),
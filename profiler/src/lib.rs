@@ -17,6 +17,9 @@
 #![allow(unused_imports)]
 pub use inner::*;
 
+mod memory;
+pub use memory::*;
+
 #[cfg(feature = "print-trace")]
 #[macro_use]
 pub mod inner {
@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+/// The maximum number of memory scopes that can be active at the same time.
+const MAX_MEMORY_SCOPES: usize = 64;
+
+static CURRENT_MEMORY: AtomicUsize = AtomicUsize::new(0);
+static PEAK_MEMORY: AtomicUsize = AtomicUsize::new(0);
+
+/// The bitmask of the slots in `SCOPE_PEAK_MEMORY` that are claimed by an active [`MemoryScope`].
+static ACTIVE_SCOPES: AtomicU64 = AtomicU64::new(0);
+#[allow(clippy::declare_interior_mutable_const)]
+const SCOPE_SLOT: AtomicUsize = AtomicUsize::new(0);
+static SCOPE_PEAK_MEMORY: [AtomicUsize; MAX_MEMORY_SCOPES] = [SCOPE_SLOT; MAX_MEMORY_SCOPES];
+
+/// A global allocator that tracks the current and peak heap usage of the process.
+///
+/// Memory tracking is opt-in. To enable it, install the allocator in the binary:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: snarkvm_profiler::TrackingAllocator = snarkvm_profiler::TrackingAllocator;
+/// ```
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+    fn record_alloc(size: usize) {
+        let current = CURRENT_MEMORY.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_MEMORY.fetch_max(current, Ordering::Relaxed);
+
+        let mut active = ACTIVE_SCOPES.load(Ordering::Relaxed);
+        while active != 0 {
+            SCOPE_PEAK_MEMORY[active.trailing_zeros() as usize].fetch_max(current, Ordering::Relaxed);
+            active &= active - 1;
+        }
+    }
+
+    fn record_dealloc(size: usize) {
+        CURRENT_MEMORY.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Returns `true` if the [`TrackingAllocator`] is installed as the global allocator.
+pub fn is_memory_tracked() -> bool {
+    // The peak only moves once the tracking allocator has served an allocation.
+    PEAK_MEMORY.load(Ordering::Relaxed) > 0
+}
+
+/// Returns the number of bytes currently allocated on the heap.
+pub fn current_memory() -> usize {
+    CURRENT_MEMORY.load(Ordering::Relaxed)
+}
+
+/// Returns the largest number of bytes allocated on the heap over the lifetime of the process.
+pub fn peak_memory() -> usize {
+    PEAK_MEMORY.load(Ordering::Relaxed)
+}
+
+/// Returns a description of the peak memory of the process for inclusion in a trace,
+/// or an empty string if memory is not tracked.
+pub fn peak_memory_info() -> String {
+    memory_info(peak_memory())
+}
+
+/// Tracks the peak heap usage of the process from its creation until it is dropped.
+///
+/// Unlike [`peak_memory`], a scope does not observe the allocations made before it was created,
+/// and any number of scopes can be active at the same time without resetting each other's peak.
+/// The heap usage is still that of the whole process, so the allocations of all threads,
+/// including those of other active scopes, count towards the peak of every active scope.
+/// If more than `MAX_MEMORY_SCOPES` scopes are active, the additional scopes fall back to the
+/// peak memory of the process.
+pub struct MemoryScope {
+    slot: Option<usize>,
+}
+
+impl MemoryScope {
+    /// Starts tracking the peak heap usage from the current heap usage.
+    pub fn new() -> Self {
+        let mut active = ACTIVE_SCOPES.load(Ordering::Relaxed);
+        while active != u64::MAX {
+            let slot = (!active).trailing_zeros() as usize;
+            let claimed = active | (1 << slot);
+            match ACTIVE_SCOPES.compare_exchange_weak(active, claimed, Ordering::AcqRel, Ordering::Relaxed) {
+                Ok(_) => {
+                    SCOPE_PEAK_MEMORY[slot].store(current_memory(), Ordering::Relaxed);
+                    return Self { slot: Some(slot) };
+                }
+                Err(updated) => active = updated,
+            }
+        }
+        Self { slot: None }
+    }
+
+    /// Returns the largest number of bytes allocated on the heap since the scope was created.
+    pub fn peak(&self) -> usize {
+        match self.slot {
+            Some(slot) => SCOPE_PEAK_MEMORY[slot].load(Ordering::Relaxed),
+            None => peak_memory(),
+        }
+    }
+
+    /// Returns a description of the peak memory of the scope for inclusion in a trace,
+    /// or an empty string if memory is not tracked.
+    pub fn info(&self) -> String {
+        memory_info(self.peak())
+    }
+}
+
+impl Default for MemoryScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for MemoryScope {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot {
+            ACTIVE_SCOPES.fetch_and(!(1 << slot), Ordering::AcqRel);
+        }
+    }
+}
+
+fn memory_info(peak: usize) -> String {
+    if !is_memory_tracked() {
+        return String::new();
+    }
+
+    let peak = peak as f64;
+    match peak {
+        peak if peak >= (1u64 << 30) as f64 => format!("(peak memory {:.2} GiB)", peak / (1u64 << 30) as f64),
+        peak if peak >= (1u64 << 20) as f64 => format!("(peak memory {:.2} MiB)", peak / (1u64 << 20) as f64),
+        peak if peak >= (1u64 << 10) as f64 => format!("(peak memory {:.2} KiB)", peak / (1u64 << 10) as f64),
+        peak => format!("(peak memory {} B)", peak),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+    #[test]
+    fn test_peak_memory() {
+        assert!(is_memory_tracked());
+
+        let before = peak_memory();
+        let buffer = vec![0u8; 1 << 24];
+        assert!(peak_memory() >= before.max(buffer.len()));
+        drop(buffer);
+
+        assert!(peak_memory() >= 1 << 24);
+        assert!(peak_memory_info().contains("MiB"));
+    }
+
+    #[test]
+    fn test_memory_scope() {
+        let outer = MemoryScope::new();
+        let buffer = vec![0u8; 1 << 24];
+        drop(buffer);
+
+        // A nested scope only observes the allocations made after its creation.
+        let inner = MemoryScope::new();
+        assert!(outer.peak() >= 1 << 24);
+        assert!(inner.peak() <= outer.peak());
+
+        let buffer = vec![0u8; 1 << 25];
+        assert!(inner.peak() >= buffer.len());
+        assert!(outer.peak() >= inner.peak());
+        drop(buffer);
+        drop(inner);
+
+        // Releasing the nested scope does not reset the peak of the outer scope.
+        assert!(outer.peak() >= 1 << 25);
+        assert!(outer.info().contains("MiB"));
+    }
+}
@@ -0,0 +1,422 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use snarkvm_fields::Field;
+
+use fxhash::FxBuildHasher;
+use indexmap::IndexMap;
+
+use std::{
+    fmt::Write,
+    ops::{Add, AddAssign},
+};
+
+/// The name given to the root namespace of a profile.
+const ROOT_NAMESPACE: &str = "root";
+
+/// The costs attributed to a namespace.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProfileCounts {
+    pub num_constraints: usize,
+    pub num_public_variables: usize,
+    pub num_private_variables: usize,
+    /// The number of nonzero entries in the `A`, `B` and `C` matrices.
+    pub num_nonzero_entries: usize,
+}
+
+impl ProfileCounts {
+    /// Returns the value of the given metric.
+    pub fn get(&self, metric: ProfileMetric) -> usize {
+        match metric {
+            ProfileMetric::Constraints => self.num_constraints,
+            ProfileMetric::Variables => self.num_public_variables + self.num_private_variables,
+            ProfileMetric::NonZeroEntries => self.num_nonzero_entries,
+        }
+    }
+}
+
+impl Add for ProfileCounts {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for ProfileCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.num_constraints += other.num_constraints;
+        self.num_public_variables += other.num_public_variables;
+        self.num_private_variables += other.num_private_variables;
+        self.num_nonzero_entries += other.num_nonzero_entries;
+    }
+}
+
+/// The metric used to weight the stacks of a folded stack export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProfileMetric {
+    Constraints,
+    Variables,
+    NonZeroEntries,
+}
+
+/// A namespace in a constraint profile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileNode {
+    /// The name of the namespace.
+    pub name: String,
+    /// The costs incurred directly in this namespace.
+    pub own: ProfileCounts,
+    /// The costs incurred in this namespace and all of its descendants.
+    pub total: ProfileCounts,
+    /// The child namespaces, in the order they were first entered.
+    pub children: Vec<ProfileNode>,
+}
+
+impl ProfileNode {
+    /// Returns the descendant at the given `/`-separated path, relative to this node.
+    pub fn find(&self, path: &str) -> Option<&ProfileNode> {
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .try_fold(self, |node, segment| {
+                node.children.iter().find(|child| child.name == segment)
+            })
+    }
+
+    /// Returns the profile as a JSON object.
+    pub fn to_json(&self) -> String {
+        let mut output = String::new();
+        self.write_json(&mut output);
+        output
+    }
+
+    /// Returns the profile as folded stacks, one `a;b;c <weight>` line per namespace
+    /// with a nonzero weight, as consumed by `flamegraph.pl` and `inferno-flamegraph`.
+    pub fn to_folded_stacks(&self, metric: ProfileMetric) -> String {
+        let mut output = String::new();
+        self.write_folded_stacks(&mut output, &mut Vec::new(), metric);
+        output
+    }
+
+    fn write_json(&self, output: &mut String) {
+        output.push_str("{\"name\":");
+        write_json_string(output, &self.name);
+        output.push_str(",\"own\":");
+        write_json_counts(output, &self.own);
+        output.push_str(",\"total\":");
+        write_json_counts(output, &self.total);
+        output.push_str(",\"children\":[");
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 {
+                output.push(',');
+            }
+            child.write_json(output);
+        }
+        output.push_str("]}");
+    }
+
+    fn write_folded_stacks<'a>(&'a self, output: &mut String, stack: &mut Vec<&'a str>, metric: ProfileMetric) {
+        stack.push(&self.name);
+
+        let weight = self.own.get(metric);
+        if weight > 0 {
+            // Semicolons separate frames, so they may not appear inside a frame name.
+            let frames: Vec<String> = stack.iter().map(|frame| frame.replace(';', ":")).collect();
+            writeln!(output, "{} {}", frames.join(";"), weight).unwrap();
+        }
+
+        for child in &self.children {
+            child.write_folded_stacks(output, stack, metric);
+        }
+
+        stack.pop();
+    }
+}
+
+fn write_json_counts(output: &mut String, counts: &ProfileCounts) {
+    write!(
+        output,
+        "{{\"constraints\":{},\"public_variables\":{},\"private_variables\":{},\"nonzero_entries\":{}}}",
+        counts.num_constraints, counts.num_public_variables, counts.num_private_variables, counts.num_nonzero_entries
+    )
+    .unwrap();
+}
+
+fn write_json_string(output: &mut String, string: &str) {
+    output.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    counts: ProfileCounts,
+    children: IndexMap<String, usize, FxBuildHasher>,
+}
+
+impl Node {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            counts: ProfileCounts::default(),
+            children: IndexMap::with_hasher(FxBuildHasher::default()),
+        }
+    }
+}
+
+/// Constraint system that attributes constraints, variables and nonzero
+/// matrix entries to the namespaces they are created in.
+///
+/// Sibling namespaces sharing a name are merged into a single node.
+#[derive(Debug)]
+pub struct ConstraintProfiler {
+    nodes: Vec<Node>,
+    // the indices of the nodes on the path to the current namespace
+    current_namespace: Vec<usize>,
+    total: ProfileCounts,
+}
+
+impl Default for ConstraintProfiler {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node::new(ROOT_NAMESPACE.to_owned())],
+            current_namespace: vec![0],
+            total: ProfileCounts::default(),
+        }
+    }
+}
+
+impl ConstraintProfiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the profile of everything synthesized so far.
+    pub fn profile(&self) -> ProfileNode {
+        self.build_node(0)
+    }
+
+    fn build_node(&self, idx: usize) -> ProfileNode {
+        let node = &self.nodes[idx];
+        let children: Vec<ProfileNode> = node.children.values().map(|&child| self.build_node(child)).collect();
+        let total = children.iter().fold(node.counts, |total, child| total + child.total);
+
+        ProfileNode {
+            name: node.name.clone(),
+            own: node.counts,
+            total,
+            children,
+        }
+    }
+
+    #[inline]
+    fn current_counts(&mut self) -> &mut ProfileCounts {
+        let idx = *self.current_namespace.last().unwrap();
+        &mut self.nodes[idx].counts
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for ConstraintProfiler {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let var = Variable::new_unchecked(Index::Private(self.total.num_private_variables));
+        self.total.num_private_variables += 1;
+        self.current_counts().num_private_variables += 1;
+
+        Ok(var)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _: A, _: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let var = Variable::new_unchecked(Index::Public(self.total.num_public_variables));
+        self.total.num_public_variables += 1;
+        self.current_counts().num_public_variables += 1;
+
+        Ok(var)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let num_nonzero_entries = a(LinearCombination::zero()).0.len()
+            + b(LinearCombination::zero()).0.len()
+            + c(LinearCombination::zero()).0.len();
+
+        self.total.num_constraints += 1;
+        self.total.num_nonzero_entries += num_nonzero_entries;
+
+        let counts = self.current_counts();
+        counts.num_constraints += 1;
+        counts.num_nonzero_entries += num_nonzero_entries;
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        let name = name_fn().as_ref().to_owned();
+        let parent = *self.current_namespace.last().unwrap();
+
+        let idx = match self.nodes[parent].children.get(&name) {
+            Some(&idx) => idx,
+            None => {
+                let idx = self.nodes.len();
+                self.nodes.push(Node::new(name.clone()));
+                self.nodes[parent].children.insert(name, idx);
+                idx
+            }
+        };
+
+        self.current_namespace.push(idx);
+    }
+
+    fn pop_namespace(&mut self) {
+        assert!(self.current_namespace.len() > 1, "cannot pop the root namespace");
+        self.current_namespace.pop();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.total.num_constraints
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.total.num_public_variables
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.total.num_private_variables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Fr;
+    use snarkvm_fields::One;
+
+    fn synthesize<CS: ConstraintSystem<Fr>>(cs: &mut CS) {
+        let a = cs.alloc_input(|| "a", || Ok(Fr::one())).unwrap();
+        for i in 0..2 {
+            let mut cs = cs.ns(|| format!("hash_{}", i));
+            let b = cs.alloc(|| "b", || Ok(Fr::one())).unwrap();
+            {
+                let mut cs = cs.ns(|| "inner");
+                cs.enforce(|| "b * b = b", |lc| lc + b, |lc| lc + b, |lc| lc + b);
+            }
+            cs.enforce(|| "a * b = a + b", |lc| lc + a, |lc| lc + b, |lc| lc + a + b);
+        }
+        let mut cs = cs.ns(|| "hash_0");
+        cs.enforce(|| "a * a = a", |lc| lc + a, |lc| lc + a, |lc| lc + a);
+    }
+
+    #[test]
+    fn test_profile_tree() {
+        let mut cs = ConstraintProfiler::new();
+        synthesize(&mut cs);
+
+        assert_eq!(ConstraintSystem::<Fr>::num_constraints(&cs), 5);
+
+        let profile = cs.profile();
+        assert_eq!(profile.name, "root");
+        assert_eq!(profile.children.len(), 2);
+        assert_eq!(profile.own, ProfileCounts {
+            num_constraints: 0,
+            num_public_variables: 1,
+            num_private_variables: 0,
+            num_nonzero_entries: 0,
+        });
+        assert_eq!(profile.total, ProfileCounts {
+            num_constraints: 5,
+            num_public_variables: 1,
+            num_private_variables: 2,
+            num_nonzero_entries: 17,
+        });
+
+        let hash_0 = profile.find("hash_0").unwrap();
+        assert_eq!(hash_0.own.num_constraints, 2);
+        assert_eq!(hash_0.total.num_constraints, 3);
+        assert_eq!(hash_0.total.num_nonzero_entries, 10);
+
+        let inner = profile.find("hash_1/inner").unwrap();
+        assert_eq!(inner.own.num_constraints, 1);
+        assert_eq!(inner.own.num_nonzero_entries, 3);
+        assert!(profile.find("hash_2").is_none());
+    }
+
+    #[test]
+    fn test_folded_stacks() {
+        let mut cs = ConstraintProfiler::new();
+        synthesize(&mut cs);
+
+        let expected = "root;hash_0 2\nroot;hash_0;inner 1\nroot;hash_1 1\nroot;hash_1;inner 1\n";
+        assert_eq!(cs.profile().to_folded_stacks(ProfileMetric::Constraints), expected);
+
+        let expected = "root 1\nroot;hash_0 1\nroot;hash_1 1\n";
+        assert_eq!(cs.profile().to_folded_stacks(ProfileMetric::Variables), expected);
+    }
+
+    #[test]
+    fn test_json() {
+        let mut cs = ConstraintProfiler::new();
+        {
+            let mut cs = cs.ns(|| "quote\"d");
+            ConstraintSystem::<Fr>::enforce(&mut cs, || "c", |lc| lc, |lc| lc, |lc| lc);
+        }
+
+        let counts = "{\"constraints\":1,\"public_variables\":0,\"private_variables\":0,\"nonzero_entries\":0}";
+        let expected = format!(
+            "{{\"name\":\"root\",\"own\":{},\"total\":{},\"children\":[{{\"name\":\"quote\\\"d\",\"own\":{},\"total\":{},\"children\":[]}}]}}",
+            "{\"constraints\":0,\"public_variables\":0,\"private_variables\":0,\"nonzero_entries\":0}",
+            counts,
+            counts,
+            counts
+        );
+        assert_eq!(cs.profile().to_json(), expected);
+    }
+}
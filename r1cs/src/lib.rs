@@ -20,6 +20,9 @@ pub use assignment::*;
 mod constraint_counter;
 pub use constraint_counter::*;
 
+mod constraint_profiler;
+pub use constraint_profiler::*;
+
 mod constraint_system;
 pub use constraint_system::{ConstraintSynthesizer, ConstraintSystem};
 
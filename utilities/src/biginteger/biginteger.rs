@@ -15,8 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    biginteger::limbs,
     bititerator::{BitIteratorBE, BitIteratorLE},
     bytes::{FromBytes, ToBytes},
+    errors::BigIntegerError,
     io::{Read, Result as IoResult, Write},
     rand::UniformRand,
 };
@@ -25,7 +27,10 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::fmt::{Debug, Display};
+use std::{
+    fmt::{Debug, Display, LowerHex, UpperHex},
+    str::FromStr,
+};
 
 bigint_impl!(BigInteger64, 1);
 bigint_impl!(BigInteger128, 2);
@@ -46,6 +51,9 @@ pub trait BigInteger:
     + Debug
     + Default
     + Display
+    + LowerHex
+    + UpperHex
+    + FromStr<Err = BigIntegerError>
     + Eq
     + Ord
     + Send
@@ -63,6 +71,38 @@ pub trait BigInteger:
     /// Subtract another representation from this one, returning the borrow bit.
     fn sub_noborrow(&mut self, other: &Self) -> bool;
 
+    /// Multiply this representation by another, returning the low and high halves
+    /// of the full product.
+    fn mul_wide(&self, other: &Self) -> (Self, Self);
+
+    /// Multiply this representation by another, keeping the low half of the product
+    /// and returning `true` if the high half is nonzero.
+    fn mul_nocarry(&mut self, other: &Self) -> bool {
+        let (lo, hi) = self.mul_wide(other);
+        *self = lo;
+        !hi.is_zero()
+    }
+
+    /// Divides this number by `divisor`, returning the quotient and the remainder,
+    /// or `None` if `divisor` is zero.
+    fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)>;
+
+    /// Returns `self * other mod modulus`, or `None` if `modulus` is zero.
+    fn mul_mod(&self, other: &Self, modulus: &Self) -> Option<Self>;
+
+    /// Returns `self^exp mod modulus`, or `None` if `modulus` is zero.
+    fn pow_mod(&self, exp: &Self, modulus: &Self) -> Option<Self> {
+        let (_, base) = self.div_rem(modulus)?;
+        let (_, mut res) = Self::from(1).div_rem(modulus)?;
+        for bit in BitIteratorBE::new_without_leading_zeros(exp) {
+            res = res.mul_mod(&res, modulus)?;
+            if bit {
+                res = res.mul_mod(&base, modulus)?;
+            }
+        }
+        Some(res)
+    }
+
     /// Performs a leftwise bitshift of this number, effectively multiplying
     /// it by 2. Overflow is ignored.
     fn mul2(&mut self);
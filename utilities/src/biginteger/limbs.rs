@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Multi-precision arithmetic on little-endian `u64` limbs, shared by every `BigInteger` width.
+
+use crate::{
+    biginteger::arithmetic::{adc, mac_with_carry, sbb},
+    errors::BigIntegerError,
+};
+
+/// Returns the low and high halves of the full product `a * b`.
+pub(crate) fn mul_wide<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], [u64; N]) {
+    let mut lo = [0u64; N];
+    let mut hi = [0u64; N];
+
+    for (i, a_i) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, b_j) in b.iter().enumerate() {
+            let limb = if i + j < N { &mut lo[i + j] } else { &mut hi[i + j - N] };
+            *limb = mac_with_carry(*limb, *a_i, *b_j, &mut carry);
+        }
+        // Row `i` is the first to reach limb `i + N`, so the carry can be stored directly.
+        hi[i] = carry;
+    }
+
+    (lo, hi)
+}
+
+/// Returns the quotient and remainder of `numerator / divisor`, or `None` if `divisor` is zero.
+pub(crate) fn div_rem<const N: usize>(numerator: &[u64; N], divisor: &[u64; N]) -> Option<([u64; N], [u64; N])> {
+    let mut quotient = [0u64; N];
+    let mut remainder = [0u64; N];
+    div_rem_slices(numerator, divisor, &mut quotient, &mut remainder)?;
+    Some((quotient, remainder))
+}
+
+/// Returns `a * b mod modulus`, or `None` if `modulus` is zero.
+pub(crate) fn mul_mod<const N: usize>(a: &[u64; N], b: &[u64; N], modulus: &[u64; N]) -> Option<[u64; N]> {
+    let (lo, hi) = mul_wide(a, b);
+    let product = [lo, hi].concat();
+
+    let mut quotient = vec![0u64; 2 * N];
+    let mut remainder = [0u64; N];
+    div_rem_slices(&product, modulus, &mut quotient, &mut remainder)?;
+    Some(remainder)
+}
+
+/// Divides `numerator` by `divisor` using Knuth's Algorithm D (TAOCP Vol. 2, 4.3.1),
+/// writing the results into `quotient` and `remainder`, which must be at least as long
+/// as `numerator` and `divisor` respectively. Returns `None` if `divisor` is zero.
+fn div_rem_slices(numerator: &[u64], divisor: &[u64], quotient: &mut [u64], remainder: &mut [u64]) -> Option<()> {
+    let n = significant_limbs(divisor);
+    if n == 0 {
+        return None;
+    }
+    let len = significant_limbs(numerator);
+
+    quotient.iter_mut().for_each(|limb| *limb = 0);
+    remainder.iter_mut().for_each(|limb| *limb = 0);
+
+    if len < n {
+        remainder[..len].copy_from_slice(&numerator[..len]);
+        return Some(());
+    }
+
+    // Short division by a single limb.
+    if n == 1 {
+        let d = u128::from(divisor[0]);
+        let mut r = 0u128;
+        for (q, limb) in quotient[..len].iter_mut().zip(&numerator[..len]).rev() {
+            let current = (r << 64) | u128::from(*limb);
+            *q = (current / d) as u64;
+            r = current % d;
+        }
+        remainder[0] = r as u64;
+        return Some(());
+    }
+
+    // Normalize, so that the most significant limb of the divisor has its top bit set.
+    let shift = divisor[n - 1].leading_zeros();
+    let v = shl(&divisor[..n], shift, n);
+    let mut u = shl(&numerator[..len], shift, len + 1);
+
+    let v_top = u128::from(v[n - 1]);
+    let v_next = u128::from(v[n - 2]);
+
+    for j in (0..=(len - n)).rev() {
+        // Estimate the quotient limb from the top two limbs of the current remainder.
+        let top = (u128::from(u[j + n]) << 64) | u128::from(u[j + n - 1]);
+        let mut q_hat = top / v_top;
+        let mut r_hat = top % v_top;
+        while q_hat >> 64 != 0 || q_hat * v_next > ((r_hat << 64) | u128::from(u[j + n - 2])) {
+            q_hat -= 1;
+            r_hat += v_top;
+            if r_hat >> 64 != 0 {
+                break;
+            }
+        }
+
+        // Subtract `q_hat * v` from the current remainder.
+        let mut q = q_hat as u64;
+        let mut carry = 0;
+        let mut borrow = 0;
+        for (i, v_i) in v.iter().enumerate() {
+            let product = mac_with_carry(0, q, *v_i, &mut carry);
+            u[i + j] = sbb(u[i + j], product, &mut borrow);
+        }
+        u[j + n] = sbb(u[j + n], carry, &mut borrow);
+
+        // The estimate was one too large, so add the divisor back.
+        if borrow != 0 {
+            q -= 1;
+            let mut carry = 0;
+            for (i, v_i) in v.iter().enumerate() {
+                u[i + j] = adc(u[i + j], *v_i, &mut carry);
+            }
+            u[j + n] = u[j + n].wrapping_add(carry);
+        }
+
+        quotient[j] = q;
+    }
+
+    // Denormalize the remainder.
+    for (i, r) in remainder[..n].iter_mut().enumerate() {
+        *r = match shift {
+            0 => u[i],
+            _ => (u[i] >> shift) | (u[i + 1] << (64 - shift)),
+        };
+    }
+
+    Some(())
+}
+
+/// Returns the number of limbs up to and including the most significant nonzero limb.
+fn significant_limbs(limbs: &[u64]) -> usize {
+    limbs.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1)
+}
+
+/// Returns `limbs << shift` as `len` limbs, for `shift < 64`.
+fn shl(limbs: &[u64], shift: u32, len: usize) -> Vec<u64> {
+    let mut result = vec![0u64; len];
+    let mut carry = 0;
+    for (r, limb) in result.iter_mut().zip(limbs) {
+        *r = (limb << shift) | carry;
+        carry = match shift {
+            0 => 0,
+            _ => limb >> (64 - shift),
+        };
+    }
+    if let Some(r) = result.get_mut(limbs.len()) {
+        *r = carry;
+    }
+    result
+}
+
+/// Returns the decimal representation of `limbs`, without leading zeros.
+pub(crate) fn to_decimal(limbs: &[u64]) -> String {
+    // The largest power of ten that fits in a `u64`.
+    const TEN_POW_19: u128 = 10_000_000_000_000_000_000;
+
+    let mut value = limbs.to_vec();
+    let mut chunks = vec![];
+    while value.iter().any(|&limb| limb != 0) {
+        let mut r = 0u128;
+        for limb in value.iter_mut().rev() {
+            let current = (r << 64) | u128::from(*limb);
+            *limb = (current / TEN_POW_19) as u64;
+            r = current % TEN_POW_19;
+        }
+        chunks.push(r as u64);
+    }
+
+    match chunks.split_last() {
+        Some((first, rest)) => {
+            let mut decimal = first.to_string();
+            for chunk in rest.iter().rev() {
+                decimal.push_str(&format!("{:019}", chunk));
+            }
+            decimal
+        }
+        None => "0".to_string(),
+    }
+}
+
+/// Returns the hexadecimal representation of `limbs`, without leading zeros.
+pub(crate) fn to_hex(limbs: &[u64], uppercase: bool) -> String {
+    let mut hex = String::new();
+    for limb in limbs.iter().rev() {
+        match (hex.is_empty(), uppercase) {
+            (true, _) if *limb == 0 => continue,
+            (true, true) => hex.push_str(&format!("{:X}", limb)),
+            (true, false) => hex.push_str(&format!("{:x}", limb)),
+            (false, true) => hex.push_str(&format!("{:016X}", limb)),
+            (false, false) => hex.push_str(&format!("{:016x}", limb)),
+        }
+    }
+
+    match hex.is_empty() {
+        true => "0".to_string(),
+        false => hex,
+    }
+}
+
+/// Parses a decimal string, or a hexadecimal string prefixed with `0x`, into limbs.
+pub(crate) fn from_str<const N: usize>(s: &str) -> Result<[u64; N], BigIntegerError> {
+    let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(digits) => (digits, 16),
+        None => (s, 10),
+    };
+    if digits.is_empty() {
+        return Err(BigIntegerError::ParsingEmptyString);
+    }
+
+    let mut limbs = [0u64; N];
+    for c in digits.chars() {
+        let mut carry = u64::from(c.to_digit(radix).ok_or(BigIntegerError::ParsingNonDigitCharacter(c))?);
+        for limb in limbs.iter_mut() {
+            *limb = mac_with_carry(0, *limb, radix as u64, &mut carry);
+        }
+        if carry != 0 {
+            return Err(BigIntegerError::Overflow(64 * N));
+        }
+    }
+
+    Ok(limbs)
+}
@@ -48,6 +48,23 @@ macro_rules! bigint_impl {
                 borrow != 0
             }
 
+            #[inline]
+            fn mul_wide(&self, other: &Self) -> (Self, Self) {
+                let (lo, hi) = limbs::mul_wide(&self.0, &other.0);
+                (Self::new(lo), Self::new(hi))
+            }
+
+            #[inline]
+            fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+                let (quotient, remainder) = limbs::div_rem(&self.0, &divisor.0)?;
+                Some((Self::new(quotient), Self::new(remainder)))
+            }
+
+            #[inline]
+            fn mul_mod(&self, other: &Self, modulus: &Self) -> Option<Self> {
+                limbs::mul_mod(&self.0, &other.0, &modulus.0).map(Self::new)
+            }
+
             #[inline]
             fn mul2(&mut self) {
                 let mut last = 0;
@@ -239,28 +256,30 @@ macro_rules! bigint_impl {
         }
 
         impl Display for $name {
+            /// Writes this number in decimal.
             fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                let mut is_nonzero = false;
-                for (i, limb) in self.0.iter().rev().enumerate() {
-                    if *limb > 0 {
-                        is_nonzero = true;
-                    }
+                f.pad_integral(true, "", &limbs::to_decimal(&self.0))
+            }
+        }
 
-                    // Begin writing the limb, for its corresponding bits.
-                    if is_nonzero {
-                        let shift = (($num_limbs - (i + 1)) * 6) as u64;
-                        let shifter = 1 << shift;
-                        write!(f, "{}", shifter - 1 + *limb)?;
-                    }
-                }
+        impl LowerHex for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.pad_integral(true, "0x", &limbs::to_hex(&self.0, false))
+            }
+        }
 
-                // If the value is 0, then `is_nonzero` will still be `false` here,
-                // so proceed to write `0`.
-                if !is_nonzero {
-                    write!(f, "0")?;
-                }
+        impl UpperHex for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.pad_integral(true, "0x", &limbs::to_hex(&self.0, true))
+            }
+        }
 
-                Ok(())
+        impl FromStr for $name {
+            type Err = BigIntegerError;
+
+            /// Reads in a decimal string, or a hexadecimal string prefixed with `0x`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                limbs::from_str(s).map(Self::new)
             }
         }
 
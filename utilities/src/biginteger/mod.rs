@@ -22,5 +22,7 @@ mod macros;
 pub mod biginteger;
 pub use biginteger::*;
 
+mod limbs;
+
 #[cfg(test)]
 mod tests;
@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{biginteger::*, rand::UniformRand};
+use crate::{biginteger::*, errors::BigIntegerError, rand::UniformRand};
 
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
+use std::str::FromStr;
 
 #[allow(clippy::eq_op)]
 fn biginteger_arithmetic_test<B: BigInteger>(a: B, b: B, zero: B) {
@@ -77,6 +78,120 @@ fn biginteger_bytes_test<B: BigInteger>() {
     assert_eq!(x, y);
 }
 
+fn biginteger_mul_div_test<B: BigInteger>(a: B, b: B) {
+    let zero = B::from(0);
+    let one = B::from(1);
+
+    // a * b = b * a
+    assert_eq!(a.mul_wide(&b), b.mul_wide(&a));
+    // a * 0 = 0
+    assert_eq!(a.mul_wide(&zero), (zero, zero));
+    // a * 1 = a
+    assert_eq!(a.mul_wide(&one), (a, zero));
+
+    // a * 2 = a + a
+    let (lo, hi) = a.mul_wide(&B::from(2));
+    let mut a_double = a;
+    let carry = a_double.add_nocarry(&a);
+    assert_eq!(lo, a_double);
+    assert_eq!(hi, B::from(carry as u64));
+
+    // a / 0 is undefined
+    assert!(a.div_rem(&zero).is_none());
+    // a / 1 = a
+    assert_eq!(a.div_rem(&one), Some((a, zero)));
+    // a / a = 1
+    assert_eq!(a.div_rem(&a), Some((one, zero)));
+
+    // Check that a = q * d + r and r < d, for divisors of every length.
+    let num_limbs = a.as_ref().len() as u32;
+    for shift in (0..num_limbs * 64).step_by(37) {
+        let mut divisor = b;
+        divisor.divn(shift);
+        if divisor.is_zero() {
+            continue;
+        }
+
+        let (quotient, remainder) = a.div_rem(&divisor).unwrap();
+        assert!(remainder < divisor);
+
+        let (mut product, hi) = quotient.mul_wide(&divisor);
+        assert!(hi.is_zero());
+        assert!(!product.add_nocarry(&remainder));
+        assert_eq!(product, a);
+    }
+
+    // (a * b) mod b = 0
+    assert_eq!(a.mul_mod(&b, &b), Some(zero));
+    // (a * 1) mod b = a mod b
+    assert_eq!(a.mul_mod(&one, &b), a.div_rem(&b).map(|(_, r)| r));
+}
+
+fn biginteger_pow_mod_test<B: BigInteger>(a: B, e1: B, e2: B, mut modulus: B) {
+    let zero = B::from(0);
+    let one = B::from(1);
+    modulus.as_mut()[0] |= 1;
+
+    let a_mod = a.div_rem(&modulus).unwrap().1;
+
+    // a^0 = 1
+    assert_eq!(a.pow_mod(&zero, &modulus), Some(one));
+    // a^1 = a
+    assert_eq!(a.pow_mod(&one, &modulus), Some(a_mod));
+    // a^2 = a * a
+    assert_eq!(a.pow_mod(&B::from(2), &modulus), a.mul_mod(&a, &modulus));
+    // a^e mod 1 = 0
+    assert_eq!(a.pow_mod(&e1, &one), Some(zero));
+    // a^e mod 0 is undefined
+    assert!(a.pow_mod(&e1, &zero).is_none());
+
+    // a^(e1 + e2) = a^e1 * a^e2
+    let (mut e1, mut e2) = (e1, e2);
+    e1.divn(1);
+    e2.divn(1);
+    let mut e_sum = e1;
+    e_sum.add_nocarry(&e2);
+    let lhs = a.pow_mod(&e_sum, &modulus).unwrap();
+    let rhs = a
+        .pow_mod(&e1, &modulus)
+        .unwrap()
+        .mul_mod(&a.pow_mod(&e2, &modulus).unwrap(), &modulus);
+    assert_eq!(Some(lhs), rhs);
+}
+
+fn biginteger_string_test<B: BigInteger>(a: B) {
+    let zero = B::from(0);
+    assert_eq!(zero.to_string(), "0");
+    assert_eq!(format!("{:x}", zero), "0");
+    assert_eq!(B::from(1234567890u64).to_string(), "1234567890");
+    assert_eq!(format!("{:#x}", B::from(0xabcdefu64)), "0xabcdef");
+    assert_eq!(format!("{:X}", B::from(0xabcdefu64)), "ABCDEF");
+
+    // Check that decimal and hexadecimal strings round trip.
+    assert_eq!(B::from_str(&a.to_string()), Ok(a));
+    assert_eq!(B::from_str(&format!("{:#x}", a)), Ok(a));
+    assert_eq!(B::from_str(&format!("{:#X}", a)), Ok(a));
+
+    // Check that the largest value is parsed, and the next one overflows.
+    let mut max = zero;
+    max.sub_noborrow(&B::from(1));
+    let num_bits = max.num_bits() as usize;
+    assert_eq!(B::from_str(&max.to_string()), Ok(max));
+    assert_eq!(
+        B::from_str(&format!("{}0", max)),
+        Err(BigIntegerError::Overflow(num_bits))
+    );
+    assert_eq!(
+        B::from_str(&format!("{:#x}0", max)),
+        Err(BigIntegerError::Overflow(num_bits))
+    );
+
+    assert_eq!(B::from_str(""), Err(BigIntegerError::ParsingEmptyString));
+    assert_eq!(B::from_str("0x"), Err(BigIntegerError::ParsingEmptyString));
+    assert_eq!(B::from_str("12a"), Err(BigIntegerError::ParsingNonDigitCharacter('a')));
+    assert_eq!(B::from_str("-1"), Err(BigIntegerError::ParsingNonDigitCharacter('-')));
+}
+
 fn test_biginteger<B: BigInteger>(zero: B) {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
     let a: B = UniformRand::rand(&mut rng);
@@ -84,6 +199,17 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_arithmetic_test(a, b, zero);
     biginteger_bytes_test::<B>();
     biginteger_bits_test::<B>();
+    biginteger_mul_div_test(a, b);
+    biginteger_string_test(a);
+
+    for _ in 0..10 {
+        let a: B = UniformRand::rand(&mut rng);
+        let e1: B = UniformRand::rand(&mut rng);
+        let e2: B = UniformRand::rand(&mut rng);
+        let modulus: B = UniformRand::rand(&mut rng);
+        biginteger_mul_div_test(a, modulus);
+        biginteger_pow_mod_test(a, e1, e2, modulus);
+    }
 }
 
 #[test]
@@ -115,3 +241,54 @@ fn test_biginteger768() {
 fn test_biginteger832() {
     test_biginteger(BigInteger832::new([0u64; 13]));
 }
+
+#[test]
+fn test_biginteger256_modulus() {
+    // The scalar field modulus of BLS12-377.
+    let modulus = BigInteger256::new([
+        725501752471715841u64,
+        6461107452199829505u64,
+        6968279316240510977u64,
+        1345280370688173398u64,
+    ]);
+    let decimal = "8444461749428370424248824938781546531375899335154063827935233455917409239041";
+    let hex = "0x12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001";
+
+    assert_eq!(modulus.to_string(), decimal);
+    assert_eq!(format!("{:#x}", modulus), hex);
+    assert_eq!(BigInteger256::from_str(decimal), Ok(modulus));
+    assert_eq!(BigInteger256::from_str(hex), Ok(modulus));
+
+    // Fermat's little theorem: a^(p - 1) = 1 (mod p).
+    let mut p_minus_one = modulus;
+    p_minus_one.sub_noborrow(&BigInteger256::from(1));
+    assert_eq!(
+        BigInteger256::from(7).pow_mod(&p_minus_one, &modulus),
+        Some(BigInteger256::from(1))
+    );
+}
+
+#[test]
+fn test_biginteger384_modulus() {
+    // The base field modulus of BLS12-377.
+    let modulus = BigInteger384::new([
+        0x8508c00000000001,
+        0x170b5d4430000000,
+        0x1ef3622fba094800,
+        0x1a22d9f300f5138f,
+        0xc63b05c06ca1493b,
+        0x1ae3a4617c510ea,
+    ]);
+    let decimal = "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177";
+
+    assert_eq!(modulus.to_string(), decimal);
+    assert_eq!(BigInteger384::from_str(decimal), Ok(modulus));
+
+    // Fermat's little theorem: a^(p - 1) = 1 (mod p).
+    let mut p_minus_one = modulus;
+    p_minus_one.sub_noborrow(&BigInteger384::from(1));
+    assert_eq!(
+        BigInteger384::from(15).pow_mod(&p_minus_one, &modulus),
+        Some(BigInteger384::from(1))
+    );
+}
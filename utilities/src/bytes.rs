@@ -28,6 +28,13 @@ pub trait ToBytes {
 pub trait FromBytes: Sized {
     /// Reads `Self` from `reader`.
     fn read<R: Read>(reader: R) -> IoResult<Self>;
+
+    /// Reads `Self` from `reader` without performing validity checks, such as the subgroup checks on curve points.
+    /// This must only be used on data that comes from a trusted source.
+    #[inline]
+    fn read_unchecked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read(reader)
+    }
 }
 
 impl<const N: usize> ToBytes for [u8; N] {
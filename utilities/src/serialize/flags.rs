@@ -15,13 +15,19 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub trait Flags: Default + Clone + Copy + Sized {
+    /// Number of bits required for these flags.
+    const BIT_SIZE: usize;
+
     fn u8_bitmask(&self) -> u8;
 
     fn from_u8(value: u8) -> Self;
 
     fn from_u8_remove_flags(value: &mut u8) -> Self;
 
-    fn len() -> usize;
+    #[inline]
+    fn len() -> usize {
+        Self::BIT_SIZE
+    }
 }
 
 /// Flags to be encoded into the serialization.
@@ -29,6 +35,8 @@ pub trait Flags: Default + Clone + Copy + Sized {
 pub struct EmptyFlags;
 
 impl Flags for EmptyFlags {
+    const BIT_SIZE: usize = 0;
+
     #[inline]
     fn u8_bitmask(&self) -> u8 {
         0
@@ -43,11 +51,6 @@ impl Flags for EmptyFlags {
     fn from_u8_remove_flags(_value: &mut u8) -> Self {
         EmptyFlags
     }
-
-    #[inline]
-    fn len() -> usize {
-        0
-    }
 }
 
 /// Flags to be encoded into the serialization.
@@ -98,6 +101,8 @@ impl Default for SWFlags {
 }
 
 impl Flags for SWFlags {
+    const BIT_SIZE: usize = 2;
+
     #[inline]
     fn u8_bitmask(&self) -> u8 {
         let mut mask = 0;
@@ -126,12 +131,6 @@ impl Flags for SWFlags {
         *value &= 0x3F;
         flags
     }
-
-    /// Number of bits required for these flags.
-    #[inline]
-    fn len() -> usize {
-        2
-    }
 }
 
 /// Flags to be encoded into the serialization.
@@ -170,6 +169,8 @@ impl Default for EdwardsFlags {
 }
 
 impl Flags for EdwardsFlags {
+    const BIT_SIZE: usize = 1;
+
     #[inline]
     fn u8_bitmask(&self) -> u8 {
         let mut mask = 0;
@@ -196,10 +197,4 @@ impl Flags for EdwardsFlags {
         *value &= 0x7F;
         flags
     }
-
-    /// Number of bits required for these flags.
-    #[inline]
-    fn len() -> usize {
-        1
-    }
 }
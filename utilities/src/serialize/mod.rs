@@ -32,10 +32,32 @@ pub use flags::*;
 #[cfg(feature = "derive")]
 pub use snarkvm_derives::*;
 
+/// Whether to use the compressed encoding of a value.
+/// Compressed curve points only store the x-coordinate and the sign of y,
+/// while uncompressed points store both coordinates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Compress {
+    Yes,
+    No,
+}
+
+/// Whether to validate a value after deserializing it.
+/// Skipping validation, such as the curve and subgroup checks on curve points,
+/// is only sound for data that comes from a trusted source. Field elements are
+/// always checked to be in canonical form.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Validate {
+    Yes,
+    No,
+}
+
 /// Serializer in little endian format allowing to encode flags.
 pub trait CanonicalSerializeWithFlags: CanonicalSerialize {
     /// Serializes `self` and `flags` into `writer`.
     fn serialize_with_flags<W: Write, F: Flags>(&self, writer: &mut W, flags: F) -> Result<(), SerializationError>;
+
+    /// Returns the size in bytes of `self` when serialized with flags of type `F`.
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize;
 }
 
 /// Helper trait to get serialized size for constant sized structs.
@@ -44,6 +66,18 @@ pub trait ConstantSerializedSize: CanonicalSerialize {
     const UNCOMPRESSED_SIZE: usize;
 }
 
+/// Helper trait to get serialized size with flags for constant sized structs.
+pub trait ConstantSerializedSizeWithFlags: ConstantSerializedSize + CanonicalSerializeWithFlags {
+    /// The number of bits needed to serialize `Self`, before padding to a whole number of bytes.
+    const SERIALIZED_SIZE_IN_BITS: usize;
+}
+
+/// Returns the size in bytes of a `T` serialized with flags of type `F`,
+/// which equals `serialized_size_with_flags::<F>()` for any `T`.
+pub const fn serialized_size_with_flags<T: ConstantSerializedSizeWithFlags, F: Flags>() -> usize {
+    buffer_byte_size(T::SERIALIZED_SIZE_IN_BITS + F::BIT_SIZE)
+}
+
 /// Serializer in little endian format.
 /// This trait can be derived if all fields of a struct implement
 /// `CanonicalSerialize` and the `derive` feature is enabled.
@@ -78,6 +112,24 @@ pub trait CanonicalSerialize {
     fn uncompressed_size(&self) -> usize {
         self.serialized_size()
     }
+
+    /// Serializes `self` into `writer` using the given compression mode.
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, writer: &mut W, compress: Compress) -> Result<(), SerializationError> {
+        match compress {
+            Compress::Yes => self.serialize(writer),
+            Compress::No => self.serialize_uncompressed(writer),
+        }
+    }
+
+    /// Returns the size in bytes of `self` when serialized using the given compression mode.
+    #[inline]
+    fn serialized_size_with_mode(&self, compress: Compress) -> usize {
+        match compress {
+            Compress::Yes => self.serialized_size(),
+            Compress::No => self.uncompressed_size(),
+        }
+    }
 }
 
 /// Deserializer in little endian format allowing flags to be encoded.
@@ -116,6 +168,35 @@ pub trait CanonicalDeserialize: Sized {
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Self::deserialize(reader)
     }
+
+    /// Reads `Self` from `reader` without performing validity checks.
+    /// This must only be used on data that comes from a trusted source.
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Self::deserialize(reader)
+    }
+
+    /// Reads `Self` from `reader` without compression and without performing validity checks.
+    /// This must only be used on data that comes from a trusted source.
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Self::deserialize_uncompressed(reader)
+    }
+
+    /// Reads `Self` from `reader` using the given compression and validation modes.
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        reader: &mut R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        match (compress, validate) {
+            (Compress::Yes, Validate::Yes) => Self::deserialize(reader),
+            (Compress::Yes, Validate::No) => Self::deserialize_unchecked(reader),
+            (Compress::No, Validate::Yes) => Self::deserialize_uncompressed(reader),
+            (Compress::No, Validate::No) => Self::deserialize_uncompressed_unchecked(reader),
+        }
+    }
 }
 
 impl CanonicalSerialize for bool {
@@ -205,11 +286,12 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
     #[inline]
     fn serialized_size(&self) -> usize {
-        8 + if let Some(item) = self {
-            item.serialized_size()
-        } else {
-            0
-        }
+        self.is_some().serialized_size()
+            + if let Some(item) = self {
+                item.serialized_size()
+            } else {
+                0
+            }
     }
 
     #[inline]
@@ -221,6 +303,16 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
         Ok(())
     }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.is_some().uncompressed_size()
+            + if let Some(item) = self {
+                item.uncompressed_size()
+            } else {
+                0
+            }
+    }
 }
 
 // No-op
@@ -251,6 +343,16 @@ impl<T> CanonicalDeserialize for std::marker::PhantomData<T> {
     fn deserialize_uncompressed<R: Read>(_reader: &mut R) -> Result<Self, SerializationError> {
         Ok(std::marker::PhantomData)
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(_reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(std::marker::PhantomData)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(_reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(std::marker::PhantomData)
+    }
 }
 
 impl<T: CanonicalSerialize + ToOwned> CanonicalSerialize for Rc<T> {
@@ -268,6 +370,11 @@ impl<T: CanonicalSerialize + ToOwned> CanonicalSerialize for Rc<T> {
     fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
         self.as_ref().serialize_uncompressed(writer)
     }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.as_ref().uncompressed_size()
+    }
 }
 
 impl<T: CanonicalDeserialize + ToOwned> CanonicalDeserialize for Rc<T> {
@@ -280,6 +387,16 @@ impl<T: CanonicalDeserialize + ToOwned> CanonicalDeserialize for Rc<T> {
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Ok(Rc::new(T::deserialize_uncompressed(reader)?))
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Rc::new(T::deserialize_unchecked(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Rc::new(T::deserialize_uncompressed_unchecked(reader)?))
+    }
 }
 
 impl<T: CanonicalSerialize + ToOwned> CanonicalSerialize for Arc<T> {
@@ -297,6 +414,11 @@ impl<T: CanonicalSerialize + ToOwned> CanonicalSerialize for Arc<T> {
     fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
         self.as_ref().serialize_uncompressed(writer)
     }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.as_ref().uncompressed_size()
+    }
 }
 
 impl<T: CanonicalDeserialize + ToOwned> CanonicalDeserialize for Arc<T> {
@@ -309,6 +431,16 @@ impl<T: CanonicalDeserialize + ToOwned> CanonicalDeserialize for Arc<T> {
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Ok(Arc::new(T::deserialize_uncompressed(reader)?))
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Arc::new(T::deserialize_unchecked(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Arc::new(T::deserialize_uncompressed_unchecked(reader)?))
+    }
 }
 
 impl<'a, T: CanonicalSerialize + ToOwned> CanonicalSerialize for Cow<'a, T> {
@@ -326,6 +458,11 @@ impl<'a, T: CanonicalSerialize + ToOwned> CanonicalSerialize for Cow<'a, T> {
     fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
         self.as_ref().serialize_uncompressed(writer)
     }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.as_ref().uncompressed_size()
+    }
 }
 
 impl<'a, T> CanonicalDeserialize for Cow<'a, T>
@@ -342,6 +479,18 @@ where
     fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
         Ok(Cow::Owned(<T as ToOwned>::Owned::deserialize_uncompressed(reader)?))
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Cow::Owned(<T as ToOwned>::Owned::deserialize_unchecked(reader)?))
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        Ok(Cow::Owned(<T as ToOwned>::Owned::deserialize_uncompressed_unchecked(
+            reader,
+        )?))
+    }
 }
 
 impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
@@ -364,6 +513,30 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
 
         Ok(data)
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let is_some = bool::deserialize(reader)?;
+        let data = if is_some {
+            Some(T::deserialize_unchecked(reader)?)
+        } else {
+            None
+        };
+
+        Ok(data)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let is_some = bool::deserialize(reader)?;
+        let data = if is_some {
+            Some(T::deserialize_uncompressed_unchecked(reader)?)
+        } else {
+            None
+        };
+
+        Ok(data)
+    }
 }
 
 impl<T: CanonicalSerialize> CanonicalSerialize for Vec<T> {
@@ -418,6 +591,26 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
         }
         Ok(values)
     }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = u64::deserialize(reader)?;
+        let mut values = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            values.push(T::deserialize_unchecked(reader)?);
+        }
+        Ok(values)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = u64::deserialize(reader)?;
+        let mut values = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            values.push(T::deserialize_uncompressed_unchecked(reader)?);
+        }
+        Ok(values)
+    }
 }
 
 impl<T: CanonicalSerialize> CanonicalSerialize for [T] {
@@ -533,6 +726,20 @@ macro_rules! impl_tuple {
                     $ty::deserialize_uncompressed(reader)?,
                 )+))
             }
+
+            #[inline]
+            fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+                Ok(($(
+                    $ty::deserialize_unchecked(reader)?,
+                )+))
+            }
+
+            #[inline]
+            fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+                Ok(($(
+                    $ty::deserialize_uncompressed_unchecked(reader)?,
+                )+))
+            }
         }
     }
 }
@@ -557,10 +764,21 @@ mod test {
     use super::*;
 
     fn test_serialize<T: PartialEq + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(data: T) {
-        let mut serialized = vec![0; data.serialized_size()];
-        data.serialize(&mut &mut serialized[..]).unwrap();
+        let mut serialized = vec![];
+        data.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), data.serialized_size());
         let de = T::deserialize(&mut &serialized[..]).unwrap();
         assert_eq!(data, de);
+
+        for compress in [Compress::Yes, Compress::No] {
+            for validate in [Validate::Yes, Validate::No] {
+                let mut serialized = vec![];
+                data.serialize_with_mode(&mut serialized, compress).unwrap();
+                assert_eq!(serialized.len(), data.serialized_size_with_mode(compress));
+                let de = T::deserialize_with_mode(&mut &serialized[..], compress, validate).unwrap();
+                assert_eq!(data, de);
+            }
+        }
     }
 
     #[test]
@@ -613,6 +831,15 @@ mod test {
     fn test_phantomdata() {
         test_serialize(std::marker::PhantomData::<u64>);
     }
+
+    #[test]
+    fn test_btreemap() {
+        let mut map = BTreeMap::new();
+        map.insert(0u64, (1u32, Some(2u16)));
+        map.insert(3u64, (4u32, None));
+        test_serialize(map);
+        test_serialize(BTreeMap::<u64, u64>::new());
+    }
 }
 
 impl<K, V> CanonicalSerialize for BTreeMap<K, V>
@@ -636,6 +863,23 @@ where
             .map(|(k, v)| k.serialized_size() + v.serialized_size())
             .sum::<usize>()
     }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: &mut W) -> Result<(), SerializationError> {
+        let len = self.len() as u64;
+        len.serialize(writer)?;
+        for (k, v) in self.iter() {
+            k.serialize_uncompressed(writer)?;
+            v.serialize_uncompressed(writer)?;
+        }
+        Ok(())
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        8 + self
+            .iter()
+            .map(|(k, v)| k.uncompressed_size() + v.uncompressed_size())
+            .sum::<usize>()
+    }
 }
 
 impl<K, V> CanonicalDeserialize for BTreeMap<K, V>
@@ -651,4 +895,37 @@ where
         }
         Ok(map)
     }
+
+    fn deserialize_uncompressed<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = u64::deserialize(reader)?;
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            map.insert(
+                K::deserialize_uncompressed(reader)?,
+                V::deserialize_uncompressed(reader)?,
+            );
+        }
+        Ok(map)
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = u64::deserialize(reader)?;
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            map.insert(K::deserialize_unchecked(reader)?, V::deserialize_unchecked(reader)?);
+        }
+        Ok(map)
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: &mut R) -> Result<Self, SerializationError> {
+        let len = u64::deserialize(reader)?;
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            map.insert(
+                K::deserialize_uncompressed_unchecked(reader)?,
+                V::deserialize_uncompressed_unchecked(reader)?,
+            );
+        }
+        Ok(map)
+    }
 }